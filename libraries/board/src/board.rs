@@ -1,7 +1,17 @@
+mod compact;
+mod geometry;
+mod invariants;
+mod layout;
+mod render;
+mod svg;
+mod zobrist;
+
 mod board {
 
+    use crate::geometry::BoardGeometry;
+    use crate::zobrist;
+    use errors::LudoError;
     use pieces::{Color, Piece};
-    use std::{cell::RefCell, rc::Rc};
 
     #[derive(Clone, Debug, PartialEq)]
     pub enum PlayerID {
@@ -9,21 +19,41 @@ mod board {
         Player1,
         Player2,
         Player3,
+        Player4,
+        Player5,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct PieceRef {
+        pub player_id: i8,
+        pub piece_id: i8,
+    }
+
+    impl PieceRef {
+        pub fn new(player_id: i8, piece_id: i8) -> PieceRef {
+            PieceRef {
+                player_id,
+                piece_id,
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct PieceSlot {
+        pub piece: PieceRef,
+        pub state: Piece,
+        pub index: usize,
     }
 
     #[derive(Clone, Debug, PartialEq)]
     pub struct BoardState {
         pub position: i8,
-        pub pieces: Vec<Rc<RefCell<Piece>>>,
+        pub pieces: Vec<PieceRef>,
         pub player_id: Option<PlayerID>,
     }
 
     impl BoardState {
-        pub fn new(
-            position: i8,
-            pieces: Vec<Rc<RefCell<Piece>>>,
-            player_id: Option<PlayerID>,
-        ) -> BoardState {
+        pub fn new(position: i8, pieces: Vec<PieceRef>, player_id: Option<PlayerID>) -> BoardState {
             BoardState {
                 position,
                 pieces,
@@ -31,12 +61,7 @@ mod board {
             }
         }
 
-        pub fn set(
-            &mut self,
-            position: i8,
-            pieces: Vec<Rc<RefCell<Piece>>>,
-            player_id: Option<PlayerID>,
-        ) {
+        pub fn set(&mut self, position: i8, pieces: Vec<PieceRef>, player_id: Option<PlayerID>) {
             self.position = position;
             self.pieces = pieces;
             self.player_id = player_id;
@@ -46,13 +71,26 @@ mod board {
             self
         }
 
-        pub fn piece(&mut self, piece_id: i8) -> Rc<RefCell<Piece>> {
+        pub fn piece(&self, piece_id: i8) -> PieceRef {
             let piece_idx = self
                 .pieces
                 .iter()
-                .position(|piece| piece.borrow_mut().id() == piece_id)
+                .position(|piece| piece.piece_id == piece_id)
                 .unwrap();
-            self.pieces[piece_idx].clone()
+            self.pieces[piece_idx]
+        }
+    }
+
+    impl PlayerID {
+        pub fn id(&self) -> i8 {
+            match self {
+                PlayerID::Player0 => 0,
+                PlayerID::Player1 => 1,
+                PlayerID::Player2 => 2,
+                PlayerID::Player3 => 3,
+                PlayerID::Player4 => 4,
+                PlayerID::Player5 => 5,
+            }
         }
     }
 
@@ -64,26 +102,41 @@ mod board {
 
     #[derive(Clone, Debug, PartialEq)]
     pub struct Board {
-        pub home: [BoardState; 4],
-        pub goal: [BoardState; 4],
-        pub outside: [BoardState; 52],
-        pub inside: [BoardState; 20],
-        pub globe: [usize; 4],
-        pub invincible: [usize; 4],
-        pub star: [usize; 8],
-        all_pieces: Vec<Rc<RefCell<Piece>>>,
+        pub home: Vec<BoardState>,
+        pub goal: Vec<BoardState>,
+        pub outside: Vec<BoardState>,
+        pub inside: Vec<BoardState>,
+        pub globe: Vec<usize>,
+        pub invincible: Vec<usize>,
+        pub star: Vec<usize>,
+        geometry: BoardGeometry,
+        pieces: Vec<[Piece; 4]>,
+        hash: u64,
+        side_to_move: i8,
+        seats: Vec<i8>,
+        roll: i8,
     }
 
     impl Board {
         pub fn new() -> Self {
-            let (home, pieces) = Self::initialize_home();
-            let goal = Self::initialize_goal();
-            let mut outside = Self::initialize_outside();
-            let inside = Self::initialize_inside();
-            let globe = Self::initialize_globe(&mut outside);
-            let invincible = Self::initialize_invincible(&mut outside);
-            let star = Self::initialize_star(&mut outside);
-            Self {
+            Self::build(BoardGeometry::standard())
+        }
+
+        pub fn with_geometry(geometry: BoardGeometry) -> Result<Self, LudoError> {
+            geometry.validate()?;
+            Ok(Self::build(geometry))
+        }
+
+        fn build(geometry: BoardGeometry) -> Self {
+            let (home, pieces) = Self::initialize_home(&geometry);
+            let goal = Self::initialize_goal(&geometry);
+            let mut outside = Self::initialize_outside(&geometry);
+            let inside = Self::initialize_inside(&geometry);
+            let globe = Self::initialize_globe(&mut outside, &geometry);
+            let invincible = Self::initialize_invincible(&mut outside, &geometry);
+            let star = Self::initialize_star(&mut outside, &geometry);
+            let seats = (0..geometry.players).collect();
+            let mut board = Self {
                 home,
                 goal,
                 outside,
@@ -91,96 +144,255 @@ mod board {
                 globe,
                 invincible,
                 star,
-                all_pieces: pieces,
+                geometry,
+                pieces,
+                hash: 0,
+                side_to_move: 0,
+                seats,
+                roll: 0,
+            };
+            board.hash = board.compute_zobrist();
+            board
+        }
+
+        pub fn geometry(&self) -> &BoardGeometry {
+            &self.geometry
+        }
+
+        pub fn zobrist(&self) -> u64 {
+            self.hash
+        }
+
+        pub fn compute_zobrist(&self) -> u64 {
+            self.home
+                .iter()
+                .chain(self.outside.iter())
+                .chain(self.inside.iter())
+                .chain(self.goal.iter())
+                .flat_map(|square| {
+                    square.pieces.iter().map(|piece| {
+                        zobrist::piece_key(piece.player_id, piece.piece_id, square.position)
+                    })
+                })
+                .fold(zobrist::turn_key(self.side_to_move), |hash, key| hash ^ key)
+        }
+
+        pub fn side_to_move(&self) -> i8 {
+            self.side_to_move
+        }
+
+        pub fn set_side_to_move(&mut self, player_id: i8) {
+            self.hash ^= zobrist::turn_key(self.side_to_move) ^ zobrist::turn_key(player_id);
+            self.side_to_move = player_id;
+        }
+
+        pub fn seats(&self) -> &[i8] {
+            &self.seats
+        }
+
+        pub fn set_seats(&mut self, player_ids: &[i8]) -> Result<(), LudoError> {
+            for &player_id in player_ids {
+                self.check_player(player_id)?;
+            }
+            self.seats = player_ids.to_vec();
+            Ok(())
+        }
+
+        pub fn is_seated(&self, player_id: i8) -> bool {
+            self.seats.contains(&player_id)
+        }
+
+        pub fn roll(&self) -> i8 {
+            self.roll
+        }
+
+        pub fn set_roll(&mut self, roll: i8) {
+            self.roll = roll;
+        }
+
+        fn rehash(&mut self, piece: PieceRef, old_position: i8, new_position: i8) {
+            self.hash ^= zobrist::piece_key(piece.player_id, piece.piece_id, old_position)
+                ^ zobrist::piece_key(piece.player_id, piece.piece_id, new_position);
+        }
+
+        pub fn from_positions(positions: [[i8; 4]; 4]) -> Result<Self, LudoError> {
+            Self::from_positions_with_geometry(BoardGeometry::standard(), &positions)
+        }
+
+        pub fn from_positions_with_geometry(
+            geometry: BoardGeometry,
+            positions: &[[i8; 4]],
+        ) -> Result<Self, LudoError> {
+            let mut board = Self::with_geometry(geometry)?;
+            for (player_id, pieces) in positions.iter().enumerate() {
+                for (piece_id, &position) in pieces.iter().enumerate() {
+                    board.place_piece(player_id as i8, piece_id as i8, position)?;
+                }
+            }
+            Ok(board)
+        }
+
+        fn place_piece(
+            &mut self,
+            player_id: i8,
+            piece_id: i8,
+            position: i8,
+        ) -> Result<(), LudoError> {
+            if position == -1 {
+                return Ok(());
+            }
+            let is_track = self.geometry.is_track(position);
+            let is_inside = self.geometry.is_inside(position);
+            let is_goal = position == self.geometry.goal_position();
+            if !(is_track || is_inside || is_goal) {
+                return Err(LudoError::InvalidPosition(position));
+            }
+            let (piece, piece_idx) = self.get_home_piece_and_index(player_id, piece_id)?;
+            self.remove_piece_from_home_position(player_id, piece_idx);
+            self.rehash(piece, -1, position);
+            if is_goal {
+                self.piece_mut(player_id, piece_id).goal();
+                self.add_piece_to_goal_position(player_id, piece);
+            } else {
+                self.piece_mut(player_id, piece_id).free();
+                self.piece_mut(player_id, piece_id).set_position(position)?;
+                if is_track {
+                    self.add_piece_to_outside_position(position, player_id, piece);
+                } else {
+                    self.add_piece_to_inside_position(position, piece, player_id);
+                }
+            }
+            Ok(())
+        }
+
+        fn check_player(&self, player_id: i8) -> Result<(), LudoError> {
+            match player_id {
+                0.. if player_id < self.geometry.players => Ok(()),
+                _ => Err(LudoError::InvalidPlayer(player_id)),
+            }
+        }
+
+        fn check_outside(position: i8) -> Result<(), LudoError> {
+            match position {
+                0.. => Ok(()),
+                _ => Err(LudoError::InvalidPosition(position)),
+            }
+        }
+
+        fn check_inside(&self, position: i8) -> Result<(), LudoError> {
+            match self.geometry.is_inside(position) {
+                true => Ok(()),
+                false => Err(LudoError::InvalidPosition(position)),
             }
         }
 
         pub fn get_player_id(&self, id: i8) -> Option<PlayerID> {
+            if id >= self.geometry.players {
+                return None;
+            }
             match id {
                 0 => Some(PlayerID::Player0),
                 1 => Some(PlayerID::Player1),
                 2 => Some(PlayerID::Player2),
                 3 => Some(PlayerID::Player3),
+                4 => Some(PlayerID::Player4),
+                5 => Some(PlayerID::Player5),
                 _ => None,
             }
         }
 
-        fn initialize_home() -> ([BoardState; 4], Vec<Rc<RefCell<Piece>>>) {
+        fn initialize_home(geometry: &BoardGeometry) -> (Vec<BoardState>, Vec<[Piece; 4]>) {
             let mut pieces = vec![];
-            let mut home: [BoardState; 4] = [
-                BoardState::default(),
-                BoardState::default(),
-                BoardState::default(),
-                BoardState::default(),
-            ];
+            let mut home = vec![];
             let player_ids = [
                 PlayerID::Player0,
                 PlayerID::Player1,
                 PlayerID::Player2,
                 PlayerID::Player3,
+                PlayerID::Player4,
+                PlayerID::Player5,
             ];
 
-            let colors = [Color::Green, Color::Yellow, Color::Blue, Color::Red];
+            let colors = [
+                Color::Green,
+                Color::Yellow,
+                Color::Blue,
+                Color::Red,
+                Color::Orange,
+                Color::Purple,
+            ];
 
-            for ((position, player_id), color) in
-                home.iter_mut().zip(player_ids.iter()).zip(colors.iter())
+            for (player_id, color) in player_ids
+                .iter()
+                .zip(colors.iter())
+                .take(geometry.players as usize)
             {
-                position.set(-1, create_vector_of_pieces(*color), Some(player_id.clone()));
+                pieces.push(create_vector_of_pieces(*color, geometry));
+                home.push(BoardState::new(
+                    -1,
+                    create_vector_of_piece_refs(player_id.id()),
+                    Some(player_id.clone()),
+                ));
             }
-            pieces.append(&mut home[0].pieces.clone());
-            pieces.append(&mut home[1].pieces.clone());
-            pieces.append(&mut home[2].pieces.clone());
-            pieces.append(&mut home[3].pieces.clone());
             (home, pieces)
         }
 
-        fn initialize_goal() -> [BoardState; 4] {
-            [
-                BoardState::new(99, Vec::default(), None),
-                BoardState::new(99, Vec::default(), None),
-                BoardState::new(99, Vec::default(), None),
-                BoardState::new(99, Vec::default(), None),
-            ]
+        fn initialize_goal(geometry: &BoardGeometry) -> Vec<BoardState> {
+            (0..geometry.players)
+                .map(|_| BoardState::new(geometry.goal_position(), Vec::default(), None))
+                .collect()
         }
 
-        fn initialize_outside() -> [BoardState; 52] {
-            let mut outside: Vec<BoardState> = vec![];
-            (0..52).for_each(|position| {
-                outside.push(BoardState::new(position as i8, Vec::default(), None));
-            });
-            outside.try_into().unwrap()
+        fn initialize_outside(geometry: &BoardGeometry) -> Vec<BoardState> {
+            (0..geometry.track_length)
+                .map(|position| BoardState::new(position, Vec::default(), None))
+                .collect()
         }
 
-        fn initialize_inside() -> [BoardState; 20] {
-            let mut inside: Vec<BoardState> = vec![];
-            (0..20).for_each(|position| {
-                inside.push(BoardState::new((position + 52) as i8, Vec::default(), None));
-            });
-            inside.try_into().unwrap()
+        fn initialize_inside(geometry: &BoardGeometry) -> Vec<BoardState> {
+            (0..geometry.total_inside())
+                .map(|position| {
+                    BoardState::new(position + geometry.track_length, Vec::default(), None)
+                })
+                .collect()
         }
 
-        fn initialize_globe(outside: &mut [BoardState; 52]) -> [usize; 4] {
-            let globes = [8, 21, 34, 47];
-            for &position in globes.iter() {
-                outside[position].set(position as i8, Vec::default(), None);
-            }
-            globes
+        fn initialize_globe(outside: &mut [BoardState], geometry: &BoardGeometry) -> Vec<usize> {
+            Self::initialize_squares(outside, &geometry.globes)
         }
 
-        fn initialize_invincible(outside: &mut [BoardState; 52]) -> [usize; 4] {
-            let invincibles = [0, 13, 26, 39];
-            for &position in invincibles.iter() {
-                outside[position].set(position as i8, Vec::default(), None);
-            }
-            invincibles
+        fn initialize_invincible(
+            outside: &mut [BoardState],
+            geometry: &BoardGeometry,
+        ) -> Vec<usize> {
+            Self::initialize_squares(outside, &geometry.start_squares)
+        }
+
+        fn initialize_star(outside: &mut [BoardState], geometry: &BoardGeometry) -> Vec<usize> {
+            Self::initialize_squares(outside, &geometry.stars)
         }
 
-        fn initialize_star(outside: &mut [BoardState; 52]) -> [usize; 8] {
-            let stars = [5, 11, 18, 24, 31, 37, 44, 50];
-            for &position in stars.iter() {
-                outside[position].set(position as i8, Vec::default(), None);
+        fn initialize_squares(outside: &mut [BoardState], squares: &[i8]) -> Vec<usize> {
+            for &position in squares.iter() {
+                outside[position as usize].set(position, Vec::default(), None);
             }
-            stars
+            squares.iter().map(|&position| position as usize).collect()
+        }
+
+        pub fn piece(&self, player_id: i8, piece_id: i8) -> &Piece {
+            &self.pieces[player_id as usize][piece_id as usize]
+        }
+
+        pub fn piece_mut(&mut self, player_id: i8, piece_id: i8) -> &mut Piece {
+            &mut self.pieces[player_id as usize][piece_id as usize]
+        }
+
+        pub fn piece_at(&self, piece: PieceRef) -> &Piece {
+            self.piece(piece.player_id, piece.piece_id)
+        }
+
+        pub fn player_pieces(&self, player_id: i8) -> &[Piece; 4] {
+            &self.pieces[player_id as usize]
         }
 
         pub fn home(&mut self, player_id: i8) -> &mut BoardState {
@@ -192,12 +404,19 @@ mod board {
         }
 
         pub fn outside(&mut self, position: i8) -> &mut BoardState {
-            &mut self.outside[(position % 52) as usize]
+            let track_length = self.geometry.track_length;
+            &mut self.outside[(position % track_length) as usize]
         }
 
         pub fn inside(&mut self, position: i8) -> &mut BoardState {
-            &mut self.inside[(position - 52) as usize]
+            let track_length = self.geometry.track_length;
+            &mut self.inside[(position - track_length) as usize]
         }
+
+        pub fn square(&self, position: i8) -> &BoardState {
+            &self.outside[(position % self.geometry.track_length) as usize]
+        }
+
         pub fn invincible(&mut self, position: i8) -> &mut BoardState {
             &mut self.outside[self.invincible[position as usize]]
         }
@@ -224,42 +443,59 @@ mod board {
             self.star.contains(&(position as usize))
         }
 
-        pub fn move_from_home(&mut self, player_id: i8, piece_id: i8, new_position: i8) {
-            let (piece, piece_idx) = self.get_home_piece_and_index(player_id, piece_id);
+        pub fn move_from_home(
+            &mut self,
+            player_id: i8,
+            piece_id: i8,
+            new_position: i8,
+        ) -> Result<(), LudoError> {
+            Self::check_outside(new_position)?;
+            let (piece, piece_idx) = self.get_home_piece_and_index(player_id, piece_id)?;
             self.add_piece_to_outside_position(new_position, player_id, piece);
             self.remove_piece_from_home_position(player_id, piece_idx);
+            self.rehash(piece, -1, new_position);
+            Ok(())
         }
 
         fn add_piece_to_outside_position(
             &mut self,
             new_position: i8,
             player_id: i8,
-            piece: Rc<RefCell<Piece>>,
+            piece: PieceRef,
         ) {
+            let player_id = self.get_player_id(player_id);
             self.outside(new_position).pieces.push(piece);
-            self.outside(new_position).player_id = self.clone().get_player_id(player_id);
+            self.outside(new_position).player_id = player_id;
         }
 
         fn get_home_piece_and_index(
             &mut self,
             player_id: i8,
             piece_id: i8,
-        ) -> (Rc<RefCell<Piece>>, usize) {
-            let piece_idx = self.get_home_piece_index(player_id, piece_id);
+        ) -> Result<(PieceRef, usize), LudoError> {
+            self.check_player(player_id)?;
+            let piece_idx = self.get_home_piece_index(player_id, piece_id)?;
             let piece = self.get_home_piece(player_id, piece_idx);
-            (piece, piece_idx)
+            Ok((piece, piece_idx))
         }
 
-        fn get_home_piece(&mut self, player_id: i8, piece_idx: usize) -> Rc<RefCell<Piece>> {
-            self.home(player_id).pieces[piece_idx].clone()
+        fn get_home_piece(&mut self, player_id: i8, piece_idx: usize) -> PieceRef {
+            self.home(player_id).pieces[piece_idx]
         }
 
-        fn get_home_piece_index(&mut self, player_id: i8, piece_id: i8) -> usize {
+        fn get_home_piece_index(
+            &mut self,
+            player_id: i8,
+            piece_id: i8,
+        ) -> Result<usize, LudoError> {
             self.home(player_id)
                 .pieces
                 .iter()
-                .position(|piece| piece.borrow().id() == piece_id)
-                .unwrap()
+                .position(|piece| piece.piece_id == piece_id)
+                .ok_or(LudoError::PieceNotFound {
+                    piece_id,
+                    position: -1,
+                })
         }
 
         fn remove_piece_from_home_position(&mut self, player_id: i8, piece_idx: usize) {
@@ -279,41 +515,53 @@ mod board {
             }
         }
 
-        pub fn move_into_home(&mut self, player_id: i8, piece_id: i8, old_position: i8) {
-            let (piece, piece_idx) = self.get_outside_piece_and_index(old_position, piece_id);
+        pub fn move_into_home(
+            &mut self,
+            player_id: i8,
+            piece_id: i8,
+            old_position: i8,
+        ) -> Result<(), LudoError> {
+            self.check_player(player_id)?;
+            let (piece, piece_idx) = self.get_outside_piece_and_index(old_position, piece_id)?;
             self.add_piece_to_home_position(player_id, piece);
             self.remove_piece_from_outside_position(old_position, piece_idx);
+            self.rehash(piece, old_position, -1);
+            Ok(())
         }
 
-        fn add_piece_to_home_position(&mut self, player_id: i8, piece: Rc<RefCell<Piece>>) {
+        fn add_piece_to_home_position(&mut self, player_id: i8, piece: PieceRef) {
             self.home[player_id as usize].pieces.push(piece);
-            self.home[player_id as usize].player_id = self.clone().get_player_id(player_id);
+            self.home[player_id as usize].player_id = self.get_player_id(player_id);
         }
 
         pub fn get_outside_piece_and_index(
             &mut self,
             old_position: i8,
             piece_id: i8,
-        ) -> (Rc<RefCell<Piece>>, usize) {
-            let piece_idx = self.get_outside_piece_index(old_position, piece_id);
+        ) -> Result<(PieceRef, usize), LudoError> {
+            Self::check_outside(old_position)?;
+            let piece_idx = self.get_outside_piece_index(old_position, piece_id)?;
             let piece = self.get_outside_piece(old_position, piece_idx);
-            (piece, piece_idx)
+            Ok((piece, piece_idx))
         }
 
-        pub fn get_outside_piece(
-            &mut self,
-            old_position: i8,
-            piece_idx: usize,
-        ) -> Rc<RefCell<Piece>> {
-            self.outside(old_position).pieces[piece_idx].clone()
+        pub fn get_outside_piece(&mut self, old_position: i8, piece_idx: usize) -> PieceRef {
+            self.outside(old_position).pieces[piece_idx]
         }
 
-        fn get_outside_piece_index(&mut self, old_position: i8, piece_id: i8) -> usize {
+        fn get_outside_piece_index(
+            &mut self,
+            old_position: i8,
+            piece_id: i8,
+        ) -> Result<usize, LudoError> {
             self.outside(old_position)
                 .pieces
                 .iter()
-                .position(|piece| piece.borrow().id() == piece_id)
-                .unwrap()
+                .position(|piece| piece.piece_id == piece_id)
+                .ok_or(LudoError::PieceNotFound {
+                    piece_id,
+                    position: old_position,
+                })
         }
 
         pub fn update_outside(
@@ -322,10 +570,14 @@ mod board {
             piece_id: i8,
             old_position: i8,
             new_position: i8,
-        ) {
-            let (piece, piece_idx) = self.get_outside_piece_and_index(old_position, piece_id);
+        ) -> Result<(), LudoError> {
+            self.check_player(player_id)?;
+            Self::check_outside(new_position)?;
+            let (piece, piece_idx) = self.get_outside_piece_and_index(old_position, piece_id)?;
             self.remove_piece_from_outside_position(old_position, piece_idx);
             self.add_piece_to_outside_position(new_position, player_id, piece);
+            self.rehash(piece, old_position, new_position);
+            Ok(())
         }
 
         fn remove_piece_from_outside_position(&mut self, old_position: i8, piece_idx: usize) {
@@ -351,20 +603,25 @@ mod board {
             piece_id: i8,
             old_position: i8,
             new_position: i8,
-        ) {
-            let (piece, piece_idx) = self.get_outside_piece_and_index(old_position, piece_id);
+        ) -> Result<(), LudoError> {
+            self.check_player(player_id)?;
+            self.check_inside(new_position)?;
+            let (piece, piece_idx) = self.get_outside_piece_and_index(old_position, piece_id)?;
             self.add_piece_to_inside_position(new_position, piece, player_id);
             self.remove_piece_from_outside_position(old_position, piece_idx);
+            self.rehash(piece, old_position, new_position);
+            Ok(())
         }
 
         fn add_piece_to_inside_position(
             &mut self,
             new_position: i8,
-            piece: Rc<RefCell<Piece>>,
+            piece: PieceRef,
             player_id: i8,
         ) {
+            let player_id = self.get_player_id(player_id);
             self.inside(new_position).pieces.push(piece);
-            self.inside(new_position).player_id = self.clone().get_player_id(player_id);
+            self.inside(new_position).player_id = player_id;
         }
 
         pub fn update_inside(
@@ -373,20 +630,25 @@ mod board {
             piece_id: i8,
             old_position: i8,
             new_position: i8,
-        ) {
-            let (piece, piece_idx) = self.get_inside_piece_and_index(old_position, piece_id);
+        ) -> Result<(), LudoError> {
+            self.check_player(player_id)?;
+            self.check_inside(new_position)?;
+            let (piece, piece_idx) = self.get_inside_piece_and_index(old_position, piece_id)?;
             self.remove_piece_from_inside_position(old_position, piece_idx);
             self.add_piece_to_inside_position(new_position, piece, player_id);
+            self.rehash(piece, old_position, new_position);
+            Ok(())
         }
 
         fn get_inside_piece_and_index(
             &mut self,
             old_position: i8,
             piece_id: i8,
-        ) -> (Rc<RefCell<Piece>>, usize) {
-            let piece_idx = self.get_inside_piece_index(old_position, piece_id);
+        ) -> Result<(PieceRef, usize), LudoError> {
+            self.check_inside(old_position)?;
+            let piece_idx = self.get_inside_piece_index(old_position, piece_id)?;
             let piece = self.get_inside_piece(old_position, piece_idx);
-            (piece, piece_idx)
+            Ok((piece, piece_idx))
         }
 
         fn remove_piece_from_inside_position(&mut self, old_position: i8, piece_idx: usize) {
@@ -406,149 +668,218 @@ mod board {
             }
         }
 
-        fn get_inside_piece_index(&mut self, old_position: i8, piece_id: i8) -> usize {
+        fn get_inside_piece_index(
+            &mut self,
+            old_position: i8,
+            piece_id: i8,
+        ) -> Result<usize, LudoError> {
             self.inside(old_position)
                 .pieces
                 .iter()
-                .position(|piece| piece.borrow().id() == piece_id)
-                .unwrap()
+                .position(|piece| piece.piece_id == piece_id)
+                .ok_or(LudoError::PieceNotFound {
+                    piece_id,
+                    position: old_position,
+                })
         }
 
-        fn get_inside_piece(&mut self, old_position: i8, piece_idx: usize) -> Rc<RefCell<Piece>> {
-            self.inside(old_position).pieces[piece_idx].clone()
+        fn get_inside_piece(&mut self, old_position: i8, piece_idx: usize) -> PieceRef {
+            self.inside(old_position).pieces[piece_idx]
         }
 
-        pub fn enter_goal(&mut self, player_id: i8, piece_id: i8, old_position: i8) {
-            match old_position {
-                (0..=51) => {
-                    let (piece, piece_idx) =
-                        self.get_outside_piece_and_index(old_position, piece_id);
-                    self.remove_piece_from_outside_position(old_position, piece_idx);
-                    self.add_piece_to_goal_position(player_id, piece);
-                }
-                (52..=71) => {
-                    let (piece, piece_idx) =
-                        self.get_inside_piece_and_index(old_position, piece_id);
-                    self.add_piece_to_goal_position(player_id, piece);
-                    self.remove_piece_from_inside_position(old_position, piece_idx);
-                }
-                _ => panic!("Invalid position"),
-            };
+        pub fn enter_goal(
+            &mut self,
+            player_id: i8,
+            piece_id: i8,
+            old_position: i8,
+        ) -> Result<(), LudoError> {
+            self.check_player(player_id)?;
+            if self.geometry.is_track(old_position) {
+                let (piece, piece_idx) =
+                    self.get_outside_piece_and_index(old_position, piece_id)?;
+                self.remove_piece_from_outside_position(old_position, piece_idx);
+                self.add_piece_to_goal_position(player_id, piece);
+                self.rehash(piece, old_position, self.geometry.goal_position());
+            } else if self.geometry.is_inside(old_position) {
+                let (piece, piece_idx) = self.get_inside_piece_and_index(old_position, piece_id)?;
+                self.add_piece_to_goal_position(player_id, piece);
+                self.remove_piece_from_inside_position(old_position, piece_idx);
+                self.rehash(piece, old_position, self.geometry.goal_position());
+            } else {
+                return Err(LudoError::InvalidPosition(old_position));
+            }
+            Ok(())
         }
 
-        fn add_piece_to_goal_position(&mut self, player_id: i8, piece: Rc<RefCell<Piece>>) {
+        fn add_piece_to_goal_position(&mut self, player_id: i8, piece: PieceRef) {
+            let player = self.get_player_id(player_id);
             self.goal(player_id).pieces.push(piece);
-            self.goal(player_id).player_id = self.clone().get_player_id(player_id);
+            self.goal(player_id).player_id = player;
         }
 
-        pub fn is_occupied_more(&mut self, position: i8) -> bool {
-            if !(0..=51).contains(&position) {
+        pub fn is_occupied_more(&self, position: i8) -> bool {
+            if !self.geometry.is_track(position) {
                 return false;
             }
-            self.outside(position).pieces.len() > 1
+            self.square(position).pieces.len() > 1
         }
 
-        pub fn is_occupied(&mut self, position: i8) -> bool {
-            if !(0..=51).contains(&position) {
+        pub fn is_occupied(&self, position: i8) -> bool {
+            if !self.geometry.is_track(position) {
                 return false;
             }
-            !self.outside(position).pieces.is_empty()
+            !self.square(position).pieces.is_empty()
         }
 
-        pub fn is_occupied_self(&mut self, player_id: i8, position: i8) -> bool {
+        pub fn is_occupied_self(&self, player_id: i8, position: i8) -> bool {
             if !self.is_occupied(position) {
                 return false;
             }
             let player_id = self.get_player_id(player_id);
-            self.outside(position).player_id == player_id
+            self.square(position).player_id == player_id
         }
 
-        pub fn is_occupied_by_more_self(&mut self, player_id: i8, position: i8) -> bool {
+        pub fn is_occupied_by_more_self(&self, player_id: i8, position: i8) -> bool {
             if !self.is_occupied_more(position) {
                 return false;
             }
             let player_id = self.get_player_id(player_id);
-            self.outside(position).player_id == player_id
+            self.square(position).player_id == player_id
         }
 
-        pub fn is_occupied_by_other(&mut self, player_id: i8, position: i8) -> bool {
+        pub fn is_occupied_by_other(&self, player_id: i8, position: i8) -> bool {
             if !self.is_occupied(position) {
                 return false;
             }
             let player_id = self.get_player_id(player_id);
-            self.outside(position).player_id != player_id
+            self.square(position).player_id != player_id
         }
 
-        pub fn is_occupied_by_other_more(&mut self, player_id: i8, position: i8) -> bool {
+        pub fn is_occupied_by_other_more(&self, player_id: i8, position: i8) -> bool {
             if !self.is_occupied_more(position) {
                 return false;
             }
             let player_id = self.get_player_id(player_id);
-            self.outside(position).player_id != player_id
+            self.square(position).player_id != player_id
         }
 
-        pub fn reset(&mut self) {
-            for piece in self.all_pieces.clone() {
-                let position = piece.borrow().position();
-                let player_id = piece.borrow_mut().color() as i8;
-                self.reset_free_pieces(position, player_id, piece);
+        pub fn piece_positions(&self) -> Vec<[i8; 4]> {
+            self.pieces
+                .iter()
+                .map(|pieces| pieces.each_ref().map(Piece::position))
+                .collect()
+        }
+
+        pub fn slot(&self, piece: PieceRef) -> PieceSlot {
+            let state = self.piece_at(piece).clone();
+            let index = self
+                .square_of(piece.player_id, state.position())
+                .pieces
+                .iter()
+                .position(|&other| other == piece)
+                .unwrap_or_default();
+            PieceSlot {
+                piece,
+                state,
+                index,
             }
-            self.reset_home();
         }
 
-        fn reset_free_pieces(&mut self, position: i8, player_id: i8, piece: Rc<RefCell<Piece>>) {
-            match position {
-                99 => {
-                    self.reset_goal(player_id, &piece);
-                }
-                0..=51 => {
-                    self.reset_outside(position, &piece, player_id);
-                }
-                52..=71 => {
-                    self.reset_inside(position, piece, player_id);
+        pub fn slots(&self) -> Vec<PieceSlot> {
+            (0..self.geometry.players)
+                .flat_map(|player_id| {
+                    (0..4).map(move |piece_id| PieceRef::new(player_id, piece_id))
+                })
+                .map(|piece| self.slot(piece))
+                .collect()
+        }
+
+        pub fn restore(&mut self, slots: &[PieceSlot]) {
+            for slot in slots {
+                let piece = slot.piece;
+                let position = self.piece_at(piece).position();
+                let square = self.square_of_mut(piece.player_id, position);
+                square.pieces.retain(|&other| other != piece);
+                if square.pieces.is_empty() {
+                    square.player_id = None;
                 }
-                _ => (),
+                self.hash ^= zobrist::piece_key(piece.player_id, piece.piece_id, position);
+            }
+            let mut slots: Vec<&PieceSlot> = slots.iter().collect();
+            slots.sort_by_key(|slot| slot.index);
+            for slot in slots {
+                let piece = slot.piece;
+                let position = slot.state.position();
+                let player_id = self.get_player_id(piece.player_id);
+                let square = self.square_of_mut(piece.player_id, position);
+                square
+                    .pieces
+                    .insert(slot.index.min(square.pieces.len()), piece);
+                square.player_id = player_id;
+                self.hash ^= zobrist::piece_key(piece.player_id, piece.piece_id, position);
+                *self.piece_mut(piece.player_id, piece.piece_id) = slot.state.clone();
             }
         }
 
-        fn reset_inside(&mut self, position: i8, piece: Rc<RefCell<Piece>>, player_id: i8) {
-            self.inside(position).pieces.clear();
-            self.inside(position).player_id = None;
-            self.home(player_id).pieces.push(piece);
+        fn square_of(&self, player_id: i8, position: i8) -> &BoardState {
+            match position {
+                -1 => &self.home[player_id as usize],
+                _ if self.geometry.is_track(position) => &self.outside[position as usize],
+                _ if self.geometry.is_inside(position) => {
+                    &self.inside[(position - self.geometry.track_length) as usize]
+                }
+                _ => &self.goal[player_id as usize],
+            }
         }
 
-        fn reset_outside(&mut self, position: i8, piece: &Rc<RefCell<Piece>>, player_id: i8) {
-            self.outside(position).pieces.clear();
-            self.outside(position).player_id = None;
-            self.home(player_id).pieces.push(piece.clone());
+        fn square_of_mut(&mut self, player_id: i8, position: i8) -> &mut BoardState {
+            match position {
+                -1 => &mut self.home[player_id as usize],
+                _ if self.geometry.is_track(position) => &mut self.outside[position as usize],
+                _ if self.geometry.is_inside(position) => {
+                    &mut self.inside[(position - self.geometry.track_length) as usize]
+                }
+                _ => &mut self.goal[player_id as usize],
+            }
         }
 
-        fn reset_goal(&mut self, player_id: i8, piece: &Rc<RefCell<Piece>>) {
-            self.goal(player_id).pieces.clear();
-            self.goal(player_id).player_id = None;
-            self.home(player_id).pieces.push(piece.clone());
+        pub fn reset(&mut self) {
+            for square in self
+                .outside
+                .iter_mut()
+                .chain(self.inside.iter_mut())
+                .chain(self.goal.iter_mut())
+            {
+                square.pieces.clear();
+                square.player_id = None;
+            }
+            self.reset_home();
+            self.side_to_move = 0;
+            self.roll = 0;
+            self.hash = self.compute_zobrist();
         }
 
         fn reset_home(&mut self) {
-            for player_id in 0..4 {
-                self.home(player_id).pieces.sort_by_key(|a| a.borrow().id());
-                self.home(player_id)
-                    .pieces
+            for player_id in 0..self.geometry.players {
+                self.home(player_id).pieces = create_vector_of_piece_refs(player_id);
+                self.pieces[player_id as usize]
                     .iter_mut()
-                    .for_each(|piece| piece.borrow_mut().home());
-                self.home(player_id).player_id = self.clone().get_player_id(player_id);
+                    .for_each(|piece| piece.home());
+                self.home(player_id).player_id = self.get_player_id(player_id);
             }
         }
     }
 
-    fn create_vector_of_pieces(color: Color) -> Vec<Rc<RefCell<Piece>>> {
-        let mut pieces = Vec::default();
-        for i in 0..4 {
-            pieces.push(Rc::new(RefCell::new(Piece::new(i, color))));
-        }
-        pieces
+    fn create_vector_of_pieces(color: Color, geometry: &BoardGeometry) -> [Piece; 4] {
+        let (last_position, goal_position) = (geometry.last_position(), geometry.goal_position());
+        [0, 1, 2, 3].map(|i| Piece::with_bounds(i, color, last_position, goal_position))
     }
 
+    fn create_vector_of_piece_refs(player_id: i8) -> Vec<PieceRef> {
+        (0..4)
+            .map(|piece_id| PieceRef::new(player_id, piece_id))
+            .collect()
+    }
     impl Default for Board {
         fn default() -> Self {
             Self::new()
@@ -558,4 +889,11 @@ mod board {
 
 pub use board::Board;
 pub use board::BoardState;
+pub use board::PieceRef;
+pub use board::PieceSlot;
 pub use board::PlayerID;
+pub use compact::{CompactMove, CompactState};
+pub use geometry::BoardGeometry;
+pub use invariants::{InvariantViolation, Square};
+pub use render::{Charset, RenderOptions};
+pub use zobrist::TranspositionTable;
@@ -0,0 +1,182 @@
+use crate::board::Board;
+use crate::geometry::BoardGeometry;
+use crate::zobrist;
+use errors::LudoError;
+
+const MAX_PLAYERS: usize = 6;
+const PIECES: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompactMove {
+    pub piece_id: u8,
+    pub to: u8,
+    pub captured: u32,
+    pub extra_turn: bool,
+}
+
+impl CompactMove {
+    pub fn new(piece_id: i8, to: i8, captured: &[(i8, i8)], extra_turn: bool) -> Self {
+        let captured = captured.iter().fold(0, |mask, &(player_id, piece_id)| {
+            mask | 1 << index(player_id, piece_id)
+        });
+        CompactMove {
+            piece_id: piece_id as u8,
+            to: to as u8,
+            captured,
+            extra_turn,
+        }
+    }
+
+    pub fn captures(&self) -> impl Iterator<Item = (i8, i8)> + '_ {
+        (0..MAX_PLAYERS * PIECES)
+            .filter(|&index| self.captured & 1 << index != 0)
+            .map(|index| ((index / PIECES) as i8, (index % PIECES) as i8))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompactState {
+    positions: [u8; MAX_PLAYERS * PIECES],
+    pub players: u8,
+    pub turn: u8,
+    pub roll: u8,
+    pub seats: u8,
+}
+
+impl CompactState {
+    pub const HOME: u8 = u8::MAX;
+
+    pub fn new() -> Self {
+        CompactState {
+            positions: [Self::HOME; MAX_PLAYERS * PIECES],
+            players: 4,
+            turn: 0,
+            roll: 0,
+            seats: all_seats(4),
+        }
+    }
+
+    pub fn with_players(players: i8) -> Result<Self, LudoError> {
+        if !(2..=MAX_PLAYERS as i8).contains(&players) {
+            return Err(LudoError::InvalidPlayer(players));
+        }
+        Ok(CompactState {
+            players: players as u8,
+            seats: all_seats(players as u8),
+            ..Self::new()
+        })
+    }
+
+    pub fn from_positions(positions: [[i8; 4]; 4]) -> Self {
+        let mut state = Self::new();
+        for (player_id, pieces) in positions.iter().enumerate() {
+            for (piece_id, &position) in pieces.iter().enumerate() {
+                state.set_position(player_id as i8, piece_id as i8, position);
+            }
+        }
+        state
+    }
+
+    pub fn from_board(board: &Board) -> Result<Self, LudoError> {
+        let players = board.geometry().players;
+        if *board.geometry() != BoardGeometry::new(players) {
+            return Err(LudoError::IllegalAction(
+                "a compact state needs a board with the standard layout".to_string(),
+            ));
+        }
+        let mut state = Self::with_players(players)?;
+        for (player_id, pieces) in board.piece_positions().iter().enumerate() {
+            for (piece_id, &position) in pieces.iter().enumerate() {
+                state.set_position(player_id as i8, piece_id as i8, position);
+            }
+        }
+        state.turn = board.side_to_move() as u8;
+        state.roll = board.roll() as u8;
+        state.seats = board
+            .seats()
+            .iter()
+            .fold(0, |seats, &player_id| seats | 1 << player_id);
+        Ok(state)
+    }
+
+    pub fn to_board(&self) -> Result<Board, LudoError> {
+        let mut board = Board::from_positions_with_geometry(self.geometry(), &self.positions())?;
+        let seats: Vec<i8> = (0..self.players as i8)
+            .filter(|&player_id| self.is_seated(player_id))
+            .collect();
+        board.set_seats(&seats)?;
+        board.set_side_to_move(self.turn as i8);
+        board.set_roll(self.roll as i8);
+        Ok(board)
+    }
+
+    pub fn geometry(&self) -> BoardGeometry {
+        BoardGeometry::new(self.players as i8)
+    }
+
+    pub fn zobrist(&self) -> u64 {
+        self.positions[..self.players as usize * PIECES]
+            .iter()
+            .enumerate()
+            .map(|(index, &position)| {
+                let (player_id, piece_id) = ((index / PIECES) as i8, (index % PIECES) as i8);
+                zobrist::piece_key(player_id, piece_id, position as i8)
+            })
+            .fold(zobrist::turn_key(self.turn as i8), |hash, key| hash ^ key)
+    }
+
+    pub fn position(&self, player_id: i8, piece_id: i8) -> i8 {
+        self.positions[index(player_id, piece_id)] as i8
+    }
+
+    pub fn set_position(&mut self, player_id: i8, piece_id: i8, position: i8) {
+        self.positions[index(player_id, piece_id)] = position as u8;
+    }
+
+    pub fn positions(&self) -> Vec<[i8; 4]> {
+        (0..self.players as i8)
+            .map(|player_id| [0, 1, 2, 3].map(|piece_id| self.position(player_id, piece_id)))
+            .collect()
+    }
+
+    pub fn is_seated(&self, player_id: i8) -> bool {
+        (0..self.players as i8).contains(&player_id) && self.seats & 1 << player_id != 0
+    }
+
+    pub fn next_turn(&mut self) {
+        if self.seats & all_seats(self.players) == 0 {
+            return;
+        }
+        loop {
+            self.turn = (self.turn + 1) % self.players;
+            if self.is_seated(self.turn as i8) {
+                break;
+            }
+        }
+    }
+
+    pub fn apply(&mut self, compact_move: CompactMove) {
+        for (player_id, piece_id) in compact_move.captures() {
+            self.positions[index(player_id, piece_id)] = Self::HOME;
+        }
+        self.positions[index(self.turn as i8, compact_move.piece_id as i8)] = compact_move.to;
+        self.roll = 0;
+        if !compact_move.extra_turn {
+            self.next_turn();
+        }
+    }
+}
+
+impl Default for CompactState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn all_seats(players: u8) -> u8 {
+    (1 << players) - 1
+}
+
+fn index(player_id: i8, piece_id: i8) -> usize {
+    player_id as usize * PIECES + piece_id as usize
+}
@@ -0,0 +1,173 @@
+use errors::LudoError;
+
+const ARM_LENGTH: i8 = 13;
+const MAX_DICE: i8 = 6;
+const PIECES: i8 = 4;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoardGeometry {
+    pub players: i8,
+    pub track_length: i8,
+    pub start_squares: Vec<i8>,
+    pub home_column_length: i8,
+    pub globes: Vec<i8>,
+    pub stars: Vec<i8>,
+}
+
+impl BoardGeometry {
+    pub fn new(players: i8) -> Self {
+        let arms = 0..players;
+        BoardGeometry {
+            players,
+            track_length: ARM_LENGTH * players,
+            start_squares: arms.clone().map(|arm| ARM_LENGTH * arm).collect(),
+            home_column_length: 5,
+            globes: arms.clone().map(|arm| ARM_LENGTH * arm + 8).collect(),
+            stars: arms
+                .flat_map(|arm| [ARM_LENGTH * arm + 5, ARM_LENGTH * arm + 11])
+                .collect(),
+        }
+    }
+
+    pub fn standard() -> Self {
+        Self::new(4)
+    }
+
+    pub fn six_player() -> Self {
+        Self::new(6)
+    }
+
+    pub fn validate(&self) -> Result<(), LudoError> {
+        if !(2..=6).contains(&self.players) {
+            return Err(LudoError::InvalidPlayer(self.players));
+        }
+        if self.start_squares.len() != self.players as usize {
+            return Err(LudoError::IllegalAction(format!(
+                "expected {} start squares, got {}",
+                self.players,
+                self.start_squares.len()
+            )));
+        }
+        if self.home_column_length < 1 {
+            return Err(LudoError::IllegalAction(
+                "the home column needs at least one square".to_string(),
+            ));
+        }
+        let furthest = self.track_length as i16
+            + (self.players as i16 + 1) * self.home_column_length as i16
+            + MAX_DICE as i16;
+        if self.track_length < 1 || furthest > i8::MAX as i16 {
+            return Err(LudoError::IllegalAction(format!(
+                "a track of {} squares does not fit the position range",
+                self.track_length
+            )));
+        }
+        let squares = self
+            .start_squares
+            .iter()
+            .chain(self.globes.iter())
+            .chain(self.stars.iter());
+        for &square in squares {
+            if !(0..self.track_length).contains(&square) {
+                return Err(LudoError::InvalidPosition(square));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn pieces(&self) -> i8 {
+        PIECES
+    }
+
+    pub fn total_inside(&self) -> i8 {
+        self.players * self.home_column_length
+    }
+
+    pub fn last_position(&self) -> i8 {
+        self.track_length + self.total_inside() - 1
+    }
+
+    pub fn goal_position(&self) -> i8 {
+        let furthest = self.last_position() + self.home_column_length + MAX_DICE;
+        if furthest < 99 {
+            99
+        } else {
+            i8::MAX
+        }
+    }
+
+    pub fn is_track(&self, position: i8) -> bool {
+        (0..self.track_length).contains(&position)
+    }
+
+    pub fn is_inside(&self, position: i8) -> bool {
+        (self.track_length..=self.last_position()).contains(&position)
+    }
+
+    pub fn start_square(&self, player_id: i8) -> Option<i8> {
+        self.start_squares.get(player_id as usize).copied()
+    }
+
+    pub fn entry_square(&self, player_id: i8) -> Option<i8> {
+        let start = self.start_square(player_id)?;
+        Some((start - 2).rem_euclid(self.track_length))
+    }
+
+    pub fn home_column_start(&self, player_id: i8) -> i8 {
+        self.track_length + self.home_column_length * player_id
+    }
+
+    pub fn goal_square(&self, player_id: i8) -> i8 {
+        self.home_column_start(player_id) + self.home_column_length
+    }
+
+    pub fn wraps_around(&self, player_id: i8) -> bool {
+        match (self.start_square(player_id), self.entry_square(player_id)) {
+            (Some(start), Some(entry)) => entry < start,
+            _ => false,
+        }
+    }
+
+    pub fn is_star(&self, position: i8) -> bool {
+        self.stars.contains(&position)
+    }
+
+    pub fn is_globe(&self, position: i8) -> bool {
+        self.globes.contains(&position)
+    }
+
+    pub fn is_start(&self, position: i8) -> bool {
+        self.start_squares.contains(&position)
+    }
+
+    pub fn next_star(&self, star: i8) -> i8 {
+        let idx = self.stars.iter().position(|&position| position == star);
+        match idx {
+            Some(idx) => self.stars[(idx + 1) % self.stars.len()],
+            None => star,
+        }
+    }
+
+    pub fn distance_to_goal(&self, player_id: i8, position: i8) -> Option<i8> {
+        let start = self.start_square(player_id)?;
+        let full_distance = self.track_length + self.home_column_length - 1;
+        let column = self.home_column_start(player_id);
+        if self.is_track(position) {
+            let progress = (position - start).rem_euclid(self.track_length);
+            if progress > self.track_length - 2 {
+                return None;
+            }
+            Some(full_distance - progress)
+        } else if (column..column + self.home_column_length).contains(&position) {
+            Some(self.goal_square(player_id) - position)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for BoardGeometry {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
@@ -0,0 +1,200 @@
+use crate::board::{Board, BoardState, PieceRef};
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Square {
+    Home(i8),
+    Goal(i8),
+    Track(i8),
+    Inside(i8),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InvariantViolation {
+    PieceCount {
+        expected: usize,
+        found: usize,
+    },
+    MissingPiece(PieceRef),
+    DuplicatePiece(PieceRef),
+    PositionMismatch {
+        piece: PieceRef,
+        square: Square,
+        position: i8,
+    },
+    StateMismatch {
+        piece: PieceRef,
+        square: Square,
+    },
+    StaleOwner(Square),
+    MixedStack(Square),
+}
+
+impl Display for Square {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Square::Home(player_id) => write!(f, "home of player {}", player_id),
+            Square::Goal(player_id) => write!(f, "goal of player {}", player_id),
+            Square::Track(position) => write!(f, "track square {}", position),
+            Square::Inside(position) => write!(f, "home column square {}", position),
+        }
+    }
+}
+
+impl Display for InvariantViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InvariantViolation::PieceCount { expected, found } => {
+                write!(
+                    f,
+                    "Expected {} pieces on the board, found {}",
+                    expected, found
+                )
+            }
+            InvariantViolation::MissingPiece(piece) => write!(
+                f,
+                "Piece {} of player {} is not on any square",
+                piece.piece_id, piece.player_id
+            ),
+            InvariantViolation::DuplicatePiece(piece) => write!(
+                f,
+                "Piece {} of player {} is on more than one square",
+                piece.piece_id, piece.player_id
+            ),
+            InvariantViolation::PositionMismatch {
+                piece,
+                square,
+                position,
+            } => write!(
+                f,
+                "Piece {} of player {} is on the {} but has position {}",
+                piece.piece_id, piece.player_id, square, position
+            ),
+            InvariantViolation::StateMismatch { piece, square } => write!(
+                f,
+                "Piece {} of player {} is on the {} in the wrong state",
+                piece.piece_id, piece.player_id, square
+            ),
+            InvariantViolation::StaleOwner(square) => {
+                write!(f, "The owner recorded on the {} is stale", square)
+            }
+            InvariantViolation::MixedStack(square) => {
+                write!(f, "Pieces of different colours share the {}", square)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InvariantViolation {}
+
+impl Board {
+    pub fn validate(&self) -> Result<(), Vec<InvariantViolation>> {
+        let mut violations = Vec::new();
+        let players = self.geometry().players;
+        let mut seen = vec![[0; 4]; players as usize];
+
+        let squares = self
+            .home
+            .iter()
+            .enumerate()
+            .map(|(player_id, square)| (Square::Home(player_id as i8), square))
+            .chain(
+                self.goal
+                    .iter()
+                    .enumerate()
+                    .map(|(player_id, square)| (Square::Goal(player_id as i8), square)),
+            )
+            .chain(
+                self.outside
+                    .iter()
+                    .map(|square| (Square::Track(square.position), square)),
+            )
+            .chain(
+                self.inside
+                    .iter()
+                    .map(|square| (Square::Inside(square.position), square)),
+            );
+
+        for (square, state) in squares {
+            for &piece in state.pieces.iter() {
+                if !(0..players).contains(&piece.player_id) || !(0..4).contains(&piece.piece_id) {
+                    violations.push(InvariantViolation::MissingPiece(piece));
+                    continue;
+                }
+                seen[piece.player_id as usize][piece.piece_id as usize] += 1;
+                self.check_piece(square, state, piece, &mut violations);
+            }
+            self.check_square(square, state, &mut violations);
+        }
+
+        let expected = players as usize * 4;
+        let found = seen.iter().flatten().sum();
+        if found != expected {
+            violations.push(InvariantViolation::PieceCount { expected, found });
+        }
+        for (player_id, pieces) in seen.iter().enumerate() {
+            for (piece_id, &count) in pieces.iter().enumerate() {
+                let piece = PieceRef::new(player_id as i8, piece_id as i8);
+                match count {
+                    0 => violations.push(InvariantViolation::MissingPiece(piece)),
+                    1 => {}
+                    _ => violations.push(InvariantViolation::DuplicatePiece(piece)),
+                }
+            }
+        }
+
+        match violations.is_empty() {
+            true => Ok(()),
+            false => Err(violations),
+        }
+    }
+
+    fn check_piece(
+        &self,
+        square: Square,
+        state: &BoardState,
+        piece: PieceRef,
+        violations: &mut Vec<InvariantViolation>,
+    ) {
+        let actual = self.piece_at(piece);
+        let state_matches = match square {
+            Square::Home(player_id) => player_id == piece.player_id && actual.is_home(),
+            Square::Goal(player_id) => player_id == piece.player_id && actual.is_goal(),
+            Square::Track(_) | Square::Inside(_) => actual.is_free(),
+        };
+        if !state_matches {
+            violations.push(InvariantViolation::StateMismatch { piece, square });
+        }
+        if actual.position() != state.position {
+            violations.push(InvariantViolation::PositionMismatch {
+                piece,
+                square,
+                position: actual.position(),
+            });
+        }
+    }
+
+    fn check_square(
+        &self,
+        square: Square,
+        state: &BoardState,
+        violations: &mut Vec<InvariantViolation>,
+    ) {
+        let owner = state.player_id.as_ref().map(|player_id| player_id.id());
+        let owned = match owner {
+            None => state.pieces.is_empty(),
+            Some(owner) => state.pieces.iter().any(|piece| piece.player_id == owner),
+        };
+        if !owned {
+            violations.push(InvariantViolation::StaleOwner(square));
+        }
+        let mixed = state
+            .pieces
+            .windows(2)
+            .any(|pair| pair[0].player_id != pair[1].player_id);
+        let is_globe = matches!(square, Square::Track(position) if self.is_globe(position));
+        if mixed && !is_globe {
+            violations.push(InvariantViolation::MixedStack(square));
+        }
+    }
+}
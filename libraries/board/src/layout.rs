@@ -0,0 +1,61 @@
+use crate::geometry::BoardGeometry;
+
+pub(crate) const GRID: usize = 15;
+
+pub(crate) fn is_cross(geometry: &BoardGeometry) -> bool {
+    geometry.players == 4
+        && geometry.track_length == 52
+        && geometry.home_column_length == 5
+        && geometry.start_squares == [0, 13, 26, 39]
+}
+
+pub(crate) fn track_cells() -> Vec<(usize, usize)> {
+    let mut cells = Vec::new();
+    cells.extend((1..6).map(|col| (6, col)));
+    cells.extend((0..6).rev().map(|row| (row, 6)));
+    cells.push((0, 7));
+    cells.extend((0..6).map(|row| (row, 8)));
+    cells.extend((9..GRID).map(|col| (6, col)));
+    cells.push((7, 14));
+    cells.extend((9..GRID).rev().map(|col| (8, col)));
+    cells.extend((9..GRID).map(|row| (row, 8)));
+    cells.push((14, 7));
+    cells.extend((9..GRID).rev().map(|row| (row, 6)));
+    cells.extend((0..6).rev().map(|col| (8, col)));
+    cells.push((7, 0));
+    cells.push((6, 0));
+    cells
+}
+
+pub(crate) fn column_cells(player_id: i8) -> [(usize, usize); 5] {
+    match player_id {
+        0 => [1, 2, 3, 4, 5].map(|col| (7, col)),
+        1 => [1, 2, 3, 4, 5].map(|row| (row, 7)),
+        2 => [13, 12, 11, 10, 9].map(|col| (7, col)),
+        _ => [13, 12, 11, 10, 9].map(|row| (row, 7)),
+    }
+}
+
+pub(crate) fn goal_cell(player_id: i8) -> (usize, usize) {
+    match player_id {
+        0 => (7, 6),
+        1 => (6, 7),
+        2 => (7, 8),
+        _ => (8, 7),
+    }
+}
+
+pub(crate) fn base_cells(player_id: i8) -> [(usize, usize); 4] {
+    let (row, col) = match player_id {
+        0 => (2, 2),
+        1 => (2, 11),
+        2 => (11, 11),
+        _ => (11, 2),
+    };
+    [
+        (row, col),
+        (row, col + 1),
+        (row + 1, col),
+        (row + 1, col + 1),
+    ]
+}
@@ -0,0 +1,230 @@
+use crate::board::Board;
+use crate::layout::{base_cells, column_cells, goal_cell, is_cross, track_cells, GRID};
+use pieces::Color;
+use std::fmt::{self, Display, Formatter};
+
+const RESET: &str = "\x1b[0m";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Charset {
+    Ascii,
+    Unicode,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RenderOptions {
+    pub charset: Charset,
+    pub color: bool,
+}
+
+impl RenderOptions {
+    pub fn ascii() -> Self {
+        RenderOptions {
+            charset: Charset::Ascii,
+            color: false,
+        }
+    }
+
+    pub fn unicode() -> Self {
+        RenderOptions {
+            charset: Charset::Unicode,
+            color: true,
+        }
+    }
+
+    fn symbol(&self, ascii: &'static str, unicode: &'static str) -> &'static str {
+        match self.charset {
+            Charset::Ascii => ascii,
+            Charset::Unicode => unicode,
+        }
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self::unicode()
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Cell {
+    Blank,
+    Empty,
+    Globe,
+    Star,
+    Start(i8),
+    Column(i8),
+    Center,
+    Pieces(i8, usize),
+}
+
+fn letter(color: Color) -> char {
+    match color {
+        Color::Green => 'G',
+        Color::Yellow => 'Y',
+        Color::Blue => 'B',
+        Color::Red => 'R',
+        Color::Orange => 'O',
+        Color::Purple => 'P',
+    }
+}
+
+fn ansi(color: Color) -> &'static str {
+    match color {
+        Color::Green => "\x1b[32m",
+        Color::Yellow => "\x1b[33m",
+        Color::Blue => "\x1b[34m",
+        Color::Red => "\x1b[31m",
+        Color::Orange => "\x1b[38;5;208m",
+        Color::Purple => "\x1b[35m",
+    }
+}
+
+impl Board {
+    pub fn render(&self, options: RenderOptions) -> String {
+        match is_cross(self.geometry()) {
+            true => self.render_cross(options),
+            false => self.render_track(options),
+        }
+    }
+
+    fn square_cell(&self, position: i8) -> Cell {
+        let square = &self.outside[position as usize];
+        if let Some(piece) = square.pieces.first() {
+            return Cell::Pieces(piece.player_id, square.pieces.len());
+        }
+        let geometry = self.geometry();
+        if let Some(owner) = geometry
+            .start_squares
+            .iter()
+            .position(|&start| start == position)
+        {
+            return Cell::Start(owner as i8);
+        }
+        match (geometry.is_globe(position), geometry.is_star(position)) {
+            (true, _) => Cell::Globe,
+            (_, true) => Cell::Star,
+            _ => Cell::Empty,
+        }
+    }
+
+    fn column_cell(&self, player_id: i8, step: i8) -> Cell {
+        let position = self.geometry().home_column_start(player_id) + step;
+        let square = &self.inside[(position - self.geometry().track_length) as usize];
+        match square.pieces.first() {
+            Some(piece) => Cell::Pieces(piece.player_id, square.pieces.len()),
+            None => Cell::Column(player_id),
+        }
+    }
+
+    fn render_cross(&self, options: RenderOptions) -> String {
+        let mut grid = [[Cell::Blank; GRID]; GRID];
+        for (position, (row, col)) in track_cells().into_iter().enumerate() {
+            grid[row][col] = self.square_cell(position as i8);
+        }
+        for player_id in 0..4 {
+            for (step, (row, col)) in column_cells(player_id).into_iter().enumerate() {
+                grid[row][col] = self.column_cell(player_id, step as i8);
+            }
+            let (row, col) = goal_cell(player_id);
+            grid[row][col] = match self.goal[player_id as usize].pieces.len() {
+                0 => Cell::Center,
+                count => Cell::Pieces(player_id, count),
+            };
+            let home = &self.home[player_id as usize].pieces;
+            for (piece_id, (row, col)) in base_cells(player_id).into_iter().enumerate() {
+                let at_home = home.iter().any(|piece| piece.piece_id == piece_id as i8);
+                grid[row][col] = match at_home {
+                    true => Cell::Pieces(player_id, 1),
+                    false => Cell::Column(player_id),
+                };
+            }
+        }
+        grid[7][7] = Cell::Center;
+
+        let mut output = String::new();
+        for row in grid.iter() {
+            let line: String = row.iter().map(|&cell| self.cell(cell, options)).collect();
+            output.push_str(line.trim_end());
+            output.push('\n');
+        }
+        output
+    }
+
+    fn render_track(&self, options: RenderOptions) -> String {
+        let geometry = self.geometry();
+        let mut output = String::new();
+        for player_id in 0..geometry.players {
+            let start = geometry.start_square(player_id).unwrap_or_default();
+            let end = match geometry.start_square(player_id + 1) {
+                Some(next) => next,
+                None => geometry.track_length,
+            };
+            let track: String = (start..end)
+                .map(|position| self.cell(self.square_cell(position), options))
+                .collect();
+            let column: String = (0..geometry.home_column_length)
+                .map(|step| self.cell(self.column_cell(player_id, step), options))
+                .collect();
+            let home = self.home[player_id as usize].pieces.len();
+            let goal = self.goal[player_id as usize].pieces.len();
+            output.push_str(&format!(
+                "{} {} | {} | home {} goal {}\n",
+                self.tint(player_id, letter(self.piece(player_id, 0).color()), options),
+                track,
+                column,
+                home,
+                goal
+            ));
+        }
+        output
+    }
+
+    fn tint(&self, player_id: i8, text: impl Display, options: RenderOptions) -> String {
+        match options.color {
+            true => format!(
+                "{}{}{}",
+                ansi(self.piece(player_id, 0).color()),
+                text,
+                RESET
+            ),
+            false => text.to_string(),
+        }
+    }
+
+    fn cell(&self, cell: Cell, options: RenderOptions) -> String {
+        match cell {
+            Cell::Blank => "  ".to_string(),
+            Cell::Empty => format!("{} ", options.symbol(".", "·")),
+            Cell::Globe => format!("{} ", options.symbol("o", "○")),
+            Cell::Star => format!("{} ", options.symbol("*", "★")),
+            Cell::Center => format!("{} ", options.symbol("X", "⌂")),
+            Cell::Start(player_id) => {
+                let symbol = options.symbol("+", "◆");
+                format!("{} ", self.tint(player_id, symbol, options))
+            }
+            Cell::Column(player_id) => {
+                let symbol = options.symbol("#", "▪");
+                format!("{} ", self.tint(player_id, symbol, options))
+            }
+            Cell::Pieces(player_id, count) => {
+                let piece = letter(self.piece(player_id, 0).color());
+                let count = match count {
+                    1 => " ".to_string(),
+                    count => count.to_string(),
+                };
+                let text = format!("{}{}", piece, count);
+                match options.color {
+                    true => format!("\x1b[1m{}", self.tint(player_id, text, options)),
+                    false => text,
+                }
+            }
+        }
+    }
+}
+
+impl Display for Board {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(RenderOptions::ascii()))
+    }
+}
@@ -0,0 +1,258 @@
+use crate::board::Board;
+use crate::layout::{base_cells, column_cells, goal_cell, is_cross, track_cells, GRID};
+use pieces::Color;
+use std::f64::consts::PI;
+use std::fmt::Write;
+
+const CELL: f64 = 32.0;
+const MARGIN: f64 = 16.0;
+
+#[derive(Clone, Copy)]
+enum Spot {
+    Track(i8),
+    Column(i8, i8),
+    Goal(i8),
+    Base(i8, i8),
+}
+
+fn hex(color: Color) -> &'static str {
+    match color {
+        Color::Green => "#2e9e44",
+        Color::Yellow => "#f2c500",
+        Color::Blue => "#2d6fd6",
+        Color::Red => "#d63a2d",
+        Color::Orange => "#f28c28",
+        Color::Purple => "#8e44ad",
+    }
+}
+
+fn star_points(x: f64, y: f64, radius: f64) -> String {
+    (0..10)
+        .map(|corner| {
+            let angle = PI * corner as f64 / 5.0 - PI / 2.0;
+            let reach = match corner % 2 {
+                0 => radius,
+                _ => radius * 0.45,
+            };
+            format!(
+                "{:.1},{:.1}",
+                x + reach * angle.cos(),
+                y + reach * angle.sin()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+impl Board {
+    pub fn to_svg(&self) -> String {
+        let size = self.svg_size();
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{size:.0}\" height=\"{size:.0}\" viewBox=\"0 0 {size:.0} {size:.0}\">\n{}{}</svg>\n",
+            self.svg_board(),
+            self.svg_pieces()
+        )
+    }
+
+    pub fn svg_size(&self) -> f64 {
+        match is_cross(self.geometry()) {
+            true => GRID as f64 * CELL + 2.0 * MARGIN,
+            false => 2.0 * (self.ring_radius() + 3.0 * CELL) + 2.0 * MARGIN,
+        }
+    }
+
+    pub fn svg_board(&self) -> String {
+        let geometry = self.geometry();
+        let size = self.svg_size();
+        let mut svg = String::from("<g class=\"board\">\n");
+        let _ = writeln!(
+            svg,
+            "<rect width=\"{size:.0}\" height=\"{size:.0}\" fill=\"#fdfaf2\"/>"
+        );
+        if is_cross(geometry) {
+            for player_id in 0..geometry.players {
+                let (row, col) = base_cells(player_id)[0];
+                let (x, y) = (
+                    MARGIN + (col - 2) as f64 * CELL,
+                    MARGIN + (row - 2) as f64 * CELL,
+                );
+                let _ = writeln!(
+                    svg,
+                    "<rect x=\"{x:.1}\" y=\"{y:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"{}\" fill-opacity=\"0.25\"/>",
+                    6.0 * CELL,
+                    6.0 * CELL,
+                    self.player_hex(player_id)
+                );
+            }
+        }
+        for position in 0..geometry.track_length {
+            let start = geometry
+                .start_squares
+                .iter()
+                .position(|&start| start == position);
+            let fill = match start {
+                Some(owner) => self.player_hex(owner as i8),
+                None => "#ffffff",
+            };
+            self.svg_square(&mut svg, Spot::Track(position), fill, 1.0);
+            let (x, y) = self.spot_center(Spot::Track(position));
+            if geometry.is_globe(position) {
+                let _ = writeln!(
+                    svg,
+                    "<circle class=\"globe\" cx=\"{x:.1}\" cy=\"{y:.1}\" r=\"{:.1}\" fill=\"none\" stroke=\"#555555\" stroke-width=\"2\"/>",
+                    CELL * 0.35
+                );
+            }
+            if geometry.is_star(position) {
+                let _ = writeln!(
+                    svg,
+                    "<polygon class=\"star\" points=\"{}\" fill=\"#c9a227\"/>",
+                    star_points(x, y, CELL * 0.4)
+                );
+            }
+        }
+        for player_id in 0..geometry.players {
+            let color = self.player_hex(player_id);
+            for step in 0..geometry.home_column_length {
+                self.svg_square(&mut svg, Spot::Column(player_id, step), color, 0.6);
+            }
+            self.svg_square(&mut svg, Spot::Goal(player_id), color, 0.9);
+            for piece_id in 0..4 {
+                self.svg_square(&mut svg, Spot::Base(player_id, piece_id), "#ffffff", 1.0);
+            }
+        }
+        svg.push_str("</g>\n");
+        svg
+    }
+
+    pub fn svg_pieces(&self) -> String {
+        let geometry = self.geometry();
+        let mut svg = String::from("<g class=\"pieces\">\n");
+        for player_id in 0..geometry.players {
+            let home = &self.home[player_id as usize].pieces;
+            for piece in home.iter() {
+                self.svg_stack(
+                    &mut svg,
+                    Spot::Base(player_id, piece.piece_id),
+                    player_id,
+                    1,
+                );
+            }
+            let goal = self.goal[player_id as usize].pieces.len();
+            if goal > 0 {
+                self.svg_stack(&mut svg, Spot::Goal(player_id), player_id, goal);
+            }
+            for step in 0..geometry.home_column_length {
+                let position = geometry.home_column_start(player_id) + step;
+                let square = &self.inside[(position - geometry.track_length) as usize];
+                if let Some(piece) = square.pieces.first() {
+                    let spot = Spot::Column(player_id, step);
+                    self.svg_stack(&mut svg, spot, piece.player_id, square.pieces.len());
+                }
+            }
+        }
+        for position in 0..geometry.track_length {
+            let square = &self.outside[position as usize];
+            if let Some(piece) = square.pieces.first() {
+                let spot = Spot::Track(position);
+                self.svg_stack(&mut svg, spot, piece.player_id, square.pieces.len());
+            }
+        }
+        svg.push_str("</g>\n");
+        svg
+    }
+
+    fn player_hex(&self, player_id: i8) -> &'static str {
+        hex(self.piece(player_id, 0).color())
+    }
+
+    fn svg_square(&self, svg: &mut String, spot: Spot, fill: &str, opacity: f64) {
+        let (x, y) = self.spot_center(spot);
+        let side = CELL * 0.92;
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{side:.1}\" height=\"{side:.1}\" fill=\"{fill}\" fill-opacity=\"{opacity}\" stroke=\"#333333\"/>",
+            x - side / 2.0,
+            y - side / 2.0
+        );
+    }
+
+    fn svg_stack(&self, svg: &mut String, spot: Spot, player_id: i8, count: usize) {
+        let (x, y) = self.spot_center(spot);
+        let color = self.player_hex(player_id);
+        for layer in 0..count {
+            let offset = 3.0 * layer as f64;
+            let _ = writeln!(
+                svg,
+                "<circle class=\"piece\" cx=\"{:.1}\" cy=\"{:.1}\" r=\"{:.1}\" fill=\"{color}\" stroke=\"#000000\"/>",
+                x - offset,
+                y - offset,
+                CELL * 0.3
+            );
+        }
+        if count > 1 {
+            let offset = 3.0 * (count - 1) as f64;
+            let _ = writeln!(
+                svg,
+                "<text x=\"{:.1}\" y=\"{:.1}\" font-size=\"12\" font-family=\"sans-serif\" text-anchor=\"middle\" fill=\"#ffffff\">{count}</text>",
+                x - offset,
+                y - offset + 4.0
+            );
+        }
+    }
+
+    fn spot_center(&self, spot: Spot) -> (f64, f64) {
+        match is_cross(self.geometry()) {
+            true => {
+                let (row, col) = match spot {
+                    Spot::Track(position) => track_cells()[position as usize],
+                    Spot::Column(player_id, step) => column_cells(player_id)[step as usize],
+                    Spot::Goal(player_id) => goal_cell(player_id),
+                    Spot::Base(player_id, piece_id) => base_cells(player_id)[piece_id as usize],
+                };
+                (
+                    MARGIN + (col as f64 + 0.5) * CELL,
+                    MARGIN + (row as f64 + 0.5) * CELL,
+                )
+            }
+            false => self.ring_center(spot),
+        }
+    }
+
+    fn ring_radius(&self) -> f64 {
+        let geometry = self.geometry();
+        let around = geometry.track_length as f64 * CELL * 1.1 / (2.0 * PI);
+        let inward = (geometry.home_column_length + 2) as f64 * CELL;
+        around.max(inward)
+    }
+
+    fn ring_center(&self, spot: Spot) -> (f64, f64) {
+        let geometry = self.geometry();
+        let radius = self.ring_radius();
+        let middle = self.svg_size() / 2.0;
+        let angle =
+            |position: i8| 2.0 * PI * position as f64 / geometry.track_length as f64 - PI / 2.0;
+        let entry = |player_id: i8| geometry.entry_square(player_id).unwrap_or_default();
+        let (angle, reach, shift) = match spot {
+            Spot::Track(position) => (angle(position), radius, 0.0),
+            Spot::Column(player_id, step) => (
+                angle(entry(player_id)),
+                radius - (step + 1) as f64 * CELL,
+                0.0,
+            ),
+            Spot::Goal(player_id) => {
+                let steps = geometry.home_column_length + 1;
+                (angle(entry(player_id)), radius - steps as f64 * CELL, 0.0)
+            }
+            Spot::Base(player_id, piece_id) => {
+                let start = geometry.start_square(player_id).unwrap_or_default();
+                let reach = radius + (1.5 + (piece_id / 2) as f64) * CELL;
+                (angle(start), reach, ((piece_id % 2) as f64 - 0.5) * CELL)
+            }
+        };
+        (
+            middle + reach * angle.cos() - shift * angle.sin(),
+            middle + reach * angle.sin() + shift * angle.cos(),
+        )
+    }
+}
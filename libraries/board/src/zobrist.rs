@@ -0,0 +1,75 @@
+const PIECE_SEED: u64 = 0x5851_f42d_4c95_7f2d;
+const TURN_SEED: u64 = 0x1405_7b7e_f767_814f;
+
+fn mix(mut value: u64) -> u64 {
+    value = value.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    value ^ (value >> 31)
+}
+
+pub fn piece_key(player_id: i8, piece_id: i8, position: i8) -> u64 {
+    let index =
+        (player_id as u8 as u64) << 16 | (piece_id as u8 as u64) << 8 | position as u8 as u64;
+    mix(PIECE_SEED ^ index)
+}
+
+pub fn turn_key(player_id: i8) -> u64 {
+    mix(TURN_SEED ^ player_id as u8 as u64)
+}
+
+#[derive(Clone, Debug)]
+pub struct TranspositionTable<V> {
+    entries: Vec<Option<(u64, V)>>,
+    len: usize,
+}
+
+impl<V> TranspositionTable<V> {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        TranspositionTable {
+            entries: (0..capacity).map(|_| None).collect(),
+            len: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn insert(&mut self, key: u64, value: V) {
+        let slot = self.slot(key);
+        if self.entries[slot].is_none() {
+            self.len += 1;
+        }
+        self.entries[slot] = Some((key, value));
+    }
+
+    pub fn get(&self, key: u64) -> Option<&V> {
+        match &self.entries[self.slot(key)] {
+            Some((stored, value)) if *stored == key => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn contains(&self, key: u64) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.iter_mut().for_each(|entry| *entry = None);
+        self.len = 0;
+    }
+
+    fn slot(&self, key: u64) -> usize {
+        (key % self.entries.len() as u64) as usize
+    }
+}
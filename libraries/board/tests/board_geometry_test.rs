@@ -0,0 +1,114 @@
+use board::{Board, BoardGeometry};
+use errors::LudoError;
+
+#[cfg(test)]
+mod board_geometry_tests {
+    use super::*;
+
+    #[test]
+    fn standard_geometry_test() {
+        let geometry = BoardGeometry::standard();
+        assert_eq!(geometry, BoardGeometry::default());
+        assert_eq!(geometry.track_length, 52);
+        assert_eq!(geometry.start_squares, vec![0, 13, 26, 39]);
+        assert_eq!(geometry.globes, vec![8, 21, 34, 47]);
+        assert_eq!(geometry.stars, vec![5, 11, 18, 24, 31, 37, 44, 50]);
+        assert_eq!(geometry.pieces(), 4);
+        assert_eq!(geometry.last_position(), 71);
+        assert_eq!(geometry.goal_position(), 99);
+        assert_eq!(geometry.entry_square(0), Some(50));
+        assert_eq!(geometry.entry_square(1), Some(11));
+        assert_eq!(geometry.entry_square(4), None);
+        assert_eq!(geometry.home_column_start(3), 67);
+        assert_eq!(geometry.goal_square(3), 72);
+        assert!(!geometry.wraps_around(0));
+        assert!(geometry.wraps_around(1));
+        assert_eq!(geometry.next_star(50), 5);
+        assert_eq!(geometry.distance_to_goal(1, 0), Some(17));
+        assert_eq!(geometry.distance_to_goal(1, 12), None);
+        assert_eq!(geometry.distance_to_goal(2, 64), Some(3));
+    }
+
+    #[test]
+    fn standard_board_test() {
+        let board = Board::new();
+        assert_eq!(board.geometry(), &BoardGeometry::standard());
+        assert_eq!(board.home.len(), 4);
+        assert_eq!(board.goal.len(), 4);
+        assert_eq!(board.outside.len(), 52);
+        assert_eq!(board.inside.len(), 20);
+        assert_eq!(board.invincible, vec![0, 13, 26, 39]);
+        assert_eq!(board.goal[0].position, 99);
+    }
+
+    #[test]
+    fn six_player_board_test() {
+        let geometry = BoardGeometry::six_player();
+        assert_eq!(geometry.track_length, 78);
+        assert_eq!(geometry.start_squares, vec![0, 13, 26, 39, 52, 65]);
+        assert_eq!(geometry.stars.len(), 12);
+        assert_eq!(geometry.last_position(), 107);
+        assert_eq!(geometry.goal_position(), i8::MAX);
+
+        let mut board = Board::with_geometry(geometry).unwrap();
+        assert_eq!(board.home.len(), 6);
+        assert_eq!(board.outside.len(), 78);
+        assert_eq!(board.inside.len(), 30);
+        assert_eq!(board.piece_positions().len(), 6);
+        assert_eq!(board.player_pieces(5).len(), 4);
+        assert!(board.get_player_id(5).is_some());
+        assert!(board.is_star(76));
+        assert!(board.is_globe(73));
+
+        board.move_from_home(5, 0, 65).unwrap();
+        board.update_outside(5, 0, 65, 77).unwrap();
+        assert_eq!(board.outside(77).pieces.len(), 1);
+        assert_eq!(
+            board.move_from_home(6, 0, 0),
+            Err(LudoError::InvalidPlayer(6))
+        );
+    }
+
+    #[test]
+    fn six_player_positions_test() {
+        let positions = [
+            [-1, 5, 78, i8::MAX],
+            [-1; 4],
+            [-1; 4],
+            [-1; 4],
+            [99, -1, -1, -1],
+            [77, 107, -1, -1],
+        ];
+        let mut board =
+            Board::from_positions_with_geometry(BoardGeometry::six_player(), &positions).unwrap();
+        assert_eq!(board.piece_positions(), positions);
+        assert_eq!(board.inside(99).pieces.len(), 1);
+        assert!(!board.player_pieces(4)[0].is_goal());
+        assert!(board.player_pieces(0)[3].is_goal());
+        assert_eq!(board.goal(0).pieces.len(), 1);
+
+        board.reset();
+        assert!(board.piece_positions().iter().flatten().all(|&p| p == -1));
+    }
+
+    #[test]
+    fn invalid_geometry_test() {
+        assert_eq!(
+            Board::with_geometry(BoardGeometry::new(7)),
+            Err(LudoError::InvalidPlayer(7))
+        );
+        assert_eq!(
+            BoardGeometry::new(1).validate(),
+            Err(LudoError::InvalidPlayer(1))
+        );
+        let mut geometry = BoardGeometry::standard();
+        geometry.stars.push(60);
+        assert_eq!(geometry.validate(), Err(LudoError::InvalidPosition(60)));
+        let mut geometry = BoardGeometry::standard();
+        geometry.start_squares.pop();
+        assert!(matches!(
+            geometry.validate(),
+            Err(LudoError::IllegalAction(_))
+        ));
+    }
+}
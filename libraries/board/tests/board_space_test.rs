@@ -1,19 +1,17 @@
-use board::{Board, PlayerID};
-use pieces::{Color, Piece};
-use std::{cell::RefCell, rc::Rc};
+use board::{Board, PieceRef, PlayerID};
 
 mod board_space_tests {
     use super::*;
     #[test]
     fn board_home_spaces_test() {
         let mut board = Board::new();
-        let pieces = vec![
-            Rc::new(RefCell::new(Piece::new(0, Color::Red))),
-            Rc::new(RefCell::new(Piece::new(1, Color::Red))),
-            Rc::new(RefCell::new(Piece::new(2, Color::Red))),
-            Rc::new(RefCell::new(Piece::new(3, Color::Red))),
+        let pieces = [
+            PieceRef::new(0, 0),
+            PieceRef::new(0, 1),
+            PieceRef::new(0, 2),
+            PieceRef::new(0, 3),
         ];
-        let player_ids = vec![
+        let player_ids = [
             PlayerID::Player0,
             PlayerID::Player1,
             PlayerID::Player2,
@@ -1,7 +1,5 @@
-use board::{BoardState, PlayerID};
-use pieces::{Color, Piece};
+use board::{BoardState, PieceRef, PlayerID};
 use std::any::{Any, TypeId};
-use std::{cell::RefCell, rc::Rc};
 
 mod board_state_tests {
     use super::*;
@@ -25,10 +23,10 @@ mod board_state_tests {
     #[test]
     fn create_a_board_state_test_2() {
         let pieces = vec![
-            Rc::new(RefCell::new(Piece::new(0, Color::Green))),
-            Rc::new(RefCell::new(Piece::new(1, Color::Green))),
-            Rc::new(RefCell::new(Piece::new(2, Color::Green))),
-            Rc::new(RefCell::new(Piece::new(3, Color::Green))),
+            PieceRef::new(1, 0),
+            PieceRef::new(1, 1),
+            PieceRef::new(1, 2),
+            PieceRef::new(1, 3),
         ];
         let board_state = BoardState::new(-1, pieces.clone(), Some(PlayerID::Player1));
         assert_eq!(TypeId::of::<BoardState>(), board_state.type_id());
@@ -49,15 +47,15 @@ mod board_state_tests {
     #[test]
     fn pieces_test() {
         let pieces = vec![
-            Rc::new(RefCell::new(Piece::new(0, Color::Green))),
-            Rc::new(RefCell::new(Piece::new(1, Color::Green))),
-            Rc::new(RefCell::new(Piece::new(2, Color::Green))),
-            Rc::new(RefCell::new(Piece::new(3, Color::Green))),
+            PieceRef::new(0, 0),
+            PieceRef::new(0, 1),
+            PieceRef::new(0, 2),
+            PieceRef::new(0, 3),
         ];
-        let mut board_state = BoardState::new(-1, pieces.clone(), Some(PlayerID::Player0));
+        let board_state = BoardState::new(-1, pieces.clone(), Some(PlayerID::Player0));
         for i in 0..4 {
             assert_eq!(board_state.piece(i), pieces[i as usize]);
-            assert_eq!(board_state.piece(i).borrow().id(), i);
+            assert_eq!(board_state.piece(i).piece_id, i);
         }
     }
 }
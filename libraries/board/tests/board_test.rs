@@ -15,4 +15,24 @@ mod board_tests {
         let board = Board::default();
         assert_eq!(TypeId::of::<Board>(), board.type_id());
     }
+
+    #[test]
+    fn board_from_positions_test() {
+        let positions = [
+            [-1, 5, 52, 99],
+            [13, 13, -1, -1],
+            [-1, -1, -1, -1],
+            [40, -1, 71, -1],
+        ];
+        let mut board = Board::from_positions(positions).unwrap();
+        assert_eq!(board.piece_positions(), positions);
+        assert_eq!(board.home(0).pieces.len(), 1);
+        assert_eq!(board.goal(0).pieces.len(), 1);
+        assert_eq!(board.inside(52).pieces.len(), 1);
+        assert_eq!(board.outside(13).pieces.len(), 2);
+        assert!(board.is_occupied_by_more_self(1, 13));
+        assert!(board.is_occupied_by_other(0, 40));
+        assert_eq!(board.player_pieces(3).len(), 4);
+        assert!(board.player_pieces(0)[3].is_goal());
+    }
 }
@@ -1,4 +1,5 @@
 use board::{Board, PlayerID};
+use errors::LudoError;
 use rand::{thread_rng, Rng};
 
 #[cfg(test)]
@@ -12,7 +13,9 @@ mod board_update_test {
         let player_id = 0;
         let new_position = 0;
 
-        board.move_from_home(player_id, piece_id, new_position);
+        board
+            .move_from_home(player_id, piece_id, new_position)
+            .unwrap();
         assert_eq!(board.home(player_id).pieces.len(), 3);
         assert_eq!(board.outside(new_position).pieces.len(), 1);
         assert_eq!(board.invincible(new_position).pieces.len(), 1);
@@ -20,13 +23,11 @@ mod board_update_test {
             board.outside(new_position).player_id,
             Some(PlayerID::Player0)
         );
-        assert_eq!(board.outside(new_position).pieces[0].borrow().id(), 0);
-        assert_eq!(board.outside(new_position).piece(piece_id).borrow().id(), 0);
+        assert_eq!(board.outside(new_position).pieces[0].piece_id, 0);
+        assert_eq!(board.outside(new_position).piece(piece_id).piece_id, 0);
         assert_eq!(
             !board
-                .outside(new_position)
-                .piece(piece_id)
-                .borrow()
+                .piece_at(board.square(new_position).piece(piece_id))
                 .position(),
             0
         );
@@ -38,8 +39,10 @@ mod board_update_test {
         for piece_id in 0..4 {
             let player_id = 0;
             let new_position = 0;
-            board.move_from_home(player_id, piece_id, new_position);
-            assert_eq!(board.outside(0).piece(piece_id).borrow().id(), piece_id);
+            board
+                .move_from_home(player_id, piece_id, new_position)
+                .unwrap();
+            assert_eq!(board.outside(0).piece(piece_id).piece_id, piece_id);
         }
         assert!(board.home(0).pieces.is_empty());
         assert_eq!(board.home(0).player_id, None);
@@ -51,7 +54,7 @@ mod board_update_test {
     #[test]
     fn move_all_from_home_test2() {
         let mut board = Board::new();
-        let player_ids = vec![
+        let player_ids = [
             PlayerID::Player0,
             PlayerID::Player1,
             PlayerID::Player2,
@@ -60,13 +63,15 @@ mod board_update_test {
         for player_id in 0..4 {
             for piece_id in 0..4 {
                 let new_position = board.invincible[player_id as usize] as i8;
-                board.move_from_home(player_id, piece_id, new_position);
+                board
+                    .move_from_home(player_id, piece_id, new_position)
+                    .unwrap();
                 assert_eq!(
-                    board.outside(new_position).piece(piece_id).borrow().id(),
+                    board.outside(new_position).piece(piece_id).piece_id,
                     piece_id
                 );
                 assert_eq!(
-                    board.invincible(player_id).piece(piece_id).borrow().id(),
+                    board.invincible(player_id).piece(piece_id).piece_id,
                     piece_id
                 );
             }
@@ -95,7 +100,9 @@ mod board_update_test {
         let player_id = 0;
         let new_position = 0;
 
-        board.move_from_home(player_id, piece_id, new_position);
+        board
+            .move_from_home(player_id, piece_id, new_position)
+            .unwrap();
         assert_eq!(board.home(player_id).pieces.len(), 3);
         assert_eq!(board.home(player_id).player_id, Some(PlayerID::Player0));
         assert_eq!(board.outside(new_position).pieces.len(), 1);
@@ -105,7 +112,9 @@ mod board_update_test {
         );
 
         let old_position = 0;
-        board.move_into_home(piece_id, old_position, old_position);
+        board
+            .move_into_home(piece_id, old_position, old_position)
+            .unwrap();
         assert_eq!(board.home(player_id).pieces.len(), 4);
         assert_eq!(board.home(player_id).player_id, Some(PlayerID::Player0));
         assert_eq!(board.outside(old_position).pieces.len(), 0);
@@ -118,8 +127,10 @@ mod board_update_test {
         let player_id = 0;
         for piece_id in 0..4 {
             let new_position = 0;
-            board.move_from_home(player_id, piece_id, new_position);
-            assert_eq!(board.outside(0).piece(piece_id).borrow().id(), piece_id);
+            board
+                .move_from_home(player_id, piece_id, new_position)
+                .unwrap();
+            assert_eq!(board.outside(0).piece(piece_id).piece_id, piece_id);
         }
         assert!(board.home(player_id).pieces.is_empty());
         assert_eq!(board.home(player_id).player_id, None);
@@ -129,7 +140,7 @@ mod board_update_test {
 
         for piece_id in 0..4 {
             let old_position = 0;
-            board.move_into_home(0, piece_id, old_position);
+            board.move_into_home(0, piece_id, old_position).unwrap();
             assert_eq!(board.home(player_id).pieces.len(), (piece_id as usize) + 1);
             assert_eq!(board.home(player_id).player_id, Some(PlayerID::Player0));
             assert_eq!(
@@ -146,7 +157,7 @@ mod board_update_test {
     #[test]
     fn move_all_into_home_test_2() {
         let mut board = Board::new();
-        let player_ids = vec![
+        let player_ids = [
             PlayerID::Player0,
             PlayerID::Player1,
             PlayerID::Player2,
@@ -155,13 +166,15 @@ mod board_update_test {
         for player_id in 0..4 {
             let new_position = board.invincible[player_id as usize] as i8;
             for piece_id in 0..4 {
-                board.move_from_home(player_id, piece_id, new_position);
+                board
+                    .move_from_home(player_id, piece_id, new_position)
+                    .unwrap();
                 assert_eq!(
-                    board.outside(new_position).piece(piece_id).borrow().id(),
+                    board.outside(new_position).piece(piece_id).piece_id,
                     piece_id
                 );
                 assert_eq!(
-                    board.invincible(player_id).piece(piece_id).borrow().id(),
+                    board.invincible(player_id).piece(piece_id).piece_id,
                     piece_id
                 );
             }
@@ -176,7 +189,9 @@ mod board_update_test {
 
             let old_position = new_position;
             for piece_id in 0..4 {
-                board.move_into_home(player_id, piece_id, old_position);
+                board
+                    .move_into_home(player_id, piece_id, old_position)
+                    .unwrap();
                 assert_eq!(board.home(player_id).pieces.len(), (piece_id as usize) + 1);
                 assert_eq!(
                     board.home(player_id).player_id,
@@ -205,25 +220,33 @@ mod board_update_test {
         let player_id = 0;
         let mut new_position = 0;
 
-        board.move_from_home(player_id, piece_id, new_position);
+        board
+            .move_from_home(player_id, piece_id, new_position)
+            .unwrap();
         assert_eq!(board.outside(new_position).pieces.len(), 1);
         assert_ne!(board.outside(1).pieces.len(), 1);
 
         let mut old_position = new_position;
         new_position = 1;
-        board.update_outside(player_id, piece_id, old_position, new_position);
+        board
+            .update_outside(player_id, piece_id, old_position, new_position)
+            .unwrap();
         assert_eq!(board.outside(new_position).pieces.len(), 1);
         assert_ne!(board.outside(old_position).pieces.len(), 1);
 
         old_position = new_position;
         new_position = 9;
-        board.update_outside(player_id, piece_id, old_position, new_position);
+        board
+            .update_outside(player_id, piece_id, old_position, new_position)
+            .unwrap();
         assert_eq!(board.outside(new_position).pieces.len(), 1);
         assert_ne!(board.outside(old_position).pieces.len(), 1);
 
         old_position = new_position;
         new_position = 10;
-        board.update_outside(player_id, piece_id, old_position, new_position);
+        board
+            .update_outside(player_id, piece_id, old_position, new_position)
+            .unwrap();
         assert_eq!(board.outside(new_position).pieces.len(), 1);
         assert_ne!(board.outside(old_position).pieces.len(), 1);
     }
@@ -234,7 +257,9 @@ mod board_update_test {
         for player_id in 0..4 {
             let start_position = board.invincible[player_id as usize] as i8;
             for piece_id in 0..4 {
-                board.move_from_home(player_id, piece_id, start_position);
+                board
+                    .move_from_home(player_id, piece_id, start_position)
+                    .unwrap();
                 assert_eq!(
                     board.outside(start_position).pieces.len(),
                     piece_id as usize + 1
@@ -244,7 +269,9 @@ mod board_update_test {
             let mut old_position = start_position;
             let mut new_position = old_position + 1;
             for piece_id in 0..4 {
-                board.update_outside(player_id, piece_id, old_position, new_position);
+                board
+                    .update_outside(player_id, piece_id, old_position, new_position)
+                    .unwrap();
                 assert_eq!(
                     board.outside(new_position).pieces.len(),
                     piece_id as usize + 1
@@ -254,7 +281,9 @@ mod board_update_test {
             old_position = new_position;
             new_position = old_position + 9;
             for piece_id in 0..4 {
-                board.update_outside(player_id, piece_id, old_position, new_position);
+                board
+                    .update_outside(player_id, piece_id, old_position, new_position)
+                    .unwrap();
                 assert_eq!(
                     board.outside(new_position).pieces.len(),
                     piece_id as usize + 1
@@ -264,7 +293,9 @@ mod board_update_test {
             old_position = new_position;
             new_position = old_position + 10;
             for piece_id in 0..4 {
-                board.update_outside(player_id, piece_id, old_position, new_position);
+                board
+                    .update_outside(player_id, piece_id, old_position, new_position)
+                    .unwrap();
                 assert_eq!(
                     board.outside(new_position).pieces.len(),
                     piece_id as usize + 1
@@ -277,7 +308,7 @@ mod board_update_test {
     #[test]
     fn update_outside_test_3() {
         let mut board = Board::new();
-        let player_ids = vec![
+        let player_ids = [
             PlayerID::Player0,
             PlayerID::Player1,
             PlayerID::Player2,
@@ -286,7 +317,9 @@ mod board_update_test {
         for player_id in 0..4 {
             let start_position = board.invincible[player_id as usize] as i8;
             for piece_id in 0..4 {
-                board.move_from_home(player_id, piece_id, start_position);
+                board
+                    .move_from_home(player_id, piece_id, start_position)
+                    .unwrap();
                 assert_eq!(
                     board.outside(start_position).pieces.len(),
                     piece_id as usize + 1
@@ -297,7 +330,9 @@ mod board_update_test {
             for _ in 0..52 {
                 let new_position = old_position + 1;
                 for piece_id in 0..4 {
-                    board.update_outside(player_id, piece_id, old_position, new_position);
+                    board
+                        .update_outside(player_id, piece_id, old_position, new_position)
+                        .unwrap();
                     assert_eq!(
                         board.outside(new_position).player_id,
                         Some(player_ids[player_id as usize].clone())
@@ -316,7 +351,9 @@ mod board_update_test {
             }
 
             for piece_id in 0..4 {
-                board.move_into_home(player_id, piece_id, old_position);
+                board
+                    .move_into_home(player_id, piece_id, old_position)
+                    .unwrap();
                 assert_eq!(
                     board.outside(old_position).pieces.len(),
                     3 - (piece_id as usize)
@@ -334,22 +371,25 @@ mod board_update_test {
         let player_id = 0;
         let mut new_position = 0;
 
-        board.move_from_home(player_id, piece_id, new_position);
+        board
+            .move_from_home(player_id, piece_id, new_position)
+            .unwrap();
 
         let mut old_position = new_position;
         new_position = 51;
 
-        board.update_outside(player_id, piece_id, old_position, new_position);
+        board
+            .update_outside(player_id, piece_id, old_position, new_position)
+            .unwrap();
 
         old_position = new_position;
         new_position = 52;
-        board.move_inside(player_id, piece_id, old_position, new_position);
+        board
+            .move_inside(player_id, piece_id, old_position, new_position)
+            .unwrap();
 
         assert_eq!(board.inside(new_position).pieces.len(), 1);
-        assert_eq!(
-            board.inside(new_position).piece(piece_id).borrow_mut().id(),
-            0
-        );
+        assert_eq!(board.inside(new_position).piece(piece_id).piece_id, 0);
         assert_eq!(board.inside(new_position).position, 52);
         assert_eq!(board.outside(old_position).pieces.len(), 0);
     }
@@ -361,22 +401,25 @@ mod board_update_test {
         let player_id = 3;
         let mut new_position = 0;
 
-        board.move_from_home(player_id, piece_id, new_position);
+        board
+            .move_from_home(player_id, piece_id, new_position)
+            .unwrap();
 
         let mut old_position = new_position;
         new_position = 51;
 
-        board.update_outside(player_id, piece_id, old_position, new_position);
+        board
+            .update_outside(player_id, piece_id, old_position, new_position)
+            .unwrap();
 
         old_position = new_position;
         new_position = 67;
-        board.move_inside(player_id, piece_id, old_position, new_position);
+        board
+            .move_inside(player_id, piece_id, old_position, new_position)
+            .unwrap();
 
         assert_eq!(board.inside(new_position).pieces.len(), 1);
-        assert_eq!(
-            board.inside(new_position).piece(piece_id).borrow_mut().id(),
-            3
-        );
+        assert_eq!(board.inside(new_position).piece(piece_id).piece_id, 3);
         assert_eq!(
             board.inside(new_position).player_id,
             Some(PlayerID::Player3)
@@ -392,20 +435,26 @@ mod board_update_test {
         for player_id in 0..4 {
             let mut new_position = board.invincible[player_id as usize] as i8;
 
-            board.move_from_home(player_id, piece_id, new_position);
+            board
+                .move_from_home(player_id, piece_id, new_position)
+                .unwrap();
 
             let mut old_position = new_position;
             new_position = 51;
 
-            board.update_outside(player_id, piece_id, old_position, new_position);
+            board
+                .update_outside(player_id, piece_id, old_position, new_position)
+                .unwrap();
 
             old_position = new_position;
             new_position = 52 + player_id * 5;
-            board.move_inside(player_id, piece_id, old_position, new_position);
+            board
+                .move_inside(player_id, piece_id, old_position, new_position)
+                .unwrap();
 
             assert_eq!(board.inside(new_position).pieces.len(), 1);
             assert_eq!(
-                board.inside(new_position).piece(piece_id).borrow_mut().id(),
+                board.inside(new_position).piece(piece_id).piece_id,
                 piece_id
             );
             assert_eq!(board.inside(new_position).position, 52 + player_id * 5);
@@ -420,26 +469,31 @@ mod board_update_test {
         let player_id = 0;
         let mut new_position = 0;
 
-        board.move_from_home(player_id, piece_id, new_position);
+        board
+            .move_from_home(player_id, piece_id, new_position)
+            .unwrap();
 
         let mut old_position = new_position;
         new_position = 51;
-        board.update_outside(player_id, piece_id, old_position, new_position);
+        board
+            .update_outside(player_id, piece_id, old_position, new_position)
+            .unwrap();
 
         old_position = new_position;
         new_position = 52;
-        board.move_inside(player_id, piece_id, old_position, new_position);
+        board
+            .move_inside(player_id, piece_id, old_position, new_position)
+            .unwrap();
 
         old_position = new_position;
         new_position = 56;
-        board.update_inside(player_id, piece_id, old_position, new_position);
+        board
+            .update_inside(player_id, piece_id, old_position, new_position)
+            .unwrap();
 
         assert_eq!(board.inside(new_position).pieces.len(), 1);
         assert_eq!(board.inside(new_position).position, 56);
-        assert_eq!(
-            board.inside(new_position).piece(piece_id).borrow_mut().id(),
-            0
-        );
+        assert_eq!(board.inside(new_position).piece(piece_id).piece_id, 0);
         assert_eq!(
             board.inside(new_position).player_id,
             Some(PlayerID::Player0)
@@ -452,7 +506,7 @@ mod board_update_test {
     fn update_all_inside_test() {
         let mut board = Board::new();
         let piece_id = 0;
-        let player_ids = vec![
+        let player_ids = [
             PlayerID::Player0,
             PlayerID::Player1,
             PlayerID::Player2,
@@ -461,29 +515,34 @@ mod board_update_test {
 
         for player_id in 0..4 {
             let mut new_position = board.invincible[player_id as usize] as i8;
-            board.move_from_home(player_id, piece_id, new_position);
+            board
+                .move_from_home(player_id, piece_id, new_position)
+                .unwrap();
 
             let mut old_position = new_position;
             new_position = 51;
-            board.update_outside(player_id, piece_id, old_position, new_position);
+            board
+                .update_outside(player_id, piece_id, old_position, new_position)
+                .unwrap();
 
             old_position = new_position;
             new_position = 52 + player_id * 5;
-            board.move_inside(player_id, piece_id, old_position, new_position);
+            board
+                .move_inside(player_id, piece_id, old_position, new_position)
+                .unwrap();
 
             for i in 0..4 {
                 old_position = new_position;
                 new_position += 1;
-                board.update_inside(player_id, piece_id, old_position, new_position);
+                board
+                    .update_inside(player_id, piece_id, old_position, new_position)
+                    .unwrap();
                 assert_eq!(board.inside(new_position).pieces.len(), 1);
                 assert_eq!(
                     board.inside(new_position).position,
                     52 + player_id * 5 + i + 1
                 );
-                assert_eq!(
-                    board.inside(new_position).piece(piece_id).borrow_mut().id(),
-                    0
-                );
+                assert_eq!(board.inside(new_position).piece(piece_id).piece_id, 0);
                 assert_eq!(
                     board.inside(new_position).player_id,
                     Some(player_ids[player_id as usize].clone())
@@ -495,16 +554,15 @@ mod board_update_test {
             for i in 0..4 {
                 old_position = new_position;
                 new_position -= 1;
-                board.update_inside(player_id, piece_id, old_position, new_position);
+                board
+                    .update_inside(player_id, piece_id, old_position, new_position)
+                    .unwrap();
                 assert_eq!(board.inside(new_position).pieces.len(), 1);
                 assert_eq!(
                     board.inside(new_position).position,
                     56 + player_id * 5 - i - 1
                 );
-                assert_eq!(
-                    board.inside(new_position).piece(piece_id).borrow_mut().id(),
-                    0
-                );
+                assert_eq!(board.inside(new_position).piece(piece_id).piece_id, 0);
                 assert_eq!(
                     board.inside(new_position).player_id,
                     Some(player_ids[player_id as usize].clone())
@@ -522,33 +580,43 @@ mod board_update_test {
         let player_id = 0;
         let mut new_position = 0;
 
-        board.move_from_home(player_id, piece_id, new_position);
+        board
+            .move_from_home(player_id, piece_id, new_position)
+            .unwrap();
 
         let mut old_position = new_position;
         new_position = 50;
-        board.update_outside(player_id, piece_id, old_position, new_position);
+        board
+            .update_outside(player_id, piece_id, old_position, new_position)
+            .unwrap();
 
         old_position = new_position;
-        board.enter_goal(player_id, piece_id, old_position);
+        board.enter_goal(player_id, piece_id, old_position).unwrap();
 
         assert_eq!(board.goal(player_id).pieces.len(), 1);
 
         let piece_id = 1;
         new_position = 0;
-        board.move_from_home(player_id, piece_id, new_position);
+        board
+            .move_from_home(player_id, piece_id, new_position)
+            .unwrap();
 
         old_position = new_position;
         new_position = 50;
-        board.update_outside(player_id, piece_id, old_position, new_position);
+        board
+            .update_outside(player_id, piece_id, old_position, new_position)
+            .unwrap();
 
         old_position = new_position;
         new_position = 56;
-        board.move_inside(player_id, piece_id, old_position, new_position);
+        board
+            .move_inside(player_id, piece_id, old_position, new_position)
+            .unwrap();
 
         assert_eq!(board.inside(new_position).pieces.len(), 1);
 
         old_position = new_position;
-        board.enter_goal(player_id, piece_id, old_position);
+        board.enter_goal(player_id, piece_id, old_position).unwrap();
         assert_eq!(board.goal(player_id).pieces.len(), 2);
     }
 
@@ -559,102 +627,127 @@ mod board_update_test {
         for player_id in 0..4 {
             let piece_id = 0;
             let mut new_position = board.invincible[player_id as usize] as i8;
-            board.move_from_home(player_id, piece_id, new_position);
+            board
+                .move_from_home(player_id, piece_id, new_position)
+                .unwrap();
 
             let mut old_position = new_position;
             new_position = (old_position + 50) % 52;
-            board.update_outside(player_id, piece_id, old_position, new_position);
+            board
+                .update_outside(player_id, piece_id, old_position, new_position)
+                .unwrap();
 
             old_position = new_position;
-            board.enter_goal(player_id, piece_id, old_position);
+            board.enter_goal(player_id, piece_id, old_position).unwrap();
 
             assert_eq!(board.goal(player_id).pieces.len(), 1);
 
             let piece_id = 1;
             new_position = board.invincible[player_id as usize] as i8;
-            board.move_from_home(player_id, piece_id, new_position);
+            board
+                .move_from_home(player_id, piece_id, new_position)
+                .unwrap();
 
             old_position = new_position;
             new_position = (old_position + 50) % 52;
-            board.update_outside(player_id, piece_id, old_position, new_position);
+            board
+                .update_outside(player_id, piece_id, old_position, new_position)
+                .unwrap();
 
             old_position = new_position;
             new_position = 51 + 5 * (player_id + 1);
-            board.move_inside(player_id, piece_id, old_position, new_position);
+            board
+                .move_inside(player_id, piece_id, old_position, new_position)
+                .unwrap();
 
             assert_eq!(board.inside(new_position).pieces.len(), 1);
 
             old_position = new_position;
-            board.enter_goal(player_id, piece_id, old_position);
+            board.enter_goal(player_id, piece_id, old_position).unwrap();
             assert_eq!(board.goal(player_id).pieces.len(), 2);
         }
     }
 
     #[test]
-    #[should_panic]
     fn enter_goal_error_test() {
         let mut board = Board::new();
         let piece_id = 0;
         let player_id = 0;
         let old_position = -1;
-        board.enter_goal(player_id, piece_id, old_position);
+        assert_eq!(
+            board.enter_goal(player_id, piece_id, old_position),
+            Err(LudoError::InvalidPosition(-1))
+        );
     }
 
     #[test]
-    #[should_panic]
     fn enter_goal_error_2_test() {
         let mut board = Board::new();
         let piece_id = 0;
         let player_id = 0;
-        board.move_from_home(player_id, piece_id, 0);
+        board.move_from_home(player_id, piece_id, 0).unwrap();
         let old_position = -1;
-        board.enter_goal(player_id, piece_id, old_position);
+        assert_eq!(
+            board.enter_goal(player_id, piece_id, old_position),
+            Err(LudoError::InvalidPosition(-1))
+        );
     }
 
     #[test]
-    #[should_panic]
     fn enter_goal_error_3_test() {
         let mut board = Board::new();
         let piece_id = 0;
         let player_id = 0;
         let position = 0;
-        board.move_from_home(player_id, piece_id, position);
+        board.move_from_home(player_id, piece_id, position).unwrap();
         let position = 72;
-        board.enter_goal(player_id, piece_id, position);
+        assert_eq!(
+            board.enter_goal(player_id, piece_id, position),
+            Err(LudoError::InvalidPosition(72))
+        );
     }
 
     #[test]
-    #[should_panic]
     fn enter_goal_error_4_test() {
         let mut board = Board::new();
         let piece_id = 0;
         let player_id = 0;
         let position = 0;
-        board.move_from_home(player_id, piece_id, position);
+        board.move_from_home(player_id, piece_id, position).unwrap();
         let position = 99;
-        board.enter_goal(player_id, piece_id, position);
+        assert_eq!(
+            board.enter_goal(player_id, piece_id, position),
+            Err(LudoError::InvalidPosition(99))
+        );
     }
 
     #[test]
-    #[should_panic]
     fn enter_goal_error_5_test() {
         let mut board = Board::new();
         let piece_id = 0;
         let player_id = 0;
         let position = 0;
-        board.move_from_home(player_id, piece_id, position);
+        board.move_from_home(player_id, piece_id, position).unwrap();
         let position = 98;
-        board.enter_goal(player_id, piece_id, position);
+        assert_eq!(
+            board.enter_goal(player_id, piece_id, position),
+            Err(LudoError::InvalidPosition(98))
+        );
     }
 
     #[test]
-    #[should_panic]
     fn enter_goal_error_6_test() {
         let mut board = Board::new();
         let piece_id = 0;
         let player_id = 0;
         let old_position = 0;
-        board.enter_goal(player_id, piece_id, old_position);
+        assert_eq!(
+            board.enter_goal(player_id, piece_id, old_position),
+            Err(LudoError::PieceNotFound {
+                piece_id: 0,
+                position: 0
+            })
+        );
     }
 
     #[test]
@@ -664,10 +757,14 @@ mod board_update_test {
         let new_position = 50;
         for player_id in 0..4 {
             for piece_id in 0..4 {
-                board.move_from_home(player_id, piece_id, start_position);
-                board.update_outside(player_id, piece_id, start_position, new_position);
+                board
+                    .move_from_home(player_id, piece_id, start_position)
+                    .unwrap();
+                board
+                    .update_outside(player_id, piece_id, start_position, new_position)
+                    .unwrap();
 
-                board.enter_goal(player_id, piece_id, new_position);
+                board.enter_goal(player_id, piece_id, new_position).unwrap();
             }
             assert_eq!(board.goal(player_id).pieces.len(), 4);
             start_position += 13;
@@ -680,12 +777,16 @@ mod board_update_test {
         let piece_id: i8 = 0;
         let player_id: i8 = 0;
         let new_position: i8 = 0;
-        board.move_from_home(player_id, piece_id, new_position);
+        board
+            .move_from_home(player_id, piece_id, new_position)
+            .unwrap();
         assert!(board.is_occupied_self(player_id, new_position));
 
         let old_position = new_position;
         let new_position = 4;
-        board.update_outside(player_id, piece_id, old_position, new_position);
+        board
+            .update_outside(player_id, piece_id, old_position, new_position)
+            .unwrap();
         assert!(board.is_occupied_self(player_id, new_position));
         assert!(!board.is_occupied_self(player_id, old_position));
     }
@@ -698,17 +799,25 @@ mod board_update_test {
         let new_position: i8 = 0;
         let mut board = Board::new();
 
-        board.move_from_home(player_id, piece_1, new_position);
-        board.move_from_home(player_id, piece_2, new_position);
+        board
+            .move_from_home(player_id, piece_1, new_position)
+            .unwrap();
+        board
+            .move_from_home(player_id, piece_2, new_position)
+            .unwrap();
         assert!(board.is_occupied_by_more_self(player_id, new_position));
 
         let old_position = new_position;
         let new_position = 4;
-        board.update_outside(player_id, piece_1, old_position, new_position);
+        board
+            .update_outside(player_id, piece_1, old_position, new_position)
+            .unwrap();
         assert!(!board.is_occupied_by_more_self(player_id, new_position));
         assert!(!board.is_occupied_by_more_self(player_id, old_position));
 
-        board.update_outside(player_id, piece_2, old_position, new_position);
+        board
+            .update_outside(player_id, piece_2, old_position, new_position)
+            .unwrap();
         assert!(board.is_occupied_by_more_self(player_id, new_position));
     }
 
@@ -720,11 +829,15 @@ mod board_update_test {
         let player_1: i8 = 1;
         let new_position: i8 = 0;
 
-        board.move_from_home(player_0, piece_id, new_position);
+        board
+            .move_from_home(player_0, piece_id, new_position)
+            .unwrap();
         assert!(board.is_occupied_by_other(player_1, new_position));
 
         let new_position: i8 = 4;
-        board.move_from_home(player_1, piece_id, new_position);
+        board
+            .move_from_home(player_1, piece_id, new_position)
+            .unwrap();
         assert!(board.is_occupied_by_other(player_0, new_position));
         assert!(!board.is_occupied_by_other(player_0, new_position + 1));
     }
@@ -738,13 +851,21 @@ mod board_update_test {
         let player_1: i8 = 1;
         let new_position: i8 = 0;
 
-        board.move_from_home(player_0, piece_0, new_position);
-        board.move_from_home(player_0, piece_1, new_position);
+        board
+            .move_from_home(player_0, piece_0, new_position)
+            .unwrap();
+        board
+            .move_from_home(player_0, piece_1, new_position)
+            .unwrap();
         assert!(board.is_occupied_by_other_more(player_1, new_position));
 
         let new_position: i8 = 4;
-        board.move_from_home(player_1, piece_0, new_position);
-        board.move_from_home(player_1, piece_1, new_position);
+        board
+            .move_from_home(player_1, piece_0, new_position)
+            .unwrap();
+        board
+            .move_from_home(player_1, piece_1, new_position)
+            .unwrap();
         assert!(board.is_occupied_by_other_more(player_0, new_position));
         assert!(!board.is_occupied_by_other_more(player_0, new_position + 1));
     }
@@ -756,58 +877,45 @@ mod board_update_test {
         let player_id: i8 = 0;
         let new_position: i8 = 0;
 
-        board.home(player_id).piece(piece_id).borrow_mut().free();
+        board.piece_mut(player_id, piece_id).free();
+        board
+            .piece_mut(player_id, piece_id)
+            .set_position(new_position)
+            .unwrap();
         board
-            .home(player_id)
-            .piece(piece_id)
-            .borrow_mut()
-            .set_position(new_position);
-        board.move_from_home(player_id, piece_id, new_position);
+            .move_from_home(player_id, piece_id, new_position)
+            .unwrap();
         assert_eq!(board.outside(new_position).pieces.len(), 1);
         assert_eq!(
-            board
-                .outside(new_position)
-                .piece(piece_id)
-                .borrow_mut()
-                .id(),
+            board.outside(new_position).piece(piece_id).piece_id,
             piece_id
         );
         assert!(!board
-            .outside(new_position)
-            .piece(piece_id)
-            .borrow_mut()
+            .piece_at(board.square(new_position).piece(piece_id))
             .is_home());
         assert!(board
-            .outside(new_position)
-            .piece(piece_id)
-            .borrow_mut()
+            .piece_at(board.square(new_position).piece(piece_id))
             .is_free());
         assert_eq!(
             board
-                .outside(new_position)
-                .piece(piece_id)
-                .borrow_mut()
+                .piece_at(board.square(new_position).piece(piece_id))
                 .position(),
             0
         );
 
         let old_position = new_position;
         let new_position = 4;
-        board.update_outside(player_id, piece_id, old_position, new_position);
+        board
+            .update_outside(player_id, piece_id, old_position, new_position)
+            .unwrap();
         assert_eq!(board.outside(old_position).pieces.len(), 0);
         assert_eq!(board.outside(new_position).pieces.len(), 1);
         assert_eq!(
-            board
-                .outside(new_position)
-                .piece(piece_id)
-                .borrow_mut()
-                .id(),
+            board.outside(new_position).piece(piece_id).piece_id,
             piece_id
         );
         assert!(board
-            .outside(new_position)
-            .piece(piece_id)
-            .borrow()
+            .piece_at(board.square(new_position).piece(piece_id))
             .is_free());
     }
 
@@ -818,23 +926,33 @@ mod board_update_test {
         for player_id in 0..4 {
             let new_position: i8 = board.invincible[player_id as usize] as i8;
             for piece_id in 0..4 {
-                board.move_from_home(player_id, piece_id, new_position)
+                board
+                    .move_from_home(player_id, piece_id, new_position)
+                    .unwrap();
             }
 
             let mut old_position1 = new_position;
             let mut old_position2 = new_position;
-            board.enter_goal(player_id, 3, old_position1);
+            board.enter_goal(player_id, 3, old_position1).unwrap();
             let new_position = 52 + player_id * 5;
-            board.move_inside(player_id, 0, old_position1, new_position);
+            board
+                .move_inside(player_id, 0, old_position1, new_position)
+                .unwrap();
             let mut old_inside_position = new_position;
 
             for _ in 0..10 {
                 let new_position1 = rng.gen_range(0..=51);
                 let new_position2 = rng.gen_range(0..=51);
                 let new_inside_position = rng.gen_range(52..72);
-                board.update_outside(player_id, 1, old_position1, new_position1);
-                board.update_outside(player_id, 2, old_position2, new_position2);
-                board.update_inside(player_id, 0, old_inside_position, new_inside_position);
+                board
+                    .update_outside(player_id, 1, old_position1, new_position1)
+                    .unwrap();
+                board
+                    .update_outside(player_id, 2, old_position2, new_position2)
+                    .unwrap();
+                board
+                    .update_inside(player_id, 0, old_inside_position, new_inside_position)
+                    .unwrap();
                 old_position1 = new_position1;
                 old_position2 = new_position2;
                 old_inside_position = new_inside_position;
@@ -842,7 +960,7 @@ mod board_update_test {
         }
 
         board.reset();
-        let player_ids = vec![
+        let player_ids = [
             PlayerID::Player0,
             PlayerID::Player1,
             PlayerID::Player2,
@@ -851,15 +969,18 @@ mod board_update_test {
         for i in 0..4 {
             assert_eq!(board.home(i).pieces.len(), 4);
             for j in 0..4 {
-                assert_eq!(board.home(i).piece(j).borrow().id(), j);
+                assert_eq!(board.home(i).piece(j).piece_id, j);
                 assert_eq!(
                     board.home(i).player_id,
                     Some(player_ids[i as usize].clone())
                 );
-                assert!(board.home(i).piece(j).borrow().is_home());
-                assert!(!board.home(i).piece(j).borrow().is_free());
-                assert!(!board.home(i).piece(j).borrow().is_goal());
-                assert_eq!(board.home(i).piece(j).borrow().position(), -1);
+                assert!(board.piece_at(board.home[i as usize].piece(j)).is_home());
+                assert!(!board.piece_at(board.home[i as usize].piece(j)).is_free());
+                assert!(!board.piece_at(board.home[i as usize].piece(j)).is_goal());
+                assert_eq!(
+                    board.piece_at(board.home[i as usize].piece(j)).position(),
+                    -1
+                );
             }
         }
     }
@@ -871,55 +992,56 @@ mod board_update_test {
         for player_id in 0..4 {
             let new_position: i8 = board.invincible[player_id as usize] as i8;
             for piece_id in 0..4 {
-                board.move_from_home(player_id, piece_id, new_position);
                 board
-                    .outside(new_position)
-                    .piece(piece_id)
-                    .borrow_mut()
-                    .free();
+                    .move_from_home(player_id, piece_id, new_position)
+                    .unwrap();
+                board.piece_mut(player_id, piece_id).free();
                 board
-                    .outside(new_position)
-                    .piece(piece_id)
-                    .borrow_mut()
+                    .piece_mut(player_id, piece_id)
                     .set_position(new_position)
+                    .unwrap();
             }
 
             let mut old_position1 = new_position;
             let mut old_position2 = new_position;
-            board.enter_goal(player_id, 3, old_position1);
-            board.goal(player_id).piece(3).borrow_mut().goal();
+            board.enter_goal(player_id, 3, old_position1).unwrap();
+            board.piece_mut(player_id, 3).goal();
 
             let new_position = 52 + player_id * 5;
-            board.move_inside(player_id, 0, old_position1, new_position);
             board
-                .inside(new_position)
-                .piece(0)
-                .borrow_mut()
-                .set_position(new_position);
+                .move_inside(player_id, 0, old_position1, new_position)
+                .unwrap();
+            board
+                .piece_mut(player_id, 0)
+                .set_position(new_position)
+                .unwrap();
             let mut old_inside_position = new_position;
 
             for _ in 0..10 {
                 let new_position1 = rng.gen_range(0..=51);
                 let new_position2 = rng.gen_range(0..=51);
                 let new_inside_position = rng.gen_range(52..72);
-                board.update_outside(player_id, 1, old_position1, new_position1);
-                board.update_outside(player_id, 2, old_position2, new_position2);
-                board.update_inside(player_id, 0, old_inside_position, new_inside_position);
                 board
-                    .outside(new_position1)
-                    .piece(1)
-                    .borrow_mut()
-                    .set_position(new_position1);
+                    .update_outside(player_id, 1, old_position1, new_position1)
+                    .unwrap();
+                board
+                    .update_outside(player_id, 2, old_position2, new_position2)
+                    .unwrap();
+                board
+                    .update_inside(player_id, 0, old_inside_position, new_inside_position)
+                    .unwrap();
                 board
-                    .outside(new_position2)
-                    .piece(2)
-                    .borrow_mut()
-                    .set_position(new_position2);
+                    .piece_mut(player_id, 1)
+                    .set_position(new_position1)
+                    .unwrap();
                 board
-                    .inside(new_inside_position)
-                    .piece(0)
-                    .borrow_mut()
-                    .set_position(new_inside_position);
+                    .piece_mut(player_id, 2)
+                    .set_position(new_position2)
+                    .unwrap();
+                board
+                    .piece_mut(player_id, 0)
+                    .set_position(new_inside_position)
+                    .unwrap();
                 old_position1 = new_position1;
                 old_position2 = new_position2;
                 old_inside_position = new_inside_position;
@@ -927,7 +1049,7 @@ mod board_update_test {
         }
 
         board.reset();
-        let player_ids = vec![
+        let player_ids = [
             PlayerID::Player0,
             PlayerID::Player1,
             PlayerID::Player2,
@@ -936,15 +1058,18 @@ mod board_update_test {
         for i in 0..4 {
             assert_eq!(board.home(i).pieces.len(), 4);
             for j in 0..4 {
-                assert_eq!(board.home(i).piece(j).borrow().id(), j);
+                assert_eq!(board.home(i).piece(j).piece_id, j);
                 assert_eq!(
                     board.home(i).player_id,
                     Some(player_ids[i as usize].clone())
                 );
-                assert!(board.home(i).piece(j).borrow().is_home());
-                assert!(!board.home(i).piece(j).borrow().is_free());
-                assert!(!board.home(i).piece(j).borrow().is_goal());
-                assert_eq!(board.home(i).piece(j).borrow().position(), -1);
+                assert!(board.piece_at(board.home[i as usize].piece(j)).is_home());
+                assert!(!board.piece_at(board.home[i as usize].piece(j)).is_free());
+                assert!(!board.piece_at(board.home[i as usize].piece(j)).is_goal());
+                assert_eq!(
+                    board.piece_at(board.home[i as usize].piece(j)).position(),
+                    -1
+                );
             }
         }
     }
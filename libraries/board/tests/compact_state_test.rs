@@ -0,0 +1,145 @@
+use board::{Board, BoardGeometry, CompactMove, CompactState};
+use errors::LudoError;
+use std::collections::HashSet;
+
+#[cfg(test)]
+mod compact_state_tests {
+    use super::*;
+
+    const POSITIONS: [[i8; 4]; 4] = [
+        [-1, 5, 56, 99],
+        [-1, -1, 20, 13],
+        [26, -1, -1, -1],
+        [-1, 71, 40, -1],
+    ];
+
+    #[test]
+    fn new_state_test() {
+        let state = CompactState::new();
+        assert_eq!(state, CompactState::default());
+        assert_eq!(state.positions(), [[-1; 4]; 4]);
+        assert_eq!(state.turn, 0);
+        assert_eq!(state.roll, 0);
+        assert_eq!(state.players, 4);
+        assert!((0..4).all(|player_id| state.is_seated(player_id)));
+        assert!(std::mem::size_of::<CompactState>() <= 28);
+    }
+
+    #[test]
+    fn board_round_trip_test() {
+        let board = Board::from_positions(POSITIONS).unwrap();
+        let state = CompactState::from_board(&board).unwrap();
+        assert_eq!(state.positions(), POSITIONS);
+        assert_eq!(state, CompactState::from_positions(POSITIONS));
+        assert_eq!(state.position(0, 3), 99);
+        assert_eq!(state.position(3, 0), -1);
+
+        let mut board = state.to_board().unwrap();
+        assert_eq!(board.piece_positions(), POSITIONS.to_vec());
+        assert_eq!(board.goal(0).pieces.len(), 1);
+        assert_eq!(board.home(1).pieces.len(), 2);
+    }
+
+    #[test]
+    fn six_player_board_test() {
+        let mut board = Board::with_geometry(BoardGeometry::six_player()).unwrap();
+        board.set_side_to_move(5);
+        let state = CompactState::from_board(&board).unwrap();
+        assert_eq!(state.players, 6);
+        assert_eq!(state.turn, 5);
+        assert!((0..6).all(|player_id| state.is_seated(player_id)));
+        assert_eq!(state.to_board().unwrap(), board);
+
+        let geometry = BoardGeometry {
+            home_column_length: 6,
+            ..BoardGeometry::standard()
+        };
+        let board = Board::with_geometry(geometry).unwrap();
+        assert!(matches!(
+            CompactState::from_board(&board),
+            Err(LudoError::IllegalAction(_))
+        ));
+        assert_eq!(
+            CompactState::with_players(7),
+            Err(LudoError::InvalidPlayer(7))
+        );
+    }
+
+    #[test]
+    fn seated_round_trip_test() {
+        let two_players = BoardGeometry::new(2);
+        let three_players = BoardGeometry::new(3);
+        let boards = [
+            Board::from_positions_with_geometry(two_players, &[[3, -1, 27, 99], [-1; 4]]),
+            Board::from_positions_with_geometry(
+                three_players,
+                &[[-1; 4], [20, 44, -1, -1], [-1; 4]],
+            ),
+            Board::from_positions(POSITIONS),
+            Board::from_positions(POSITIONS),
+        ];
+        let seats: [&[i8]; 4] = [&[0, 1], &[0, 1, 2], &[0, 2], &[1, 2, 3]];
+        for (board, seats) in boards.into_iter().zip(seats) {
+            let mut board = board.unwrap();
+            board.set_seats(seats).unwrap();
+            board.set_side_to_move(seats[1]);
+            board.set_roll(4);
+            let state = CompactState::from_board(&board).unwrap();
+            assert_eq!(state.players as i8, board.geometry().players);
+            assert_eq!(state.roll, 4);
+            assert_eq!(state.zobrist(), board.zobrist());
+            let round_trip = state.to_board().unwrap();
+            assert_eq!(round_trip.seats(), seats);
+            assert_eq!(round_trip.roll(), 4);
+            assert_eq!(round_trip, board);
+        }
+
+        let mut state = CompactState::with_players(3).unwrap();
+        state.seats = 0b101;
+        state.next_turn();
+        assert_eq!(state.turn, 2);
+        state.next_turn();
+        assert_eq!(state.turn, 0);
+    }
+
+    #[test]
+    fn copy_and_hash_test() {
+        let state = CompactState::from_positions(POSITIONS);
+        let mut copy = state;
+        copy.set_position(2, 0, 27);
+        assert_eq!(state.position(2, 0), 26);
+        assert_eq!(copy.position(2, 0), 27);
+
+        let states: HashSet<CompactState> = [state, copy, state].into_iter().collect();
+        assert_eq!(states.len(), 2);
+    }
+
+    #[test]
+    fn apply_move_test() {
+        let mut state = CompactState::from_positions(POSITIONS);
+        state.turn = 1;
+        state.roll = 6;
+        state.apply(CompactMove::new(2, 26, &[(2, 0)], true));
+        assert_eq!(state.position(1, 2), 26);
+        assert_eq!(state.position(2, 0), -1);
+        assert_eq!(state.turn, 1);
+        assert_eq!(state.roll, 0);
+
+        let compact_move = CompactMove::new(0, 13, &[], false);
+        assert_eq!(compact_move.captures().count(), 0);
+        state.apply(CompactMove::new(3, 14, &[], false));
+        assert_eq!(state.position(1, 3), 14);
+        assert_eq!(state.turn, 2);
+    }
+
+    #[test]
+    fn seats_test() {
+        let mut state = CompactState::new();
+        state.seats = 0b0101;
+        assert!(!state.is_seated(1));
+        state.next_turn();
+        assert_eq!(state.turn, 2);
+        state.next_turn();
+        assert_eq!(state.turn, 0);
+    }
+}
@@ -0,0 +1,114 @@
+use board::{Board, BoardGeometry, InvariantViolation, PieceRef, PlayerID, Square};
+
+#[cfg(test)]
+mod invariants_tests {
+    use super::*;
+
+    #[test]
+    fn valid_boards_test() {
+        assert_eq!(Board::new().validate(), Ok(()));
+        let board =
+            Board::from_positions([[-1, 5, 56, 99], [-1, -1, 20, 13], [26, 26, -1, -1], [-1; 4]])
+                .unwrap();
+        assert_eq!(board.validate(), Ok(()));
+
+        let geometry = BoardGeometry::six_player();
+        assert_eq!(Board::with_geometry(geometry).unwrap().validate(), Ok(()));
+    }
+
+    #[test]
+    fn position_mismatch_test() {
+        let mut board = Board::new();
+        board.move_from_home(0, 0, 0).unwrap();
+        let violations = board.validate().unwrap_err();
+        let piece = PieceRef::new(0, 0);
+        assert!(violations.contains(&InvariantViolation::StateMismatch {
+            piece,
+            square: Square::Track(0),
+        }));
+        assert!(violations.contains(&InvariantViolation::PositionMismatch {
+            piece,
+            square: Square::Track(0),
+            position: -1,
+        }));
+
+        board.piece_mut(0, 0).free();
+        board.piece_mut(0, 0).set_position(0).unwrap();
+        assert_eq!(board.validate(), Ok(()));
+    }
+
+    #[test]
+    fn stale_owner_test() {
+        let mut board =
+            Board::from_positions([[4, -1, -1, -1], [-1; 4], [-1; 4], [-1; 4]]).unwrap();
+        board.outside(4).player_id = Some(PlayerID::Player2);
+        board.outside(5).player_id = Some(PlayerID::Player1);
+        let violations = board.validate().unwrap_err();
+        assert_eq!(
+            violations,
+            vec![
+                InvariantViolation::StaleOwner(Square::Track(4)),
+                InvariantViolation::StaleOwner(Square::Track(5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn mixed_stack_test() {
+        let mut board =
+            Board::from_positions([[4, -1, -1, -1], [-1; 4], [-1; 4], [-1; 4]]).unwrap();
+        board.move_from_home(1, 0, 4).unwrap();
+        board.piece_mut(1, 0).free();
+        board.piece_mut(1, 0).set_position(4).unwrap();
+        assert_eq!(
+            board.validate(),
+            Err(vec![InvariantViolation::MixedStack(Square::Track(4))])
+        );
+
+        let mut board =
+            Board::from_positions([[8, -1, -1, -1], [-1; 4], [-1; 4], [-1; 4]]).unwrap();
+        board.move_from_home(1, 0, 8).unwrap();
+        board.piece_mut(1, 0).free();
+        board.piece_mut(1, 0).set_position(8).unwrap();
+        assert!(board.is_globe(8));
+        assert_eq!(board.validate(), Ok(()));
+    }
+
+    #[test]
+    fn missing_and_duplicate_piece_test() {
+        let mut board = Board::new();
+        board.home(1).pieces.pop();
+        board.home(2).pieces.push(PieceRef::new(2, 0));
+        let violations = board.validate().unwrap_err();
+        assert!(violations.contains(&InvariantViolation::MissingPiece(PieceRef::new(1, 3))));
+        assert!(violations.contains(&InvariantViolation::DuplicatePiece(PieceRef::new(2, 0))));
+        assert!(!violations
+            .iter()
+            .any(|violation| matches!(violation, InvariantViolation::PieceCount { .. })));
+
+        board.home(2).pieces.pop();
+        assert_eq!(
+            board.validate(),
+            Err(vec![
+                InvariantViolation::PieceCount {
+                    expected: 16,
+                    found: 15
+                },
+                InvariantViolation::MissingPiece(PieceRef::new(1, 3)),
+            ])
+        );
+    }
+
+    #[test]
+    fn violation_display_test() {
+        let violation = InvariantViolation::PositionMismatch {
+            piece: PieceRef::new(2, 1),
+            square: Square::Goal(2),
+            position: 30,
+        };
+        assert_eq!(
+            violation.to_string(),
+            "Piece 1 of player 2 is on the goal of player 2 but has position 30"
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use board::{Board, BoardGeometry, Charset, RenderOptions};
+
+#[cfg(test)]
+mod render_tests {
+    use super::*;
+
+    const POSITIONS: [[i8; 4]; 4] = [
+        [-1, 5, 56, 99],
+        [-1, -1, 20, 13],
+        [26, 26, -1, -1],
+        [-1, 71, 40, -1],
+    ];
+
+    #[test]
+    fn empty_board_test() {
+        let rendered = Board::new().render(RenderOptions::ascii());
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines.len(), 15);
+        assert_eq!(lines[0], "            . * .");
+        assert_eq!(lines[2], "    G G     o # .     Y Y");
+        assert_eq!(lines[6], ". + . . . .   X   * . . o . .");
+        assert_eq!(lines[7], "* # # # # # X X X # # # # # *");
+        assert_eq!(lines[12], "    R R     . # o     B B");
+        assert_eq!(lines[13], "            + # .");
+    }
+
+    #[test]
+    fn pieces_test() {
+        let board = Board::from_positions(POSITIONS).unwrap();
+        let rendered = board.render(RenderOptions::ascii());
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[5], "            G # .");
+        assert_eq!(lines[7], "* # # # # G G X X # # # # # *");
+        assert_eq!(lines[8], ". . o . . *   X   . . . . B2.");
+        assert_eq!(lines[9], "            . R *");
+        assert_eq!(rendered, board.to_string());
+    }
+
+    #[test]
+    fn unicode_test() {
+        let board = Board::from_positions(POSITIONS).unwrap();
+        let plain = RenderOptions {
+            charset: Charset::Unicode,
+            color: false,
+        };
+        let rendered = board.render(plain);
+        assert!(rendered.starts_with("            · ★ ·\n"));
+        assert!(rendered.contains('◆'));
+        assert!(rendered.contains('○'));
+        assert!(!rendered.contains('\x1b'));
+
+        let colored = board.render(RenderOptions::unicode());
+        assert!(colored.contains("\x1b[32m"));
+        assert!(colored.contains("\x1b[1m\x1b[34mB2\x1b[0m"));
+    }
+
+    #[test]
+    fn other_geometry_test() {
+        let mut board = Board::with_geometry(BoardGeometry::six_player()).unwrap();
+        board.move_from_home(4, 0, 53).unwrap();
+        let rendered = board.render(RenderOptions::ascii());
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(
+            lines[4],
+            "O + O . . . * . . o . . * .  | # # # # #  | home 3 goal 0"
+        );
+    }
+}
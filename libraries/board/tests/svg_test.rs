@@ -0,0 +1,52 @@
+use board::{Board, BoardGeometry};
+
+#[cfg(test)]
+mod svg_tests {
+    use super::*;
+
+    fn count(svg: &str, pattern: &str) -> usize {
+        svg.matches(pattern).count()
+    }
+
+    #[test]
+    fn empty_board_svg_test() {
+        let svg = Board::new().to_svg();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"512\""));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert_eq!(count(&svg, "class=\"piece\""), 16);
+        assert_eq!(count(&svg, "class=\"globe\""), 4);
+        assert_eq!(count(&svg, "class=\"star\""), 8);
+        assert_eq!(count(&svg, "<text"), 0);
+        assert_eq!(count(&svg, "#2e9e44"), 1 + 1 + 5 + 1 + 4);
+    }
+
+    #[test]
+    fn stacked_pieces_svg_test() {
+        let board =
+            Board::from_positions([[-1, 5, 56, 99], [-1, -1, 20, 13], [26, 26, -1, -1], [-1; 4]])
+                .unwrap();
+        let pieces = board.svg_pieces();
+        assert_eq!(count(&pieces, "class=\"piece\""), 16);
+        assert_eq!(count(&pieces, "<text"), 1);
+        assert!(pieces.contains(">2</text>"));
+        assert_eq!(board.to_svg(), {
+            let size = board.svg_size();
+            format!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{size:.0}\" height=\"{size:.0}\" viewBox=\"0 0 {size:.0} {size:.0}\">\n{}{}</svg>\n",
+                board.svg_board(),
+                pieces
+            )
+        });
+    }
+
+    #[test]
+    fn ring_layout_svg_test() {
+        let board = Board::with_geometry(BoardGeometry::six_player()).unwrap();
+        let svg = board.to_svg();
+        assert!(board.svg_size() > Board::new().svg_size());
+        assert_eq!(count(&svg, "class=\"piece\""), 24);
+        assert_eq!(count(&svg, "class=\"globe\""), 6);
+        assert_eq!(count(&svg, "class=\"star\""), 12);
+        assert!(!svg.contains("NaN"));
+    }
+}
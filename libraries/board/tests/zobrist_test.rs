@@ -0,0 +1,123 @@
+use board::{Board, CompactState, TranspositionTable};
+
+#[cfg(test)]
+mod zobrist_tests {
+    use super::*;
+
+    #[test]
+    fn move_functions_update_hash_test() {
+        let mut board = Board::new();
+        let start = board.zobrist();
+        assert_eq!(start, board.compute_zobrist());
+
+        board.move_from_home(0, 0, 0).unwrap();
+        assert_ne!(board.zobrist(), start);
+        assert_eq!(board.zobrist(), board.compute_zobrist());
+
+        board.update_outside(0, 0, 0, 50).unwrap();
+        assert_eq!(board.zobrist(), board.compute_zobrist());
+
+        board.move_inside(0, 0, 50, 53).unwrap();
+        assert_eq!(board.zobrist(), board.compute_zobrist());
+
+        board.update_inside(0, 0, 53, 55).unwrap();
+        assert_eq!(board.zobrist(), board.compute_zobrist());
+
+        board.enter_goal(0, 0, 55).unwrap();
+        assert_eq!(board.zobrist(), board.compute_zobrist());
+
+        board.move_from_home(1, 0, 13).unwrap();
+        board.move_into_home(1, 0, 13).unwrap();
+        assert_eq!(board.zobrist(), board.compute_zobrist());
+    }
+
+    #[test]
+    fn hash_depends_on_positions_only_test() {
+        let mut board = Board::new();
+        board.move_from_home(0, 0, 0).unwrap();
+        board.update_outside(0, 0, 0, 4).unwrap();
+
+        let other = Board::from_positions([[4, -1, -1, -1], [-1; 4], [-1; 4], [-1; 4]]).unwrap();
+        assert_eq!(board.zobrist(), other.zobrist());
+
+        board.move_from_home(0, 1, 0).unwrap();
+        let swapped = Board::from_positions([[0, 4, -1, -1], [-1; 4], [-1; 4], [-1; 4]]).unwrap();
+        assert_ne!(board.zobrist(), swapped.zobrist());
+    }
+
+    #[test]
+    fn side_to_move_test() {
+        let mut board = Board::new();
+        let start = board.zobrist();
+
+        board.set_side_to_move(2);
+        assert_eq!(board.side_to_move(), 2);
+        assert_ne!(board.zobrist(), start);
+        assert_eq!(board.zobrist(), board.compute_zobrist());
+
+        board.set_side_to_move(0);
+        assert_eq!(board.zobrist(), start);
+    }
+
+    #[test]
+    fn reset_restores_hash_test() {
+        let mut board = Board::new();
+        let start = board.zobrist();
+        board.move_from_home(0, 0, 0).unwrap();
+        board.move_from_home(2, 3, 26).unwrap();
+        board.set_side_to_move(3);
+
+        board.reset();
+        assert_eq!(board.side_to_move(), 0);
+        assert_eq!(board.zobrist(), start);
+    }
+
+    #[test]
+    fn compact_state_hash_test() {
+        let mut board =
+            Board::from_positions([[-1, 5, 56, 99], [-1, -1, 20, 13], [26, -1, -1, -1], [-1; 4]])
+                .unwrap();
+        board.set_side_to_move(1);
+
+        let state = CompactState::from_board(&board).unwrap();
+        assert_eq!(state.turn, 1);
+        assert_eq!(state.zobrist(), board.zobrist());
+        assert_eq!(state.to_board().unwrap().zobrist(), board.zobrist());
+    }
+
+    #[test]
+    fn transposition_table_test() {
+        let mut table = TranspositionTable::new(8);
+        assert_eq!(table.capacity(), 8);
+        assert!(table.is_empty());
+
+        table.insert(3, "three");
+        table.insert(4, "four");
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.get(3), Some(&"three"));
+        assert!(table.contains(4));
+        assert_eq!(table.get(5), None);
+
+        table.insert(11, "eleven");
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.get(3), None);
+        assert_eq!(table.get(11), Some(&"eleven"));
+
+        table.clear();
+        assert!(table.is_empty());
+        assert_eq!(table.get(4), None);
+    }
+
+    #[test]
+    fn transposition_table_is_bounded_test() {
+        let mut table = TranspositionTable::new(16);
+        let mut board = Board::new();
+        for position in 0..40 {
+            board.reset();
+            board.move_from_home(0, 0, position).unwrap();
+            table.insert(board.zobrist(), position);
+        }
+        assert!(table.len() <= table.capacity());
+        assert_eq!(table.get(board.zobrist()), Some(&39));
+    }
+}
@@ -1,27 +1,98 @@
 mod dice {
     use rand::distributions::Uniform;
-    use rand::Rng;
+    use rand::rngs::StdRng;
+    use rand::{Rng, RngCore, SeedableRng};
+    use std::fmt::{self, Debug, Display, Formatter};
+
+    #[derive(PartialEq, Debug, Clone, Copy)]
+    pub enum DiceError {
+        Exhausted,
+    }
+
+    pub trait DiceSource: Debug + Send + Sync {
+        fn roll(&mut self) -> Result<i8, DiceError>;
+        fn get_value(&self) -> i8;
+        fn box_clone(&self) -> Box<dyn DiceSource>;
+    }
+
+    impl Clone for Box<dyn DiceSource> {
+        fn clone(&self) -> Self {
+            self.box_clone()
+        }
+    }
+
+    impl<D: DiceSource + 'static> From<D> for Box<dyn DiceSource> {
+        fn from(dice: D) -> Self {
+            Box::new(dice)
+        }
+    }
 
     #[derive(PartialEq, Debug, Clone)]
-    pub struct Dice {
+    pub struct Dice<R = StdRng> {
         value: i8,
         dist: Uniform<i8>,
+        rng: Option<R>,
     }
 
     impl Dice {
+        /// An unseeded dice rolls with `rand::thread_rng`, so its rolls cannot be
+        /// reproduced. Use `with_seed` or `with_rng` for repeatable games.
         pub fn new(high_value: i8) -> Dice {
             let dist = Uniform::new_inclusive(1, high_value);
-            Dice { value: 0, dist }
+            Dice {
+                value: 0,
+                dist,
+                rng: None,
+            }
+        }
+
+        pub fn with_seed(seed: u64) -> Dice {
+            Dice::with_rng(6, StdRng::seed_from_u64(seed))
+        }
+    }
+
+    impl<R: RngCore> Dice<R> {
+        pub fn with_rng(high_value: i8, rng: R) -> Dice<R> {
+            let dist = Uniform::new_inclusive(1, high_value);
+            Dice {
+                value: 0,
+                dist,
+                rng: Some(rng),
+            }
         }
 
         pub fn roll(&mut self) {
-            let mut rng = rand::thread_rng();
-            self.value = rng.sample(self.dist);
+            self.value = match &mut self.rng {
+                Some(rng) => rng.sample(self.dist),
+                None => rand::thread_rng().sample(self.dist),
+            };
         }
 
         pub fn get_value(&self) -> i8 {
             self.value
         }
+
+        pub fn is_seeded(&self) -> bool {
+            self.rng.is_some()
+        }
+    }
+
+    impl<R> DiceSource for Dice<R>
+    where
+        R: RngCore + Clone + Debug + Send + Sync + 'static,
+    {
+        fn roll(&mut self) -> Result<i8, DiceError> {
+            Dice::roll(self);
+            Ok(self.value)
+        }
+
+        fn get_value(&self) -> i8 {
+            self.value
+        }
+
+        fn box_clone(&self) -> Box<dyn DiceSource> {
+            Box::new(self.clone())
+        }
     }
 
     impl Default for Dice {
@@ -29,6 +100,54 @@ mod dice {
             Self::new(6)
         }
     }
+
+    #[derive(PartialEq, Debug, Clone, Default)]
+    pub struct ScriptedDice {
+        rolls: Vec<i8>,
+        index: usize,
+        value: i8,
+    }
+
+    impl ScriptedDice {
+        pub fn new(rolls: Vec<i8>) -> ScriptedDice {
+            ScriptedDice {
+                rolls,
+                index: 0,
+                value: 0,
+            }
+        }
+
+        pub fn remaining(&self) -> usize {
+            self.rolls.len() - self.index
+        }
+    }
+
+    impl DiceSource for ScriptedDice {
+        fn roll(&mut self) -> Result<i8, DiceError> {
+            let value = *self.rolls.get(self.index).ok_or(DiceError::Exhausted)?;
+            self.index += 1;
+            self.value = value;
+            Ok(value)
+        }
+
+        fn get_value(&self) -> i8 {
+            self.value
+        }
+
+        fn box_clone(&self) -> Box<dyn DiceSource> {
+            Box::new(self.clone())
+        }
+    }
+
+    impl Display for DiceError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            match self {
+                DiceError::Exhausted => write!(f, "The dice has no rolls left"),
+            }
+        }
+    }
+
+    impl std::error::Error for DiceError {}
 }
 
-pub use dice::Dice;
+pub use dice::{Dice, DiceError, DiceSource, ScriptedDice};
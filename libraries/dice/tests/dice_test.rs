@@ -1,4 +1,6 @@
-use dice::Dice;
+use dice::{Dice, DiceError, DiceSource, ScriptedDice};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use std::any::{Any, TypeId};
 
 #[cfg(test)]
@@ -24,4 +26,61 @@ mod dice_test {
         let dice = Dice::default();
         assert_eq!(dice.type_id(), TypeId::of::<Dice>());
     }
+
+    #[test]
+    fn seeded_dice_test() {
+        let mut dice_1 = Dice::with_seed(42);
+        let mut dice_2 = Dice::with_seed(42);
+        assert!(dice_1.is_seeded());
+        for _ in 0..100 {
+            dice_1.roll();
+            dice_2.roll();
+            assert!((1..=6).contains(&dice_1.get_value()));
+            assert_eq!(dice_1.get_value(), dice_2.get_value());
+        }
+    }
+
+    #[test]
+    fn custom_rng_dice_test() {
+        let mut dice_1 = Dice::with_rng(6, StdRng::seed_from_u64(7));
+        let mut dice_2 = Dice::with_seed(7);
+        for _ in 0..100 {
+            dice_1.roll();
+            dice_2.roll();
+            assert_eq!(dice_1.get_value(), dice_2.get_value());
+        }
+        assert!(!Dice::default().is_seeded());
+    }
+
+    #[test]
+    fn scripted_dice_test() {
+        let mut dice = ScriptedDice::new(vec![6, 1, 4]);
+        assert_eq!(dice.remaining(), 3);
+        assert_eq!(dice.roll(), Ok(6));
+        assert_eq!(dice.roll(), Ok(1));
+        assert_eq!(dice.roll(), Ok(4));
+        assert_eq!(dice.get_value(), 4);
+        assert_eq!(dice.remaining(), 0);
+        assert_eq!(dice.roll(), Err(DiceError::Exhausted));
+    }
+
+    #[test]
+    fn dice_error_test() {
+        let error: Box<dyn std::error::Error> = Box::new(DiceError::Exhausted);
+        assert_eq!(error.to_string(), "The dice has no rolls left");
+    }
+
+    #[test]
+    fn boxed_dice_source_test() {
+        let mut dice: Box<dyn DiceSource> = ScriptedDice::new(vec![2, 3]).into();
+        assert_eq!(dice.roll(), Ok(2));
+        let mut copy = dice.clone();
+        assert_eq!(copy.roll(), Ok(3));
+        assert_eq!(dice.roll(), Ok(3));
+
+        let mut dice: Box<dyn DiceSource> = Dice::with_seed(5).into();
+        let value = dice.roll().unwrap();
+        assert!((1..=6).contains(&value));
+        assert_eq!(dice.get_value(), value);
+    }
 }
@@ -0,0 +1,54 @@
+mod errors {
+    use dice::DiceError;
+    use std::fmt::{self, Display, Formatter};
+
+    #[derive(PartialEq, Debug, Clone)]
+    pub enum LudoError {
+        InvalidPosition(i8),
+        InvalidPlayer(i8),
+        PieceNotFound { piece_id: i8, position: i8 },
+        IllegalAction(String),
+        NoDice,
+        InputClosed,
+        Dice(DiceError),
+    }
+
+    impl From<DiceError> for LudoError {
+        fn from(error: DiceError) -> Self {
+            LudoError::Dice(error)
+        }
+    }
+
+    impl Display for LudoError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            match self {
+                LudoError::InvalidPosition(position) => write!(f, "Invalid position: {}", position),
+                LudoError::InvalidPlayer(player_id) => {
+                    write!(f, "Invalid player id: {}", player_id)
+                }
+                LudoError::PieceNotFound { piece_id, position } => {
+                    write!(
+                        f,
+                        "Piece {} was not found at position {}",
+                        piece_id, position
+                    )
+                }
+                LudoError::IllegalAction(message) => write!(f, "Illegal action: {}", message),
+                LudoError::NoDice => write!(f, "The player doesn't have the dice"),
+                LudoError::InputClosed => write!(f, "The input ended before a move was chosen"),
+                LudoError::Dice(error) => write!(f, "{}", error),
+            }
+        }
+    }
+
+    impl std::error::Error for LudoError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                LudoError::Dice(error) => Some(error),
+                _ => None,
+            }
+        }
+    }
+}
+
+pub use errors::LudoError;
@@ -0,0 +1,125 @@
+use crate::record::{GameRecord, RecordError};
+use board::Board;
+use std::fmt::Write;
+
+const CAPTION: f64 = 28.0;
+
+impl GameRecord {
+    pub fn boards(&self) -> Result<Vec<Board>, RecordError> {
+        self.validate()?;
+        let geometry = self.geometry();
+        let mut boards = vec![Board::with_geometry(geometry.clone())
+            .map_err(|error| RecordError::IllegalMove { ply: 0, error })?];
+        for (index, ply) in self.plies.iter().enumerate() {
+            let board = Board::from_positions_with_geometry(geometry.clone(), &ply.positions)
+                .map_err(|error| RecordError::IllegalMove { ply: index, error })?;
+            boards.push(board);
+        }
+        Ok(boards)
+    }
+
+    pub fn to_svg(&self, seconds_per_ply: f64) -> Result<String, RecordError> {
+        let boards = self.boards()?;
+        let (size, mut svg) = svg_header(&boards[0]);
+        let last = boards.len() - 1;
+        for (frame, board) in boards.iter().enumerate() {
+            let begin = frame as f64 * seconds_per_ply;
+            let timing = match frame == last {
+                true => "fill=\"freeze\"".to_string(),
+                false => format!("dur=\"{}s\"", seconds_per_ply),
+            };
+            let _ = writeln!(
+                svg,
+                "<g class=\"frame\" visibility=\"hidden\">\n<set attributeName=\"visibility\" to=\"visible\" begin=\"{}s\" {}/>",
+                begin, timing
+            );
+            svg.push_str(&board.svg_pieces());
+            let _ = writeln!(
+                svg,
+                "<text x=\"16\" y=\"{:.0}\" font-size=\"14\" font-family=\"sans-serif\">{}</text>\n</g>",
+                size + CAPTION / 2.0 + 5.0,
+                self.caption(frame)
+            );
+        }
+        svg.push_str("</svg>\n");
+        Ok(svg)
+    }
+
+    pub fn to_html(&self) -> Result<String, RecordError> {
+        let boards = self.boards()?;
+        let (_, mut svg) = svg_header(&boards[0]);
+        for (frame, board) in boards.iter().enumerate() {
+            let display = match frame {
+                0 => "inline",
+                _ => "none",
+            };
+            let _ = writeln!(
+                svg,
+                "<g class=\"frame\" data-caption=\"{}\" style=\"display:{}\">",
+                self.caption(frame),
+                display
+            );
+            svg.push_str(&board.svg_pieces());
+            svg.push_str("</g>\n");
+        }
+        svg.push_str("</svg>\n");
+        Ok(format!(
+            "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>Ludo game</title>
+</head>
+<body>
+{svg}<p id=\"caption\">{caption}</p>
+<button id=\"previous\">Previous</button>
+<input id=\"ply\" type=\"range\" min=\"0\" max=\"{last}\" value=\"0\">
+<button id=\"next\">Next</button>
+<script>
+const frames = document.querySelectorAll(\".frame\");
+const slider = document.getElementById(\"ply\");
+function show(index) {{
+  index = Math.max(0, Math.min(frames.length - 1, index));
+  frames.forEach((frame, i) => frame.style.display = i === index ? \"inline\" : \"none\");
+  document.getElementById(\"caption\").textContent = frames[index].dataset.caption;
+  slider.value = index;
+}}
+slider.addEventListener(\"input\", () => show(Number(slider.value)));
+document.getElementById(\"previous\").addEventListener(\"click\", () => show(Number(slider.value) - 1));
+document.getElementById(\"next\").addEventListener(\"click\", () => show(Number(slider.value) + 1));
+</script>
+</body>
+</html>
+",
+            caption = self.caption(0),
+            last = boards.len() - 1
+        ))
+    }
+
+    fn caption(&self, frame: usize) -> String {
+        match frame.checked_sub(1).and_then(|index| self.plies.get(index)) {
+            None => "Start".to_string(),
+            Some(ply) => {
+                let rolls: Vec<String> = ply.rolls.iter().map(|roll| roll.to_string()).collect();
+                format!(
+                    "Ply {}: player {} rolled {} and played {} with piece {}",
+                    frame,
+                    ply.player_id,
+                    rolls.join(", "),
+                    ply.act,
+                    ply.piece_id
+                )
+            }
+        }
+    }
+}
+
+fn svg_header(board: &Board) -> (f64, String) {
+    let size = board.svg_size();
+    let height = size + CAPTION;
+    let svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{size:.0}\" height=\"{height:.0}\" viewBox=\"0 0 {size:.0} {height:.0}\">\n{}",
+        board.svg_board()
+    );
+    (size, svg)
+}
@@ -1,20 +1,66 @@
+mod export;
+mod record;
+
 mod game {
+    use crate::record::{GameRecord, RecordError};
     pub use board::Board;
-    use dice::Dice;
+    use board::{BoardGeometry, PlayerID};
+    use dice::{Dice, DiceSource};
+    use errors::LudoError;
     use iplayers::{Behavior, IPlayer, Playstyle};
-    use std::{cell::RefCell, rc::Rc};
+    use players::{Player, RuleSet};
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum GameOutcome {
+        Winner { player_id: i8, turns: usize },
+        Placements { placements: Vec<i8>, turns: usize },
+        TurnLimit { leader: Option<i8>, turns: usize },
+        Stalemate { turns: usize },
+    }
+
+    impl GameOutcome {
+        pub fn turns(&self) -> usize {
+            match self {
+                GameOutcome::Winner { turns, .. }
+                | GameOutcome::Placements { turns, .. }
+                | GameOutcome::TurnLimit { turns, .. }
+                | GameOutcome::Stalemate { turns } => *turns,
+            }
+        }
+
+        pub fn is_timeout(&self) -> bool {
+            matches!(self, GameOutcome::TurnLimit { .. })
+        }
+    }
 
-    #[derive(Clone, Debug, PartialEq)]
+    #[derive(Clone, Debug)]
     pub struct Game {
         iplayers: Vec<IPlayer>,
-        board: Rc<RefCell<Board>>,
-        dice: Dice,
+        board: Board,
+        dice: Box<dyn DiceSource>,
+        recording: bool,
+        record: Option<GameRecord>,
+        forfeited: Vec<i8>,
+        finish_all: bool,
+        placements: Vec<i8>,
+        turn_limit: usize,
+        tiebreak: bool,
+        rules: RuleSet,
     }
 
     impl Game {
         pub fn new() -> Self {
-            let board = Rc::new(RefCell::new(Board::new()));
-            let dice = Dice::default();
+            Self::with_dice(Dice::default())
+        }
+
+        pub fn with_seed(seed: u64) -> Self {
+            let mut game = Self::with_dice(Dice::with_seed(seed));
+            game.seed_iplayers(seed);
+            game
+        }
+
+        pub fn with_dice(dice: impl Into<Box<dyn DiceSource>>) -> Self {
+            let board = Board::new();
             Self {
                 iplayers: vec![
                     IPlayer::new(0),
@@ -23,25 +69,163 @@ mod game {
                     IPlayer::new(3),
                 ],
                 board,
-                dice,
+                dice: dice.into(),
+                recording: false,
+                record: None,
+                forfeited: Vec::new(),
+                finish_all: false,
+                placements: Vec::new(),
+                turn_limit: 1000,
+                tiebreak: false,
+                rules: RuleSet::default(),
+            }
+        }
+
+        pub fn with_players(player_ids: &[PlayerID]) -> Result<Self, LudoError> {
+            Self::with_geometry(BoardGeometry::standard(), player_ids)
+        }
+
+        pub fn with_geometry(
+            geometry: BoardGeometry,
+            player_ids: &[PlayerID],
+        ) -> Result<Self, LudoError> {
+            let ids: Vec<i8> = player_ids.iter().map(PlayerID::id).collect();
+            if let Some(&id) = ids.iter().find(|&&id| id >= geometry.players) {
+                return Err(LudoError::InvalidPlayer(id));
+            }
+            if ids.len() < 2 {
+                return Err(LudoError::IllegalAction(
+                    "A game needs at least two players".to_string(),
+                ));
+            }
+            if (1..ids.len()).any(|idx| ids[..idx].contains(&ids[idx])) {
+                return Err(LudoError::IllegalAction(
+                    "A player can only be seated once".to_string(),
+                ));
+            }
+            let mut game = Self::new();
+            game.board = Board::with_geometry(geometry)?;
+            game.board.set_seats(&ids)?;
+            game.iplayers = ids.into_iter().map(IPlayer::new).collect();
+            game.set_rules(game.rules.clone());
+            Ok(game)
+        }
+
+        pub fn player_ids(&self) -> Vec<i8> {
+            self.iplayers
+                .iter()
+                .map(|iplayer| iplayer.player().id())
+                .collect()
+        }
+
+        pub fn set_rules(&mut self, rules: RuleSet) {
+            for iplayer in &mut self.iplayers {
+                iplayer.set_rules(rules.clone());
+            }
+            self.rules = rules;
+        }
+
+        pub fn rules(&self) -> &RuleSet {
+            &self.rules
+        }
+
+        pub fn set_dice(&mut self, dice: impl Into<Box<dyn DiceSource>>) {
+            self.dice = dice.into();
+        }
+
+        pub fn set_seed(&mut self, seed: u64) {
+            self.set_dice(Dice::with_seed(seed));
+            self.seed_iplayers(seed);
+        }
+
+        fn seed_iplayers(&mut self, seed: u64) {
+            for iplayer in &mut self.iplayers {
+                let offset = iplayer.player().id() as u64 + 1;
+                iplayer.set_seed(seed.wrapping_add(offset));
+            }
+        }
+
+        pub fn set_recording(&mut self, recording: bool) {
+            self.recording = recording;
+            self.record = None;
+            for iplayer in &mut self.iplayers {
+                iplayer.set_recording(recording);
+            }
+        }
+
+        pub fn record(&self) -> Option<&GameRecord> {
+            self.record.as_ref()
+        }
+
+        pub fn replay(record: &GameRecord) -> Result<(), RecordError> {
+            Self::replay_with_rules(record, &RuleSet::default())
+        }
+
+        pub fn replay_with_rules(record: &GameRecord, rules: &RuleSet) -> Result<(), RecordError> {
+            record.validate()?;
+            let mut board = Board::with_geometry(record.geometry())
+                .map_err(|error| RecordError::IllegalMove { ply: 0, error })?;
+            let mut players: Vec<Player> = (0..record.players).map(Player::new).collect();
+            for player in &mut players {
+                player.set_rules(rules.clone());
+            }
+            for (index, ply) in record.plies.iter().enumerate() {
+                let player =
+                    players
+                        .get_mut(ply.player_id as usize)
+                        .ok_or(RecordError::UnknownPlayer {
+                            ply: index,
+                            player_id: ply.player_id,
+                        })?;
+                player
+                    .make_move(&mut board, ply.piece_id, ply.dice_number(), ply.act)
+                    .map_err(|error| RecordError::IllegalMove { ply: index, error })?;
+                let actual = board.piece_positions();
+                if actual != ply.positions {
+                    return Err(RecordError::Mismatch {
+                        ply: index,
+                        expected: ply.positions.clone(),
+                        actual,
+                    });
+                }
             }
+            Ok(())
+        }
+
+        pub fn forfeited(&self) -> &[i8] {
+            &self.forfeited
+        }
+
+        pub fn set_finish_all(&mut self, finish_all: bool) {
+            self.finish_all = finish_all;
+        }
+
+        pub fn placements(&self) -> &[i8] {
+            &self.placements
+        }
+
+        pub fn set_turn_limit(&mut self, turn_limit: usize) {
+            self.turn_limit = turn_limit;
+        }
+
+        pub fn set_tiebreak(&mut self, tiebreak: bool) {
+            self.tiebreak = tiebreak;
         }
 
         pub fn reset_game(&mut self) {
-            self.board.borrow_mut().reset();
+            self.forfeited.clear();
+            self.board.reset();
             self.iplayers.iter_mut().for_each(|iplayer| {
                 iplayer.reset_first_round();
             });
         }
 
-        pub fn setup_game(&mut self) {
-            for iplayer in &mut self.iplayers {
-                iplayer.setup_board(self.board.clone());
-            }
+        pub fn get_board(&self) -> &Board {
+            &self.board
         }
 
-        pub fn get_board(&self) -> Rc<RefCell<Board>> {
-            self.board.clone()
+        pub fn get_board_mut(&mut self) -> &mut Board {
+            &mut self.board
         }
 
         pub fn reset_scores(&mut self) {
@@ -50,78 +234,226 @@ mod game {
             });
         }
 
-        pub fn start_game(&mut self, total_games: u16) {
+        pub fn start_game(&mut self, total_games: u16) -> Result<(), LudoError> {
             self.reset_scores();
             for _ in 0..total_games {
-                self.play_game();
+                self.play_game()?;
             }
+            Ok(())
         }
 
-        fn play_game(&mut self) {
-            self.beginning();
-            self.run();
+        fn play_game(&mut self) -> Result<GameOutcome, LudoError> {
+            self.beginning()?;
+            let result = self.run();
             self.reset_game();
+            result
         }
 
-        pub fn iplayer(&mut self, id: i8) -> &mut IPlayer {
-            let index = self
-                .iplayers
-                .iter()
-                .position(|player: &IPlayer| player.player().id() == id)
-                .unwrap();
-            &mut self.iplayers[index]
+        pub fn iplayer(&mut self, id: i8) -> Result<&mut IPlayer, LudoError> {
+            self.iplayers
+                .iter_mut()
+                .find(|player| player.player().id() == id)
+                .ok_or(LudoError::InvalidPlayer(id))
         }
 
-        pub fn give_iplayer_a_playstyle(&mut self, id: i8, playstyle: Playstyle) {
-            self.iplayers[id as usize].set_playstyle(playstyle);
+        pub fn give_iplayer_a_playstyle(
+            &mut self,
+            id: i8,
+            playstyle: Playstyle,
+        ) -> Result<(), LudoError> {
+            self.iplayer(id)?.set_playstyle(playstyle);
+            Ok(())
         }
 
-        pub fn set_iplayer(&mut self, id: i8, iplayer: &mut IPlayer) {
-            self.iplayer(id).substitute(iplayer);
+        pub fn set_iplayer(&mut self, id: i8, iplayer: &mut IPlayer) -> Result<(), LudoError> {
+            self.iplayer(id)?.substitute(iplayer);
+            Ok(())
         }
 
-        pub fn get_iplayer(&mut self, id: i8, iplayer: &mut IPlayer) {
-            iplayer.substitute(self.iplayer(id));
+        pub fn get_iplayer(&mut self, id: i8, iplayer: &mut IPlayer) -> Result<(), LudoError> {
+            iplayer.substitute(self.iplayer(id)?);
+            Ok(())
         }
 
-        pub fn run(&mut self) {
-            for i in 0..1000 {
-                let player_idx = i % 4;
-                    self.play_turn(player_idx);
-                    if self.has_player_won(player_idx) {
-                        break;
+        pub fn run(&mut self) -> Result<GameOutcome, LudoError> {
+            let mut turns = 0;
+            while !self.is_game_over() {
+                if turns == self.turn_limit {
+                    return Ok(self.time_out(turns));
+                }
+                let player_idx = turns % self.iplayers.len();
+                turns += 1;
+                if self.is_active(player_idx) {
+                    match self.play_turn(player_idx) {
+                        Err(LudoError::Dice(error)) => return Err(LudoError::Dice(error)),
+                        Err(LudoError::InputClosed) => return Err(LudoError::InputClosed),
+                        Err(_) => self.forfeit(player_idx),
+                        Ok(()) => self.check_if_finished(player_idx),
                     }
-                    self.next_turn(player_idx);
+                }
+                self.next_turn(player_idx)?;
+            }
+            self.place_remaining_players();
+            Ok(self.outcome(turns))
+        }
+
+        fn outcome(&self, turns: usize) -> GameOutcome {
+            match self.placements[..] {
+                [] => GameOutcome::Stalemate { turns },
+                [player_id] => GameOutcome::Winner { player_id, turns },
+                _ => GameOutcome::Placements {
+                    placements: self.placements.clone(),
+                    turns,
+                },
+            }
+        }
+
+        fn time_out(&mut self, turns: usize) -> GameOutcome {
+            self.iplayers
+                .iter_mut()
+                .for_each(|iplayer| iplayer.time_out());
+            if self.tiebreak {
+                let mut active_players: Vec<usize> = (0..self.iplayers.len())
+                    .filter(|&player_idx| self.is_active(player_idx))
+                    .collect();
+                active_players.sort_by_key(|&player_idx| self.distance_left(player_idx));
+                if !self.finish_all {
+                    active_players.truncate(1);
+                }
+                for player_idx in active_players {
+                    self.place(player_idx);
+                }
+                self.place_remaining_players();
+            }
+            let leader = self.placements.first().copied();
+            GameOutcome::TurnLimit { leader, turns }
+        }
+
+        fn distance_left(&self, player_idx: usize) -> i32 {
+            let player = self.iplayers[player_idx].player();
+            let geometry = self.board.geometry();
+            let unfreed = (geometry.track_length + geometry.home_column_length) as i32;
+            (0..geometry.pieces())
+                .map(|piece_id| {
+                    player
+                        .get_heuristics(&self.board, piece_id)
+                        .map_or(unfreed, i32::from)
+                })
+                .sum()
+        }
+
+        fn is_game_over(&self) -> bool {
+            let active_players = (0..self.iplayers.len())
+                .filter(|&player_idx| self.is_active(player_idx))
+                .count();
+            if self.finish_all {
+                active_players <= 1
+            } else {
+                !self.placements.is_empty() || active_players <= 1
             }
         }
 
-        fn play_turn(&mut self, player_idx: usize) {
+        fn is_active(&self, player_idx: usize) -> bool {
+            let id = self.iplayers[player_idx].player().id();
+            !self.forfeited.contains(&id) && !self.placements.contains(&id)
+        }
+
+        fn place_remaining_players(&mut self) {
+            let active_players: Vec<usize> = (0..self.iplayers.len())
+                .filter(|&player_idx| self.is_active(player_idx))
+                .collect();
+            if let [player_idx] = active_players[..] {
+                if self.finish_all || self.placements.is_empty() {
+                    self.place(player_idx);
+                }
+            }
+            if !self.finish_all {
+                return;
+            }
+            for id in self.forfeited.clone().into_iter().rev() {
+                let player_idx = self
+                    .iplayers
+                    .iter()
+                    .position(|iplayer| iplayer.player().id() == id)
+                    .unwrap();
+                self.place(player_idx);
+            }
+        }
+
+        fn place(&mut self, player_idx: usize) {
+            let id = self.iplayers[player_idx].player().id();
+            self.placements.push(id);
+            let rank = self.placements.len();
+            self.iplayers[player_idx].place(rank);
+            if rank == 1 {
+                self.iplayers[player_idx].win();
+                if let Some(record) = &mut self.record {
+                    record.winner = Some(id);
+                }
+            }
+        }
+
+        fn forfeit(&mut self, player_idx: usize) {
+            let id = self.iplayers[player_idx].player().id();
+            self.forfeited.push(id);
+        }
+
+        fn play_turn(&mut self, player_idx: usize) -> Result<(), LudoError> {
             self.iplayers[player_idx].my_turn();
-            self.iplayers[player_idx].play(false);
+            let id = self.iplayers[player_idx].player().id();
+            self.board.set_side_to_move(id);
+            let result = self.iplayers[player_idx].play(&mut self.board, false);
+            self.collect_dice(player_idx);
+            self.collect_plies(player_idx);
+            result
         }
 
-        fn next_turn(&mut self, player_idx: usize) {
+        fn collect_plies(&mut self, player_idx: usize) {
+            if let Some(record) = &mut self.record {
+                record
+                    .plies
+                    .append(&mut self.iplayers[player_idx].take_plies());
+            }
+        }
+
+        fn collect_dice(&mut self, player_idx: usize) {
+            if let Some(dice) = self.iplayers[player_idx].player().dice() {
+                self.dice = dice.clone();
+            }
+        }
+
+        fn next_turn(&mut self, player_idx: usize) -> Result<(), LudoError> {
+            let next_idx = (player_idx + 1) % self.iplayers.len();
             self.iplayers[player_idx]
                 .clone()
-                .give_dice(&mut self.iplayers[(player_idx + 1) % 4]);
+                .give_dice(&mut self.iplayers[next_idx])
         }
 
-        fn has_player_won(&mut self, player_idx: usize) -> bool {
-            if self.iplayers[player_idx].player().is_finished() {
-                self.iplayers[player_idx].win();
-                return true;
+        fn check_if_finished(&mut self, player_idx: usize) {
+            if self.iplayers[player_idx].player().is_finished(&self.board) {
+                self.place(player_idx);
             }
-            false
         }
 
-        pub fn beginning(&mut self) {
-            let mut scores: Vec<(i8, i32)> = vec![(0, 0), (1, 0), (2, 0), (3, 0)];
-            self.roll_dice_for_players(&mut scores);
+        pub fn beginning(&mut self) -> Result<(), LudoError> {
+            self.placements.clear();
+            let player_ids = self.player_ids();
+            let mut scores: Vec<(i8, i32)> = player_ids.iter().map(|&id| (id, 0)).collect();
+            self.roll_dice_for_players(&mut scores, &player_ids)?;
             while self.has_ties(&scores) {
-                self.adjust_scores_for_tied_players(&mut scores);
+                self.adjust_scores_for_tied_players(&mut scores)?;
             }
             self.sort_players_by_scores(&scores);
             self.iplayers[0].take_dice(self.dice.clone());
+            if self.recording {
+                let seating = self
+                    .iplayers
+                    .iter()
+                    .map(|iplayer| iplayer.player().id())
+                    .collect();
+                self.record = Some(GameRecord::new(self.board.geometry().players, seating));
+            }
+            Ok(())
         }
 
         fn has_ties(&self, scores: &[(i8, i32)]) -> bool {
@@ -137,27 +469,36 @@ mod game {
             scores.iter().map(|(_, score)| *score).max().unwrap_or(0)
         }
 
-        fn roll_dice_for_players(&mut self, scores: &mut [(i8, i32)]) {
-            for (idx, iplayer) in self.iplayers.iter_mut().enumerate() {
+        fn roll_dice_for_players(
+            &mut self,
+            scores: &mut [(i8, i32)],
+            player_ids: &[i8],
+        ) -> Result<(), LudoError> {
+            for iplayer in self
+                .iplayers
+                .iter_mut()
+                .filter(|iplayer| player_ids.contains(&iplayer.player().id()))
+            {
                 iplayer.take_dice(self.dice.clone());
-                iplayer.roll_dice();
-                scores[idx].1 += iplayer.player().get_dice_number() as i32;
-            }
-        }
-
-        fn adjust_scores_for_tied_players(&mut self, scores: &mut [(i8, i32)]) {
-            loop {
-                self.roll_dice_for_players(scores);
-                let (tied_players, _max_score) = self.get_tied_players(scores);
-                if tied_players.len() == 1 {
-                    break;
+                iplayer.roll_dice()?;
+                if let Some(dice) = iplayer.player().dice() {
+                    self.dice = dice.clone();
                 }
-                for &player_id in &tied_players {
-                    if let Some((_id, _player_score)) =
-                        scores.iter_mut().find(|(id, _)| *id == player_id)
-                    {}
+                let id = iplayer.player().id();
+                if let Some((_id, score)) = scores.iter_mut().find(|(score_id, _)| *score_id == id)
+                {
+                    *score += iplayer.player().get_dice_number() as i32;
                 }
             }
+            Ok(())
+        }
+
+        fn adjust_scores_for_tied_players(
+            &mut self,
+            scores: &mut [(i8, i32)],
+        ) -> Result<(), LudoError> {
+            let (tied_players, _max_score) = self.get_tied_players(scores);
+            self.roll_dice_for_players(scores, &tied_players)
         }
 
         fn get_tied_players(&self, scores: &[(i8, i32)]) -> (Vec<i8>, i32) {
@@ -188,4 +529,5 @@ mod game {
     }
 }
 
-pub use game::Game;
+pub use game::{Game, GameOutcome};
+pub use record::{GameRecord, RecordError};
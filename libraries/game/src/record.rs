@@ -0,0 +1,305 @@
+use board::BoardGeometry;
+use errors::LudoError;
+use iplayers::Ply;
+use players::Act;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RecordError {
+    Parse {
+        line: usize,
+        message: String,
+    },
+    Json(String),
+    Header(String),
+    Seats {
+        ply: usize,
+        players: i8,
+        positions: usize,
+    },
+    UnknownPlayer {
+        ply: usize,
+        player_id: i8,
+    },
+    UnknownPiece {
+        ply: usize,
+        piece_id: i8,
+    },
+    InvalidRoll {
+        ply: usize,
+        roll: i8,
+    },
+    IllegalMove {
+        ply: usize,
+        error: LudoError,
+    },
+    Mismatch {
+        ply: usize,
+        expected: Vec<[i8; 4]>,
+        actual: Vec<[i8; 4]>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GameRecord {
+    #[serde(default = "standard_players")]
+    pub players: i8,
+    pub seating: Vec<i8>,
+    pub plies: Vec<Ply>,
+    pub winner: Option<i8>,
+}
+
+fn standard_players() -> i8 {
+    BoardGeometry::standard().players
+}
+
+impl GameRecord {
+    pub fn new(players: i8, seating: Vec<i8>) -> Self {
+        Self {
+            players,
+            seating,
+            plies: Vec::new(),
+            winner: None,
+        }
+    }
+
+    pub fn geometry(&self) -> BoardGeometry {
+        BoardGeometry::new(self.players)
+    }
+
+    pub fn validate(&self) -> Result<(), RecordError> {
+        self.geometry()
+            .validate()
+            .map_err(|error| RecordError::Header(error.to_string()))?;
+        if let Some(id) = self
+            .seating
+            .iter()
+            .find(|&&id| !(0..self.players).contains(&id))
+        {
+            return Err(RecordError::Header(format!(
+                "player {} is seated on a board for {} players",
+                id, self.players
+            )));
+        }
+        for (index, ply) in self.plies.iter().enumerate() {
+            if ply.positions.len() != self.players as usize {
+                return Err(RecordError::Seats {
+                    ply: index,
+                    players: self.players,
+                    positions: ply.positions.len(),
+                });
+            }
+            if !(0..self.players).contains(&ply.player_id) {
+                return Err(RecordError::UnknownPlayer {
+                    ply: index,
+                    player_id: ply.player_id,
+                });
+            }
+            if let Some(&roll) = ply.rolls.iter().find(|roll| !(1..=6).contains(*roll)) {
+                return Err(RecordError::InvalidRoll { ply: index, roll });
+            }
+            if ply.act == Act::Nothing {
+                continue;
+            }
+            if !(0..self.geometry().pieces()).contains(&ply.piece_id) {
+                return Err(RecordError::UnknownPiece {
+                    ply: index,
+                    piece_id: ply.piece_id,
+                });
+            }
+            if ply.rolls.is_empty() {
+                return Err(RecordError::InvalidRoll {
+                    ply: index,
+                    roll: 0,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = format!("seating {}\n", join(&self.seating, " "));
+        text.push_str(&format!("players {}\n", self.players));
+        for ply in &self.plies {
+            let positions: Vec<String> = ply
+                .positions
+                .iter()
+                .map(|player| join(player, ","))
+                .collect();
+            text.push_str(&format!(
+                "ply {} {} {} {} {}\n",
+                ply.player_id,
+                join_rolls(&ply.rolls),
+                ply.act,
+                ply.piece_id,
+                positions.join(" ")
+            ));
+        }
+        match self.winner {
+            Some(winner) => text.push_str(&format!("winner {}\n", winner)),
+            None => text.push_str("winner none\n"),
+        }
+        text
+    }
+
+    pub fn from_text(text: &str) -> Result<Self, RecordError> {
+        let mut record = GameRecord::default();
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.first() {
+                None => continue,
+                Some(&"seating") => {
+                    record.seating = parse_list(&fields[1..].join(","), line_number)?;
+                }
+                Some(&"players") => {
+                    record.players = match fields.get(1) {
+                        Some(players) => parse_number(players, line_number)?,
+                        None => return Err(parse_error(line_number, "missing player count")),
+                    };
+                }
+                Some(&"ply") => record.plies.push(parse_ply(&fields, line_number)?),
+                Some(&"winner") => {
+                    record.winner = match fields.get(1) {
+                        Some(&"none") => None,
+                        Some(winner) => Some(parse_number(winner, line_number)?),
+                        None => return Err(parse_error(line_number, "missing winner")),
+                    };
+                }
+                Some(keyword) => {
+                    return Err(parse_error(
+                        line_number,
+                        &format!("unknown keyword {}", keyword),
+                    ))
+                }
+            }
+        }
+        Ok(record)
+    }
+
+    pub fn to_json(&self) -> Result<String, RecordError> {
+        serde_json::to_string_pretty(self).map_err(|error| RecordError::Json(error.to_string()))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, RecordError> {
+        serde_json::from_str(json).map_err(|error| RecordError::Json(error.to_string()))
+    }
+}
+
+fn parse_ply(fields: &[&str], line: usize) -> Result<Ply, RecordError> {
+    if fields.len() < 7 {
+        return Err(parse_error(
+            line,
+            "a ply needs positions for at least two players",
+        ));
+    }
+    let act: Act = fields[3]
+        .parse()
+        .map_err(|message: String| parse_error(line, &message))?;
+    let mut positions = Vec::new();
+    for field in &fields[5..] {
+        let pieces = parse_list(field, line)?;
+        positions.push(
+            pieces
+                .try_into()
+                .map_err(|_| parse_error(line, "each player needs 4 positions"))?,
+        );
+    }
+    Ok(Ply {
+        player_id: parse_number(fields[1], line)?,
+        rolls: match fields[2] {
+            "-" => Vec::new(),
+            rolls => parse_list(rolls, line)?,
+        },
+        act,
+        piece_id: parse_number(fields[4], line)?,
+        positions,
+    })
+}
+
+fn parse_list(field: &str, line: usize) -> Result<Vec<i8>, RecordError> {
+    field
+        .split(',')
+        .filter(|value| !value.is_empty())
+        .map(|value| parse_number(value, line))
+        .collect()
+}
+
+fn parse_number(value: &str, line: usize) -> Result<i8, RecordError> {
+    value
+        .parse()
+        .map_err(|_| parse_error(line, &format!("invalid number {}", value)))
+}
+
+fn parse_error(line: usize, message: &str) -> RecordError {
+    RecordError::Parse {
+        line,
+        message: message.to_string(),
+    }
+}
+
+fn join_rolls(rolls: &[i8]) -> String {
+    match rolls.is_empty() {
+        true => "-".to_string(),
+        false => join(rolls, ","),
+    }
+}
+
+fn join(values: &[i8], separator: &str) -> String {
+    values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+impl Display for RecordError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordError::Parse { line, message } => {
+                write!(f, "Invalid record at line {}: {}", line, message)
+            }
+            RecordError::Json(message) => write!(f, "Invalid JSON record: {}", message),
+            RecordError::Header(message) => write!(f, "Invalid record header: {}", message),
+            RecordError::Seats {
+                ply,
+                players,
+                positions,
+            } => write!(
+                f,
+                "Ply {} has positions for {} players, the record is for {}",
+                ply, positions, players
+            ),
+            RecordError::UnknownPlayer { ply, player_id } => {
+                write!(f, "Ply {} is played by unknown player {}", ply, player_id)
+            }
+            RecordError::UnknownPiece { ply, piece_id } => {
+                write!(f, "Ply {} moves unknown piece {}", ply, piece_id)
+            }
+            RecordError::InvalidRoll { ply, roll } => {
+                write!(f, "Ply {} has an invalid roll {}", ply, roll)
+            }
+            RecordError::IllegalMove { ply, error } => {
+                write!(f, "Ply {} could not be replayed: {}", ply, error)
+            }
+            RecordError::Mismatch {
+                ply,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Board diverged at ply {}: expected {:?}, got {:?}",
+                ply, expected, actual
+            ),
+        }
+    }
+}
+
+impl Default for GameRecord {
+    fn default() -> Self {
+        Self::new(standard_players(), Vec::new())
+    }
+}
+
+impl std::error::Error for RecordError {}
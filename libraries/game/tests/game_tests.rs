@@ -1,5 +1,10 @@
-use game::Game;
-use iplayers::Playstyle;
+use board::{Board, BoardGeometry, PlayerID};
+use dice::{DiceError, ScriptedDice};
+use errors::LudoError;
+use game::{Game, GameOutcome, GameRecord, RecordError};
+use iplayers::{IPlayer, Playstyle, ScriptedInput};
+use players::{Overshoot, Player, RuleSet};
+use std::thread;
 
 #[cfg(test)]
 mod init_game_test {
@@ -9,122 +14,817 @@ mod init_game_test {
     #[test]
     fn initialization_test() {
         let mut game = Game::new();
-        game.setup_game();
-        assert_eq!(game.iplayer(0).player().id(), 0);
-        assert_eq!(game.iplayer(1).player().id(), 1);
-        assert_eq!(game.iplayer(2).player().id(), 2);
-        assert_eq!(game.iplayer(3).player().id(), 3);
+        assert_eq!(game.iplayer(0).unwrap().player().id(), 0);
+        assert_eq!(game.iplayer(1).unwrap().player().id(), 1);
+        assert_eq!(game.iplayer(2).unwrap().player().id(), 2);
+        assert_eq!(game.iplayer(3).unwrap().player().id(), 3);
     }
 
     #[test]
     fn beginning_test() {
         let mut game = Game::new();
-        game.setup_game();
-        game.beginning();
-        println!("1st Player: {:?}\n", game.iplayer(0).player().id());
-        println!("2nd Player: {:?}\n", game.iplayer(1).player().id());
-        println!("3rd Player: {:?}\n", game.iplayer(2).player().id());
-        println!("4th Player: {:?}\n", game.iplayer(3).player().id());
+        game.beginning().unwrap();
+        println!("1st Player: {:?}\n", game.iplayer(0).unwrap().player().id());
+        println!("2nd Player: {:?}\n", game.iplayer(1).unwrap().player().id());
+        println!("3rd Player: {:?}\n", game.iplayer(2).unwrap().player().id());
+        println!("4th Player: {:?}\n", game.iplayer(3).unwrap().player().id());
     }
 
     #[test]
     fn run_game_random_test() {
         let mut game = Game::new();
-        game.setup_game();
-        game.give_iplayer_a_playstyle(0, Playstyle::Random);
-        game.give_iplayer_a_playstyle(1, Playstyle::Random);
-        game.give_iplayer_a_playstyle(2, Playstyle::Random);
-        game.give_iplayer_a_playstyle(3, Playstyle::Random);
-        game.beginning();
-        game.run();
+        game.give_iplayer_a_playstyle(0, Playstyle::Random).unwrap();
+        game.give_iplayer_a_playstyle(1, Playstyle::Random).unwrap();
+        game.give_iplayer_a_playstyle(2, Playstyle::Random).unwrap();
+        game.give_iplayer_a_playstyle(3, Playstyle::Random).unwrap();
+        game.beginning().unwrap();
+        game.run().unwrap();
     }
 
     #[test]
     fn run_game_aggro_test() {
         let mut game = Game::new();
-        game.setup_game();
-        game.give_iplayer_a_playstyle(0, Playstyle::Aggressive);
-        game.give_iplayer_a_playstyle(1, Playstyle::Aggressive);
-        game.give_iplayer_a_playstyle(2, Playstyle::Aggressive);
-        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive);
-        game.beginning();
-        game.run();
+        game.give_iplayer_a_playstyle(0, Playstyle::Aggressive)
+            .unwrap();
+        game.give_iplayer_a_playstyle(1, Playstyle::Aggressive)
+            .unwrap();
+        game.give_iplayer_a_playstyle(2, Playstyle::Aggressive)
+            .unwrap();
+        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive)
+            .unwrap();
+        game.beginning().unwrap();
+        game.run().unwrap();
     }
 
     #[test]
     fn run_game_fast_test() {
         let mut game = Game::new();
-        game.setup_game();
-        game.give_iplayer_a_playstyle(0, Playstyle::Fast);
-        game.give_iplayer_a_playstyle(1, Playstyle::Fast);
-        game.give_iplayer_a_playstyle(2, Playstyle::Fast);
-        game.give_iplayer_a_playstyle(3, Playstyle::Fast);
-        game.beginning();
-        game.run();
+        game.give_iplayer_a_playstyle(0, Playstyle::Fast).unwrap();
+        game.give_iplayer_a_playstyle(1, Playstyle::Fast).unwrap();
+        game.give_iplayer_a_playstyle(2, Playstyle::Fast).unwrap();
+        game.give_iplayer_a_playstyle(3, Playstyle::Fast).unwrap();
+        game.beginning().unwrap();
+        game.run().unwrap();
     }
 
     #[test]
     fn run_game_mixed_test() {
         let mut game = Game::new();
-        game.setup_game();
-        game.give_iplayer_a_playstyle(0, Playstyle::Fast);
-        game.give_iplayer_a_playstyle(1, Playstyle::Random);
-        game.give_iplayer_a_playstyle(2, Playstyle::Safe);
-        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive);
-        game.beginning();
-        game.run();
+        game.give_iplayer_a_playstyle(0, Playstyle::Fast).unwrap();
+        game.give_iplayer_a_playstyle(1, Playstyle::Random).unwrap();
+        game.give_iplayer_a_playstyle(2, Playstyle::Safe).unwrap();
+        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive)
+            .unwrap();
+        game.beginning().unwrap();
+        game.run().unwrap();
     }
 
     #[test]
     fn proper_beginning_test() {
         let mut game = Game::new();
-        game.setup_game();
-        game.give_iplayer_a_playstyle(0, Playstyle::Fast);
-        game.give_iplayer_a_playstyle(1, Playstyle::Random);
-        game.give_iplayer_a_playstyle(2, Playstyle::Safe);
-        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive);
-        game.beginning();
-        game.run();
+        game.give_iplayer_a_playstyle(0, Playstyle::Fast).unwrap();
+        game.give_iplayer_a_playstyle(1, Playstyle::Random).unwrap();
+        game.give_iplayer_a_playstyle(2, Playstyle::Safe).unwrap();
+        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive)
+            .unwrap();
+        game.beginning().unwrap();
+        game.run().unwrap();
     }
 
     #[test]
     fn play_game_test() {
         let mut game = Game::new();
-        game.setup_game();
-        game.give_iplayer_a_playstyle(0, Playstyle::Fast);
-        game.give_iplayer_a_playstyle(1, Playstyle::Random);
-        game.give_iplayer_a_playstyle(2, Playstyle::Safe);
-        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive);
-        game.start_game(1000);
-        game.iplayer(0).calculate_winrate(1000);
-        game.iplayer(1).calculate_winrate(1000);
-        game.iplayer(2).calculate_winrate(1000);
-        game.iplayer(3).calculate_winrate(1000);
-        game.iplayer(0).print_winrate();
-        game.iplayer(1).print_winrate();
-        game.iplayer(2).print_winrate();
-        game.iplayer(3).print_winrate();
+        game.give_iplayer_a_playstyle(0, Playstyle::Fast).unwrap();
+        game.give_iplayer_a_playstyle(1, Playstyle::Random).unwrap();
+        game.give_iplayer_a_playstyle(2, Playstyle::Safe).unwrap();
+        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive)
+            .unwrap();
+        game.start_game(1000).unwrap();
+        game.iplayer(0).unwrap().calculate_winrate(1000);
+        game.iplayer(1).unwrap().calculate_winrate(1000);
+        game.iplayer(2).unwrap().calculate_winrate(1000);
+        game.iplayer(3).unwrap().calculate_winrate(1000);
+        game.iplayer(0).unwrap().print_winrate();
+        game.iplayer(1).unwrap().print_winrate();
+        game.iplayer(2).unwrap().print_winrate();
+        game.iplayer(3).unwrap().print_winrate();
     }
 
     #[test]
     fn get_iplayer_id_test() {
         let mut game = Game::new();
-        game.setup_game();
-        game.give_iplayer_a_playstyle(0, Playstyle::Fast);
-        game.give_iplayer_a_playstyle(1, Playstyle::Random);
-        game.give_iplayer_a_playstyle(2, Playstyle::Safe);
-        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive);
+        game.give_iplayer_a_playstyle(0, Playstyle::Fast).unwrap();
+        game.give_iplayer_a_playstyle(1, Playstyle::Random).unwrap();
+        game.give_iplayer_a_playstyle(2, Playstyle::Safe).unwrap();
+        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive)
+            .unwrap();
 
         for _ in 0..1000 {
-            game.beginning();
-            let id0 = game.iplayer(0).player().id();
-            let id1 = game.iplayer(1).player().id();
-            let id2 = game.iplayer(2).player().id();
-            let id3 = game.iplayer(3).player().id();
+            game.beginning().unwrap();
+            let id0 = game.iplayer(0).unwrap().player().id();
+            let id1 = game.iplayer(1).unwrap().player().id();
+            let id2 = game.iplayer(2).unwrap().player().id();
+            let id3 = game.iplayer(3).unwrap().player().id();
             assert_eq!(id0, 0);
             assert_eq!(id1, 1);
             assert_eq!(id2, 2);
             assert_eq!(id3, 3);
         }
     }
+
+    #[test]
+    fn seeded_game_test() {
+        let mut games = [Game::with_seed(1234), Game::with_seed(1234)];
+        for game in games.iter_mut() {
+            game.give_iplayer_a_playstyle(0, Playstyle::Fast).unwrap();
+            game.give_iplayer_a_playstyle(1, Playstyle::Random).unwrap();
+            game.give_iplayer_a_playstyle(2, Playstyle::Safe).unwrap();
+            game.give_iplayer_a_playstyle(3, Playstyle::Aggressive)
+                .unwrap();
+            game.start_game(20).unwrap();
+        }
+        assert_eq!(games[0].get_board(), games[1].get_board());
+        for id in 0..4 {
+            games[0].iplayer(id).unwrap().calculate_winrate(20);
+            games[1].iplayer(id).unwrap().calculate_winrate(20);
+            let winrate = *games[1].iplayer(id).unwrap().get_winrate();
+            assert_eq!(games[0].iplayer(id).unwrap().get_winrate(), &winrate);
+        }
+    }
+
+    #[test]
+    fn scripted_game_test() {
+        let rolls = vec![
+            6, 5, 4, 3, // beginning
+            1, 2, 6, 4, // player 0 frees a piece and moves it
+            1, 1, 1, // player 1
+            2, 2, 2, // player 2
+            3, 3, 3, // player 3
+        ];
+        let mut game = Game::with_dice(ScriptedDice::new(rolls));
+        for id in 0..4 {
+            game.give_iplayer_a_playstyle(id, Playstyle::Fast).unwrap();
+        }
+        game.beginning().unwrap();
+        assert_eq!(game.run(), Err(LudoError::Dice(DiceError::Exhausted)));
+
+        assert_eq!(game.get_board().piece(0, 0).position(), 4);
+        for id in 0..4 {
+            for piece_id in 0..4 {
+                if (id, piece_id) != (0, 0) {
+                    assert!(game.get_board().piece(id, piece_id).is_home());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn forfeit_game_test() {
+        let mut game = Game::with_seed(7);
+        for id in 0..4 {
+            game.give_iplayer_a_playstyle(id, Playstyle::Fast).unwrap();
+        }
+        for piece_id in 0..4 {
+            let piece = game.get_board_mut().piece_mut(0, piece_id);
+            piece.free();
+            piece.set_position(10 + piece_id).unwrap();
+        }
+        game.beginning().unwrap();
+        game.run().unwrap();
+
+        assert_eq!(game.forfeited(), &[0]);
+        let board = game.get_board().clone();
+        assert!(!game.iplayer(0).unwrap().player().is_finished(&board));
+        assert!((1..4).any(|id| game.iplayer(id).unwrap().player().is_finished(&board)));
+
+        game.reset_game();
+        assert!(game.forfeited().is_empty());
+    }
+
+    #[test]
+    fn closed_input_test() {
+        let mut game = Game::with_seed(7);
+        game.give_iplayer_a_playstyle(0, Playstyle::Human).unwrap();
+        game.iplayer(0).unwrap().set_input(ScriptedInput::new(&[]));
+        for id in 1..4 {
+            game.give_iplayer_a_playstyle(id, Playstyle::Fast).unwrap();
+        }
+        game.beginning().unwrap();
+
+        assert_eq!(game.run(), Err(LudoError::InputClosed));
+        assert!(game.forfeited().is_empty());
+    }
+
+    #[test]
+    fn winner_only_placement_test() {
+        let mut game = Game::with_seed(42);
+        for id in 0..4 {
+            game.give_iplayer_a_playstyle(id, Playstyle::Random)
+                .unwrap();
+        }
+        game.beginning().unwrap();
+        let outcome = game.run().unwrap();
+
+        assert_eq!(game.placements().len(), 1);
+        let winner = game.placements()[0];
+        assert!(matches!(outcome, GameOutcome::Winner { player_id, .. } if player_id == winner));
+        let board = game.get_board().clone();
+        assert!(game.iplayer(winner).unwrap().player().is_finished(&board));
+        assert_eq!(
+            game.iplayer(winner).unwrap().get_placements(),
+            &[1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn finish_all_placement_test() {
+        let mut game = Game::with_seed(42);
+        game.set_finish_all(true);
+        for id in 0..4 {
+            game.give_iplayer_a_playstyle(id, Playstyle::Random)
+                .unwrap();
+        }
+        game.beginning().unwrap();
+        let outcome = game.run().unwrap();
+
+        let placements = game.placements().to_vec();
+        assert_eq!(
+            outcome,
+            GameOutcome::Placements {
+                placements: placements.clone(),
+                turns: outcome.turns()
+            }
+        );
+        let mut ids = placements.clone();
+        ids.sort();
+        assert_eq!(ids, vec![0, 1, 2, 3]);
+        let board = game.get_board().clone();
+        for (rank, &id) in placements.iter().enumerate() {
+            assert_eq!(
+                game.iplayer(id).unwrap().player().is_finished(&board),
+                rank < 3
+            );
+            assert_eq!(game.iplayer(id).unwrap().get_placements()[rank], 1);
+        }
+    }
+
+    #[test]
+    fn placement_histogram_test() {
+        let total_games = 20;
+        let mut game = Game::with_seed(1234);
+        game.set_finish_all(true);
+        game.give_iplayer_a_playstyle(0, Playstyle::Fast).unwrap();
+        game.give_iplayer_a_playstyle(1, Playstyle::Random).unwrap();
+        game.give_iplayer_a_playstyle(2, Playstyle::Safe).unwrap();
+        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive)
+            .unwrap();
+        game.start_game(total_games).unwrap();
+
+        let mut rank_sum = 0.0;
+        for id in 0..4 {
+            let iplayer = game.iplayer(id).unwrap();
+            iplayer.calculate_winrate(total_games);
+            iplayer.calculate_average_rank();
+            let placements = iplayer.get_placements().to_vec();
+            assert_eq!(placements.iter().sum::<u16>(), total_games);
+            assert_eq!(
+                *iplayer.get_winrate(),
+                placements[0] as f64 / total_games as f64 * 100.0
+            );
+            assert!((1.0..=4.0).contains(iplayer.get_average_rank()));
+            rank_sum += iplayer.get_average_rank();
+        }
+        assert!((rank_sum - 10.0).abs() < 1e-9);
+
+        game.reset_scores();
+        assert_eq!(game.iplayer(0).unwrap().get_placements(), &[0; 4]);
+        assert_eq!(*game.iplayer(0).unwrap().get_average_rank(), 0.0);
+    }
+
+    #[test]
+    fn turn_limit_test() {
+        let mut game = Game::with_seed(42);
+        game.set_turn_limit(10);
+        for id in 0..4 {
+            game.give_iplayer_a_playstyle(id, Playstyle::Random)
+                .unwrap();
+        }
+        game.beginning().unwrap();
+        let outcome = game.run().unwrap();
+
+        assert_eq!(
+            outcome,
+            GameOutcome::TurnLimit {
+                leader: None,
+                turns: 10
+            }
+        );
+        assert!(outcome.is_timeout());
+        assert!(game.placements().is_empty());
+        for id in 0..4 {
+            assert_eq!(game.iplayer(id).unwrap().get_timeouts(), 1);
+            assert_eq!(game.iplayer(id).unwrap().get_placements(), &[0; 4]);
+        }
+    }
+
+    #[test]
+    fn tiebreak_test() {
+        let mut game = Game::with_seed(42);
+        game.set_turn_limit(40);
+        game.set_tiebreak(true);
+        for id in 0..4 {
+            game.give_iplayer_a_playstyle(id, Playstyle::Fast).unwrap();
+        }
+        game.beginning().unwrap();
+        let outcome = game.run().unwrap();
+
+        assert_eq!(game.placements().len(), 1);
+        let leader = game.placements()[0];
+        assert_eq!(
+            outcome,
+            GameOutcome::TurnLimit {
+                leader: Some(leader),
+                turns: 40
+            }
+        );
+        assert_eq!(
+            game.iplayer(leader).unwrap().get_placements(),
+            &[1, 0, 0, 0]
+        );
+        assert_eq!(game.iplayer(leader).unwrap().get_timeouts(), 1);
+    }
+
+    #[test]
+    fn tiebreak_finish_all_test() {
+        let mut game = Game::with_seed(42);
+        game.set_turn_limit(40);
+        game.set_tiebreak(true);
+        game.set_finish_all(true);
+        for id in 0..4 {
+            game.give_iplayer_a_playstyle(id, Playstyle::Fast).unwrap();
+        }
+        game.beginning().unwrap();
+        let outcome = game.run().unwrap();
+
+        assert!(outcome.is_timeout());
+        let mut ids = game.placements().to_vec();
+        ids.sort();
+        assert_eq!(ids, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn last_player_standing_test() {
+        let mut game = Game::with_seed(7);
+        for id in 0..4 {
+            game.give_iplayer_a_playstyle(id, Playstyle::Fast).unwrap();
+            for piece_id in 0..4 {
+                let piece = game.get_board_mut().piece_mut(id, piece_id);
+                piece.free();
+                piece.set_position(13 * id + piece_id + 1).unwrap();
+            }
+        }
+        game.beginning().unwrap();
+        let outcome = game.run().unwrap();
+
+        let GameOutcome::Winner { player_id, .. } = outcome else {
+            panic!("expected a winner, got {:?}", outcome);
+        };
+        assert_eq!(game.forfeited().len(), 3);
+        assert!(!game.forfeited().contains(&player_id));
+        assert_eq!(game.placements(), &[player_id]);
+        assert_eq!(game.iplayer(player_id).unwrap().get_placements()[0], 1);
+    }
+
+    #[test]
+    fn heads_up_game_test() {
+        let total_games = 20;
+        let mut game = Game::with_players(&[PlayerID::Player0, PlayerID::Player2]).unwrap();
+        game.set_seed(99);
+        game.give_iplayer_a_playstyle(0, Playstyle::Fast).unwrap();
+        game.give_iplayer_a_playstyle(2, Playstyle::Aggressive)
+            .unwrap();
+        assert_eq!(
+            game.give_iplayer_a_playstyle(1, Playstyle::Fast),
+            Err(LudoError::InvalidPlayer(1))
+        );
+        game.set_recording(true);
+        game.beginning().unwrap();
+        let outcome = game.run().unwrap();
+
+        assert!(
+            matches!(outcome, GameOutcome::Winner { player_id, .. } if player_id == 0 || player_id == 2)
+        );
+        let record = game.record().unwrap().clone();
+        let mut seating = record.seating.clone();
+        seating.sort();
+        assert_eq!(seating, vec![0, 2]);
+        assert!(record
+            .plies
+            .iter()
+            .all(|ply| ply.player_id == 0 || ply.player_id == 2));
+        assert_eq!(Game::replay(&record), Ok(()));
+        for id in [1, 3] {
+            assert_eq!(game.get_board().home[id as usize].pieces.len(), 4);
+        }
+        game.reset_game();
+
+        game.start_game(total_games).unwrap();
+        let wins: u16 = [0, 2]
+            .iter()
+            .map(|&id| game.iplayer(id).unwrap().get_placements()[0])
+            .sum();
+        assert_eq!(wins, total_games);
+    }
+
+    #[test]
+    fn three_player_game_test() {
+        let mut game =
+            Game::with_players(&[PlayerID::Player1, PlayerID::Player2, PlayerID::Player3]).unwrap();
+        game.set_seed(5);
+        game.set_finish_all(true);
+        for id in 1..4 {
+            game.give_iplayer_a_playstyle(id, Playstyle::Random)
+                .unwrap();
+        }
+        game.beginning().unwrap();
+        let outcome = game.run().unwrap();
+
+        assert!(matches!(outcome, GameOutcome::Placements { .. }));
+        let mut placements = game.placements().to_vec();
+        placements.sort();
+        assert_eq!(placements, vec![1, 2, 3]);
+        assert!(game.get_board().home[0].pieces.len() == 4);
+    }
+
+    #[test]
+    fn six_player_game_test() {
+        let ids = [
+            PlayerID::Player0,
+            PlayerID::Player1,
+            PlayerID::Player2,
+            PlayerID::Player3,
+            PlayerID::Player4,
+            PlayerID::Player5,
+        ];
+        let mut game = Game::with_geometry(BoardGeometry::six_player(), &ids).unwrap();
+        game.set_seed(11);
+        game.set_turn_limit(5000);
+        game.set_finish_all(true);
+        for id in 0..6 {
+            game.give_iplayer_a_playstyle(id, Playstyle::Random)
+                .unwrap();
+        }
+        game.beginning().unwrap();
+        let outcome = game.run().unwrap();
+
+        assert!(matches!(outcome, GameOutcome::Placements { .. }));
+        let mut placements = game.placements().to_vec();
+        placements.sort();
+        assert_eq!(placements, vec![0, 1, 2, 3, 4, 5]);
+        assert!(matches!(
+            Game::with_geometry(BoardGeometry::standard(), &ids),
+            Err(LudoError::InvalidPlayer(4))
+        ));
+    }
+
+    #[test]
+    fn rule_set_test() {
+        let mut game = Game::with_players(&[PlayerID::Player0, PlayerID::Player2]).unwrap();
+        assert_eq!(game.rules(), &RuleSet::standard());
+        game.set_rules(RuleSet::exact_goal());
+        assert_eq!(game.rules().overshoot, Overshoot::Exact);
+        assert_eq!(
+            game.iplayer(2).unwrap().player().rules(),
+            &RuleSet::exact_goal()
+        );
+    }
+
+    #[test]
+    fn rule_variant_games_test() {
+        let variants = [
+            RuleSet::exact_goal(),
+            RuleSet::no_bounce(),
+            RuleSet::triple_six_forfeits(),
+            RuleSet {
+                six_grants_extra_turn: false,
+                first_round_attempts: 1,
+                star_jumping: false,
+                globe_safety: false,
+                blockades: false,
+                overshoot: Overshoot::Bounce,
+                capture_grants_extra_roll: false,
+                triple_six_forfeits_turn: true,
+            },
+        ];
+        for rules in variants {
+            let mut game = Game::new();
+            game.set_seed(3);
+            game.set_rules(rules);
+            game.set_finish_all(true);
+            game.set_turn_limit(5000);
+            for id in 0..4 {
+                game.give_iplayer_a_playstyle(id, Playstyle::Random)
+                    .unwrap();
+            }
+            game.beginning().unwrap();
+            let outcome = game.run().unwrap();
+
+            assert!(matches!(outcome, GameOutcome::Placements { .. }));
+            assert!(game.forfeited().is_empty());
+        }
+    }
+
+    #[test]
+    fn invalid_players_test() {
+        assert!(matches!(
+            Game::with_players(&[PlayerID::Player0]),
+            Err(LudoError::IllegalAction(_))
+        ));
+        assert!(matches!(
+            Game::with_players(&[PlayerID::Player1, PlayerID::Player1]),
+            Err(LudoError::IllegalAction(_))
+        ));
+    }
+
+    #[test]
+    fn forfeit_placement_test() {
+        let mut game = Game::with_seed(7);
+        game.set_finish_all(true);
+        for id in 0..4 {
+            game.give_iplayer_a_playstyle(id, Playstyle::Fast).unwrap();
+        }
+        for piece_id in 0..4 {
+            let piece = game.get_board_mut().piece_mut(0, piece_id);
+            piece.free();
+            piece.set_position(10 + piece_id).unwrap();
+        }
+        game.beginning().unwrap();
+        game.run().unwrap();
+
+        assert_eq!(game.forfeited(), &[0]);
+        assert_eq!(game.placements().len(), 4);
+        assert_eq!(game.placements()[3], 0);
+        assert_eq!(game.iplayer(0).unwrap().get_placements(), &[0, 0, 0, 1]);
+    }
+
+    fn recorded_game() -> GameRecord {
+        let mut game = Game::with_seed(42);
+        game.give_iplayer_a_playstyle(0, Playstyle::Fast).unwrap();
+        game.give_iplayer_a_playstyle(1, Playstyle::Random).unwrap();
+        game.give_iplayer_a_playstyle(2, Playstyle::Safe).unwrap();
+        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive)
+            .unwrap();
+        game.set_recording(true);
+        game.beginning().unwrap();
+        game.run().unwrap();
+        game.record().unwrap().clone()
+    }
+
+    #[test]
+    fn record_game_test() {
+        let record = recorded_game();
+        let mut seating = record.seating.clone();
+        seating.sort();
+        assert_eq!(seating, vec![0, 1, 2, 3]);
+        assert!(!record.plies.is_empty());
+        assert!(record.winner.is_some());
+        assert_eq!(record.plies[0].player_id, record.seating[0]);
+        assert!(record.plies.iter().all(|ply| !ply.rolls.is_empty()));
+        let winner = record.winner.unwrap() as usize;
+        assert_eq!(record.plies.last().unwrap().positions[winner], [99; 4]);
+    }
+
+    #[test]
+    fn replay_game_test() {
+        let record = recorded_game();
+        assert_eq!(Game::replay(&record), Ok(()));
+
+        let mut tampered = record.clone();
+        let ply = tampered
+            .plies
+            .iter()
+            .position(|ply| ply.positions[ply.player_id as usize] != [-1; 4])
+            .unwrap();
+        tampered.plies[ply].positions[0][0] = 42;
+        assert!(matches!(
+            Game::replay(&tampered),
+            Err(RecordError::Mismatch { ply: index, .. }) if index == ply
+        ));
+    }
+
+    #[test]
+    fn record_header_test() {
+        let record = recorded_game();
+        assert_eq!(record.players, 4);
+        assert!(record.to_text().contains("\nplayers 4\n"));
+
+        let mut five_seats = record.clone();
+        five_seats.players = 5;
+        assert!(matches!(
+            Game::replay(&five_seats),
+            Err(RecordError::Seats {
+                ply: 0,
+                players: 5,
+                positions: 4
+            })
+        ));
+        let mut oversized = record.clone();
+        oversized.players = 9;
+        assert!(matches!(
+            Game::replay(&oversized),
+            Err(RecordError::Header(_))
+        ));
+        let mut unseated = record;
+        unseated.seating.push(5);
+        assert!(matches!(unseated.validate(), Err(RecordError::Header(_))));
+    }
+
+    #[test]
+    fn record_text_format_test() {
+        let record = recorded_game();
+        let text = record.to_text();
+        assert!(text.starts_with("seating "));
+        assert_eq!(GameRecord::from_text(&text), Ok(record));
+
+        let line = "ply 0 1,6 Free 0 0,-1,-1,-1 -1,-1,-1,-1 -1,-1,-1,-1 -1,-1,-1,-1";
+        let parsed = GameRecord::from_text(line).unwrap();
+        assert_eq!(parsed.plies[0].dice_number(), 6);
+        assert_eq!(Game::replay(&parsed), Ok(()));
+
+        assert!(matches!(
+            GameRecord::from_text("seating 0 1 2 3\nply 0 6 Fly 0"),
+            Err(RecordError::Parse { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn malformed_record_test() {
+        let empty = "-1,-1,-1,-1 -1,-1,-1,-1 -1,-1,-1,-1 -1,-1,-1,-1";
+        let piece = GameRecord::from_text(&format!("ply 0 6 Free 7 {}", empty)).unwrap();
+        assert_eq!(
+            Game::replay(&piece),
+            Err(RecordError::UnknownPiece {
+                ply: 0,
+                piece_id: 7
+            })
+        );
+        let roll = GameRecord::from_text(&format!("ply 0 9 Free 0 {}", empty)).unwrap();
+        assert_eq!(
+            Game::replay(&roll),
+            Err(RecordError::InvalidRoll { ply: 0, roll: 9 })
+        );
+        let player = GameRecord::from_text(&format!("ply 4 6 Free 0 {}", empty)).unwrap();
+        assert_eq!(
+            Game::replay(&player),
+            Err(RecordError::UnknownPlayer {
+                ply: 0,
+                player_id: 4
+            })
+        );
+        let unrolled = GameRecord::from_text(&format!("ply 0 - Free 0 {}", empty)).unwrap();
+        assert_eq!(
+            Game::replay(&unrolled),
+            Err(RecordError::InvalidRoll { ply: 0, roll: 0 })
+        );
+    }
+
+    #[test]
+    fn record_json_format_test() {
+        let record = recorded_game();
+        let json = record.to_json().unwrap();
+        assert_eq!(GameRecord::from_json(&json), Ok(record));
+        assert!(matches!(
+            GameRecord::from_json("{"),
+            Err(RecordError::Json(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod export_tests {
+    use super::*;
+
+    fn recorded_game() -> GameRecord {
+        let mut game = Game::new();
+        game.set_seed(18);
+        for id in 0..4 {
+            game.give_iplayer_a_playstyle(id, Playstyle::Random)
+                .unwrap();
+        }
+        game.set_recording(true);
+        game.beginning().unwrap();
+        game.run().unwrap();
+        game.record().unwrap().clone()
+    }
+
+    #[test]
+    fn boards_test() {
+        let record = recorded_game();
+        let boards = record.boards().unwrap();
+        assert_eq!(boards.len(), record.plies.len() + 1);
+        assert_eq!(boards[0], Board::new());
+        for (board, ply) in boards[1..].iter().zip(record.plies.iter()) {
+            assert_eq!(board.piece_positions(), ply.positions);
+        }
+    }
+
+    #[test]
+    fn animated_svg_test() {
+        let record = recorded_game();
+        let svg = record.to_svg(0.5).unwrap();
+        let frames = record.plies.len() + 1;
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("class=\"board\"").count(), 1);
+        assert_eq!(svg.matches("class=\"frame\"").count(), frames);
+        assert_eq!(svg.matches("fill=\"freeze\"").count(), 1);
+        assert!(svg.contains("begin=\"0.5s\" dur=\"0.5s\""));
+        assert!(svg.contains(">Start</text>"));
+    }
+
+    #[test]
+    fn html_export_test() {
+        let record = recorded_game();
+        let html = record.to_html().unwrap();
+        let last = record.plies.len();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains(&format!("max=\"{}\"", last)));
+        assert_eq!(html.matches("class=\"frame\"").count(), last + 1);
+        assert_eq!(html.matches("style=\"display:inline\"").count(), 1);
+        let ply = &record.plies[0];
+        assert!(html.contains(&format!(
+            "Ply 1: player {} rolled {}",
+            ply.player_id, ply.rolls[0]
+        )));
+    }
+
+    #[test]
+    fn invalid_record_export_test() {
+        let mut record = recorded_game();
+        record.plies[2].positions[1][0] = 120;
+        assert!(matches!(
+            record.to_svg(1.0),
+            Err(RecordError::IllegalMove { ply: 2, .. })
+        ));
+    }
+}
+
+#[cfg(test)]
+mod thread_safety_tests {
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    fn seeded_outcome(seed: u64) -> GameOutcome {
+        let mut game = Game::with_seed(seed);
+        for id in 0..4 {
+            game.give_iplayer_a_playstyle(id, Playstyle::Random)
+                .unwrap();
+        }
+        game.beginning().unwrap();
+        game.run().unwrap()
+    }
+
+    #[test]
+    fn send_sync_test() {
+        assert_send_sync::<Board>();
+        assert_send_sync::<Player>();
+        assert_send_sync::<IPlayer>();
+        assert_send_sync::<Game>();
+    }
+
+    #[test]
+    fn parallel_games_test() {
+        let seeds: Vec<u64> = (0..8).collect();
+        let sequential: Vec<GameOutcome> = seeds.iter().map(|&seed| seeded_outcome(seed)).collect();
+        let parallel: Vec<GameOutcome> = thread::scope(|scope| {
+            let handles: Vec<_> = seeds
+                .iter()
+                .map(|&seed| scope.spawn(move || seeded_outcome(seed)))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        });
+        assert_eq!(parallel, sequential);
+    }
+
+    #[test]
+    fn move_game_across_threads_test() {
+        let mut game = Game::with_seed(3);
+        for id in 0..4 {
+            game.give_iplayer_a_playstyle(id, Playstyle::Fast).unwrap();
+        }
+        game.beginning().unwrap();
+        let mut game = thread::spawn(move || {
+            game.run().unwrap();
+            game
+        })
+        .join()
+        .unwrap();
+        assert_eq!(game.placements().len(), 1);
+        let winner = game.placements()[0];
+        let board = game.get_board().clone();
+        assert!(game.iplayer(winner).unwrap().player().is_finished(&board));
+    }
 }
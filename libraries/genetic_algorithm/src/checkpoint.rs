@@ -0,0 +1,170 @@
+use crate::experiment::{Experiment, ExperimentError};
+use crate::genetic_algorithm::{GeneticAlgorithm, RunError};
+use iplayers::{IPlayer, Playstyle};
+use players::{Act, Select};
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CheckpointError {
+    Io { path: PathBuf, message: String },
+    Parse { path: PathBuf, message: String },
+    Serialize { path: PathBuf, message: String },
+    Invalid(ExperimentError),
+    CustomEvaluator,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Genome {
+    pub actions: [Act; 10],
+    pub select: Select,
+    pub winrate: f64,
+    pub timeouts: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub experiment: Experiment,
+    pub generation: usize,
+    pub population: Vec<Genome>,
+    pub history: Vec<(usize, Vec<Genome>)>,
+    pub rng: ChaCha12Rng,
+}
+
+impl Genome {
+    fn from_iplayer(iplayer: &IPlayer) -> Self {
+        Genome {
+            actions: *iplayer.get_actions(),
+            select: *iplayer.get_piece_selector(),
+            winrate: *iplayer.get_winrate(),
+            timeouts: iplayer.get_timeouts(),
+        }
+    }
+
+    fn to_iplayer(&self) -> IPlayer {
+        let mut iplayer = IPlayer::new(0);
+        iplayer.set_playstyle(Playstyle::GeneticAlgorithm);
+        iplayer.set_actions(self.actions);
+        iplayer.select_which_piece(self.select);
+        iplayer.set_fitness(self.winrate, self.timeouts);
+        iplayer
+    }
+}
+
+impl Checkpoint {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, CheckpointError> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|error| CheckpointError::Io {
+            path: path.to_path_buf(),
+            message: error.to_string(),
+        })?;
+        serde_json::from_str(&json).map_err(|error| CheckpointError::Parse {
+            path: path.to_path_buf(),
+            message: error.to_string(),
+        })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), CheckpointError> {
+        let path = path.as_ref();
+        let io_error = |error: std::io::Error| CheckpointError::Io {
+            path: path.to_path_buf(),
+            message: error.to_string(),
+        };
+        let json = serde_json::to_string(self).map_err(|error| CheckpointError::Serialize {
+            path: path.to_path_buf(),
+            message: error.to_string(),
+        })?;
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let partial = path.with_extension("partial");
+        std::fs::write(&partial, json).map_err(io_error)?;
+        std::fs::rename(&partial, path).map_err(io_error)
+    }
+}
+
+impl GeneticAlgorithm {
+    pub fn checkpoint(&self) -> Checkpoint {
+        let genomes = |iplayers: &[IPlayer]| iplayers.iter().map(Genome::from_iplayer).collect();
+        Checkpoint {
+            experiment: Experiment::from(self),
+            generation: self.generation,
+            population: genomes(&self.population),
+            history: self
+                .data
+                .iter()
+                .map(|(generation, iplayers)| (*generation, genomes(iplayers)))
+                .collect(),
+            rng: self.rng.clone(),
+        }
+    }
+
+    pub fn save_checkpoint(&self, path: impl AsRef<Path>) -> Result<(), CheckpointError> {
+        self.can_checkpoint()?;
+        self.checkpoint().save(path)
+    }
+
+    /// A checkpoint rebuilds the evaluator from the opponents, so a custom
+    /// evaluator would be lost on resume.
+    pub(crate) fn can_checkpoint(&self) -> Result<(), CheckpointError> {
+        match self.has_custom_evaluator() {
+            true => Err(CheckpointError::CustomEvaluator),
+            false => Ok(()),
+        }
+    }
+
+    pub fn from_checkpoint(path: impl AsRef<Path>) -> Result<Self, CheckpointError> {
+        let path = path.as_ref();
+        let checkpoint = Checkpoint::load(path)?;
+        let mut ga = checkpoint
+            .experiment
+            .build()
+            .map_err(CheckpointError::Invalid)?;
+        if checkpoint.experiment.checkpoint_interval > 0 {
+            ga.set_checkpoint(path, checkpoint.experiment.checkpoint_interval);
+        }
+        let iplayers = |genomes: &[Genome]| genomes.iter().map(Genome::to_iplayer).collect();
+        ga.generation = checkpoint.generation;
+        ga.population = iplayers(&checkpoint.population);
+        ga.data = checkpoint
+            .history
+            .iter()
+            .map(|(generation, genomes)| (*generation, iplayers(genomes)))
+            .collect();
+        ga.rng = checkpoint.rng;
+        Ok(ga)
+    }
+
+    pub fn resume_from(path: impl AsRef<Path>) -> Result<Self, RunError> {
+        let mut ga = Self::from_checkpoint(path)?;
+        ga.resume()?;
+        Ok(ga)
+    }
+}
+
+impl Display for CheckpointError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckpointError::Io { path, message } => write!(f, "{}: {}", path.display(), message),
+            CheckpointError::Parse { path, message } => {
+                write!(f, "Invalid checkpoint {}: {}", path.display(), message)
+            }
+            CheckpointError::Serialize { path, message } => {
+                write!(
+                    f,
+                    "Could not write checkpoint {}: {}",
+                    path.display(),
+                    message
+                )
+            }
+            CheckpointError::Invalid(error) => write!(f, "Invalid checkpoint: {}", error),
+            CheckpointError::CustomEvaluator => {
+                write!(f, "A GA with a custom evaluator cannot be checkpointed")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CheckpointError {}
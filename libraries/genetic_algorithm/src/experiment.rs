@@ -0,0 +1,250 @@
+use crate::checkpoint::CheckpointError;
+use crate::genetic_algorithm::{
+    CrossoverType, GeneticAlgorithm, MutationType, RunError, CROSSOVER_TYPES, DEFAULT_OPPONENTS,
+    MUTATION_TYPES,
+};
+use crate::selection::SelectionStrategy;
+use iplayers::Playstyle;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExperimentError {
+    Io { path: PathBuf, message: String },
+    Parse { path: PathBuf, message: String },
+    Invalid { name: String, message: String },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Experiment {
+    pub name: String,
+    pub population_size: usize,
+    pub mutation_rate: f64,
+    pub crossover_rate: f64,
+    pub elitism_count: usize,
+    pub generations: usize,
+    pub total_games: u16,
+    pub threads: usize,
+    pub seed: Option<u64>,
+    pub opponents: Vec<Playstyle>,
+    pub crossover: Vec<CrossoverType>,
+    pub mutation: Vec<MutationType>,
+    pub selection: SelectionStrategy,
+    pub write_to_csv: bool,
+    pub output_dir: String,
+    pub checkpoint_interval: usize,
+}
+
+impl Default for Experiment {
+    fn default() -> Self {
+        Experiment {
+            name: String::new(),
+            population_size: 10,
+            mutation_rate: 0.01,
+            crossover_rate: 0.95,
+            elitism_count: 2,
+            generations: 5,
+            total_games: 100,
+            threads: 0,
+            seed: None,
+            opponents: DEFAULT_OPPONENTS.to_vec(),
+            crossover: CROSSOVER_TYPES.to_vec(),
+            mutation: MUTATION_TYPES.to_vec(),
+            selection: SelectionStrategy::default(),
+            write_to_csv: true,
+            output_dir: "./data".to_string(),
+            checkpoint_interval: 0,
+        }
+    }
+}
+
+impl Experiment {
+    pub fn from_toml(toml: &str) -> Result<Self, String> {
+        toml::from_str(toml).map_err(|error| error.to_string())
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|error| error.to_string())
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ExperimentError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|error| ExperimentError::Io {
+            path: path.to_path_buf(),
+            message: error.to_string(),
+        })?;
+        let experiment = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Self::from_json(&text),
+            _ => Self::from_toml(&text),
+        };
+        let mut experiment = experiment.map_err(|message| ExperimentError::Parse {
+            path: path.to_path_buf(),
+            message,
+        })?;
+        if experiment.name.is_empty() {
+            if let Some(stem) = path.file_stem() {
+                experiment.name = stem.to_string_lossy().to_string();
+            }
+        }
+        experiment.validate()?;
+        Ok(experiment)
+    }
+
+    pub fn load_dir(dir: impl AsRef<Path>) -> Result<Vec<Self>, ExperimentError> {
+        let dir = dir.as_ref();
+        let io_error = |error: std::io::Error| ExperimentError::Io {
+            path: dir.to_path_buf(),
+            message: error.to_string(),
+        };
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(dir).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
+            let extension = path.extension().and_then(|extension| extension.to_str());
+            if matches!(extension, Some("toml") | Some("json")) {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        let mut experiments: Vec<Experiment> = Vec::new();
+        for path in paths {
+            let experiment = Self::from_file(&path)?;
+            if experiments
+                .iter()
+                .any(|other| other.name == experiment.name)
+            {
+                return Err(experiment.invalid("another experiment in the directory has this name"));
+            }
+            experiments.push(experiment);
+        }
+        Ok(experiments)
+    }
+
+    pub fn validate(&self) -> Result<(), ExperimentError> {
+        if self.name.is_empty() {
+            return Err(self.invalid("the experiment needs a name"));
+        }
+        if self.population_size == 0 {
+            return Err(self.invalid("population_size must be at least 1"));
+        }
+        if self.total_games == 0 {
+            return Err(self.invalid("total_games must be at least 1"));
+        }
+        if self.elitism_count > self.population_size {
+            return Err(self.invalid("elitism_count is larger than population_size"));
+        }
+        if !(0.0..=1.0).contains(&self.mutation_rate) || !(0.0..=1.0).contains(&self.crossover_rate)
+        {
+            return Err(self.invalid("rates must be between 0 and 1"));
+        }
+        if self.opponents.is_empty() || self.opponents.len() > 3 {
+            return Err(self.invalid("an experiment needs between 1 and 3 opponents"));
+        }
+        if self.opponents.contains(&Playstyle::Human) {
+            return Err(self.invalid("human players cannot be opponents"));
+        }
+        if self.crossover.is_empty() {
+            return Err(self.invalid("at least one crossover type is needed"));
+        }
+        if self.mutation.is_empty() {
+            return Err(self.invalid("at least one mutation type is needed"));
+        }
+        self.selection
+            .validate()
+            .map_err(|message| self.invalid(&message))?;
+        Ok(())
+    }
+
+    pub fn build(&self) -> Result<GeneticAlgorithm, ExperimentError> {
+        self.validate()?;
+        let mut ga = GeneticAlgorithm::default();
+        ga.set_opponents(&self.opponents)
+            .map_err(|error| self.invalid(&error.to_string()))?;
+        ga.set_population_size(self.population_size);
+        ga.set_mutation_rate(self.mutation_rate);
+        ga.set_crossover_rate(self.crossover_rate);
+        ga.set_elitism_count(self.elitism_count);
+        ga.set_generations(self.generations);
+        ga.set_total_games(self.total_games);
+        ga.set_threads(self.threads);
+        ga.set_crossover_types(&self.crossover)
+            .map_err(|error| self.invalid(&error.to_string()))?;
+        ga.set_mutation_types(&self.mutation)
+            .map_err(|error| self.invalid(&error.to_string()))?;
+        ga.set_selection(self.selection);
+        if let Some(seed) = self.seed {
+            ga.set_seed(seed);
+        }
+        ga.set_write_to_csv(self.write_to_csv);
+        ga.set_csv_name(&self.name);
+        ga.set_output_dir(&self.output_dir);
+        if self.checkpoint_interval > 0 {
+            ga.set_checkpoint(self.checkpoint_path(), self.checkpoint_interval);
+        }
+        Ok(ga)
+    }
+
+    pub fn checkpoint_path(&self) -> PathBuf {
+        Path::new(&self.output_dir)
+            .join(&self.name)
+            .join(format!("{}_checkpoint.json", self.name))
+    }
+
+    pub fn run(&self) -> Result<GeneticAlgorithm, ExperimentError> {
+        let mut ga = self.build()?;
+        ga.run_gentic_algorithm().map_err(|error| match error {
+            RunError::Checkpoint(CheckpointError::Io { path, message }) => {
+                ExperimentError::Io { path, message }
+            }
+            error => self.invalid(&error.to_string()),
+        })?;
+        Ok(ga)
+    }
+
+    fn invalid(&self, message: &str) -> ExperimentError {
+        ExperimentError::Invalid {
+            name: self.name.clone(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl From<&GeneticAlgorithm> for Experiment {
+    fn from(ga: &GeneticAlgorithm) -> Self {
+        Experiment {
+            name: ga.csv_name().to_string(),
+            population_size: ga.population_size(),
+            mutation_rate: ga.mutation_rate(),
+            crossover_rate: ga.crossover_rate(),
+            elitism_count: ga.elitism_count(),
+            generations: ga.generations(),
+            total_games: ga.total_games(),
+            threads: ga.threads(),
+            seed: ga.seed(),
+            opponents: ga.opponents(),
+            crossover: ga.crossover_types().to_vec(),
+            mutation: ga.mutation_types().to_vec(),
+            selection: ga.selection(),
+            write_to_csv: ga.write_to_csv(),
+            output_dir: ga.output_dir().to_string(),
+            checkpoint_interval: ga.checkpoint_interval(),
+        }
+    }
+}
+
+impl Display for ExperimentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ExperimentError::Io { path, message } => write!(f, "{}: {}", path.display(), message),
+            ExperimentError::Parse { path, message } => {
+                write!(f, "Invalid experiment file {}: {}", path.display(), message)
+            }
+            ExperimentError::Invalid { name, message } => {
+                write!(f, "Invalid experiment {}: {}", name, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExperimentError {}
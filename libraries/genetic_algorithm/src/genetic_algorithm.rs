@@ -1,28 +1,95 @@
+mod checkpoint;
+mod experiment;
+mod operators;
+mod plot;
+mod selection;
+
 mod genetic_algorithm {
+    use crate::checkpoint::CheckpointError;
+    use crate::selection::SelectionStrategy;
+    use board::PlayerID;
+    use errors::LudoError;
     use game::Game;
     use iplayers::{IPlayer, Playstyle, ACTIONS, SELECTIONS};
     use players::{Act, Select};
-    use rand::{rngs::ThreadRng, seq::SliceRandom, thread_rng, Rng};
-
+    use rand::{seq::SliceRandom, Rng, SeedableRng};
+    use rand_chacha::ChaCha12Rng;
+    use rayon::prelude::*;
+    use serde::{Deserialize, Serialize};
+    use std::fmt::{self, Display, Formatter};
+    use std::path::PathBuf;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
     pub enum CrossoverType {
         SinglePoint,
         TwoPoint,
         Uniform,
+        #[serde(alias = "pmx")]
+        PartiallyMapped,
+        #[serde(alias = "ox")]
+        Order,
+        #[serde(alias = "cx")]
+        Cycle,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum MutationType {
+        Reset,
+        Swap,
+        Insertion,
+        Inversion,
+    }
+
+    pub static DEFAULT_OPPONENTS: [Playstyle; 3] =
+        [Playstyle::Random, Playstyle::Fast, Playstyle::Aggressive];
+
+    pub static CROSSOVER_TYPES: [CrossoverType; 3] = [
+        CrossoverType::SinglePoint,
+        CrossoverType::Uniform,
+        CrossoverType::TwoPoint,
+    ];
+
+    pub static PERMUTATION_CROSSOVER_TYPES: [CrossoverType; 3] = [
+        CrossoverType::PartiallyMapped,
+        CrossoverType::Order,
+        CrossoverType::Cycle,
+    ];
+
+    pub static MUTATION_TYPES: [MutationType; 1] = [MutationType::Reset];
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum RunError {
+        Evaluation(LudoError),
+        Checkpoint(CheckpointError),
     }
 
     pub struct GeneticAlgorithm {
-        population: Vec<IPlayer>,
-        data: Vec<(usize, Vec<IPlayer>)>,
+        pub(crate) population: Vec<IPlayer>,
+        pub(crate) data: Vec<(usize, Vec<IPlayer>)>,
+        pub(crate) generation: usize,
+        checkpoint_path: Option<PathBuf>,
+        checkpoint_interval: usize,
         evaluator: Box<Game>,
+        custom_evaluator: bool,
         population_size: usize,
         mutation_rate: f64,
         crossover_rate: f64,
         elitism_count: usize,
-        tournament_size: usize,
+        generations: usize,
         total_games: u16,
+        threads: usize,
+        pool: Option<rayon::ThreadPool>,
+        crossover_types: Vec<CrossoverType>,
+        mutation_types: Vec<MutationType>,
+        selection: SelectionStrategy,
+        mating_pool: Vec<IPlayer>,
         write_to_csv: bool,
         csv_name: String,
-        rng: ThreadRng,
+        output_dir: String,
+        seed: Option<u64>,
+        pub(crate) rng: ChaCha12Rng,
     }
 
     impl GeneticAlgorithm {
@@ -30,35 +97,73 @@ mod genetic_algorithm {
             GeneticAlgorithm {
                 population: Vec::new(),
                 data: Vec::new(),
+                generation: 0,
+                checkpoint_path: None,
+                checkpoint_interval: 0,
                 evaluator: Box::default(),
+                custom_evaluator: false,
                 population_size: 0,
                 mutation_rate: 0.0,
                 crossover_rate: 0.0,
                 elitism_count: 0,
-                tournament_size: 0,
+                generations: 0,
                 total_games: 100,
+                threads: 0,
+                pool: None,
+                crossover_types: CROSSOVER_TYPES.to_vec(),
+                mutation_types: MUTATION_TYPES.to_vec(),
+                selection: SelectionStrategy::default(),
+                mating_pool: Vec::new(),
                 write_to_csv: false,
                 csv_name: "GA data".to_string(),
-                rng: thread_rng(),
+                output_dir: "./data".to_string(),
+                seed: None,
+                rng: ChaCha12Rng::from_entropy(),
             }
         }
 
-        pub fn run_gentic_algorithm(&mut self) {
+        pub fn run_gentic_algorithm(&mut self) -> Result<(), RunError> {
             self.initialize_all_populations();
-            for tournament_size in 0..self.tournament_size {
-                self.evaluate_fitness_for_all_populations(tournament_size);
+            self.data.clear();
+            self.generation = 0;
+            self.resume()
+        }
+
+        pub fn resume(&mut self) -> Result<(), RunError> {
+            if self.checkpoint_path.is_some() && self.checkpoint_interval > 0 {
+                self.can_checkpoint()?;
+            }
+            while self.generation < self.generations {
+                self.evaluate_fitness_for_all_populations(self.generation)?;
                 self.select_best_populations();
                 self.create_children_and_replace_bad_populations();
+                self.generation += 1;
+                if let Some(path) = self.checkpoint_path.clone() {
+                    if self.generation.checked_rem(self.checkpoint_interval) == Some(0) {
+                        self.save_checkpoint(&path)?;
+                    }
+                }
             }
-            self.evaluate_fitness_for_all_populations(self.tournament_size);
+            self.evaluate_fitness_for_all_populations(self.generations)?;
             if self.write_to_csv {
                 self.export_2_csv();
             }
+            Ok(())
         }
 
         pub fn set_total_games(&mut self, total_games: u16) {
             self.total_games = total_games;
         }
+
+        pub fn set_threads(&mut self, threads: usize) {
+            self.threads = threads;
+            self.pool = None;
+        }
+
+        pub fn threads(&self) -> usize {
+            self.threads
+        }
+
         pub fn population(&self) -> &Vec<IPlayer> {
             &self.population
         }
@@ -79,12 +184,106 @@ mod genetic_algorithm {
             self.elitism_count
         }
 
-        pub fn tournament_size(&self) -> usize {
-            self.tournament_size
+        pub fn generations(&self) -> usize {
+            self.generations
+        }
+
+        pub fn seed(&self) -> Option<u64> {
+            self.seed
+        }
+
+        pub fn set_seed(&mut self, seed: u64) {
+            self.seed = Some(seed);
+            self.rng = ChaCha12Rng::seed_from_u64(seed);
+            self.evaluator.set_seed(seed);
         }
 
+        /// Replaces the game the population is evaluated in. A checkpoint only
+        /// stores the opponents, so a GA with a custom evaluator cannot be
+        /// checkpointed.
         pub fn set_evaluator(&mut self, evaluator: Game) {
-            self.evaluator = Box::new(evaluator);
+            *self.evaluator = evaluator;
+            self.custom_evaluator = true;
+        }
+
+        pub fn has_custom_evaluator(&self) -> bool {
+            self.custom_evaluator
+        }
+
+        pub fn set_opponents(&mut self, opponents: &[Playstyle]) -> Result<(), LudoError> {
+            let player_ids = [
+                PlayerID::Player0,
+                PlayerID::Player1,
+                PlayerID::Player2,
+                PlayerID::Player3,
+            ];
+            if opponents.len() >= player_ids.len() {
+                return Err(LudoError::IllegalAction(format!(
+                    "At most {} opponents fit on the board, got {}",
+                    player_ids.len() - 1,
+                    opponents.len()
+                )));
+            }
+            let mut game = Game::with_players(&player_ids[..=opponents.len()])?;
+            game.give_iplayer_a_playstyle(0, Playstyle::GeneticAlgorithm)?;
+            for (id, opponent) in opponents.iter().enumerate() {
+                game.give_iplayer_a_playstyle(id as i8 + 1, *opponent)?;
+            }
+            *self.evaluator = game;
+            self.custom_evaluator = false;
+            Ok(())
+        }
+
+        pub fn opponents(&self) -> Vec<Playstyle> {
+            let mut evaluator = self.evaluator.clone();
+            evaluator
+                .player_ids()
+                .into_iter()
+                .filter(|&id| id != 0)
+                .filter_map(|id| Some(*evaluator.iplayer(id).ok()?.get_playstyle()))
+                .collect()
+        }
+
+        pub fn set_crossover_types(
+            &mut self,
+            crossover_types: &[CrossoverType],
+        ) -> Result<(), LudoError> {
+            if crossover_types.is_empty() {
+                return Err(LudoError::IllegalAction(
+                    "At least one crossover type is needed".to_string(),
+                ));
+            }
+            self.crossover_types = crossover_types.to_vec();
+            Ok(())
+        }
+
+        pub fn crossover_types(&self) -> &[CrossoverType] {
+            &self.crossover_types
+        }
+
+        pub fn set_mutation_types(
+            &mut self,
+            mutation_types: &[MutationType],
+        ) -> Result<(), LudoError> {
+            if mutation_types.is_empty() {
+                return Err(LudoError::IllegalAction(
+                    "At least one mutation type is needed".to_string(),
+                ));
+            }
+            self.mutation_types = mutation_types.to_vec();
+            Ok(())
+        }
+
+        pub fn mutation_types(&self) -> &[MutationType] {
+            &self.mutation_types
+        }
+
+        pub fn set_selection(&mut self, selection: SelectionStrategy) {
+            self.selection = selection;
+        }
+
+        pub fn selection(&self) -> SelectionStrategy {
+            self.selection
         }
 
         pub fn initialize_all_populations(&mut self) {
@@ -99,10 +298,10 @@ mod genetic_algorithm {
                 iplayer.set_playstyle(Playstyle::GeneticAlgorithm);
 
                 let mut actions = ACTIONS;
-                actions.shuffle(&mut rand::thread_rng());
+                actions.shuffle(&mut self.rng);
                 iplayer.set_actions(ACTIONS);
 
-                let select = SELECTIONS.choose(&mut rand::thread_rng()).unwrap();
+                let select = SELECTIONS.choose(&mut self.rng).unwrap();
                 iplayer.select_which_piece(*select);
 
                 iplayer.select_which_piece(Select::Nearest);
@@ -110,12 +309,12 @@ mod genetic_algorithm {
             }
         }
 
+        /// Keeps the `elitism_count` best populations and sets the whole evaluated
+        /// population aside as the mating pool for the selection strategy.
         pub fn select_best_populations(&mut self) {
-            if self.elitism_count == 0 {
-                panic!("Elitism count must be greater than 0");
-            }
             self.population
-                .sort_unstable_by(|a, b| b.get_winrate().partial_cmp(a.get_winrate()).unwrap());
+                .sort_unstable_by(|a, b| b.get_winrate().total_cmp(a.get_winrate()));
+            self.mating_pool = self.population.clone();
             self.population.truncate(self.elitism_count);
         }
 
@@ -139,35 +338,109 @@ mod genetic_algorithm {
             self.csv_name = csv_name.to_string();
         }
 
+        pub fn csv_name(&self) -> &str {
+            &self.csv_name
+        }
+
+        pub fn set_output_dir(&mut self, output_dir: &str) {
+            self.output_dir = output_dir.to_string();
+        }
+
+        pub fn output_dir(&self) -> &str {
+            &self.output_dir
+        }
+
+        pub fn total_games(&self) -> u16 {
+            self.total_games
+        }
+
         pub fn set_write_to_csv(&mut self, write_to_csv: bool) {
             self.write_to_csv = write_to_csv;
         }
 
-        pub fn set_tournament_size(&mut self, tournament_size: usize) {
-            self.tournament_size = tournament_size;
+        pub fn write_to_csv(&self) -> bool {
+            self.write_to_csv
+        }
+
+        pub fn set_checkpoint(&mut self, path: impl Into<PathBuf>, interval: usize) {
+            self.checkpoint_path = Some(path.into());
+            self.checkpoint_interval = interval;
+        }
+
+        pub fn checkpoint_interval(&self) -> usize {
+            self.checkpoint_interval
+        }
+
+        pub fn generation(&self) -> usize {
+            self.generation
+        }
+
+        pub fn set_generations(&mut self, generations: usize) {
+            self.generations = generations;
         }
 
-        pub fn evaluate_fitness_for_all_populations(&mut self, tournament_size: usize) {
-            for population in self.population.iter_mut() {
-                self.evaluator.set_iplayer(0, population);
-                self.evaluator.start_game(self.total_games);
-                self.evaluator.get_iplayer(0, population);
-                population.calculate_winrate(self.total_games);
+        pub fn evaluate_fitness_for_all_populations(
+            &mut self,
+            generation: usize,
+        ) -> Result<(), RunError> {
+            let seeds: Vec<u64> = self.population.iter().map(|_| self.rng.gen()).collect();
+            let evaluator = &self.evaluator;
+            let total_games = self.total_games;
+            let threads = self.threads;
+            let pool = self.pool.get_or_insert_with(|| {
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .expect("Failed to build the evaluation thread pool")
+            });
+            let population = &mut self.population;
+            pool.install(|| {
+                population
+                    .par_iter_mut()
+                    .zip(seeds.par_iter())
+                    .try_for_each_init(
+                        || evaluator.clone(),
+                        |game, (population, &seed)| {
+                            game.clone_from(evaluator);
+                            game.set_iplayer(0, population)?;
+                            game.set_seed(seed);
+                            game.start_game(total_games)?;
+                            game.get_iplayer(0, population)?;
+                            population.calculate_winrate(total_games);
+                            Ok(())
+                        },
+                    )
+            })
+            .map_err(RunError::Evaluation)?;
+            for population in &self.population {
                 population.print_winrate();
             }
             if self.write_to_csv {
-                self.data.push((tournament_size, self.population.clone()));
+                self.data.push((generation, self.population.clone()));
             }
+            Ok(())
         }
 
         pub fn create_children_and_replace_bad_populations(&mut self) {
-            let first_parent = self.population[0].clone();
-            let second_parent = self.population.choose(&mut self.rng).unwrap().clone();
+            let mating_pool = match self.mating_pool.is_empty() {
+                true => self.population.clone(),
+                false => std::mem::take(&mut self.mating_pool),
+            };
+            let fitness: Vec<f64> = mating_pool
+                .iter()
+                .map(|iplayer| *iplayer.get_winrate())
+                .collect();
+            let children_count = self.population_size.saturating_sub(self.population.len());
+            let parents = self
+                .selection
+                .select(&fitness, 2 * children_count, &mut self.rng);
             let mut children: Vec<IPlayer> = Vec::new();
-            for _i in 0..(self.population_size - self.elitism_count) {
+            for pair in parents.chunks(2) {
+                let first_parent = &mating_pool[pair[0]];
+                let second_parent = &mating_pool[pair[1]];
                 let parent_actions_1 = first_parent.get_actions();
                 let parent_selector_1 = first_parent.get_piece_selector();
-                let parent_selector_2 = first_parent.get_piece_selector();
+                let parent_selector_2 = second_parent.get_piece_selector();
                 let parent_actions_2 = second_parent.get_actions();
 
                 let child_action =
@@ -182,10 +455,14 @@ mod genetic_algorithm {
         }
 
         pub fn export_2_csv(&mut self) {
-            std::fs::create_dir_all(format!("./data/{}", self.csv_name)).unwrap();
+            std::fs::create_dir_all(format!("{}/{}", self.output_dir, self.csv_name)).unwrap();
+
+            let mut wtr = csv::Writer::from_path(format!(
+                "{}/{}/{}_winrates.csv",
+                self.output_dir, self.csv_name, self.csv_name
+            ))
+            .unwrap();
 
-            let mut wtr = csv::Writer::from_path(format!("./data/{}/{}_winrates.csv", self.csv_name, self.csv_name)).unwrap();
-            
             let headers: Vec<String> = (0..=self.population_size)
                 .flat_map(|i| {
                     if i == 0 {
@@ -193,37 +470,51 @@ mod genetic_algorithm {
                     } else {
                         vec![
                             format!("population {} winrate", i),
+                            format!("population {} timeouts", i),
                             format!("population {} select", i),
                             format!("population {} actions", i),
                         ]
                     }
                 })
                 .collect();
-        
+
             wtr.write_record(&headers).unwrap();
-        
+
             // Write the data rows
             for (tournament_index, iplayers) in &self.data {
                 let row: Vec<String> = std::iter::once(format!("{}", tournament_index))
                     .chain(iplayers.iter().flat_map(|iplayer| {
                         vec![
                             iplayer.get_winrate().to_string(),
+                            iplayer.get_timeouts().to_string(),
                             iplayer.select_which_piece.to_string(),
-                            iplayer.actions.as_ref().map(|actions| {
-                                actions.iter().map(Act::to_string).collect::<Vec<_>>().join(", ")
-                            }).unwrap_or_default(),
+                            iplayer
+                                .actions
+                                .as_ref()
+                                .map(|actions| {
+                                    actions
+                                        .iter()
+                                        .map(Act::to_string)
+                                        .collect::<Vec<_>>()
+                                        .join(", ")
+                                })
+                                .unwrap_or_default(),
                         ]
                     }))
                     .collect();
-        
+
                 wtr.write_record(&row).unwrap();
             }
-        
+
             wtr.flush().unwrap();
-        
+
             // Write the parameters in a separate CSV file
-            let mut param_wtr = csv::Writer::from_path(format!("./data/{}/{}_params.csv", self.csv_name, self.csv_name)).unwrap();
-        
+            let mut param_wtr = csv::Writer::from_path(format!(
+                "{}/{}/{}_params.csv",
+                self.output_dir, self.csv_name, self.csv_name
+            ))
+            .unwrap();
+
             let parameter_names = vec![
                 "Total Generations",
                 "Total Populations",
@@ -231,23 +522,24 @@ mod genetic_algorithm {
                 "Mutation Rate",
                 "Crossover Rate",
                 "Number of Elites",
+                "Selection",
             ];
-        
+
             let parameter_values = vec![
-                format!("{}", self.tournament_size),
+                format!("{}", self.generations),
                 format!("{}", self.population_size),
                 format!("{}", self.total_games),
                 format!("{}", self.mutation_rate),
                 format!("{}", self.crossover_rate),
                 format!("{}", self.elitism_count),
+                format!("{}", self.selection),
             ];
-        
+
             param_wtr.write_record(&parameter_names).unwrap();
             param_wtr.write_record(&parameter_values).unwrap();
-        
+
             param_wtr.flush().unwrap();
         }
-        
 
         pub fn try_to_crossover_selector(
             &mut self,
@@ -257,8 +549,7 @@ mod genetic_algorithm {
             if selector1 == selector2 {
                 return *selector1;
             }
-            let mut rng = thread_rng();
-            let crossover_rate = rng.gen_range(0.0..1.0);
+            let crossover_rate = self.rng.gen_range(0.0..1.0);
             if crossover_rate < self.crossover_rate {
                 self.crossover_selector(selector1, selector2)
             } else {
@@ -290,8 +581,7 @@ mod genetic_algorithm {
         }
 
         pub fn try_to_mutate_selector(&mut self, selector: Select) -> Select {
-            let mut rng = thread_rng();
-            let mutation_rate = rng.gen_range(0.0..1.0);
+            let mutation_rate = self.rng.gen_range(0.0..1.0);
             if mutation_rate < self.mutation_rate {
                 self.mutate_selector(selector)
             } else {
@@ -300,9 +590,8 @@ mod genetic_algorithm {
         }
 
         pub fn mutate_selector(&mut self, selector: Select) -> Select {
-            let mut rng = thread_rng();
             loop {
-                let new_selector_int = rng.gen_range(0..=2);
+                let new_selector_int = self.rng.gen_range(0..=2);
                 let new_selector = match new_selector_int {
                     0 => Select::Nearest,
                     1 => Select::Furthest,
@@ -331,8 +620,7 @@ mod genetic_algorithm {
             if parent_actions_1 == parent_actions_2 {
                 return *parent_actions_1;
             }
-            let mut rng = thread_rng();
-            let crossover_rate = rng.gen_range(0.0..1.0);
+            let crossover_rate = self.rng.gen_range(0.0..1.0);
             if crossover_rate < self.crossover_rate {
                 self.crossover_actions(parent_actions_1, parent_actions_2)
             } else {
@@ -410,52 +698,105 @@ mod genetic_algorithm {
             parent_actions_1: &[Act; 10],
             parent_actions_2: &[Act; 10],
         ) -> [Act; 10] {
-            let crossover_type = self.rng.gen_range(0..=2);
+            let crossover_type =
+                self.crossover_types[self.rng.gen_range(0..self.crossover_types.len())];
             match crossover_type {
-                0 => self.single_point_crossover(parent_actions_1, parent_actions_2),
-                1 => self.uniform_crossover(parent_actions_1, parent_actions_2),
-                2 => self.two_point_crossover(parent_actions_1, parent_actions_2),
-                _ => panic!("Invalid crossover type"),
+                CrossoverType::SinglePoint => {
+                    self.single_point_crossover(parent_actions_1, parent_actions_2)
+                }
+                CrossoverType::Uniform => {
+                    self.uniform_crossover(parent_actions_1, parent_actions_2)
+                }
+                CrossoverType::TwoPoint => {
+                    self.two_point_crossover(parent_actions_1, parent_actions_2)
+                }
+                CrossoverType::PartiallyMapped => {
+                    self.pmx_crossover(parent_actions_1, parent_actions_2)
+                }
+                CrossoverType::Order => self.order_crossover(parent_actions_1, parent_actions_2),
+                CrossoverType::Cycle => self.cycle_crossover(parent_actions_1, parent_actions_2),
             }
         }
 
         pub fn mutate_actions(&mut self, actions: [Act; 10]) -> [Act; 10] {
-            let mut mutated_actions = actions;
-            for action in mutated_actions.iter_mut() {
-                let mutation_rate = self.rng.gen_range(0.0..1.0);
-                if mutation_rate < self.mutation_rate {
-                    *action = *ACTIONS.choose(&mut self.rng).unwrap();
-                }
+            let mutation_type = match self.mutation_types.len() {
+                1 => self.mutation_types[0],
+                len => self.mutation_types[self.rng.gen_range(0..len)],
+            };
+            if mutation_type == MutationType::Reset {
+                return self.reset_mutation(actions);
+            }
+            let mutation_rate = self.rng.gen_range(0.0..1.0);
+            if mutation_rate >= self.mutation_rate {
+                return actions;
+            }
+            match mutation_type {
+                MutationType::Reset => actions,
+                MutationType::Swap => self.swap_mutation(actions),
+                MutationType::Insertion => self.insertion_mutation(actions),
+                MutationType::Inversion => self.inversion_mutation(actions),
             }
-            mutated_actions
         }
     }
 
     impl Default for GeneticAlgorithm {
         fn default() -> Self {
-            let mut game = Game::new();
-            game.setup_game();
-            game.give_iplayer_a_playstyle(0, Playstyle::GeneticAlgorithm);
-            game.give_iplayer_a_playstyle(1, Playstyle::Random);
-            game.give_iplayer_a_playstyle(2, Playstyle::Fast);
-            game.give_iplayer_a_playstyle(3, Playstyle::Aggressive);
-            let evaluator = Box::new(game);
-            GeneticAlgorithm {
+            let mut ga = GeneticAlgorithm {
                 population: Vec::new(),
                 data: Vec::new(),
-                evaluator,
+                generation: 0,
+                checkpoint_path: None,
+                checkpoint_interval: 0,
+                evaluator: Box::default(),
+                custom_evaluator: false,
                 population_size: 10,
                 mutation_rate: 0.01,
                 crossover_rate: 0.95,
                 elitism_count: 2,
-                tournament_size: 5,
+                generations: 5,
                 total_games: 100,
+                threads: 0,
+                pool: None,
+                crossover_types: CROSSOVER_TYPES.to_vec(),
+                mutation_types: MUTATION_TYPES.to_vec(),
+                selection: SelectionStrategy::default(),
+                mating_pool: Vec::new(),
                 write_to_csv: false,
                 csv_name: "GA data".to_string(),
-                rng: thread_rng(),
+                output_dir: "./data".to_string(),
+                seed: None,
+                rng: ChaCha12Rng::from_entropy(),
+            };
+            ga.set_opponents(&DEFAULT_OPPONENTS)
+                .expect("The default opponents fit on the board");
+            ga
+        }
+    }
+
+    impl From<CheckpointError> for RunError {
+        fn from(error: CheckpointError) -> Self {
+            RunError::Checkpoint(error)
+        }
+    }
+
+    impl Display for RunError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            match self {
+                RunError::Evaluation(error) => write!(f, "Evaluation failed: {}", error),
+                RunError::Checkpoint(error) => write!(f, "{}", error),
             }
         }
     }
+
+    impl std::error::Error for RunError {}
 }
 
-pub use genetic_algorithm::{CrossoverType, GeneticAlgorithm};
+pub use checkpoint::{Checkpoint, CheckpointError, Genome};
+pub use experiment::{Experiment, ExperimentError};
+pub use genetic_algorithm::{
+    CrossoverType, GeneticAlgorithm, MutationType, RunError, CROSSOVER_TYPES, DEFAULT_OPPONENTS,
+    MUTATION_TYPES, PERMUTATION_CROSSOVER_TYPES,
+};
+pub use operators::{is_permutation, repair_actions};
+pub use plot::{plot_results, GaResults, GenerationStats, PlotError};
+pub use selection::SelectionStrategy;
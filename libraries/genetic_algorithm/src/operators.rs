@@ -0,0 +1,172 @@
+use crate::genetic_algorithm::{CrossoverType, GeneticAlgorithm, MutationType};
+use iplayers::ACTIONS;
+use players::Act;
+use rand::{seq::SliceRandom, Rng};
+use std::str::FromStr;
+
+pub fn is_permutation(actions: &[Act; 10]) -> bool {
+    ACTIONS.iter().all(|action| actions.contains(action))
+}
+
+/// Keeps the first copy of every action and fills the slots of the duplicates
+/// with the missing actions, in the order they appear in `ACTIONS`.
+pub fn repair_actions(actions: &[Act; 10]) -> [Act; 10] {
+    let mut missing = ACTIONS.iter().filter(|action| !actions.contains(action));
+    let mut repaired = *actions;
+    for i in 0..10 {
+        if repaired[..i].contains(&repaired[i]) {
+            repaired[i] = *missing.next().unwrap();
+        }
+    }
+    repaired
+}
+
+fn position(actions: &[Act; 10], action: Act) -> usize {
+    actions.iter().position(|&other| other == action).unwrap()
+}
+
+impl GeneticAlgorithm {
+    fn segment(&mut self) -> (usize, usize) {
+        let start = self.rng.gen_range(0..10);
+        let end = self.rng.gen_range(start..=10);
+        (start, end)
+    }
+
+    fn two_positions(&mut self) -> (usize, usize) {
+        let first = self.rng.gen_range(0..10);
+        let mut second = self.rng.gen_range(0..9);
+        if second >= first {
+            second += 1;
+        }
+        (first, second)
+    }
+
+    pub fn pmx_crossover(&mut self, parent1: &[Act; 10], parent2: &[Act; 10]) -> [Act; 10] {
+        let (parent1, parent2) = (repair_actions(parent1), repair_actions(parent2));
+        let (start, end) = self.segment();
+        let mut child: [Option<Act>; 10] = [None; 10];
+        for i in start..end {
+            child[i] = Some(parent1[i]);
+        }
+        for i in start..end {
+            let action = parent2[i];
+            if parent1[start..end].contains(&action) {
+                continue;
+            }
+            let mut slot = i;
+            while (start..end).contains(&slot) {
+                slot = position(&parent2, parent1[slot]);
+            }
+            child[slot] = Some(action);
+        }
+        let mut result = parent2;
+        for (action, gene) in result.iter_mut().zip(child) {
+            if let Some(gene) = gene {
+                *action = gene;
+            }
+        }
+        result
+    }
+
+    pub fn order_crossover(&mut self, parent1: &[Act; 10], parent2: &[Act; 10]) -> [Act; 10] {
+        let (parent1, parent2) = (repair_actions(parent1), repair_actions(parent2));
+        let (start, end) = self.segment();
+        let segment = &parent1[start..end];
+        let mut rest = (0..10)
+            .map(|i| parent2[(end + i) % 10])
+            .filter(|action| !segment.contains(action));
+        let mut child = parent1;
+        for i in 0..10 - segment.len() {
+            child[(end + i) % 10] = rest.next().unwrap();
+        }
+        child
+    }
+
+    pub fn cycle_crossover(&mut self, parent1: &[Act; 10], parent2: &[Act; 10]) -> [Act; 10] {
+        let (parent1, parent2) = (repair_actions(parent1), repair_actions(parent2));
+        let mut child = parent1;
+        let mut visited = [false; 10];
+        let mut from_second = self.rng.gen_bool(0.5);
+        for start in 0..10 {
+            if visited[start] {
+                continue;
+            }
+            let mut i = start;
+            while !visited[i] {
+                visited[i] = true;
+                if from_second {
+                    child[i] = parent2[i];
+                }
+                i = position(&parent1, parent2[i]);
+            }
+            from_second = !from_second;
+        }
+        child
+    }
+
+    pub fn reset_mutation(&mut self, actions: [Act; 10]) -> [Act; 10] {
+        let mut mutated_actions = actions;
+        for action in mutated_actions.iter_mut() {
+            let mutation_rate = self.rng.gen_range(0.0..1.0);
+            if mutation_rate < self.mutation_rate() {
+                *action = *ACTIONS.choose(&mut self.rng).unwrap();
+            }
+        }
+        mutated_actions
+    }
+
+    pub fn swap_mutation(&mut self, actions: [Act; 10]) -> [Act; 10] {
+        let (first, second) = self.two_positions();
+        let mut mutated_actions = actions;
+        mutated_actions.swap(first, second);
+        mutated_actions
+    }
+
+    pub fn insertion_mutation(&mut self, actions: [Act; 10]) -> [Act; 10] {
+        let (from, to) = self.two_positions();
+        let mut mutated_actions = actions;
+        if from < to {
+            mutated_actions[from..=to].rotate_left(1);
+        } else {
+            mutated_actions[to..=from].rotate_right(1);
+        }
+        mutated_actions
+    }
+
+    pub fn inversion_mutation(&mut self, actions: [Act; 10]) -> [Act; 10] {
+        let (first, second) = self.two_positions();
+        let mut mutated_actions = actions;
+        mutated_actions[first.min(second)..=first.max(second)].reverse();
+        mutated_actions
+    }
+}
+
+impl FromStr for CrossoverType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "single_point" => Ok(CrossoverType::SinglePoint),
+            "two_point" => Ok(CrossoverType::TwoPoint),
+            "uniform" => Ok(CrossoverType::Uniform),
+            "partially_mapped" | "pmx" => Ok(CrossoverType::PartiallyMapped),
+            "order" | "ox" => Ok(CrossoverType::Order),
+            "cycle" | "cx" => Ok(CrossoverType::Cycle),
+            _ => Err(format!("Unknown crossover type: {}", s)),
+        }
+    }
+}
+
+impl FromStr for MutationType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "reset" => Ok(MutationType::Reset),
+            "swap" => Ok(MutationType::Swap),
+            "insertion" => Ok(MutationType::Insertion),
+            "inversion" => Ok(MutationType::Inversion),
+            _ => Err(format!("Unknown mutation type: {}", s)),
+        }
+    }
+}
@@ -0,0 +1,527 @@
+use iplayers::{ACTIONS, SELECTIONS};
+use players::Act;
+use std::fmt::{self, Display, Formatter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const WIDTH: f64 = 960.0;
+const HEIGHT: f64 = 540.0;
+const LEFT: f64 = 64.0;
+const RIGHT: f64 = 240.0;
+const TOP: f64 = 48.0;
+const BOTTOM: f64 = 56.0;
+
+type Series = (&'static str, &'static str, fn(&GenerationStats) -> f64);
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlotError {
+    Io { path: PathBuf, message: String },
+    Csv { path: PathBuf, message: String },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenerationStats {
+    pub generation: usize,
+    pub best: f64,
+    pub upper_quartile: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub lower_quartile: f64,
+    pub worst: f64,
+    pub std_dev: f64,
+    pub selectors: Vec<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GaResults {
+    pub name: String,
+    pub params: Vec<(String, String)>,
+    pub generations: Vec<GenerationStats>,
+    pub final_actions: Vec<[Act; 10]>,
+}
+
+impl GaResults {
+    pub fn load(output_dir: impl AsRef<Path>, name: &str) -> Result<Self, PlotError> {
+        let dir = output_dir.as_ref().join(name);
+        let winrates = read_csv(&dir.join(format!("{}_winrates.csv", name)))?;
+        let params = read_csv(&dir.join(format!("{}_params.csv", name)))?;
+        let params = match params.get(1) {
+            Some(values) => params[0]
+                .iter()
+                .cloned()
+                .zip(values.iter().cloned())
+                .collect(),
+            None => Vec::new(),
+        };
+        GaResults::from_rows(name, params, &winrates)
+            .map_err(|message| PlotError::Csv { path: dir, message })
+    }
+
+    pub fn from_rows(
+        name: &str,
+        params: Vec<(String, String)>,
+        rows: &[Vec<String>],
+    ) -> Result<Self, String> {
+        let headers = rows.first().ok_or("the winrates file is empty")?;
+        let columns = |suffix: &str| -> Vec<usize> {
+            (0..headers.len())
+                .filter(|&index| headers[index].ends_with(suffix))
+                .collect()
+        };
+        let (winrates, selects, actions) =
+            (columns(" winrate"), columns(" select"), columns(" actions"));
+        let mut results = GaResults {
+            name: name.to_string(),
+            params,
+            generations: Vec::new(),
+            final_actions: Vec::new(),
+        };
+        for (line, row) in rows.iter().enumerate().skip(1) {
+            let field = |index: usize| row.get(index).map(String::as_str).unwrap_or_default();
+            let generation = field(0)
+                .parse()
+                .map_err(|_| format!("line {}: invalid generation {:?}", line + 1, field(0)))?;
+            let mut values = Vec::new();
+            for &index in &winrates {
+                let value = field(index).parse().map_err(|_| {
+                    format!("line {}: invalid winrate {:?}", line + 1, field(index))
+                })?;
+                values.push(value);
+            }
+            let selectors = SELECTIONS
+                .iter()
+                .map(|select| {
+                    let select = select.to_string();
+                    selects
+                        .iter()
+                        .filter(|&&index| field(index) == select)
+                        .count()
+                })
+                .collect();
+            results
+                .generations
+                .push(GenerationStats::new(generation, values, selectors));
+            results.final_actions.clear();
+            for &index in &actions {
+                let parsed: Result<Vec<Act>, String> =
+                    field(index).split(", ").map(Act::from_str).collect();
+                let parsed = parsed.map_err(|message| format!("line {}: {}", line + 1, message))?;
+                let ordering: [Act; 10] = parsed
+                    .try_into()
+                    .map_err(|_| format!("line {}: an ordering needs 10 actions", line + 1))?;
+                results.final_actions.push(ordering);
+            }
+        }
+        Ok(results)
+    }
+
+    pub fn write_charts(&self, output_dir: impl AsRef<Path>) -> Result<Vec<PathBuf>, PlotError> {
+        let images = output_dir.as_ref().join(&self.name).join("images");
+        std::fs::create_dir_all(&images).map_err(|error| PlotError::Io {
+            path: images.clone(),
+            message: error.to_string(),
+        })?;
+        let charts = [
+            ("fitness", self.fitness_svg()),
+            ("selectors", self.selector_svg()),
+            ("actions", self.action_svg()),
+        ];
+        let mut paths = Vec::new();
+        for (chart, svg) in charts {
+            let path = images.join(format!("{}_{}.svg", self.name, chart));
+            std::fs::write(&path, svg).map_err(|error| PlotError::Io {
+                path: path.clone(),
+                message: error.to_string(),
+            })?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    pub fn fitness_svg(&self) -> String {
+        let title = format!(
+            "GA players' winrates for {} in {} generations",
+            self.name,
+            self.generations.last().map_or(0, |stats| stats.generation)
+        );
+        let mut svg = svg_header(&title);
+        let frame = Frame::new(self.generation_range(), (0.0, 100.0));
+        frame.axes(&mut svg, "Generation", "Winrate (%)");
+        let std_dev = self.band(
+            |stats| (stats.mean + stats.std_dev).min(100.0),
+            |stats| (stats.mean - stats.std_dev).max(0.0),
+        );
+        let _ = writeln!(
+            svg,
+            "<polygon class=\"std-dev\" points=\"{}\" fill=\"#f2c500\" fill-opacity=\"0.25\"/>",
+            frame.points(&std_dev)
+        );
+        let quartiles = self.band(|stats| stats.upper_quartile, |stats| stats.lower_quartile);
+        let _ = writeln!(
+            svg,
+            "<polygon class=\"iqr\" points=\"{}\" fill=\"#2e9e44\" fill-opacity=\"0.2\"/>",
+            frame.points(&quartiles)
+        );
+        let series: [Series; 4] = [
+            ("Best", "#2d6fd6", |stats| stats.best),
+            ("Median", "#2e9e44", |stats| stats.median),
+            ("Mean", "#f28c28", |stats| stats.mean),
+            ("Worst", "#d63a2d", |stats| stats.worst),
+        ];
+        for (_, color, value) in series {
+            let line: Vec<(f64, f64)> = self
+                .generations
+                .iter()
+                .map(|stats| (stats.generation as f64, value(stats)))
+                .collect();
+            let _ = writeln!(
+                svg,
+                "<polyline class=\"series\" points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"2.5\"/>",
+                frame.points(&line),
+                color
+            );
+        }
+        let mut legend: Vec<(String, &str)> = series
+            .iter()
+            .map(|(label, color, _)| (label.to_string(), *color))
+            .collect();
+        legend.push(("Mean ± std dev".to_string(), "#f2c500"));
+        legend.push(("Interquartile range".to_string(), "#2e9e44"));
+        let mut notes = legend_svg(&legend);
+        for (index, (name, value)) in self.params.iter().enumerate() {
+            let _ = writeln!(
+                notes,
+                "<text x=\"{:.0}\" y=\"{:.0}\" font-size=\"12\">{} = {}</text>",
+                WIDTH - RIGHT + 24.0,
+                TOP + 150.0 + 18.0 * index as f64,
+                escape(name),
+                escape(value)
+            );
+        }
+        svg.push_str(&notes);
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn band(
+        &self,
+        upper: fn(&GenerationStats) -> f64,
+        lower: fn(&GenerationStats) -> f64,
+    ) -> Vec<(f64, f64)> {
+        self.generations
+            .iter()
+            .map(|stats| (stats.generation as f64, upper(stats)))
+            .chain(
+                self.generations
+                    .iter()
+                    .rev()
+                    .map(|stats| (stats.generation as f64, lower(stats))),
+            )
+            .collect()
+    }
+
+    pub fn selector_svg(&self) -> String {
+        let mut svg = svg_header(&format!("Piece selectors in {}", self.name));
+        let frame = Frame::new(self.generation_range(), (0.0, 100.0));
+        frame.axes(&mut svg, "Generation", "Share of the population (%)");
+        let colors = ["#2d6fd6", "#2e9e44", "#d63a2d"];
+        let mut below = vec![0.0; self.generations.len()];
+        for (select, color) in colors.iter().enumerate() {
+            let above: Vec<f64> = self
+                .generations
+                .iter()
+                .zip(&below)
+                .map(|(stats, low)| {
+                    let total: usize = stats.selectors.iter().sum();
+                    let share = match total {
+                        0 => 0.0,
+                        total => 100.0 * stats.selectors[select] as f64 / total as f64,
+                    };
+                    low + share
+                })
+                .collect();
+            let area: Vec<(f64, f64)> = self
+                .generations
+                .iter()
+                .zip(&above)
+                .map(|(stats, &high)| (stats.generation as f64, high))
+                .chain(
+                    self.generations
+                        .iter()
+                        .zip(&below)
+                        .rev()
+                        .map(|(stats, &low)| (stats.generation as f64, low)),
+                )
+                .collect();
+            let _ = writeln!(
+                svg,
+                "<polygon class=\"selector\" points=\"{}\" fill=\"{}\" fill-opacity=\"0.8\"/>",
+                frame.points(&area),
+                color
+            );
+            below = above;
+        }
+        let legend: Vec<(String, &str)> = SELECTIONS
+            .iter()
+            .zip(colors)
+            .map(|(select, color)| (select.to_string(), color))
+            .collect();
+        svg.push_str(&legend_svg(&legend));
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    pub fn action_svg(&self) -> String {
+        let mut svg = svg_header(&format!(
+            "Action priorities in the last generation of {}",
+            self.name
+        ));
+        let (cell_width, cell_height) = (
+            (WIDTH - LEFT - RIGHT) / 10.0,
+            (HEIGHT - TOP - BOTTOM) / 10.0,
+        );
+        let total = self.final_actions.len().max(1) as f64;
+        for (row, act) in ACTIONS.iter().enumerate() {
+            let y = TOP + row as f64 * cell_height;
+            let _ = writeln!(
+                svg,
+                "<text x=\"{:.0}\" y=\"{:.1}\" font-size=\"12\" text-anchor=\"end\">{}</text>",
+                LEFT - 8.0,
+                y + cell_height / 2.0 + 4.0,
+                act
+            );
+            for rank in 0..10 {
+                let count = self
+                    .final_actions
+                    .iter()
+                    .filter(|ordering| ordering[rank] == *act)
+                    .count();
+                let share = count as f64 / total;
+                let x = LEFT + rank as f64 * cell_width;
+                let _ = writeln!(
+                    svg,
+                    "<rect class=\"share\" x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"#2d6fd6\" fill-opacity=\"{:.3}\" stroke=\"#cccccc\"/>",
+                    x,
+                    y,
+                    cell_width,
+                    cell_height,
+                    share
+                );
+                if count > 0 {
+                    let _ = writeln!(
+                        svg,
+                        "<text x=\"{:.1}\" y=\"{:.1}\" font-size=\"11\" text-anchor=\"middle\">{:.0}%</text>",
+                        x + cell_width / 2.0,
+                        y + cell_height / 2.0 + 4.0,
+                        100.0 * share
+                    );
+                }
+            }
+        }
+        for rank in 0..10 {
+            let _ = writeln!(
+                svg,
+                "<text x=\"{:.1}\" y=\"{:.0}\" font-size=\"12\" text-anchor=\"middle\">{}</text>",
+                LEFT + (rank as f64 + 0.5) * cell_width,
+                HEIGHT - BOTTOM + 18.0,
+                rank + 1
+            );
+        }
+        let _ = writeln!(
+            svg,
+            "<text x=\"{:.0}\" y=\"{:.0}\" font-size=\"14\" text-anchor=\"middle\">Priority</text>",
+            LEFT + (WIDTH - LEFT - RIGHT) / 2.0,
+            HEIGHT - 12.0
+        );
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn generation_range(&self) -> (f64, f64) {
+        let first = self.generations.first().map_or(0, |stats| stats.generation);
+        let last = self.generations.last().map_or(0, |stats| stats.generation);
+        (first as f64, last.max(first + 1) as f64)
+    }
+}
+
+impl GenerationStats {
+    fn new(generation: usize, mut winrates: Vec<f64>, selectors: Vec<usize>) -> Self {
+        winrates.sort_by(f64::total_cmp);
+        let count = winrates.len() as f64;
+        let mean = winrates.iter().sum::<f64>() / count.max(1.0);
+        let variance = match winrates.len() {
+            0 | 1 => 0.0,
+            _ => {
+                winrates
+                    .iter()
+                    .map(|value| (value - mean).powi(2))
+                    .sum::<f64>()
+                    / (count - 1.0)
+            }
+        };
+        GenerationStats {
+            generation,
+            best: winrates.last().copied().unwrap_or_default(),
+            upper_quartile: quantile(&winrates, 0.75),
+            median: quantile(&winrates, 0.5),
+            mean,
+            lower_quartile: quantile(&winrates, 0.25),
+            worst: winrates.first().copied().unwrap_or_default(),
+            std_dev: variance.sqrt(),
+            selectors,
+        }
+    }
+}
+
+pub fn plot_results(output_dir: impl AsRef<Path>, name: &str) -> Result<Vec<PathBuf>, PlotError> {
+    let output_dir = output_dir.as_ref();
+    GaResults::load(output_dir, name)?.write_charts(output_dir)
+}
+
+struct Frame {
+    x: (f64, f64),
+    y: (f64, f64),
+}
+
+impl Frame {
+    fn new(x: (f64, f64), y: (f64, f64)) -> Self {
+        Frame { x, y }
+    }
+
+    fn point(&self, x: f64, y: f64) -> (f64, f64) {
+        let width = WIDTH - LEFT - RIGHT;
+        let height = HEIGHT - TOP - BOTTOM;
+        (
+            LEFT + (x - self.x.0) / (self.x.1 - self.x.0) * width,
+            HEIGHT - BOTTOM - (y - self.y.0) / (self.y.1 - self.y.0) * height,
+        )
+    }
+
+    fn points(&self, values: &[(f64, f64)]) -> String {
+        values
+            .iter()
+            .map(|&(x, y)| {
+                let (x, y) = self.point(x, y);
+                format!("{:.1},{:.1}", x, y)
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    fn axes(&self, svg: &mut String, x_label: &str, y_label: &str) {
+        for tick in 0..=5 {
+            let value = self.y.0 + (self.y.1 - self.y.0) * tick as f64 / 5.0;
+            let (_, y) = self.point(self.x.0, value);
+            let _ = writeln!(
+                svg,
+                "<line x1=\"{LEFT:.0}\" y1=\"{y:.1}\" x2=\"{:.0}\" y2=\"{y:.1}\" stroke=\"#dddddd\" stroke-dasharray=\"4 4\"/>\n<text x=\"{:.0}\" y=\"{:.1}\" font-size=\"12\" text-anchor=\"end\">{:.0}</text>",
+                WIDTH - RIGHT,
+                LEFT - 6.0,
+                y + 4.0,
+                value
+            );
+        }
+        let steps = (self.x.1 - self.x.0).clamp(1.0, 10.0) as usize;
+        for tick in 0..=steps {
+            let value = self.x.0 + (self.x.1 - self.x.0) * tick as f64 / steps as f64;
+            let (x, _) = self.point(value, self.y.0);
+            let _ = writeln!(
+                svg,
+                "<text x=\"{x:.1}\" y=\"{:.0}\" font-size=\"12\" text-anchor=\"middle\">{:.0}</text>",
+                HEIGHT - BOTTOM + 18.0,
+                value
+            );
+        }
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{LEFT:.0}\" y=\"{TOP:.0}\" width=\"{:.0}\" height=\"{:.0}\" fill=\"none\" stroke=\"#333333\"/>\n<text x=\"{:.0}\" y=\"{:.0}\" font-size=\"14\" text-anchor=\"middle\">{}</text>\n<text x=\"16\" y=\"{:.0}\" font-size=\"14\" text-anchor=\"middle\" transform=\"rotate(-90 16 {:.0})\">{}</text>",
+            WIDTH - LEFT - RIGHT,
+            HEIGHT - TOP - BOTTOM,
+            LEFT + (WIDTH - LEFT - RIGHT) / 2.0,
+            HEIGHT - 12.0,
+            x_label,
+            TOP + (HEIGHT - TOP - BOTTOM) / 2.0,
+            TOP + (HEIGHT - TOP - BOTTOM) / 2.0,
+            y_label
+        );
+    }
+}
+
+fn svg_header(title: &str) -> String {
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH:.0}\" height=\"{HEIGHT:.0}\" viewBox=\"0 0 {WIDTH:.0} {HEIGHT:.0}\" font-family=\"sans-serif\">\n<rect width=\"{WIDTH:.0}\" height=\"{HEIGHT:.0}\" fill=\"#ffffff\"/>\n<text x=\"{:.0}\" y=\"28\" font-size=\"16\" text-anchor=\"middle\">{}</text>\n",
+        WIDTH / 2.0,
+        escape(title)
+    )
+}
+
+fn legend_svg(entries: &[(String, &str)]) -> String {
+    let mut svg = String::new();
+    for (index, (label, color)) in entries.iter().enumerate() {
+        let y = TOP + 18.0 * index as f64;
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{:.0}\" y=\"{:.0}\" width=\"14\" height=\"10\" fill=\"{}\"/>\n<text x=\"{:.0}\" y=\"{:.0}\" font-size=\"12\">{}</text>",
+            WIDTH - RIGHT + 24.0,
+            y,
+            color,
+            WIDTH - RIGHT + 44.0,
+            y + 10.0,
+            escape(label)
+        );
+    }
+    svg
+}
+
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    match sorted.len() {
+        0 => 0.0,
+        len => {
+            let position = q * (len - 1) as f64;
+            let (low, high) = (position.floor() as usize, position.ceil() as usize);
+            sorted[low] + (sorted[high] - sorted[low]) * (position - low as f64)
+        }
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn read_csv(path: &Path) -> Result<Vec<Vec<String>>, PlotError> {
+    let csv_error = |error: csv::Error| PlotError::Csv {
+        path: path.to_path_buf(),
+        message: error.to_string(),
+    };
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_path(path)
+        .map_err(csv_error)?;
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        rows.push(
+            record
+                .map_err(csv_error)?
+                .iter()
+                .map(str::to_string)
+                .collect(),
+        );
+    }
+    Ok(rows)
+}
+
+impl Display for PlotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PlotError::Io { path, message } => write!(f, "{}: {}", path.display(), message),
+            PlotError::Csv { path, message } => {
+                write!(f, "Invalid GA results in {}: {}", path.display(), message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PlotError {}
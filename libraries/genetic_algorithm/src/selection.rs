@@ -0,0 +1,153 @@
+use rand::{seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// How parents are drawn from the evaluated population. The elites are kept
+/// regardless of the strategy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SelectionStrategy {
+    Tournament {
+        #[serde(default = "default_tournament_k")]
+        k: usize,
+    },
+    #[serde(alias = "roulette")]
+    RouletteWheel,
+    Rank,
+    #[serde(alias = "sus")]
+    StochasticUniversalSampling,
+}
+
+fn default_tournament_k() -> usize {
+    2
+}
+
+impl Default for SelectionStrategy {
+    fn default() -> Self {
+        SelectionStrategy::Tournament {
+            k: default_tournament_k(),
+        }
+    }
+}
+
+impl SelectionStrategy {
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            SelectionStrategy::Tournament { k: 0 } => {
+                Err("a tournament needs at least one contestant".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the indices of `count` parents, picked by their fitness.
+    pub fn select(&self, fitness: &[f64], count: usize, rng: &mut impl Rng) -> Vec<usize> {
+        if fitness.is_empty() {
+            return Vec::new();
+        }
+        match self {
+            SelectionStrategy::Tournament { k } => (0..count)
+                .map(|_| {
+                    (0..(*k).max(1))
+                        .map(|_| rng.gen_range(0..fitness.len()))
+                        .reduce(|best, index| match fitness[index] > fitness[best] {
+                            true => index,
+                            false => best,
+                        })
+                        .unwrap()
+                })
+                .collect(),
+            SelectionStrategy::RouletteWheel => {
+                let weights = wheel(fitness);
+                let total: f64 = weights.iter().sum();
+                (0..count)
+                    .map(|_| spin(&weights, rng.gen_range(0.0..total)))
+                    .collect()
+            }
+            SelectionStrategy::Rank => {
+                let mut ranked: Vec<usize> = (0..fitness.len()).collect();
+                ranked.sort_by(|&a, &b| fitness[b].total_cmp(&fitness[a]));
+                let mut weights = vec![0.0; fitness.len()];
+                for (rank, &index) in ranked.iter().enumerate() {
+                    weights[index] = (fitness.len() - rank) as f64;
+                }
+                let total: f64 = weights.iter().sum();
+                (0..count)
+                    .map(|_| spin(&weights, rng.gen_range(0.0..total)))
+                    .collect()
+            }
+            SelectionStrategy::StochasticUniversalSampling => {
+                if count == 0 {
+                    return Vec::new();
+                }
+                let weights = wheel(fitness);
+                let step = weights.iter().sum::<f64>() / count as f64;
+                let start = rng.gen_range(0.0..step);
+                let mut parents: Vec<usize> = (0..count)
+                    .map(|i| spin(&weights, start + i as f64 * step))
+                    .collect();
+                parents.shuffle(rng);
+                parents
+            }
+        }
+    }
+}
+
+/// Fitness proportional weights, or equal weights when nobody has won a game.
+fn wheel(fitness: &[f64]) -> Vec<f64> {
+    let weights: Vec<f64> = fitness.iter().map(|fitness| fitness.max(0.0)).collect();
+    match weights.iter().sum::<f64>() > 0.0 {
+        true => weights,
+        false => vec![1.0; fitness.len()],
+    }
+}
+
+fn spin(weights: &[f64], pointer: f64) -> usize {
+    let mut cumulative = 0.0;
+    for (index, weight) in weights.iter().enumerate() {
+        cumulative += weight;
+        if pointer < cumulative {
+            return index;
+        }
+    }
+    weights.iter().rposition(|&weight| weight > 0.0).unwrap()
+}
+
+impl Display for SelectionStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SelectionStrategy::Tournament { k } => write!(f, "tournament:{}", k),
+            SelectionStrategy::RouletteWheel => write!(f, "roulette"),
+            SelectionStrategy::Rank => write!(f, "rank"),
+            SelectionStrategy::StochasticUniversalSampling => write!(f, "sus"),
+        }
+    }
+}
+
+impl FromStr for SelectionStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lowercase = s.to_lowercase().replace('-', "_");
+        let (name, k) = match lowercase.split_once(':') {
+            Some((name, k)) => (name, Some(k)),
+            None => (lowercase.as_str(), None),
+        };
+        let strategy = match (name, k) {
+            ("tournament", None) => SelectionStrategy::default(),
+            ("tournament", Some(k)) => SelectionStrategy::Tournament {
+                k: k.parse()
+                    .map_err(|_| format!("Invalid tournament size: {}", k))?,
+            },
+            ("roulette" | "roulette_wheel", None) => SelectionStrategy::RouletteWheel,
+            ("rank", None) => SelectionStrategy::Rank,
+            ("sus" | "stochastic_universal_sampling", None) => {
+                SelectionStrategy::StochasticUniversalSampling
+            }
+            _ => return Err(format!("Unknown selection strategy: {}", s)),
+        };
+        strategy.validate()?;
+        Ok(strategy)
+    }
+}
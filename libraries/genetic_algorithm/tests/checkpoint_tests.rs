@@ -0,0 +1,171 @@
+use game::Game;
+use genetic_algorithm::{Checkpoint, CheckpointError, Experiment, GeneticAlgorithm, RunError};
+use iplayers::Playstyle;
+use std::path::{Path, PathBuf};
+
+#[cfg(test)]
+mod checkpoint_tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("ludo_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn small_ga(generations: usize, output_dir: &Path) -> GeneticAlgorithm {
+        let mut ga = GeneticAlgorithm::default();
+        ga.set_population_size(6);
+        ga.set_elitism_count(2);
+        ga.set_generations(generations);
+        ga.set_total_games(4);
+        ga.set_seed(23);
+        ga.set_write_to_csv(true);
+        ga.set_csv_name("checkpointed");
+        ga.set_output_dir(output_dir.to_str().unwrap());
+        ga
+    }
+
+    fn winrates(output_dir: &Path) -> String {
+        std::fs::read_to_string(
+            output_dir
+                .join("checkpointed")
+                .join("checkpointed_winrates.csv"),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn checkpoint_contents_test() {
+        let dir = scratch_dir("checkpoint_contents");
+        let path = dir.join("run.json");
+        let mut ga = small_ga(3, &dir);
+        ga.set_checkpoint(&path, 2);
+        ga.run_gentic_algorithm().unwrap();
+
+        let checkpoint = Checkpoint::load(&path).unwrap();
+        assert_eq!(checkpoint.generation, 2);
+        assert_eq!(checkpoint.population.len(), 6);
+        assert_eq!(checkpoint.history.len(), 2);
+        assert_eq!(checkpoint.history[1].0, 1);
+        assert_eq!(checkpoint.experiment.name, "checkpointed");
+        assert_eq!(checkpoint.experiment.generations, 3);
+        assert_eq!(checkpoint.experiment.seed, Some(23));
+        assert_eq!(checkpoint.experiment.opponents, ga.opponents());
+        assert!(!dir.join("run.partial").exists());
+
+        let restored = GeneticAlgorithm::from_checkpoint(&path).unwrap();
+        assert_eq!(restored.generation(), 2);
+        assert_eq!(restored.checkpoint(), checkpoint);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn resume_matches_uninterrupted_run_test() {
+        let dir = scratch_dir("resume");
+        let full_dir = dir.join("full");
+        let mut full = small_ga(4, &full_dir);
+        full.run_gentic_algorithm().unwrap();
+
+        let path = dir.join("half.json");
+        let mut half = small_ga(2, &dir.join("half"));
+        half.set_checkpoint(&path, 2);
+        half.run_gentic_algorithm().unwrap();
+
+        let resumed_dir = dir.join("resumed");
+        let mut resumed = GeneticAlgorithm::from_checkpoint(&path).unwrap();
+        resumed.set_generations(4);
+        resumed.set_output_dir(resumed_dir.to_str().unwrap());
+        resumed.resume().unwrap();
+
+        assert_eq!(winrates(&resumed_dir), winrates(&full_dir));
+        assert_eq!(
+            resumed.checkpoint().population,
+            full.checkpoint().population
+        );
+        assert_eq!(resumed.checkpoint().rng, full.checkpoint().rng);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn resume_from_test() {
+        let dir = scratch_dir("resume_from");
+        let experiment = Experiment {
+            name: "resumable".to_string(),
+            population_size: 4,
+            generations: 2,
+            total_games: 2,
+            seed: Some(3),
+            output_dir: dir.to_str().unwrap().to_string(),
+            checkpoint_interval: 1,
+            ..Experiment::default()
+        };
+        let finished = experiment.run().unwrap();
+        let path = experiment.checkpoint_path();
+        assert!(path.exists());
+
+        let resumed = GeneticAlgorithm::resume_from(&path).unwrap();
+        assert_eq!(resumed.generation(), 2);
+        assert_eq!(
+            resumed.checkpoint().population,
+            finished.checkpoint().population
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn unwritable_checkpoint_test() {
+        let dir = scratch_dir("unwritable_checkpoint");
+        let blocker = dir.join("blocker");
+        std::fs::write(&blocker, "").unwrap();
+        let mut ga = small_ga(2, &dir);
+        ga.set_checkpoint(blocker.join("run.json"), 1);
+        assert!(matches!(
+            ga.run_gentic_algorithm(),
+            Err(RunError::Checkpoint(CheckpointError::Io { .. }))
+        ));
+        assert_eq!(ga.generation(), 1);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn custom_evaluator_checkpoint_test() {
+        let dir = scratch_dir("custom_evaluator_checkpoint");
+        let path = dir.join("run.json");
+        let mut ga = small_ga(2, &dir);
+        ga.set_evaluator(Game::new());
+        ga.set_checkpoint(&path, 1);
+        assert_eq!(
+            ga.run_gentic_algorithm(),
+            Err(RunError::Checkpoint(CheckpointError::CustomEvaluator))
+        );
+        assert_eq!(ga.generation(), 0);
+        assert_eq!(
+            ga.save_checkpoint(&path),
+            Err(CheckpointError::CustomEvaluator)
+        );
+        assert!(!path.exists());
+
+        ga.set_opponents(&[Playstyle::Fast]).unwrap();
+        assert!(!ga.has_custom_evaluator());
+        ga.save_checkpoint(&path).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn invalid_checkpoint_test() {
+        let dir = scratch_dir("invalid_checkpoint");
+        assert!(matches!(
+            GeneticAlgorithm::from_checkpoint(dir.join("missing.json")),
+            Err(CheckpointError::Io { .. })
+        ));
+        let path = dir.join("broken.json");
+        std::fs::write(&path, "{\"generation\": 1}").unwrap();
+        assert!(matches!(
+            GeneticAlgorithm::from_checkpoint(&path),
+            Err(CheckpointError::Parse { .. })
+        ));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
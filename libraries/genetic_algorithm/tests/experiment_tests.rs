@@ -0,0 +1,198 @@
+use genetic_algorithm::{
+    CrossoverType, Experiment, ExperimentError, GeneticAlgorithm, DEFAULT_OPPONENTS,
+};
+use iplayers::Playstyle;
+use std::path::PathBuf;
+
+#[cfg(test)]
+mod experiment_tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("ludo_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn toml_experiment_test() {
+        let experiment = Experiment::from_toml(
+            r#"
+            name = "low mutation"
+            population_size = 20
+            mutation_rate = 0.1
+            elitism_count = 4
+            seed = 7
+            opponents = ["safe", "fast-aggressive"]
+            crossover = ["two_point"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(experiment.name, "low mutation");
+        assert_eq!(experiment.population_size, 20);
+        assert_eq!(experiment.mutation_rate, 0.1);
+        assert_eq!(experiment.crossover_rate, 0.95);
+        assert_eq!(experiment.seed, Some(7));
+        assert_eq!(
+            experiment.opponents,
+            vec![Playstyle::Safe, Playstyle::FastAggressive]
+        );
+        assert_eq!(experiment.crossover, vec![CrossoverType::TwoPoint]);
+
+        let ga = experiment.build().unwrap();
+        assert_eq!(ga.population_size(), 20);
+        assert_eq!(ga.mutation_rate(), 0.1);
+        assert_eq!(ga.elitism_count(), 4);
+        assert_eq!(ga.generations(), 5);
+        assert_eq!(ga.csv_name(), "low mutation");
+        assert_eq!(ga.crossover_types(), &[CrossoverType::TwoPoint]);
+        assert_eq!(
+            ga.opponents(),
+            vec![Playstyle::Safe, Playstyle::FastAggressive]
+        );
+    }
+
+    #[test]
+    fn json_experiment_test() {
+        let experiment =
+            Experiment::from_json(r#"{"name": "json", "generations": 3, "total_games": 10}"#)
+                .unwrap();
+        assert_eq!(experiment.generations, 3);
+        assert_eq!(experiment.total_games, 10);
+        assert_eq!(experiment.opponents, DEFAULT_OPPONENTS.to_vec());
+        assert_eq!(
+            Experiment::from_json(&serde_json::to_string(&experiment).unwrap()).unwrap(),
+            experiment
+        );
+    }
+
+    #[test]
+    fn default_experiment_matches_default_ga_test() {
+        let ga = Experiment {
+            name: "default".to_string(),
+            ..Experiment::default()
+        }
+        .build()
+        .unwrap();
+        let default = GeneticAlgorithm::default();
+        assert_eq!(ga.population_size(), default.population_size());
+        assert_eq!(ga.mutation_rate(), default.mutation_rate());
+        assert_eq!(ga.crossover_rate(), default.crossover_rate());
+        assert_eq!(ga.elitism_count(), default.elitism_count());
+        assert_eq!(ga.generations(), default.generations());
+        assert_eq!(ga.total_games(), default.total_games());
+        assert_eq!(ga.crossover_types(), default.crossover_types());
+        assert_eq!(ga.opponents(), default.opponents());
+    }
+
+    #[test]
+    fn invalid_experiment_test() {
+        assert!(Experiment::from_toml("population = 10").is_err());
+        assert!(Experiment::from_toml("opponents = [\"sneaky\"]").is_err());
+
+        let invalid = |experiment: Experiment| {
+            matches!(experiment.validate(), Err(ExperimentError::Invalid { .. }))
+        };
+        let named = Experiment {
+            name: "invalid".to_string(),
+            ..Experiment::default()
+        };
+        assert!(named.validate().is_ok());
+        assert!(invalid(Experiment::default()));
+        assert!(invalid(Experiment {
+            elitism_count: 11,
+            ..named.clone()
+        }));
+        assert!(invalid(Experiment {
+            mutation_rate: 1.5,
+            ..named.clone()
+        }));
+        assert!(invalid(Experiment {
+            opponents: vec![Playstyle::Fast; 4],
+            ..named.clone()
+        }));
+        assert!(invalid(Experiment {
+            opponents: vec![Playstyle::Human],
+            ..named.clone()
+        }));
+        assert!(invalid(Experiment {
+            crossover: Vec::new(),
+            ..named
+        }));
+    }
+
+    #[test]
+    fn zero_games_test() {
+        let experiment = Experiment {
+            name: "no games".to_string(),
+            total_games: 0,
+            ..Experiment::default()
+        };
+        assert!(matches!(
+            experiment.build(),
+            Err(ExperimentError::Invalid { message, .. }) if message.contains("total_games")
+        ));
+    }
+
+    #[test]
+    fn load_dir_test() {
+        let dir = scratch_dir("load_dir");
+        std::fs::write(dir.join("b.json"), r#"{"population_size": 4}"#).unwrap();
+        std::fs::write(dir.join("a.toml"), "population_size = 3").unwrap();
+        std::fs::write(dir.join("notes.txt"), "not an experiment").unwrap();
+        let experiments = Experiment::load_dir(&dir).unwrap();
+        let names: Vec<&str> = experiments.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b"]);
+        assert_eq!(experiments[1].population_size, 4);
+
+        std::fs::write(dir.join("c.toml"), "name = \"a\"").unwrap();
+        assert!(matches!(
+            Experiment::load_dir(&dir),
+            Err(ExperimentError::Invalid { .. })
+        ));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn run_experiments_test() {
+        let dir = scratch_dir("run_experiments");
+        let output = dir.join("data");
+        for (file, name) in [("first.toml", "first"), ("second.toml", "second")] {
+            std::fs::write(
+                dir.join(file),
+                format!(
+                    "name = \"{}\"\npopulation_size = 4\nelitism_count = 1\ngenerations = 2\n\
+                     total_games = 2\nseed = 5\nopponents = [\"fast\"]\noutput_dir = \"{}\"\n",
+                    name,
+                    output.display()
+                ),
+            )
+            .unwrap();
+        }
+        let experiments = Experiment::load_dir(&dir).unwrap();
+        assert_eq!(experiments.len(), 2);
+        for experiment in &experiments {
+            experiment.run().unwrap();
+        }
+        for name in ["first", "second"] {
+            assert!(output
+                .join(name)
+                .join(format!("{}_winrates.csv", name))
+                .exists());
+            assert!(output
+                .join(name)
+                .join(format!("{}_params.csv", name))
+                .exists());
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn bundled_experiments_test() {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../experiments");
+        let experiments = Experiment::load_dir(dir).unwrap();
+        assert_eq!(experiments.len(), 10);
+        assert_eq!(experiments[0].name, "GA control test");
+    }
+}
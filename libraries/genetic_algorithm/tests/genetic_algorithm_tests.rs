@@ -16,13 +16,13 @@ mod genetic_algorithm_test {
         ga.set_mutation_rate(0.01);
         ga.set_crossover_rate(0.95);
         ga.set_elitism_count(2);
-        ga.set_tournament_size(5);
+        ga.set_generations(5);
 
         assert_eq!(ga.population_size(), 10);
         assert_eq!(ga.mutation_rate(), 0.01);
         assert_eq!(ga.crossover_rate(), 0.95);
         assert_eq!(ga.elitism_count(), 2);
-        assert_eq!(ga.tournament_size(), 5);
+        assert_eq!(ga.generations(), 5);
     }
 
     #[test]
@@ -32,7 +32,7 @@ mod genetic_algorithm_test {
         assert_eq!(ga.mutation_rate(), 0.01);
         assert_eq!(ga.crossover_rate(), 0.95);
         assert_eq!(ga.elitism_count(), 2);
-        assert_eq!(ga.tournament_size(), 5);
+        assert_eq!(ga.generations(), 5);
     }
 
     #[test]
@@ -76,32 +76,34 @@ mod genetic_algorithm_test {
     fn evaluation_test() {
         let mut ga = GeneticAlgorithm::new();
         let mut game = Game::new();
-        game.setup_game();
-        game.give_iplayer_a_playstyle(0, Playstyle::GeneticAlgorithm);
-        game.give_iplayer_a_playstyle(1, Playstyle::Random);
-        game.give_iplayer_a_playstyle(2, Playstyle::Fast);
-        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive);
+        game.give_iplayer_a_playstyle(0, Playstyle::GeneticAlgorithm)
+            .unwrap();
+        game.give_iplayer_a_playstyle(1, Playstyle::Random).unwrap();
+        game.give_iplayer_a_playstyle(2, Playstyle::Fast).unwrap();
+        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive)
+            .unwrap();
         ga.set_evaluator(game);
         ga.set_population_size(10);
         ga.initialize_all_populations();
-        ga.evaluate_fitness_for_all_populations(0);
+        ga.evaluate_fitness_for_all_populations(0).unwrap();
     }
 
     #[test]
     fn selection_test() {
         let mut ga = GeneticAlgorithm::new();
         let mut game = Game::new();
-        game.setup_game();
-        game.give_iplayer_a_playstyle(0, Playstyle::GeneticAlgorithm);
-        game.give_iplayer_a_playstyle(1, Playstyle::Random);
-        game.give_iplayer_a_playstyle(2, Playstyle::Fast);
-        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive);
+        game.give_iplayer_a_playstyle(0, Playstyle::GeneticAlgorithm)
+            .unwrap();
+        game.give_iplayer_a_playstyle(1, Playstyle::Random).unwrap();
+        game.give_iplayer_a_playstyle(2, Playstyle::Fast).unwrap();
+        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive)
+            .unwrap();
 
         ga.set_evaluator(game);
         ga.set_population_size(10);
         ga.set_elitism_count(2);
         ga.initialize_all_populations();
-        ga.evaluate_fitness_for_all_populations(0);
+        ga.evaluate_fitness_for_all_populations(0).unwrap();
         ga.select_best_populations();
         assert_eq!(ga.population().len(), 2);
     }
@@ -131,18 +133,19 @@ mod genetic_algorithm_test {
     fn recombination_test() {
         let mut ga = GeneticAlgorithm::new();
         let mut game = Game::new();
-        game.setup_game();
-        game.give_iplayer_a_playstyle(0, Playstyle::GeneticAlgorithm);
-        game.give_iplayer_a_playstyle(1, Playstyle::Random);
-        game.give_iplayer_a_playstyle(2, Playstyle::Fast);
-        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive);
+        game.give_iplayer_a_playstyle(0, Playstyle::GeneticAlgorithm)
+            .unwrap();
+        game.give_iplayer_a_playstyle(1, Playstyle::Random).unwrap();
+        game.give_iplayer_a_playstyle(2, Playstyle::Fast).unwrap();
+        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive)
+            .unwrap();
         ga.set_evaluator(game);
         ga.set_mutation_rate(1.0);
         ga.set_crossover_rate(1.0);
         ga.set_population_size(10);
         ga.set_elitism_count(2);
         ga.initialize_all_populations();
-        ga.evaluate_fitness_for_all_populations(0);
+        ga.evaluate_fitness_for_all_populations(0).unwrap();
         ga.select_best_populations();
         ga.create_children_and_replace_bad_populations();
         assert_eq!(ga.population().len(), 10);
@@ -152,22 +155,23 @@ mod genetic_algorithm_test {
     fn genetic_algorithm_test() {
         let mut ga = GeneticAlgorithm::new();
         let mut game = Game::new();
-        game.setup_game();
-        game.give_iplayer_a_playstyle(0, Playstyle::GeneticAlgorithm);
-        game.give_iplayer_a_playstyle(1, Playstyle::Random);
-        game.give_iplayer_a_playstyle(2, Playstyle::Fast);
-        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive);
+        game.give_iplayer_a_playstyle(0, Playstyle::GeneticAlgorithm)
+            .unwrap();
+        game.give_iplayer_a_playstyle(1, Playstyle::Random).unwrap();
+        game.give_iplayer_a_playstyle(2, Playstyle::Fast).unwrap();
+        game.give_iplayer_a_playstyle(3, Playstyle::Aggressive)
+            .unwrap();
         ga.set_evaluator(game);
         ga.set_mutation_rate(1.0);
         ga.set_write_to_csv(true);
         ga.set_crossover_rate(1.0);
         ga.set_population_size(10);
-        ga.set_tournament_size(10);
+        ga.set_generations(10);
         ga.set_total_games(10);
         ga.set_elitism_count(2);
         ga.set_csv_name("GA test");
         ga.initialize_all_populations();
-        ga.run_gentic_algorithm();
+        ga.run_gentic_algorithm().unwrap();
     }
 
     #[test]
@@ -177,13 +181,13 @@ mod genetic_algorithm_test {
         ga.set_mutation_rate(0.01);
         ga.set_crossover_rate(0.95);
         ga.set_population_size(10);
-        ga.set_tournament_size(50);
+        ga.set_generations(50);
         ga.set_elitism_count(2);
         ga.set_write_to_csv(true);
         ga.set_total_games(1000);
         ga.set_csv_name("GA test");
         ga.initialize_all_populations();
-        ga.run_gentic_algorithm();
+        ga.run_gentic_algorithm().unwrap();
     }
 
     #[test]
@@ -192,12 +196,72 @@ mod genetic_algorithm_test {
         ga.set_mutation_rate(0.01);
         ga.set_crossover_rate(0.95);
         ga.set_population_size(100);
-        ga.set_tournament_size(100);
+        ga.set_generations(100);
         ga.set_elitism_count(2);
         ga.set_write_to_csv(true);
         ga.set_total_games(100);
         ga.set_csv_name("GA data");
         ga.initialize_all_populations();
-        ga.run_gentic_algorithm();
+        ga.run_gentic_algorithm().unwrap();
+    }
+
+    #[test]
+    fn seeded_genetic_algorithm_test() {
+        let mut populations = vec![];
+        for _ in 0..2 {
+            let mut ga = GeneticAlgorithm::default();
+            ga.set_seed(99);
+            ga.set_mutation_rate(0.5);
+            ga.set_crossover_rate(0.5);
+            ga.set_population_size(4);
+            ga.set_generations(2);
+            ga.set_total_games(5);
+            ga.set_elitism_count(2);
+            ga.run_gentic_algorithm().unwrap();
+            populations.push(ga.population().clone());
+        }
+        assert_eq!(populations[0].len(), populations[1].len());
+        for (first, second) in populations[0].iter().zip(populations[1].iter()) {
+            assert_eq!(first.get_actions(), second.get_actions());
+            assert_eq!(first.get_piece_selector(), second.get_piece_selector());
+            assert_eq!(first.get_winrate(), second.get_winrate());
+        }
+    }
+
+    #[test]
+    fn zero_games_test() {
+        let mut ga = GeneticAlgorithm::default();
+        ga.set_seed(5);
+        ga.set_population_size(4);
+        ga.set_generations(2);
+        ga.set_total_games(0);
+        ga.run_gentic_algorithm().unwrap();
+        assert!(ga
+            .population()
+            .iter()
+            .all(|iplayer| *iplayer.get_winrate() == 0.0));
+    }
+
+    #[test]
+    fn parallel_evaluation_test() {
+        let mut winrates = vec![];
+        for threads in [1, 2, 4] {
+            let mut ga = GeneticAlgorithm::default();
+            ga.set_seed(7);
+            ga.set_threads(threads);
+            ga.set_population_size(8);
+            ga.set_total_games(5);
+            ga.initialize_all_populations();
+            ga.evaluate_fitness_for_all_populations(0).unwrap();
+            assert_eq!(ga.threads(), threads);
+            winrates.push(
+                ga.population()
+                    .iter()
+                    .map(|iplayer| *iplayer.get_winrate())
+                    .collect::<Vec<_>>(),
+            );
+        }
+        assert_eq!(winrates[0], winrates[1]);
+        assert_eq!(winrates[0], winrates[2]);
     }
 }
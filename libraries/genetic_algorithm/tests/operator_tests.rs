@@ -0,0 +1,147 @@
+use genetic_algorithm::{
+    is_permutation, repair_actions, CrossoverType, Experiment, GeneticAlgorithm, MutationType,
+    PERMUTATION_CROSSOVER_TYPES,
+};
+use iplayers::ACTIONS;
+use players::Act;
+use rand::{seq::SliceRandom, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+
+#[cfg(test)]
+mod operator_tests {
+    use super::*;
+
+    fn parents(rng: &mut ChaCha12Rng) -> ([Act; 10], [Act; 10]) {
+        let mut first = ACTIONS;
+        let mut second = ACTIONS;
+        first.shuffle(rng);
+        second.shuffle(rng);
+        (first, second)
+    }
+
+    #[test]
+    fn repair_actions_test() {
+        let mut duplicated = ACTIONS;
+        duplicated[3] = Act::Move;
+        duplicated[7] = Act::Move;
+        assert!(!is_permutation(&duplicated));
+        let repaired = repair_actions(&duplicated);
+        assert!(is_permutation(&repaired));
+        assert_eq!(repaired[0], Act::Move);
+        assert_eq!(repaired[3], ACTIONS[3]);
+        assert_eq!(repaired[7], ACTIONS[7]);
+        assert_eq!(repair_actions(&ACTIONS), ACTIONS);
+    }
+
+    #[test]
+    fn permutation_crossovers_test() {
+        let mut ga = GeneticAlgorithm::default();
+        ga.set_seed(24);
+        let mut rng = ChaCha12Rng::seed_from_u64(24);
+        for _ in 0..200 {
+            let (first, second) = parents(&mut rng);
+            for child in [
+                ga.pmx_crossover(&first, &second),
+                ga.order_crossover(&first, &second),
+                ga.cycle_crossover(&first, &second),
+            ] {
+                assert!(is_permutation(&child), "{:?}", child);
+            }
+        }
+    }
+
+    #[test]
+    fn cycle_crossover_keeps_slots_test() {
+        let mut ga = GeneticAlgorithm::default();
+        let mut rng = ChaCha12Rng::seed_from_u64(5);
+        for _ in 0..50 {
+            let (first, second) = parents(&mut rng);
+            let child = ga.cycle_crossover(&first, &second);
+            for i in 0..10 {
+                assert!(child[i] == first[i] || child[i] == second[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn crossovers_repair_broken_parents_test() {
+        let mut ga = GeneticAlgorithm::default();
+        let broken = [Act::Kill; 10];
+        let mut shuffled = ACTIONS;
+        shuffled.reverse();
+        assert!(is_permutation(&ga.pmx_crossover(&broken, &shuffled)));
+        assert!(is_permutation(&ga.order_crossover(&shuffled, &broken)));
+        assert!(is_permutation(&ga.cycle_crossover(&broken, &broken)));
+    }
+
+    #[test]
+    fn permutation_mutations_test() {
+        let mut ga = GeneticAlgorithm::default();
+        let mut rng = ChaCha12Rng::seed_from_u64(7);
+        for _ in 0..200 {
+            let (actions, _) = parents(&mut rng);
+            let swapped = ga.swap_mutation(actions);
+            assert!(is_permutation(&swapped));
+            assert_eq!((0..10).filter(|&i| swapped[i] != actions[i]).count(), 2);
+            assert!(is_permutation(&ga.insertion_mutation(actions)));
+            let inverted = ga.inversion_mutation(actions);
+            assert!(is_permutation(&inverted));
+            assert_ne!(inverted, actions);
+        }
+    }
+
+    #[test]
+    fn chosen_operators_test() {
+        let mut ga = GeneticAlgorithm::default();
+        ga.set_seed(3);
+        ga.set_crossover_rate(1.0);
+        ga.set_mutation_rate(1.0);
+        ga.set_crossover_types(&PERMUTATION_CROSSOVER_TYPES)
+            .unwrap();
+        ga.set_mutation_types(&[MutationType::Swap, MutationType::Inversion])
+            .unwrap();
+        let mut rng = ChaCha12Rng::seed_from_u64(3);
+        for _ in 0..100 {
+            let (first, second) = parents(&mut rng);
+            let child = ga.try_to_crossover_actions(&first, &second);
+            assert!(is_permutation(&ga.mutate_actions(child)));
+        }
+
+        ga.set_mutation_rate(0.0);
+        assert_eq!(ga.mutate_actions(ACTIONS), ACTIONS);
+
+        assert!(ga.set_crossover_types(&[]).is_err());
+        assert!(ga.set_mutation_types(&[]).is_err());
+        assert_eq!(ga.crossover_types(), PERMUTATION_CROSSOVER_TYPES);
+    }
+
+    #[test]
+    fn operator_names_test() {
+        assert_eq!("pmx".parse(), Ok(CrossoverType::PartiallyMapped));
+        assert_eq!("two-point".parse(), Ok(CrossoverType::TwoPoint));
+        assert_eq!("Inversion".parse(), Ok(MutationType::Inversion));
+        assert!("shuffle".parse::<MutationType>().is_err());
+
+        let experiment = Experiment::from_toml(
+            r#"
+            name = "permutations"
+            crossover = ["pmx", "order", "cx"]
+            mutation = ["swap", "insertion"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(experiment.crossover, PERMUTATION_CROSSOVER_TYPES.to_vec());
+        let ga = experiment.build().unwrap();
+        assert_eq!(
+            ga.mutation_types(),
+            &[MutationType::Swap, MutationType::Insertion]
+        );
+        assert_eq!(Experiment::from(&ga).mutation, experiment.mutation);
+        assert!(Experiment {
+            mutation: Vec::new(),
+            ..experiment
+        }
+        .validate()
+        .is_err());
+    }
+}
@@ -0,0 +1,139 @@
+use genetic_algorithm::{plot_results, GaResults, GeneticAlgorithm, PlotError};
+use iplayers::ACTIONS;
+use players::Act;
+
+#[cfg(test)]
+mod plot_tests {
+    use super::*;
+
+    fn row(fields: &[&str]) -> Vec<String> {
+        fields.iter().map(|field| field.to_string()).collect()
+    }
+
+    fn results() -> GaResults {
+        let reversed = "Nothing, Starjump, Safe, Goal, Die, Leave, Join, Kill, Free, Move";
+        let ordered = "Move, Free, Kill, Join, Leave, Die, Goal, Safe, Starjump, Nothing";
+        let rows = vec![
+            row(&[
+                "tournament",
+                "population 1 winrate",
+                "population 1 timeouts",
+                "population 1 select",
+                "population 1 actions",
+                "population 2 winrate",
+                "population 2 timeouts",
+                "population 2 select",
+                "population 2 actions",
+                "population 3 winrate",
+                "population 3 timeouts",
+                "population 3 select",
+                "population 3 actions",
+                "population 4 winrate",
+                "population 4 timeouts",
+                "population 4 select",
+                "population 4 actions",
+            ]),
+            row(&[
+                "0", "10", "0", "Nearest", ordered, "20", "0", "Nearest", ordered, "30", "1",
+                "Random", ordered, "40", "0", "Furthest", ordered,
+            ]),
+            row(&[
+                "1", "50", "0", "Nearest", reversed, "50", "0", "Random", ordered, "50", "0",
+                "Random", ordered, "50", "0", "Random", ordered,
+            ]),
+        ];
+        let params = vec![("Total Generations".to_string(), "1".to_string())];
+        GaResults::from_rows("unit", params, &rows).unwrap()
+    }
+
+    #[test]
+    fn generation_stats_test() {
+        let results = results();
+        assert_eq!(results.generations.len(), 2);
+        let first = &results.generations[0];
+        assert_eq!(first.generation, 0);
+        assert_eq!(first.best, 40.0);
+        assert_eq!(first.worst, 10.0);
+        assert_eq!(first.mean, 25.0);
+        assert_eq!(first.median, 25.0);
+        assert_eq!(first.upper_quartile, 32.5);
+        assert_eq!(first.lower_quartile, 17.5);
+        assert!((first.std_dev - 12.909944).abs() < 1e-6);
+        assert_eq!(first.selectors, vec![2, 1, 1]);
+
+        let second = &results.generations[1];
+        assert_eq!(second.std_dev, 0.0);
+        assert_eq!(second.selectors, vec![1, 0, 3]);
+    }
+
+    #[test]
+    fn final_actions_test() {
+        let results = results();
+        assert_eq!(results.final_actions.len(), 4);
+        assert_eq!(results.final_actions[0][0], Act::Nothing);
+        assert_eq!(results.final_actions[1], ACTIONS);
+    }
+
+    #[test]
+    fn invalid_rows_test() {
+        let header = row(&["tournament", "population 1 winrate"]);
+        assert!(GaResults::from_rows("empty", Vec::new(), &[]).is_err());
+        assert!(
+            GaResults::from_rows("bad", Vec::new(), &[header.clone(), row(&["0", "x"])]).is_err()
+        );
+        assert!(GaResults::from_rows("ok", Vec::new(), &[header, row(&["0", "5"])]).is_ok());
+    }
+
+    #[test]
+    fn charts_test() {
+        let results = results();
+        let fitness = results.fitness_svg();
+        assert!(fitness.starts_with("<svg"));
+        assert_eq!(fitness.matches("class=\"series\"").count(), 4);
+        assert_eq!(fitness.matches("class=\"std-dev\"").count(), 1);
+        assert_eq!(fitness.matches("class=\"iqr\"").count(), 1);
+        assert!(fitness.contains("Interquartile range"));
+        assert!(fitness.contains("Total Generations = 1"));
+        assert_eq!(
+            results.selector_svg().matches("class=\"selector\"").count(),
+            3
+        );
+        let actions = results.action_svg();
+        assert_eq!(actions.matches("class=\"share\"").count(), 100);
+        assert!(actions.contains(">75%</text>"));
+        assert!(!fitness.contains("NaN") && !actions.contains("NaN"));
+    }
+
+    #[test]
+    fn plot_results_test() {
+        let dir = std::env::temp_dir().join(format!("ludo_plot_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        assert!(matches!(
+            plot_results(&dir, "missing"),
+            Err(PlotError::Csv { .. })
+        ));
+
+        let mut ga = GeneticAlgorithm::default();
+        ga.set_population_size(4);
+        ga.set_generations(2);
+        ga.set_total_games(2);
+        ga.set_seed(22);
+        ga.set_write_to_csv(true);
+        ga.set_csv_name("plotted");
+        ga.set_output_dir(dir.to_str().unwrap());
+        ga.run_gentic_algorithm().unwrap();
+
+        let results = GaResults::load(&dir, "plotted").unwrap();
+        assert_eq!(results.generations.len(), 3);
+        assert_eq!(results.final_actions.len(), 4);
+        let charts = plot_results(&dir, "plotted").unwrap();
+        assert_eq!(charts.len(), 3);
+        for chart in charts {
+            assert!(chart.starts_with(dir.join("plotted").join("images")));
+            assert!(std::fs::read_to_string(chart)
+                .unwrap()
+                .ends_with("</svg>\n"));
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,137 @@
+use genetic_algorithm::{Experiment, GeneticAlgorithm, SelectionStrategy};
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+
+#[cfg(test)]
+mod selection_tests {
+    use super::*;
+
+    const STRATEGIES: [SelectionStrategy; 4] = [
+        SelectionStrategy::Tournament { k: 3 },
+        SelectionStrategy::RouletteWheel,
+        SelectionStrategy::Rank,
+        SelectionStrategy::StochasticUniversalSampling,
+    ];
+
+    fn counts(strategy: SelectionStrategy, fitness: &[f64], draws: usize) -> Vec<usize> {
+        let mut rng = ChaCha12Rng::seed_from_u64(25);
+        let mut counts = vec![0; fitness.len()];
+        for index in strategy.select(fitness, draws, &mut rng) {
+            counts[index] += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn fitter_populations_are_picked_more_test() {
+        let fitness = [10.0, 40.0, 20.0, 30.0];
+        for strategy in STRATEGIES {
+            let counts = counts(strategy, &fitness, 4000);
+            assert_eq!(counts.iter().sum::<usize>(), 4000);
+            assert!(counts[1] > counts[3], "{}: {:?}", strategy, counts);
+            assert!(counts[3] > counts[2], "{}: {:?}", strategy, counts);
+            assert!(counts[2] > counts[0], "{}: {:?}", strategy, counts);
+        }
+    }
+
+    #[test]
+    fn fitness_proportional_test() {
+        let fitness = [0.0, 25.0, 0.0, 75.0];
+        let roulette = counts(SelectionStrategy::RouletteWheel, &fitness, 1000);
+        assert_eq!(roulette[0] + roulette[2], 0);
+        let sus = counts(SelectionStrategy::StochasticUniversalSampling, &fitness, 8);
+        assert_eq!(sus, vec![0, 2, 0, 6]);
+
+        let rank = counts(SelectionStrategy::Rank, &fitness, 1000);
+        assert!(rank[0] + rank[2] > 0);
+        let tournament = counts(SelectionStrategy::Tournament { k: 1 }, &fitness, 1000);
+        assert!(tournament.iter().all(|&count| count > 0));
+    }
+
+    #[test]
+    fn no_winners_test() {
+        let fitness = [0.0; 5];
+        for strategy in STRATEGIES {
+            let counts = counts(strategy, &fitness, 500);
+            assert!(counts.iter().all(|&count| count > 0), "{}", strategy);
+        }
+        let mut rng = ChaCha12Rng::seed_from_u64(1);
+        assert!(SelectionStrategy::Rank.select(&[], 4, &mut rng).is_empty());
+    }
+
+    #[test]
+    fn strategy_names_test() {
+        for strategy in STRATEGIES {
+            assert_eq!(strategy.to_string().parse(), Ok(strategy));
+        }
+        assert_eq!("tournament".parse(), Ok(SelectionStrategy::default()));
+        assert_eq!(
+            "Roulette-Wheel".parse(),
+            Ok(SelectionStrategy::RouletteWheel)
+        );
+        assert!("tournament:0".parse::<SelectionStrategy>().is_err());
+        assert!("tournament:x".parse::<SelectionStrategy>().is_err());
+        assert!("rank:2".parse::<SelectionStrategy>().is_err());
+
+        let experiment = Experiment::from_toml(
+            r#"
+            name = "tournament"
+            selection = { type = "tournament", k = 4 }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(experiment.selection, SelectionStrategy::Tournament { k: 4 });
+        assert_eq!(
+            experiment.build().unwrap().selection(),
+            experiment.selection
+        );
+        let sus = Experiment::from_json(r#"{"name": "sus", "selection": {"type": "sus"}}"#);
+        assert_eq!(
+            sus.unwrap().selection,
+            SelectionStrategy::StochasticUniversalSampling
+        );
+        assert!(Experiment {
+            selection: SelectionStrategy::Tournament { k: 0 },
+            ..experiment
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn elites_survive_selection_test() {
+        for strategy in STRATEGIES {
+            let mut ga = GeneticAlgorithm::default();
+            ga.set_population_size(6);
+            ga.set_elitism_count(2);
+            ga.set_total_games(4);
+            ga.set_seed(25);
+            ga.set_selection(strategy);
+            ga.initialize_all_populations();
+            ga.evaluate_fitness_for_all_populations(0).unwrap();
+            ga.select_best_populations();
+            let elites = ga.population().clone();
+            assert_eq!(elites.len(), 2);
+            assert!(elites[0].get_winrate() >= elites[1].get_winrate());
+            ga.create_children_and_replace_bad_populations();
+            assert_eq!(ga.population().len(), 6);
+            for (elite, population) in elites.iter().zip(ga.population()) {
+                assert_eq!(elite.get_actions(), population.get_actions());
+                assert_eq!(elite.get_winrate(), population.get_winrate());
+            }
+        }
+    }
+
+    #[test]
+    fn no_elites_test() {
+        let mut ga = GeneticAlgorithm::default();
+        ga.set_population_size(4);
+        ga.set_elitism_count(0);
+        ga.set_generations(2);
+        ga.set_total_games(2);
+        ga.set_seed(2);
+        ga.set_selection(SelectionStrategy::Rank);
+        ga.run_gentic_algorithm().unwrap();
+        assert_eq!(ga.population().len(), 4);
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::io::BufRead;
+
+pub trait MoveInput: Debug + Send + Sync {
+    fn read_line(&mut self) -> Option<String>;
+    fn box_clone(&self) -> Box<dyn MoveInput>;
+}
+
+impl Clone for Box<dyn MoveInput> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
+}
+
+impl<I: MoveInput + 'static> From<I> for Box<dyn MoveInput> {
+    fn from(input: I) -> Self {
+        Box::new(input)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StdinInput;
+
+impl MoveInput for StdinInput {
+    fn read_line(&mut self) -> Option<String> {
+        let mut line = String::new();
+        match std::io::stdin().lock().read_line(&mut line) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(line),
+        }
+    }
+
+    fn box_clone(&self) -> Box<dyn MoveInput> {
+        Box::new(*self)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ScriptedInput {
+    lines: VecDeque<String>,
+}
+
+impl ScriptedInput {
+    pub fn new(lines: &[&str]) -> Self {
+        ScriptedInput {
+            lines: lines.iter().map(|line| line.to_string()).collect(),
+        }
+    }
+}
+
+impl MoveInput for ScriptedInput {
+    fn read_line(&mut self) -> Option<String> {
+        self.lines.pop_front()
+    }
+
+    fn box_clone(&self) -> Box<dyn MoveInput> {
+        Box::new(self.clone())
+    }
+}
@@ -1,9 +1,13 @@
+mod human;
+
 mod iplayers {
-    use board::Board;
-    use dice::Dice;
-    use players::{Act, Player, Select};
-    use std::cell::RefCell;
-    use std::rc::Rc;
+    use crate::human::MoveInput;
+    use board::{Board, RenderOptions};
+    use dice::DiceSource;
+    use errors::LudoError;
+    use players::{Act, Move, Player, RuleSet, Select};
+    use serde::{Deserialize, Serialize};
+    use std::str::FromStr;
 
     pub static AGGRO_ACTIONS: [Act; 10] = [
         Act::Kill,
@@ -72,17 +76,36 @@ mod iplayers {
 
     pub static SELECTIONS: [Select; 3] = [Select::Nearest, Select::Furthest, Select::Random];
 
-    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
     pub enum Playstyle {
         Aggressive,
         Fast,
         Random,
         Safe,
+        #[serde(alias = "fast-aggressive")]
         FastAggressive,
+        #[serde(alias = "genetic")]
         GeneticAlgorithm,
+        Human,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct Ply {
+        pub player_id: i8,
+        pub rolls: Vec<i8>,
+        pub act: Act,
+        pub piece_id: i8,
+        pub positions: Vec<[i8; 4]>,
+    }
+
+    impl Ply {
+        pub fn dice_number(&self) -> i8 {
+            self.rolls.last().copied().unwrap_or(0)
+        }
     }
 
-    #[derive(Clone, Debug, PartialEq)]
+    #[derive(Clone, Debug)]
     pub struct IPlayer {
         player: Player,
         playstyle: Option<Playstyle>,
@@ -91,71 +114,90 @@ mod iplayers {
         dice_number: i8,
         wins: u16,
         winrate: f64,
+        placements: Vec<u16>,
+        average_rank: f64,
+        timeouts: u16,
         first_round: bool,
+        recording: bool,
+        rolls: Vec<i8>,
+        plies: Vec<Ply>,
+        input: Option<Box<dyn MoveInput>>,
     }
 
     pub trait Behavior {
-        fn play(&mut self, is_in_debug_mode: bool);
-        fn take_dice(&mut self, dice: Dice);
-        fn roll_dice(&mut self);
-        fn give_dice(&mut self, iplayer: &mut IPlayer);
-        fn hit_first_round(&mut self);
+        fn play(&mut self, board: &mut Board, is_in_debug_mode: bool) -> Result<(), LudoError>;
+        fn take_dice(&mut self, dice: impl Into<Box<dyn DiceSource>>);
+        fn roll_dice(&mut self) -> Result<(), LudoError>;
+        fn give_dice(&mut self, iplayer: &mut IPlayer) -> Result<(), LudoError>;
+        fn hit_first_round(&mut self) -> Result<(), LudoError>;
         fn my_turn(&mut self);
         fn is_my_turn(&self) -> bool;
         fn win(&mut self);
     }
 
     impl Behavior for IPlayer {
-        fn play(&mut self, shall_debug: bool) {
+        fn play(&mut self, board: &mut Board, shall_debug: bool) -> Result<(), LudoError> {
+            let mut sixes_in_a_row = 0;
             while self.is_my_turn() {
-                if self.first_round {
-                    self.hit_first_round();
+                if self.first_round && self.player.rules().first_round_attempts > 0 {
+                    self.hit_first_round()?;
                 } else {
-                    self.roll_dice();
+                    self.roll_dice()?;
+                }
+                sixes_in_a_row = match self.dice_number {
+                    6 => sixes_in_a_row + 1,
+                    _ => 0,
+                };
+                if sixes_in_a_row == 3 && self.player.rules().triple_six_forfeits_turn {
+                    self.player.action = (Act::Nothing, self.player.id(), 57);
+                    self.take_action(board, shall_debug)?;
+                    continue;
                 }
                 match self.playstyle {
-                    Some(Playstyle::Aggressive) => self.aggro(),
-                    Some(Playstyle::Fast) => self.fast(),
-                    Some(Playstyle::Random) => self.random(),
-                    Some(Playstyle::Safe) => self.safe(),
-                    Some(Playstyle::FastAggressive) => self.fast_aggro(),
-                    Some(Playstyle::GeneticAlgorithm) => self.genetic(),
+                    Some(Playstyle::Aggressive) => self.aggro(board)?,
+                    Some(Playstyle::Fast) => self.fast(board)?,
+                    Some(Playstyle::Random) => self.random(board)?,
+                    Some(Playstyle::Safe) => self.safe(board)?,
+                    Some(Playstyle::FastAggressive) => self.fast_aggro(board)?,
+                    Some(Playstyle::GeneticAlgorithm) => self.genetic(board)?,
+                    Some(Playstyle::Human) => self.human(board)?,
                     None => panic!("Player has no playstyle. Please give it with some playstyle"),
                 }
-                self.take_action(shall_debug);
+                self.take_action(board, shall_debug)?;
             }
+            Ok(())
         }
 
-        fn hit_first_round(&mut self) {
+        fn hit_first_round(&mut self) -> Result<(), LudoError> {
             let mut roll_count = 0;
-            while roll_count < 3 {
-                self.roll_dice();
+            while roll_count < self.player.rules().first_round_attempts {
+                self.roll_dice()?;
                 if self.dice_number == 6 {
                     self.first_round = false;
                 }
                 roll_count += 1;
             }
+            Ok(())
         }
 
-        fn roll_dice(&mut self) {
-            self.player.roll_dice();
+        fn roll_dice(&mut self) -> Result<(), LudoError> {
+            self.player.roll_dice()?;
             self.dice_number = self.player.get_dice_number();
-            if self.dice_number == 0 {
-                panic!("This player doesn't have the dice!");
+            if self.recording {
+                self.rolls.push(self.dice_number);
             }
+            Ok(())
         }
 
-        fn take_dice(&mut self, dice: Dice) {
+        fn take_dice(&mut self, dice: impl Into<Box<dyn DiceSource>>) {
             self.player.get_dice(dice);
         }
 
-        fn give_dice(&mut self, iplayer: &mut IPlayer) {
-            if let Some(dice) = self.player.dice() {
-                iplayer.take_dice(dice.clone());
-                self.player.drop_dice();
-            } else {
-                panic!("This player doesn't have the dice!");
-            }
+        fn give_dice(&mut self, iplayer: &mut IPlayer) -> Result<(), LudoError> {
+            let dice = self.player.dice().clone().ok_or(LudoError::NoDice)?;
+            iplayer.take_dice(dice);
+            self.player.drop_dice();
+            Ok(())
         }
 
         fn my_turn(&mut self) {
@@ -180,8 +222,15 @@ mod iplayers {
                 select_which_piece: Select::Random,
                 wins: 0,
                 winrate: 0.0,
+                placements: vec![0; 4],
+                average_rank: 0.0,
+                timeouts: 0,
                 dice_number: 0,
                 first_round: true,
+                recording: false,
+                rolls: Vec::new(),
+                plies: Vec::new(),
+                input: None,
             }
         }
 
@@ -193,8 +242,15 @@ mod iplayers {
                 select_which_piece: Select::Random,
                 wins: 0,
                 winrate: 0.0,
+                placements: vec![0; 4],
+                average_rank: 0.0,
+                timeouts: 0,
                 dice_number: 0,
                 first_round: true,
+                recording: false,
+                rolls: Vec::new(),
+                plies: Vec::new(),
+                input: None,
             }
         }
 
@@ -204,6 +260,9 @@ mod iplayers {
             self.select_which_piece = iplayer.select_which_piece;
             self.wins = iplayer.wins;
             self.winrate = iplayer.winrate;
+            self.placements = iplayer.placements.clone();
+            self.average_rank = iplayer.average_rank;
+            self.timeouts = iplayer.timeouts;
             self.dice_number = iplayer.dice_number;
             self.first_round = iplayer.first_round;
         }
@@ -223,8 +282,16 @@ mod iplayers {
             self.playstyle = Some(playstyle);
         }
 
-        pub fn setup_board(&mut self, board: Rc<RefCell<Board>>) {
-            self.player.setup(board);
+        pub fn set_seed(&mut self, seed: u64) {
+            self.player.set_seed(seed);
+        }
+
+        pub fn set_input(&mut self, input: impl Into<Box<dyn MoveInput>>) {
+            self.input = Some(input.into());
+        }
+
+        pub fn set_rules(&mut self, rules: RuleSet) {
+            self.player.set_rules(rules);
         }
 
         pub fn player(&self) -> &Player {
@@ -251,8 +318,16 @@ mod iplayers {
             &self.select_which_piece
         }
 
+        pub fn set_fitness(&mut self, winrate: f64, timeouts: u16) {
+            self.winrate = winrate;
+            self.timeouts = timeouts;
+        }
+
         pub fn calculate_winrate(&mut self, total_games: u16) {
-            self.winrate = self.wins as f64 / total_games as f64 * 100.0;
+            self.winrate = match total_games {
+                0 => 0.0,
+                _ => self.wins as f64 / total_games as f64 * 100.0,
+            };
         }
 
         pub fn get_winrate(&self) -> &f64 {
@@ -263,9 +338,50 @@ mod iplayers {
             println!("Winrate: {}%", self.winrate);
         }
 
+        pub fn place(&mut self, rank: usize) {
+            if self.placements.len() < rank {
+                self.placements.resize(rank, 0);
+            }
+            self.placements[rank - 1] += 1;
+        }
+
+        pub fn get_placements(&self) -> &[u16] {
+            &self.placements
+        }
+
+        pub fn calculate_average_rank(&mut self) {
+            let placed_games: u16 = self.placements.iter().sum();
+            if placed_games == 0 {
+                self.average_rank = 0.0;
+                return;
+            }
+            let rank_sum: u32 = self
+                .placements
+                .iter()
+                .enumerate()
+                .map(|(idx, &count)| (idx as u32 + 1) * count as u32)
+                .sum();
+            self.average_rank = rank_sum as f64 / placed_games as f64;
+        }
+
+        pub fn get_average_rank(&self) -> &f64 {
+            &self.average_rank
+        }
+
+        pub fn time_out(&mut self) {
+            self.timeouts += 1;
+        }
+
+        pub fn get_timeouts(&self) -> u16 {
+            self.timeouts
+        }
+
         pub fn reset_scores(&mut self) {
             self.wins = 0;
             self.winrate = 0.0;
+            self.placements = vec![0; 4];
+            self.average_rank = 0.0;
+            self.timeouts = 0;
         }
 
         pub fn reset_first_round(&mut self) {
@@ -276,69 +392,168 @@ mod iplayers {
             self.dice_number
         }
 
-        fn genetic(&mut self) {
-            self.choose_ordered_action(self.select_which_piece);
+        pub fn set_recording(&mut self, recording: bool) {
+            self.recording = recording;
+            self.rolls.clear();
+            self.plies.clear();
         }
 
-        fn safe(&mut self) {
-            self.choose_ordered_action(Select::Furthest);
+        pub fn is_recording(&self) -> bool {
+            self.recording
         }
 
-        fn fast(&mut self) {
-            self.choose_ordered_action(Select::Nearest);
+        pub fn take_plies(&mut self) -> Vec<Ply> {
+            std::mem::take(&mut self.plies)
+        }
+
+        fn record_ply(&mut self, board: &Board) {
+            if !self.recording {
+                return;
+            }
+            let (act, piece_id, _) = self.player.action;
+            let positions = board.piece_positions();
+            self.plies.push(Ply {
+                player_id: self.player.id(),
+                rolls: std::mem::take(&mut self.rolls),
+                act,
+                piece_id,
+                positions,
+            });
         }
 
-        fn aggro(&mut self) {
-            self.choose_ordered_action(Select::Random);
+        fn genetic(&mut self, board: &Board) -> Result<(), LudoError> {
+            self.choose_ordered_action(board, self.select_which_piece)
         }
 
-        fn fast_aggro(&mut self) {
-            self.choose_ordered_action(Select::Nearest);
+        fn safe(&mut self, board: &Board) -> Result<(), LudoError> {
+            self.choose_ordered_action(board, Select::Furthest)
         }
 
-        fn choose_ordered_action(&mut self, select: Select) {
-            self.player.action =
-                self.player
-                    .get_ordered_action(*self.get_actions(), self.dice_number, select);
+        fn fast(&mut self, board: &Board) -> Result<(), LudoError> {
+            self.choose_ordered_action(board, Select::Nearest)
         }
 
-        fn random(&mut self) {
-            self.choose_random_action();
+        fn aggro(&mut self, board: &Board) -> Result<(), LudoError> {
+            self.choose_ordered_action(board, Select::Random)
         }
 
-        fn choose_random_action(&mut self) {
-            let movesets = self
-                .player
-                .generate_vector_of_random_actions(*self.get_actions(), self.dice_number);
+        fn fast_aggro(&mut self, board: &Board) -> Result<(), LudoError> {
+            self.choose_ordered_action(board, Select::Nearest)
+        }
+
+        fn choose_ordered_action(
+            &mut self,
+            board: &Board,
+            select: Select,
+        ) -> Result<(), LudoError> {
+            self.player.action = self.player.get_ordered_action(
+                board,
+                *self.get_actions(),
+                self.dice_number,
+                select,
+            )?;
+            Ok(())
+        }
+
+        fn human(&mut self, board: &Board) -> Result<(), LudoError> {
+            let id = self.player.id();
+            let moves = self.player.legal_moves(board, self.dice_number)?;
+            println!("{}", board.render(RenderOptions::default()));
+            println!("Player {} rolled {}", id, self.dice_number);
+            if moves.is_empty() {
+                println!("No legal moves");
+                self.player.action = (Act::Nothing, id, 57);
+                return Ok(());
+            }
+            for (idx, option) in moves.iter().enumerate() {
+                println!("{}) {}", idx + 1, describe_move(option));
+            }
+            let choice = match moves.len() {
+                1 => 0,
+                _ => self.read_choice(moves.len())?,
+            };
+            let chosen = &moves[choice];
+            println!("Player {} plays {}", id, describe_move(chosen));
+            self.player.action = (chosen.act, chosen.piece_id, 57);
+            Ok(())
+        }
+
+        fn read_choice(&mut self, options: usize) -> Result<usize, LudoError> {
+            let input = self.input.as_mut().ok_or(LudoError::IllegalAction(
+                "a human player needs an input to read moves from".to_string(),
+            ))?;
+            loop {
+                print!("Choose a move [1-{}]: ", options);
+                let _ = std::io::Write::flush(&mut std::io::stdout());
+                let line = input.read_line().ok_or(LudoError::InputClosed)?;
+                match line.trim().parse::<usize>() {
+                    Ok(choice) if (1..=options).contains(&choice) => return Ok(choice - 1),
+                    _ => println!("'{}' is not a move between 1 and {}", line.trim(), options),
+                }
+            }
+        }
+
+        fn random(&mut self, board: &Board) -> Result<(), LudoError> {
+            self.choose_random_action(board)
+        }
+
+        fn choose_random_action(&mut self, board: &Board) -> Result<(), LudoError> {
+            let movesets = self.player.generate_vector_of_random_actions(
+                board,
+                *self.get_actions(),
+                self.dice_number,
+            )?;
             self.player.action = self.player.select_random_piece(movesets);
+            Ok(())
         }
 
-        fn take_action(&mut self, debug: bool) {
+        fn take_action(&mut self, board: &mut Board, debug: bool) -> Result<(), LudoError> {
             if debug {
-                self.log_moves();
+                self.log_moves(board)?;
             } else {
                 self.player.make_move(
+                    board,
                     self.player.action.1,
                     self.dice_number,
                     self.player().action.0,
-                );
+                )?;
             }
+            self.record_ply(board);
+            Ok(())
         }
 
-        fn log_moves(&mut self) {
+        fn log_moves(&mut self, board: &mut Board) -> Result<(), LudoError> {
             println!("\n\n------------------------");
             println!("Prior play\n");
-            self.player.print_status();
+            self.player.print_status(board);
+            println!("{}", board.render(RenderOptions::default()));
             self.player.make_move(
+                board,
                 self.player.action.1,
                 self.dice_number,
                 self.player().action.0,
-            );
+            )?;
             println!("Posterior play\n");
-            self.player.print_status();
+            self.player.print_status(board);
+            println!("{}", board.render(RenderOptions::default()));
+            Ok(())
         }
     }
 
+    fn describe_move(option: &Move) -> String {
+        let mut text = format!(
+            "{} piece {} from {} to {}",
+            option.act, option.piece_id, option.from, option.to
+        );
+        for (player_id, piece_id) in option.captured.iter() {
+            text.push_str(&format!(
+                ", capturing piece {} of player {}",
+                piece_id, player_id
+            ));
+        }
+        text
+    }
+
     fn get_action_from_playstyle(playstyle: Playstyle) -> Option<[Act; 10]> {
         match playstyle {
             Playstyle::Aggressive => Some(AGGRO_ACTIONS),
@@ -347,8 +562,27 @@ mod iplayers {
             Playstyle::Safe => Some(SAFE_ACTIONS),
             Playstyle::FastAggressive => Some(FAST_AGGRO_ACTIONS),
             Playstyle::GeneticAlgorithm => None,
+            Playstyle::Human => None,
+        }
+    }
+
+    impl FromStr for Playstyle {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s.to_lowercase().as_str() {
+                "aggressive" => Ok(Playstyle::Aggressive),
+                "fast" => Ok(Playstyle::Fast),
+                "random" => Ok(Playstyle::Random),
+                "safe" => Ok(Playstyle::Safe),
+                "fastaggressive" | "fast-aggressive" => Ok(Playstyle::FastAggressive),
+                "genetic" | "geneticalgorithm" => Ok(Playstyle::GeneticAlgorithm),
+                "human" => Ok(Playstyle::Human),
+                _ => Err(format!("Unknown playstyle: {}", s)),
+            }
         }
     }
 }
 
-pub use iplayers::{Behavior, IPlayer, Playstyle, ACTIONS, SELECTIONS};
+pub use human::{MoveInput, ScriptedInput, StdinInput};
+pub use iplayers::{Behavior, IPlayer, Playstyle, Ply, ACTIONS, SELECTIONS};
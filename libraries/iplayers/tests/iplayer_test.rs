@@ -1,9 +1,9 @@
 use board::Board;
-use dice::Dice;
-use iplayers::{Behavior, IPlayer, Playstyle};
-use players::{Act, Player};
-use std::cell::RefCell;
-use std::rc::Rc;
+use dice::{Dice, DiceError, ScriptedDice};
+use errors::LudoError;
+use iplayers::{Behavior, IPlayer, Playstyle, ScriptedInput};
+use players::{Act, Player, RuleSet};
+use std::str::FromStr;
 
 #[cfg(test)]
 mod iplayer_tests {
@@ -91,46 +91,28 @@ mod iplayer_tests {
     #[test]
     fn play_test() {
         let mut iplayer = IPlayer::new(0);
-        let mut simple_player = Player::new(0);
-        let board = Rc::new(RefCell::new(Board::new()));
-        iplayer.setup_board(board.clone());
+        let simple_player = Player::new(0);
+        let mut board = Board::new();
         iplayer.set_playstyle(Playstyle::Random);
         iplayer.take_dice(Dice::default());
-        iplayer.play(true);
-
-        simple_player.setup(board);
+        iplayer.play(&mut board, true).unwrap();
 
         assert_eq!(iplayer.get_actions().to_vec(), ACTIONS.to_vec());
-        assert_eq!(
-            simple_player.board().as_ptr(),
-            iplayer.player().board().as_ptr()
-        );
-        assert_eq!(
-            simple_player.piece(0).as_ptr(),
-            iplayer.player().piece(0).as_ptr()
-        );
-        assert_eq!(
-            simple_player.piece(1).as_ptr(),
-            iplayer.player().piece(1).as_ptr()
-        );
-        assert_eq!(
-            simple_player.piece(2).as_ptr(),
-            iplayer.player().piece(2).as_ptr()
-        );
-        assert_eq!(
-            simple_player.piece(3).as_ptr(),
-            iplayer.player().piece(3).as_ptr()
-        );
+        for piece_id in 0..4 {
+            assert!(std::ptr::eq(
+                simple_player.piece(&board, piece_id),
+                iplayer.player().piece(&board, piece_id)
+            ));
+        }
     }
 
     #[test]
     fn aggro_player_test() {
         let mut player = IPlayer::create(0, Playstyle::Aggressive);
         let dice = Dice::default();
-        let board = Rc::new(RefCell::new(Board::new()));
-        player.setup_board(board);
+        let mut board = Board::new();
         player.take_dice(dice);
-        player.play(true);
+        player.play(&mut board, true).unwrap();
         assert_eq!(player.get_actions().to_owned(), AGGRO_ACTIONS.to_owned());
     }
 
@@ -138,10 +120,9 @@ mod iplayer_tests {
     fn fast_aggro_player_test() {
         let mut player = IPlayer::create(0, Playstyle::FastAggressive);
         let dice = Dice::default();
-        let board = Rc::new(RefCell::new(Board::new()));
-        player.setup_board(board);
+        let mut board = Board::new();
         player.take_dice(dice);
-        player.play(true);
+        player.play(&mut board, true).unwrap();
         assert_eq!(
             player.get_actions().to_owned(),
             FAST_AGGRO_ACTIONS.to_owned()
@@ -152,10 +133,9 @@ mod iplayer_tests {
     fn fast_player_test() {
         let mut player = IPlayer::create(0, Playstyle::Fast);
         let dice = Dice::default();
-        let board = Rc::new(RefCell::new(Board::new()));
-        player.setup_board(board);
+        let mut board = Board::new();
         player.take_dice(dice);
-        player.play(true);
+        player.play(&mut board, true).unwrap();
         assert_eq!(player.get_actions().to_owned(), FAST_ACTIONS.to_owned());
     }
 
@@ -163,10 +143,135 @@ mod iplayer_tests {
     fn safe_player_test() {
         let mut player = IPlayer::create(0, Playstyle::Safe);
         let dice = Dice::default();
-        let board = Rc::new(RefCell::new(Board::new()));
-        player.setup_board(board);
+        let mut board = Board::new();
         player.take_dice(dice);
-        player.play(true);
+        player.play(&mut board, true).unwrap();
         assert_eq!(player.get_actions().to_owned(), SAFE_ACTIONS.to_owned());
     }
+
+    fn scripted_player(rolls: Vec<i8>, rules: RuleSet) -> (IPlayer, Board) {
+        let board = Board::from_positions([[20, -1, -1, -1], [-1; 4], [-1; 4], [-1; 4]]).unwrap();
+        let mut player = IPlayer::create(0, Playstyle::Fast);
+        player.set_rules(rules);
+        player.take_dice(ScriptedDice::new(rolls));
+        player.my_turn();
+        (player, board)
+    }
+
+    #[test]
+    fn first_round_attempts_test() {
+        let rules = RuleSet {
+            first_round_attempts: 1,
+            ..RuleSet::standard()
+        };
+        let (mut player, mut board) = scripted_player(vec![2], rules);
+        player.play(&mut board, false).unwrap();
+        assert!(!player.is_my_turn());
+
+        let (mut player, mut board) = scripted_player(vec![2], RuleSet::standard());
+        assert_eq!(
+            player.play(&mut board, false),
+            Err(LudoError::Dice(DiceError::Exhausted))
+        );
+    }
+
+    #[test]
+    fn triple_six_forfeits_turn_test() {
+        let rules = RuleSet {
+            first_round_attempts: 0,
+            ..RuleSet::triple_six_forfeits()
+        };
+        let (mut player, mut board) = scripted_player(vec![6, 6, 6], rules);
+        player.set_recording(true);
+        player.play(&mut board, false).unwrap();
+        assert!(!player.is_my_turn());
+        let plies = player.take_plies();
+        assert_eq!(plies.len(), 3);
+        assert_eq!(plies[2].act, Act::Nothing);
+
+        let rules = RuleSet {
+            first_round_attempts: 0,
+            ..RuleSet::standard()
+        };
+        let (mut player, mut board) = scripted_player(vec![6, 6, 6], rules);
+        assert_eq!(
+            player.play(&mut board, false),
+            Err(LudoError::Dice(DiceError::Exhausted))
+        );
+    }
+}
+
+#[cfg(test)]
+mod human_player_tests {
+    use super::*;
+
+    fn human_player(roll: i8, lines: &[&str]) -> (IPlayer, Board) {
+        let board = Board::from_positions([[20, 30, -1, -1], [-1; 4], [-1; 4], [-1; 4]]).unwrap();
+        let mut player = IPlayer::create(0, Playstyle::Human);
+        player.set_rules(RuleSet {
+            first_round_attempts: 0,
+            ..RuleSet::standard()
+        });
+        player.take_dice(ScriptedDice::new(vec![roll]));
+        player.set_input(ScriptedInput::new(lines));
+        player.my_turn();
+        (player, board)
+    }
+
+    #[test]
+    fn human_choice_test() {
+        let (mut player, mut board) = human_player(3, &["2"]);
+        player.play(&mut board, false).unwrap();
+        assert_eq!(player.player().piece(&board, 0).position(), 20);
+        assert_eq!(player.player().piece(&board, 1).position(), 33);
+        assert_eq!(player.player().action.0, Act::Move);
+    }
+
+    #[test]
+    fn human_retries_invalid_input_test() {
+        let (mut player, mut board) = human_player(3, &["x", "7", "0", " 1 "]);
+        player.play(&mut board, false).unwrap();
+        assert_eq!(player.player().piece(&board, 0).position(), 23);
+        assert_eq!(player.player().piece(&board, 1).position(), 30);
+    }
+
+    #[test]
+    fn human_single_option_test() {
+        let (mut player, _) = human_player(3, &[]);
+        let mut board =
+            Board::from_positions([[20, -1, -1, -1], [-1; 4], [-1; 4], [-1; 4]]).unwrap();
+        player.play(&mut board, false).unwrap();
+        assert_eq!(player.player().piece(&board, 0).position(), 23);
+    }
+
+    #[test]
+    fn human_input_errors_test() {
+        let (mut player, mut board) = human_player(3, &[]);
+        assert_eq!(player.play(&mut board, false), Err(LudoError::InputClosed));
+
+        let mut player = IPlayer::create(0, Playstyle::Human);
+        let mut board =
+            Board::from_positions([[20, 30, -1, -1], [-1; 4], [-1; 4], [-1; 4]]).unwrap();
+        player.set_rules(RuleSet {
+            first_round_attempts: 0,
+            ..RuleSet::standard()
+        });
+        player.take_dice(ScriptedDice::new(vec![3]));
+        player.my_turn();
+        assert!(matches!(
+            player.play(&mut board, false),
+            Err(LudoError::IllegalAction(_))
+        ));
+    }
+
+    #[test]
+    fn playstyle_from_str_test() {
+        assert_eq!(Playstyle::from_str("human"), Ok(Playstyle::Human));
+        assert_eq!(Playstyle::from_str("Aggressive"), Ok(Playstyle::Aggressive));
+        assert_eq!(
+            Playstyle::from_str("fast-aggressive"),
+            Ok(Playstyle::FastAggressive)
+        );
+        assert!(Playstyle::from_str("sneaky").is_err());
+    }
 }
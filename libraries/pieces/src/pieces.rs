@@ -1,4 +1,5 @@
 mod pieces {
+    use errors::LudoError;
 
     #[derive(PartialEq, Debug, Clone)]
     pub enum State {
@@ -13,6 +14,8 @@ mod pieces {
         Yellow,
         Blue,
         Red,
+        Orange,
+        Purple,
     }
 
     #[derive(PartialEq, Debug, Clone)]
@@ -21,15 +24,23 @@ mod pieces {
         color: Color,
         state: State,
         position: i8,
+        last_position: i8,
+        goal_position: i8,
     }
 
     impl Piece {
         pub fn new(id: i8, color: Color) -> Piece {
+            Self::with_bounds(id, color, 71, 99)
+        }
+
+        pub fn with_bounds(id: i8, color: Color, last_position: i8, goal_position: i8) -> Piece {
             Piece {
                 id,
                 color,
                 position: -1,
                 state: State::Home,
+                last_position,
+                goal_position,
             }
         }
 
@@ -57,9 +68,12 @@ mod pieces {
             self.position
         }
 
-        pub fn set_position(&mut self, position: i8) {
-            assert!((-1..=71).contains(&position) && position != 99);
+        pub fn set_position(&mut self, position: i8) -> Result<(), LudoError> {
+            if !(-1..=self.last_position).contains(&position) {
+                return Err(LudoError::InvalidPosition(position));
+            }
             self.position = position;
+            Ok(())
         }
 
         pub fn dead(&mut self) {
@@ -75,19 +89,14 @@ mod pieces {
         }
 
         pub fn goal(&mut self) {
-            self.position = 99;
+            self.position = self.goal_position;
             self.state = State::Goal;
         }
     }
 
     impl Default for Piece {
         fn default() -> Piece {
-            Piece {
-                id: 0,
-                color: Color::Red,
-                position: -1,
-                state: State::Home,
-            }
+            Piece::new(0, Color::Red)
         }
     }
 }
@@ -19,7 +19,7 @@ mod pieces_test {
 
     #[test]
     fn creating_all_piece_all_color_test() {
-        let colors = vec![Color::Red, Color::Blue, Color::Yellow, Color::Green];
+        let colors = [Color::Red, Color::Blue, Color::Yellow, Color::Green];
         for color in colors.iter() {
             for i in 0..4 {
                 let piece = Piece::new(i, *color);
@@ -36,7 +36,7 @@ mod pieces_test {
     #[test]
     fn move_piece_test() {
         let mut piece = Piece::new(1, Color::Green);
-        piece.set_position(3);
+        piece.set_position(3).unwrap();
         assert_eq!(piece.id(), 1);
         assert_eq!(piece.position(), 3);
     }
@@ -46,7 +46,7 @@ mod pieces_test {
         let mut piece = Piece::new(1, Color::Green);
         assert_eq!(piece.id(), 1);
         piece.free();
-        piece.set_position(0);
+        piece.set_position(0).unwrap();
         assert_eq!(piece.position(), 0);
         assert!(!piece.is_home());
         assert!(!piece.is_goal());
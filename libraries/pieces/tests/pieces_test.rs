@@ -1,3 +1,4 @@
+use errors::LudoError;
 use pieces::{Color, Piece};
 
 #[cfg(test)]
@@ -16,7 +17,7 @@ mod pieces_test {
     #[test]
     fn move_piece_test() {
         let mut piece = Piece::new(1, Color::Green);
-        piece.set_position(3);
+        piece.set_position(3).unwrap();
         assert_eq!(piece.id(), 1);
         assert_eq!(piece.position(), 3);
     }
@@ -60,36 +61,35 @@ mod pieces_test {
     }
 
     #[test]
-    #[should_panic]
     fn invalid_position_test() {
         let mut piece = Piece::new(1, Color::Green);
         assert_eq!(piece.id(), 1);
-        piece.set_position(100);
+        assert_eq!(
+            piece.set_position(100),
+            Err(LudoError::InvalidPosition(100))
+        );
     }
 
     #[test]
-    #[should_panic]
     fn invalid_position_test_2() {
         let mut piece = Piece::new(1, Color::Green);
         assert_eq!(piece.id(), 1);
-        piece.set_position(-2);
+        assert_eq!(piece.set_position(-2), Err(LudoError::InvalidPosition(-2)));
     }
 
     #[test]
-    #[should_panic]
     fn invalid_position_test_3() {
         let mut piece = Piece::new(1, Color::Green);
         assert_eq!(piece.id(), 1);
-        piece.set_position(72);
+        assert_eq!(piece.set_position(72), Err(LudoError::InvalidPosition(72)));
     }
 
     #[test]
-    #[should_panic]
     fn invalid_position_test_4() {
         let mut piece = Piece::new(1, Color::Green);
         assert_eq!(piece.id(), 1);
         for i in 72..=98 {
-            piece.set_position(i);
+            assert_eq!(piece.set_position(i), Err(LudoError::InvalidPosition(i)));
         }
     }
 }
@@ -0,0 +1,455 @@
+use crate::players::{Act, Move};
+use crate::rules::{Overshoot, RuleSet};
+use board::BoardGeometry;
+
+static LEGAL_ACTS: [Act; 9] = [
+    Act::Free,
+    Act::Move,
+    Act::Join,
+    Act::Kill,
+    Act::Die,
+    Act::Goal,
+    Act::Leave,
+    Act::Safe,
+    Act::Starjump,
+];
+
+#[derive(Clone, Copy, Default)]
+struct Square {
+    owner: Option<i8>,
+    pieces: u8,
+}
+
+/// Works out the legal moves of one player from the piece positions alone, so
+/// neither a `Board` nor a scratch copy of it is needed. The checks mirror
+/// `Player::valid_choices` and the outcomes mirror `Player::make_move`.
+pub struct MoveGenerator<'a> {
+    geometry: &'a BoardGeometry,
+    rules: &'a RuleSet,
+    positions: &'a [[i8; 4]],
+    squares: Vec<Square>,
+    id: i8,
+}
+
+impl<'a> MoveGenerator<'a> {
+    pub fn new(
+        geometry: &'a BoardGeometry,
+        rules: &'a RuleSet,
+        positions: &'a [[i8; 4]],
+        player_id: i8,
+    ) -> Self {
+        let mut squares = vec![Square::default(); geometry.track_length.max(0) as usize];
+        for (other_id, pieces) in positions.iter().enumerate() {
+            for &position in pieces
+                .iter()
+                .filter(|&&position| geometry.is_track(position))
+            {
+                let square = &mut squares[position as usize];
+                square.owner = Some(other_id as i8);
+                square.pieces += 1;
+            }
+        }
+        MoveGenerator {
+            geometry,
+            rules,
+            positions,
+            squares,
+            id: player_id,
+        }
+    }
+
+    /// One move per piece and destination. Candidates the board would reject
+    /// are skipped.
+    pub fn legal_moves(&self, roll: i8) -> Vec<Move> {
+        let mut moves: Vec<Move> = Vec::new();
+        for piece_id in 0..4 {
+            for act in LEGAL_ACTS {
+                if !self.is_valid(piece_id, roll, act) {
+                    continue;
+                }
+                let Some((to, captured)) = self.resolve(piece_id, roll, act) else {
+                    continue;
+                };
+                if moves
+                    .iter()
+                    .any(|other| other.piece_id == piece_id && other.to == to)
+                {
+                    continue;
+                }
+                moves.push(Move {
+                    piece_id,
+                    from: self.from(piece_id),
+                    to,
+                    act,
+                    captured,
+                });
+            }
+        }
+        moves
+    }
+
+    fn from(&self, piece_id: i8) -> i8 {
+        self.positions[self.id as usize][piece_id as usize]
+    }
+
+    fn occupied(&self, position: i8) -> (bool, bool) {
+        match self.geometry.is_track(position) {
+            true => {
+                let pieces = self.squares[position as usize].pieces;
+                (pieces > 0, pieces > 1)
+            }
+            false => (false, false),
+        }
+    }
+
+    fn is_owner(&self, position: i8) -> bool {
+        self.squares[position as usize].owner == Some(self.id)
+    }
+
+    fn occupied_by_self(&self, position: i8) -> (bool, bool) {
+        let (occupied, more) = self.occupied(position);
+        match occupied && self.is_owner(position) {
+            true => (occupied, more),
+            false => (false, false),
+        }
+    }
+
+    fn occupied_by_others(&self, position: i8) -> (bool, bool) {
+        let (occupied, more) = self.occupied(position);
+        match occupied && !self.is_owner(position) {
+            true => (occupied, more && self.rules.blockades),
+            false => (false, false),
+        }
+    }
+
+    /// The owner of whatever stands on the track square under `position`, the
+    /// way `Board::square` folds positions onto the track.
+    fn owner_at(&self, position: i8) -> i8 {
+        let square = position.rem_euclid(self.geometry.track_length) as usize;
+        self.squares[square].owner.unwrap_or_default()
+    }
+
+    fn has_own_piece(&self, position: i8) -> bool {
+        self.geometry.is_track(position) && self.positions[self.id as usize].contains(&position)
+    }
+
+    fn others_at(&self, position: i8) -> Vec<(i8, i8)> {
+        (0..self.positions.len() as i8)
+            .filter(|&other_id| other_id != self.id)
+            .flat_map(|other_id| (0..4).map(move |piece_id| (other_id, piece_id)))
+            .filter(|&(other_id, piece_id)| {
+                self.positions[other_id as usize][piece_id as usize] == position
+            })
+            .collect()
+    }
+
+    fn is_star(&self, position: i8) -> bool {
+        self.rules.star_jumping && self.geometry.is_star(position)
+    }
+
+    fn is_globe(&self, position: i8) -> bool {
+        self.rules.globe_safety && self.geometry.is_globe(position)
+    }
+
+    fn advance(&self, from: i8, roll: i8) -> Option<i8> {
+        if from == -1 {
+            return self.geometry.start_square(self.id);
+        }
+        let to = from + roll;
+        match self.geometry.wraps_around(self.id)
+            && self.geometry.is_track(from)
+            && to >= self.geometry.track_length
+        {
+            true => Some(to - self.geometry.track_length),
+            false => Some(to),
+        }
+    }
+
+    fn is_entering(&self, from: i8, to: i8, reach: i8) -> bool {
+        match self.geometry.entry_square(self.id) {
+            Some(entry) => {
+                (entry - 5..=entry).contains(&from) && (entry + 1..=entry + reach).contains(&to)
+            }
+            None => false,
+        }
+    }
+
+    fn corrected(&self, from: i8, to: i8) -> i8 {
+        match self.is_entering(from, to, 5) {
+            true => {
+                let entry = self.geometry.entry_square(self.id).unwrap_or_default();
+                to + self.geometry.home_column_start(self.id) - entry - 1
+            }
+            false => to,
+        }
+    }
+
+    fn in_column(&self, position: i8) -> bool {
+        let column = self.geometry.home_column_start(self.id);
+        (column..self.geometry.goal_square(self.id)).contains(&position)
+    }
+
+    fn is_inside(&self, from: i8, to: i8) -> bool {
+        let column = self.geometry.home_column_start(self.id);
+        let goal = self.geometry.goal_square(self.id);
+        self.id < self.geometry.players
+            && self.in_column(from)
+            && (column..=goal + self.geometry.home_column_length).contains(&to)
+    }
+
+    fn overshoot(&self, from: i8, to: i8) -> Option<i8> {
+        let goal = self.geometry.goal_square(self.id);
+        match self.id < self.geometry.players
+            && self.in_column(from)
+            && (goal + 1..=goal + self.geometry.home_column_length).contains(&to)
+        {
+            true => Some(goal),
+            false => None,
+        }
+    }
+
+    fn reaches_goal(&self, from: i8, to: i8) -> bool {
+        let geometry = self.geometry;
+        let Some(entry) = geometry.entry_square(self.id) else {
+            return to == geometry.goal_position();
+        };
+        let goal = geometry.goal_square(self.id);
+        to == geometry.goal_position()
+            || (from == entry && to == entry + geometry.home_column_length + 1)
+            || (self.in_column(from) && to == goal)
+            || (self.rules.overshoot == Overshoot::Enter
+                && self.in_column(from)
+                && (goal..=goal + geometry.home_column_length).contains(&to))
+            || (self.rules.star_jumping && (entry - 6..entry).contains(&from) && to == entry)
+            || from == goal
+    }
+
+    fn star_position(&self, from: i8, to: i8) -> i8 {
+        let steps = (to - from).rem_euclid(self.geometry.track_length);
+        let is_own_entry = self.geometry.entry_square(self.id) == Some(to);
+        match self.is_star(to) && !is_own_entry && (1..=6).contains(&steps) {
+            true => self.geometry.next_star(to),
+            false => to,
+        }
+    }
+
+    fn star_occupied(&self, from: i8, to: i8) -> (bool, bool) {
+        match self.is_star(to) {
+            true => self.occupied(self.star_position(from, to)),
+            false => (false, false),
+        }
+    }
+
+    fn star_occupied_by_others(&self, from: i8, to: i8) -> (bool, bool) {
+        match self.is_star(to) {
+            true => self.occupied_by_others(self.star_position(from, to)),
+            false => (false, false),
+        }
+    }
+
+    fn is_valid(&self, piece_id: i8, roll: i8, act: Act) -> bool {
+        let from = self.from(piece_id);
+        let is_home = from == -1;
+        let is_goal = from == self.geometry.goal_position();
+        if !matches!(
+            (is_goal, is_home, roll),
+            (_, true, 6) | (false, false, 1..=6)
+        ) {
+            return false;
+        }
+        if self.rules.overshoot == Overshoot::Exact && self.overshoot(from, from + roll).is_some() {
+            return false;
+        }
+        let Some(to) = self.advance(from, roll) else {
+            return false;
+        };
+        match act {
+            Act::Free => is_home && !self.occupied_by_others(to).0 && roll == 6,
+            Act::Move => {
+                let to = self.corrected(from, to);
+                let is_outside = to < self.geometry.track_length;
+                !(is_home
+                    || self.reaches_goal(from, to)
+                    || self.is_star(to)
+                    || ((self.occupied_by_self(from).1 || self.occupied(to).0) && is_outside)
+                    || self.is_globe(to))
+            }
+            Act::Join => {
+                let star_self = match self.is_star(to) {
+                    true => self.occupied_by_self(self.star_position(from, to)).0,
+                    false => false,
+                };
+                !is_home
+                    && !self.reaches_goal(from, to)
+                    && ((self.occupied_by_self(to).0 && !self.is_star(to))
+                        || (star_self && !self.occupied_by_others(to).0))
+            }
+            Act::Kill => {
+                let Some(invincible) = self.geometry.start_square(self.owner_at(to)) else {
+                    return false;
+                };
+                let others = self.occupied_by_others(to);
+                let star = self.star_occupied_by_others(from, to);
+                let plain = !is_home
+                    && to != invincible
+                    && !self.is_globe(to)
+                    && !others.1
+                    && !self.is_entering(from, to, 5);
+                (plain && (others.0 || (star.0 && !star.1))) || (is_home && others.0 && roll == 6)
+            }
+            Act::Die => {
+                let Some(invincible) = self.geometry.start_square(self.owner_at(to)) else {
+                    return false;
+                };
+                let others = self.occupied_by_others(to);
+                let other_invincible = self.occupied_by_others(invincible).0 && to == invincible;
+                !is_home
+                    && (others.1
+                        || self.star_occupied_by_others(from, to).1
+                        || other_invincible
+                        || (others.0 && self.is_globe(to)))
+            }
+            Act::Goal => !self.occupied_by_others(to).1 && self.reaches_goal(from, to),
+            Act::Leave => {
+                self.occupied_by_self(from).1
+                    && !self.is_star(to)
+                    && !self.is_globe(to)
+                    && !self.occupied(to).0
+            }
+            Act::Safe => {
+                let is_globe = self.is_globe(to) && !self.occupied_by_others(to).0;
+                let can_enter = self.is_entering(from, to, 5);
+                let is_inside = self.is_inside(from, to);
+                let is_goal = self.reaches_goal(from, self.corrected(from, to));
+                (is_globe && (!is_inside || (can_enter && !is_goal))) || (can_enter && !is_inside)
+            }
+            Act::Starjump => {
+                self.is_star(to)
+                    && !is_home
+                    && !self.occupied_by_others(to).0
+                    && !self.star_occupied(from, to).0
+            }
+            Act::Nothing => false,
+        }
+    }
+
+    /// Where the piece ends up and which pieces of other players are sent
+    /// home, or `None` when the board would refuse the move.
+    fn resolve(&self, piece_id: i8, roll: i8, act: Act) -> Option<(i8, Vec<(i8, i8)>)> {
+        let from = self.from(piece_id);
+        let to = self.advance(from, roll)?;
+        match act {
+            Act::Free => Some((to, Vec::new())),
+            Act::Move | Act::Leave => Some((self.step(from, to)?, Vec::new())),
+            Act::Join => Some((
+                self.track_step(from, self.star_position(from, to))?,
+                Vec::new(),
+            )),
+            Act::Safe => match self.is_entering(from, to, 6) {
+                true => Some((
+                    self.column_step(from, self.corrected(from, to))?,
+                    Vec::new(),
+                )),
+                false if self.is_globe(to) => Some((self.track_step(from, to)?, Vec::new())),
+                false => None,
+            },
+            Act::Starjump => match self.reaches_goal(from, to) {
+                true => Some((self.goal_step(from)?, Vec::new())),
+                false => Some((
+                    self.track_step(from, self.star_position(from, to))?,
+                    Vec::new(),
+                )),
+            },
+            Act::Goal => {
+                let captured = match self.occupied_by_others(to).0 {
+                    true => self.others_at(to),
+                    false => Vec::new(),
+                };
+                match self.reaches_goal(from, to) {
+                    true => Some((self.goal_step(from)?, captured)),
+                    false => None,
+                }
+            }
+            Act::Kill => self.kill(from, to),
+            Act::Die => {
+                let invincible = self.geometry.start_square(self.owner_at(to))?;
+                let captured = match self.occupied_by_others(to).0 && invincible != to {
+                    true => self.others_at(to),
+                    false => Vec::new(),
+                };
+                self.geometry.is_track(from).then_some((-1, captured))
+            }
+            Act::Nothing => None,
+        }
+    }
+
+    fn kill(&self, from: i8, to: i8) -> Option<(i8, Vec<(i8, i8)>)> {
+        // The capture clears the whole square, so it is refused while one of
+        // the player's own pieces stands there.
+        if self.has_own_piece(to) {
+            return None;
+        }
+        let mut captured = self.others_at(to);
+        if from == -1 {
+            return Some((to, captured));
+        }
+        // Everything on the target square has gone home before the star is
+        // looked at.
+        let star = self.star_position(from, to);
+        let star_occupied = match self.is_star(to) && star != to {
+            true => self.occupied_by_others(star),
+            false => (false, false),
+        };
+        let (landing, position) = match star_occupied {
+            (true, false) if self.has_own_piece(star) => return None,
+            (true, false) => {
+                captured.extend(self.others_at(star));
+                captured.sort();
+                (star, star)
+            }
+            (_, true) => (-1, to),
+            _ => (star, to),
+        };
+        let to = match landing {
+            -1 if self.geometry.is_track(from) => -1,
+            -1 => return None,
+            landing => self.track_step(from, landing)?,
+        };
+        match self.reaches_goal(from, position) {
+            true if to == position => Some((self.geometry.goal_position(), captured)),
+            true => None,
+            false => Some((to, captured)),
+        }
+    }
+
+    fn step(&self, from: i8, to: i8) -> Option<i8> {
+        match (self.is_inside(from, to), self.is_entering(from, to, 5)) {
+            (true, false) => match (self.overshoot(from, to), self.rules.overshoot) {
+                (None, _) => self.inside_step(from, to),
+                (Some(goal), Overshoot::Bounce) => self.inside_step(from, goal - (to - goal)),
+                (Some(_), Overshoot::Enter) => self.goal_step(from),
+                (Some(_), Overshoot::Exact) => None,
+            },
+            (false, true) => self.column_step(from, self.corrected(from, to)),
+            (false, false) => self.track_step(from, to),
+            (true, true) => None,
+        }
+    }
+
+    fn track_step(&self, from: i8, to: i8) -> Option<i8> {
+        (self.geometry.is_track(from) && self.geometry.is_track(to)).then_some(to)
+    }
+
+    fn column_step(&self, from: i8, to: i8) -> Option<i8> {
+        (self.geometry.is_track(from) && self.geometry.is_inside(to)).then_some(to)
+    }
+
+    fn inside_step(&self, from: i8, to: i8) -> Option<i8> {
+        (self.geometry.is_inside(from) && self.geometry.is_inside(to)).then_some(to)
+    }
+
+    fn goal_step(&self, from: i8) -> Option<i8> {
+        (self.geometry.is_track(from) || self.geometry.is_inside(from))
+            .then_some(self.geometry.goal_position())
+    }
+}
@@ -1,24 +1,32 @@
+mod movegen;
+mod rules;
+
 mod players {
-    use board::Board;
-    use dice::Dice;
+    use crate::movegen::MoveGenerator;
+    use crate::rules::{Overshoot, RuleSet};
+    use board::{Board, CompactMove, CompactState, PieceRef, PieceSlot, PlayerID};
+    use dice::DiceSource;
+    use errors::LudoError;
     use pieces::Piece;
     use prettytable::{row, Table};
     use rand::prelude::SliceRandom;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use serde::{Deserialize, Serialize};
     use std::fmt::{Display, Formatter, Result};
+    use std::str::FromStr;
 
-    use std::{cell::RefCell, rc::Rc};
-
-    #[derive(PartialEq, Debug, Clone)]
+    #[derive(Debug, Clone)]
     pub struct Player {
         id: i8,
         color: Color,
         turn: bool,
-        dice: Option<Dice>,
-        board: Rc<RefCell<Board>>,
-        pieces: Vec<Rc<RefCell<Piece>>>,
+        dice: Option<Box<dyn DiceSource>>,
         pub action: (Act, i8, i8),
         pub old_position: i8,
         pub new_position: i8,
+        rng: Option<StdRng>,
+        rules: RuleSet,
     }
 
     #[derive(PartialEq, Debug, Copy, Clone)]
@@ -27,16 +35,18 @@ mod players {
         Yellow,
         Blue,
         Red,
+        Orange,
+        Purple,
     }
 
-    #[derive(PartialEq, Debug, Copy, Clone)]
+    #[derive(PartialEq, Debug, Copy, Clone, Serialize, Deserialize)]
     pub enum Select {
         Nearest,
         Furthest,
         Random,
     }
 
-    #[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+    #[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Serialize, Deserialize)]
     pub enum Act {
         Move,
         Free,
@@ -50,6 +60,77 @@ mod players {
         Nothing,
     }
 
+    #[derive(PartialEq, Eq, Debug, Clone)]
+    pub struct Move {
+        pub piece_id: i8,
+        pub from: i8,
+        pub to: i8,
+        pub act: Act,
+        pub captured: Vec<(i8, i8)>,
+    }
+
+    impl Move {
+        pub fn to_compact(&self, roll: i8, rules: &RuleSet) -> CompactMove {
+            let six = rules.six_grants_extra_turn && roll == 6;
+            let extra_turn = match self.act {
+                Act::Goal => true,
+                Act::Free => rules.six_grants_extra_turn,
+                Act::Kill => rules.capture_grants_extra_roll || six,
+                _ => six,
+            };
+            CompactMove::new(self.piece_id, self.to, &self.captured, extra_turn)
+        }
+    }
+
+    #[derive(PartialEq, Debug, Clone)]
+    pub struct MoveUndo {
+        pub played: Move,
+        pub dice_number: i8,
+        pub star_jump: bool,
+        pub bounced: bool,
+        slots: Vec<PieceSlot>,
+        turn: bool,
+        action: (Act, i8, i8),
+        old_position: i8,
+        new_position: i8,
+    }
+
+    pub fn legal_moves(
+        board: &Board,
+        player: PlayerID,
+        roll: i8,
+    ) -> std::result::Result<Vec<Move>, LudoError> {
+        legal_moves_with_rules(board, player, roll, &RuleSet::default())
+    }
+
+    pub fn legal_moves_with_rules(
+        board: &Board,
+        player: PlayerID,
+        roll: i8,
+        rules: &RuleSet,
+    ) -> std::result::Result<Vec<Move>, LudoError> {
+        let positions = board.piece_positions();
+        let generator = MoveGenerator::new(board.geometry(), rules, &positions, player.id());
+        Ok(generator.legal_moves(roll))
+    }
+
+    pub fn compact_legal_moves(
+        state: &CompactState,
+        rules: &RuleSet,
+    ) -> std::result::Result<Vec<CompactMove>, LudoError> {
+        let turn = state.turn as i8;
+        if !state.is_seated(turn) {
+            return Err(LudoError::InvalidPlayer(turn));
+        }
+        let (geometry, positions) = (state.geometry(), state.positions());
+        let roll = state.roll as i8;
+        Ok(MoveGenerator::new(&geometry, rules, &positions, turn)
+            .legal_moves(roll)
+            .iter()
+            .map(|legal_move| legal_move.to_compact(roll, rules))
+            .collect())
+    }
+
     impl Player {
         pub fn new(player_id: i8) -> Player {
             let id = player_id;
@@ -58,393 +139,648 @@ mod players {
                 id,
                 color,
                 turn: false,
-                board: Rc::default(),
                 dice: None,
-                pieces: Vec::default(),
                 action: (Act::Nothing, player_id, 57),
                 old_position: -1,
                 new_position: -1,
+                rng: None,
+                rules: RuleSet::default(),
             }
         }
 
-        pub fn setup(&mut self, board: Rc<RefCell<Board>>) {
-            self.board = board;
-            self.init_pieces();
+        pub fn id(&self) -> i8 {
+            self.id
         }
 
-        pub fn init_pieces(&mut self) {
-            self.pieces = self.board.borrow_mut().home(self.id()).pieces.clone();
+        pub fn color(&self) -> Color {
+            self.color
         }
 
-        pub fn id(&self) -> i8 {
-            self.id
+        pub fn set_seed(&mut self, seed: u64) {
+            self.rng = Some(StdRng::seed_from_u64(seed));
         }
 
-        pub fn piece(&self, piece_id: i8) -> Rc<RefCell<Piece>> {
+        pub fn piece<'a>(&self, board: &'a Board, piece_id: i8) -> &'a Piece {
             if piece_id > 3 {
                 panic!("The highest piece id cannot be higher than 3, else you have more than 4 pieces!");
             }
-            self.pieces[piece_id as usize].clone()
+            board.piece(self.id, piece_id)
+        }
+
+        pub fn set_rules(&mut self, rules: RuleSet) {
+            self.rules = rules;
         }
 
-        pub fn board(&self) -> &Rc<RefCell<Board>> {
-            &self.board
+        pub fn rules(&self) -> &RuleSet {
+            &self.rules
         }
 
-        pub fn get_dice(&mut self, dice: Dice) {
-            self.dice = Some(dice);
+        fn is_star(&self, board: &Board, position: i8) -> bool {
+            self.rules.star_jumping && board.geometry().is_star(position)
+        }
+
+        fn is_globe(&self, board: &Board, position: i8) -> bool {
+            self.rules.globe_safety && board.geometry().is_globe(position)
+        }
+
+        fn is_entering_inside(
+            &self,
+            board: &Board,
+            old_position: i8,
+            new_position: i8,
+            reach: i8,
+        ) -> bool {
+            match board.geometry().entry_square(self.id) {
+                Some(entry) => {
+                    (entry - 5..=entry).contains(&old_position)
+                        && (entry + 1..=entry + reach).contains(&new_position)
+                }
+                None => false,
+            }
+        }
+
+        pub fn legal_moves(
+            &self,
+            board: &Board,
+            roll: i8,
+        ) -> std::result::Result<Vec<Move>, LudoError> {
+            let player = board
+                .get_player_id(self.id)
+                .ok_or(LudoError::InvalidPlayer(self.id))?;
+            legal_moves_with_rules(board, player, roll, &self.rules)
+        }
+
+        pub fn get_dice(&mut self, dice: impl Into<Box<dyn DiceSource>>) {
+            self.dice = Some(dice.into());
         }
 
         pub fn drop_dice(&mut self) {
             self.dice = None;
         }
 
-        pub fn dice(&self) -> &Option<Dice> {
+        pub fn dice(&self) -> &Option<Box<dyn DiceSource>> {
             &self.dice
         }
 
-        pub fn make_move(&mut self, piece_id: i8, dice_number: i8, choice: Act) {
+        pub fn make_move(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            dice_number: i8,
+            choice: Act,
+        ) -> std::result::Result<(), LudoError> {
             match choice {
                 Act::Move => {
-                    self.move_piece(piece_id, dice_number);
+                    self.move_piece(board, piece_id, dice_number)?;
                     self.can_continue();
                 }
                 Act::Safe => {
-                    self.save_piece(piece_id, dice_number);
+                    self.save_piece(board, piece_id, dice_number)?;
                     self.can_continue();
                 }
                 Act::Starjump => {
-                    self.starjump_piece(piece_id, dice_number);
+                    self.starjump_piece(board, piece_id, dice_number)?;
                     self.can_continue();
                 }
                 Act::Goal => {
-                    self.win_piece(piece_id, dice_number);
+                    self.win_piece(board, piece_id, dice_number)?;
                     self.my_turn();
                 }
                 Act::Free => {
-                    self.free_piece(piece_id);
-                    self.my_turn();
+                    self.free_piece(board, piece_id)?;
+                    self.end_move(self.rules.six_grants_extra_turn);
                 }
                 Act::Kill => {
-                    self.kill_piece(piece_id, dice_number);
-                    self.my_turn();
+                    self.kill_piece(board, piece_id, dice_number)?;
+                    self.end_move(self.rules.capture_grants_extra_roll);
                 }
                 Act::Join => {
-                    self.join_piece(piece_id, dice_number);
+                    self.join_piece(board, piece_id, dice_number)?;
                     self.can_continue();
                 }
                 Act::Leave => {
-                    self.leave_piece(piece_id, dice_number);
+                    self.leave_piece(board, piece_id, dice_number)?;
                     self.can_continue();
                 }
                 Act::Die => {
-                    self.die_piece(piece_id, dice_number);
+                    self.die_piece(board, piece_id, dice_number)?;
                     self.can_continue();
                 }
                 Act::Nothing => {
                     self.turn = false;
                 }
             }
+            #[cfg(feature = "debug-invariants")]
+            if let Err(violations) = board.validate() {
+                panic!(
+                    "{:?} of piece {} broke the board: {:?}",
+                    choice, piece_id, violations
+                );
+            }
+            Ok(())
         }
 
-        pub fn win_piece(&mut self, piece_id: i8, dice_number: i8) {
-            self.update_position(piece_id, dice_number);
-            if self.is_occupied_by_others(self.new_position).0 {
-                self.send_other_piece_home(self.new_position);
+        pub fn apply_move(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            dice_number: i8,
+            choice: Act,
+        ) -> std::result::Result<MoveUndo, LudoError> {
+            let before = board.slots();
+            let (turn, action) = (self.turn, self.action);
+            let (old_position, new_position) = (self.old_position, self.new_position);
+            if let Err(error) = self.make_move(board, piece_id, dice_number, choice) {
+                board.restore(&before);
+                self.turn = turn;
+                self.old_position = old_position;
+                self.new_position = new_position;
+                return Err(error);
             }
-            match self.goal_positions(self.old_position, self.new_position) {
-                99 => self.enter_goal(piece_id, self.old_position),
-                _ => panic!("New position is not a goal!"),
-            };
+            let slots: Vec<PieceSlot> = before
+                .into_iter()
+                .filter(|slot| board.slot(slot.piece) != *slot)
+                .collect();
+            let moved = PieceRef::new(self.id, piece_id);
+            let from = slots
+                .iter()
+                .find(|slot| slot.piece == moved)
+                .map_or(board.piece_at(moved).position(), |slot| {
+                    slot.state.position()
+                });
+            let to = board.piece_at(moved).position();
+            let captured = slots
+                .iter()
+                .filter(|slot| slot.piece.player_id != self.id)
+                .filter(|slot| board.piece_at(slot.piece).is_home())
+                .map(|slot| (slot.piece.player_id, slot.piece.piece_id))
+                .collect();
+            let geometry = board.geometry();
+            let target = self.circumvent_player_0(board, from, from + dice_number);
+            let star_jump = geometry.is_track(from) && geometry.is_track(to) && to != target;
+            let bounced = geometry.is_inside(from) && geometry.is_inside(to) && to != target;
+            Ok(MoveUndo {
+                played: Move {
+                    piece_id,
+                    from,
+                    to,
+                    act: choice,
+                    captured,
+                },
+                dice_number,
+                star_jump,
+                bounced,
+                slots,
+                turn,
+                action,
+                old_position,
+                new_position,
+            })
         }
 
-        pub fn send_other_piece_home(&mut self, new_position: i8) {
-            let pieces = self
-                .board()
-                .borrow_mut()
-                .outside(new_position)
-                .pieces
-                .clone();
+        pub fn unmake_move(&mut self, board: &mut Board, undo: &MoveUndo) {
+            board.restore(&undo.slots);
+            self.turn = undo.turn;
+            self.action = undo.action;
+            self.old_position = undo.old_position;
+            self.new_position = undo.new_position;
+        }
+
+        pub fn redo_move(
+            &mut self,
+            board: &mut Board,
+            undo: &MoveUndo,
+        ) -> std::result::Result<MoveUndo, LudoError> {
+            let played = &undo.played;
+            self.apply_move(board, played.piece_id, undo.dice_number, played.act)
+        }
+
+        pub fn win_piece(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            dice_number: i8,
+        ) -> std::result::Result<(), LudoError> {
+            self.update_position(board, piece_id, dice_number)?;
+            if self.is_occupied_by_others(board, self.new_position).0 {
+                self.send_other_piece_home(board, self.new_position)?;
+            }
+            match self.is_goal_position(board) {
+                true => self.enter_goal(board, piece_id, self.old_position),
+                false => Err(LudoError::IllegalAction(format!(
+                    "piece {} cannot reach the goal from {}",
+                    piece_id, self.old_position
+                ))),
+            }
+        }
+
+        pub fn send_other_piece_home(
+            &mut self,
+            board: &mut Board,
+            new_position: i8,
+        ) -> std::result::Result<(), LudoError> {
+            let pieces = board.outside(new_position).pieces.clone();
             for piece in pieces {
-                let position = piece.borrow().position();
-                piece.borrow_mut().dead();
-                let (other_player_id, other_piece_id) = get_piece_and_id_from_other_players(piece);
-                self.board()
-                    .borrow_mut()
-                    .move_into_home(other_player_id, other_piece_id, position);
+                let (other_player_id, other_piece_id) = (piece.player_id, piece.piece_id);
+                let position = board.piece(other_player_id, other_piece_id).position();
+                board.piece_mut(other_player_id, other_piece_id).dead();
+                board.move_into_home(other_player_id, other_piece_id, position)?;
             }
+            Ok(())
         }
 
-        pub fn kill_piece(&mut self, piece_id: i8, dice_number: i8) {
-            if self.piece(piece_id).borrow().is_home() {
-                let invincible_position = self.invincible_positions(self.id());
-                self.send_other_piece_home(invincible_position);
-                self.free_piece(piece_id);
+        pub fn kill_piece(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            dice_number: i8,
+        ) -> std::result::Result<(), LudoError> {
+            if self.piece(board, piece_id).is_home() {
+                let invincible_position = self.invincible_positions(board, self.id())?;
+                self.send_other_piece_home(board, invincible_position)?;
+                self.free_piece(board, piece_id)?;
             } else {
-                self.update_position(piece_id, dice_number);
-                self.kill(piece_id, self.old_position, self.new_position);
-                if self.is_goal_position() {
-                    self.enter_goal(piece_id, self.new_position);
+                self.update_position(board, piece_id, dice_number)?;
+                self.kill(board, piece_id, self.old_position, self.new_position)?;
+                if self.is_goal_position(board) {
+                    self.enter_goal(board, piece_id, self.new_position)?;
                 }
             }
+            Ok(())
         }
 
-        pub fn kill(&mut self, piece_id: i8, old_position: i8, new_position: i8) {
-            self.send_other_piece_home(new_position);
-            let occupied_starspaces = self.is_star_occupied_by_others(old_position, new_position);
+        pub fn kill(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            old_position: i8,
+            new_position: i8,
+        ) -> std::result::Result<(), LudoError> {
+            self.send_other_piece_home(board, new_position)?;
+            let occupied_starspaces =
+                self.is_star_occupied_by_others(board, old_position, new_position);
             match occupied_starspaces {
                 (true, false) => {
-                    let star_position = self.star_position(old_position, new_position);
-                    self.send_other_piece_home(star_position);
-                    self.starjump(piece_id, old_position, new_position);
-                }
-                (_, true) => {
-                    self.die(piece_id);
-                }
-                _ => {
-                    self.join(piece_id, old_position, new_position);
+                    let star_position = self.star_position(board, old_position, new_position);
+                    self.send_other_piece_home(board, star_position)?;
+                    self.starjump(board, piece_id, old_position, new_position)
                 }
+                (_, true) => self.die(board, piece_id),
+                _ => self.join(board, piece_id, old_position, new_position),
             }
         }
 
-        pub fn leave_piece(&mut self, piece_id: i8, dice_number: i8) {
-            self.update_position(piece_id, dice_number);
-            self.leave(piece_id, self.old_position, self.new_position);
+        pub fn leave_piece(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            dice_number: i8,
+        ) -> std::result::Result<(), LudoError> {
+            self.update_position(board, piece_id, dice_number)?;
+            self.leave(board, piece_id, self.old_position, self.new_position)
         }
 
-        pub fn leave(&mut self, piece_id: i8, old_position: i8, new_position: i8) {
-            self.update_piece(piece_id, old_position, new_position);
+        pub fn leave(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            old_position: i8,
+            new_position: i8,
+        ) -> std::result::Result<(), LudoError> {
+            self.update_piece(board, piece_id, old_position, new_position)
         }
 
-        pub fn join_piece(&mut self, piece_id: i8, dice_number: i8) {
-            self.update_position(piece_id, dice_number);
-            self.join(piece_id, self.old_position, self.new_position);
+        pub fn join_piece(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            dice_number: i8,
+        ) -> std::result::Result<(), LudoError> {
+            self.update_position(board, piece_id, dice_number)?;
+            self.join(board, piece_id, self.old_position, self.new_position)
         }
 
-        pub fn join(&mut self, piece_id: i8, old_position: i8, new_position: i8) {
-            let new_position = self.star_position(old_position, new_position);
-            self.update_outside(piece_id, old_position, new_position);
+        pub fn join(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            old_position: i8,
+            new_position: i8,
+        ) -> std::result::Result<(), LudoError> {
+            let new_position = self.star_position(board, old_position, new_position);
+            self.update_outside(board, piece_id, old_position, new_position)
         }
 
-        pub fn move_piece(&mut self, piece_id: i8, dice_number: i8) {
-            self.update_position(piece_id, dice_number);
-            self.update_piece(piece_id, self.old_position, self.new_position);
+        pub fn move_piece(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            dice_number: i8,
+        ) -> std::result::Result<(), LudoError> {
+            self.update_position(board, piece_id, dice_number)?;
+            self.update_piece(board, piece_id, self.old_position, self.new_position)
         }
 
-        pub fn save_piece(&mut self, piece_id: i8, dice_number: i8) {
-            self.update_position(piece_id, dice_number);
-            match (self.id, self.old_position, self.new_position) {
-                (0, 45..=50, 51..=56)
-                | (1, 6..=11, 12..=17)
-                | (2, 19..=24, 25..=30)
-                | (3, 32..=37, 38..=43) => {
-                    self.correct_position();
-                    self.enter_inside(piece_id, self.old_position, self.new_position);
+        pub fn save_piece(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            dice_number: i8,
+        ) -> std::result::Result<(), LudoError> {
+            self.update_position(board, piece_id, dice_number)?;
+            match self.is_entering_inside(board, self.old_position, self.new_position, 6) {
+                true => {
+                    self.correct_position(board);
+                    self.enter_inside(board, piece_id, self.old_position, self.new_position)
                 }
-                _ => {
-                    if !self.board().borrow_mut().is_globe(self.new_position) {
-                        panic!("New position is not a Globe!");
+                false => {
+                    if !self.is_globe(board, self.new_position) {
+                        return Err(LudoError::IllegalAction(format!(
+                            "position {} is not a globe",
+                            self.new_position
+                        )));
                     };
-                    self.enter_globe(piece_id, self.old_position, self.new_position);
+                    self.enter_globe(board, piece_id, self.old_position, self.new_position)
                 }
-            };
+            }
         }
 
-        pub fn update_position(&mut self, piece_id: i8, dice_number: i8) {
-            self.old_position = self.piece(piece_id).borrow_mut().position();
-            if self.piece(piece_id).borrow().is_home() {
-                self.new_position = self.invincible_positions(self.id());
+        pub fn update_position(
+            &mut self,
+            board: &Board,
+            piece_id: i8,
+            dice_number: i8,
+        ) -> std::result::Result<(), LudoError> {
+            self.old_position = self.piece(board, piece_id).position();
+            if self.piece(board, piece_id).is_home() {
+                self.new_position = self.invincible_positions(board, self.id())?;
             } else {
                 self.new_position = self.old_position + dice_number;
-                self.new_position = self.circumvent_player_0(self.old_position, self.new_position);
+                self.new_position =
+                    self.circumvent_player_0(board, self.old_position, self.new_position);
             }
+            Ok(())
         }
 
-        pub fn update_piece(&mut self, piece_id: i8, old_position: i8, new_position: i8) {
-            let is_inside = self.is_inside(old_position, new_position);
-            let can_enter_inside = self.can_enter_inside(old_position, new_position);
+        pub fn update_piece(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            old_position: i8,
+            new_position: i8,
+        ) -> std::result::Result<(), LudoError> {
+            let is_inside = self.is_inside(board, old_position, new_position);
+            let can_enter_inside = self.can_enter_inside(board, old_position, new_position);
             match (is_inside, can_enter_inside) {
-                (true, false) => self.update_inside(piece_id, old_position, new_position),
+                (true, false) => self.update_inside(board, piece_id, old_position, new_position),
                 (false, true) => {
-                    self.correct_position();
-                    self.enter_inside(piece_id, self.old_position, self.new_position);
+                    self.correct_position(board);
+                    self.enter_inside(board, piece_id, self.old_position, self.new_position)
                 }
-                (false, false) => self.update_outside(piece_id, old_position, new_position),
-                _ => panic!("Invalid position given to this!"),
-            };
-        }
-
-        pub fn is_inside(&mut self, old_position: i8, new_position: i8) -> bool {
-            matches!(
-                (self.id, old_position, new_position),
-                (0, 52..=56, 52..=62)
-                    | (1, 57..=61, 57..=67)
-                    | (2, 62..=66, 62..=72)
-                    | (3, 67..=71, 67..=77)
-            )
+                (false, false) => self.update_outside(board, piece_id, old_position, new_position),
+                _ => Err(LudoError::InvalidPosition(new_position)),
+            }
         }
 
-        pub fn can_enter_inside(&mut self, old_position: i8, new_position: i8) -> bool {
-            matches!(
-                (self.id, old_position, new_position),
-                (0, 45..=50, 51..=56)
-                    | (1, 6..=11, 12..=16)
-                    | (2, 19..=24, 25..=29)
-                    | (3, 32..=37, 38..=42)
-            )
+        pub fn is_inside(&mut self, board: &Board, old_position: i8, new_position: i8) -> bool {
+            let geometry = board.geometry();
+            if self.id >= geometry.players {
+                return false;
+            }
+            let column = geometry.home_column_start(self.id);
+            let goal = geometry.goal_square(self.id);
+            (column..goal).contains(&old_position)
+                && (column..=goal + geometry.home_column_length).contains(&new_position)
         }
 
-        pub fn update_inside(&mut self, piece_id: i8, old_position: i8, new_position: i8) {
-            let new_position = self.move_back_if_overshoot(old_position, new_position);
-            self.piece(piece_id).borrow_mut().set_position(new_position);
-            self.board().borrow_mut().update_inside(
-                self.id(),
-                piece_id,
-                old_position,
-                new_position,
-            );
+        pub fn can_enter_inside(
+            &mut self,
+            board: &Board,
+            old_position: i8,
+            new_position: i8,
+        ) -> bool {
+            self.is_entering_inside(board, old_position, new_position, 5)
         }
 
-        pub fn star_position(&mut self, old_position: i8, new_position: i8) -> i8 {
-            match (old_position, new_position) {
-                (51, 5) | (0..=4, 5) => 11,
-                (5..=10, 11) if self.id() != 1 => 18,
-                (12..=17, 18) => 24,
-                (18..=23, 24) if self.id() != 2 => 31,
-                (25..=30, 31) => 37,
-                (31..=36, 37) if self.id() != 3 => 44,
-                (38..=43, 44) => 50,
-                (44..=49, 50) if self.id() != 0 => 5,
-                _ => new_position,
+        pub fn update_inside(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            old_position: i8,
+            new_position: i8,
+        ) -> std::result::Result<(), LudoError> {
+            let new_position = match self.rules.overshoot {
+                Overshoot::Bounce => self.move_back_if_overshoot(board, old_position, new_position),
+                _ if !self.is_overshoot(board, old_position, new_position) => new_position,
+                Overshoot::Enter => return self.enter_goal(board, piece_id, old_position),
+                Overshoot::Exact => {
+                    return Err(LudoError::IllegalAction(format!(
+                        "piece {} overshoots the goal from {}",
+                        piece_id, old_position
+                    )))
+                }
+            };
+            board
+                .piece_mut(self.id, piece_id)
+                .set_position(new_position)?;
+            board.update_inside(self.id(), piece_id, old_position, new_position)
+        }
+
+        pub fn star_position(&mut self, board: &Board, old_position: i8, new_position: i8) -> i8 {
+            let geometry = board.geometry();
+            let steps = (new_position - old_position).rem_euclid(geometry.track_length);
+            let is_own_entry = geometry.entry_square(self.id) == Some(new_position);
+            let is_star = self.rules.star_jumping && geometry.is_star(new_position);
+            match is_star && !is_own_entry && (1..=6).contains(&steps) {
+                true => geometry.next_star(new_position),
+                false => new_position,
             }
         }
 
-        pub fn starjump_piece(&mut self, piece_id: i8, dice_number: i8) {
-            self.update_position(piece_id, dice_number);
-            match self.goal_positions(self.old_position, self.new_position) {
-                99 => self.enter_goal(piece_id, self.old_position),
-                _ => self.starjump(piece_id, self.old_position, self.new_position),
-            };
+        pub fn starjump_piece(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            dice_number: i8,
+        ) -> std::result::Result<(), LudoError> {
+            self.update_position(board, piece_id, dice_number)?;
+            match self.is_goal_position(board) {
+                true => self.enter_goal(board, piece_id, self.old_position),
+                false => self.starjump(board, piece_id, self.old_position, self.new_position),
+            }
         }
 
-        pub fn starjump(&mut self, piece_id: i8, old_position: i8, new_position: i8) {
-            self.new_position = self.star_position(old_position, new_position);
-            self.update_outside(piece_id, old_position, self.new_position)
+        pub fn starjump(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            old_position: i8,
+            new_position: i8,
+        ) -> std::result::Result<(), LudoError> {
+            self.new_position = self.star_position(board, old_position, new_position);
+            self.update_outside(board, piece_id, old_position, self.new_position)
         }
 
-        pub fn enter_globe(&mut self, piece_id: i8, old_position: i8, new_position: i8) {
-            self.piece(piece_id).borrow_mut().set_position(new_position);
-            self.board().borrow_mut().update_outside(
-                self.id(),
-                piece_id,
-                old_position,
-                new_position,
-            );
+        pub fn enter_globe(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            old_position: i8,
+            new_position: i8,
+        ) -> std::result::Result<(), LudoError> {
+            self.update_outside(board, piece_id, old_position, new_position)
         }
 
-        pub fn circumvent_player_0(&mut self, old_position: i8, new_position: i8) -> i8 {
-            match (self.id(), old_position, new_position) {
-                (1..=3, 45..=51, 52..=57) => new_position - 52,
-                _ => new_position,
+        pub fn circumvent_player_0(
+            &mut self,
+            board: &Board,
+            old_position: i8,
+            new_position: i8,
+        ) -> i8 {
+            let geometry = board.geometry();
+            let track_length = geometry.track_length;
+            match geometry.wraps_around(self.id)
+                && geometry.is_track(old_position)
+                && new_position >= track_length
+            {
+                true => new_position - track_length,
+                false => new_position,
             }
         }
 
-        pub fn update_outside(&mut self, piece_id: i8, old_position: i8, new_position: i8) {
-            self.piece(piece_id).borrow_mut().set_position(new_position);
-            self.board().borrow_mut().update_outside(
-                self.id(),
-                piece_id,
-                old_position,
-                new_position,
-            );
+        pub fn update_outside(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            old_position: i8,
+            new_position: i8,
+        ) -> std::result::Result<(), LudoError> {
+            board
+                .piece_mut(self.id, piece_id)
+                .set_position(new_position)?;
+            board.update_outside(self.id(), piece_id, old_position, new_position)
         }
 
-        pub fn move_back_if_overshoot(&mut self, old_position: i8, new_position: i8) -> i8 {
-            let subtract = self.get_subtraction_position(old_position, new_position);
+        pub fn move_back_if_overshoot(
+            &mut self,
+            board: &Board,
+            old_position: i8,
+            new_position: i8,
+        ) -> i8 {
+            let subtract = self.get_subtraction_position(board, old_position, new_position);
             subtract - (new_position - subtract)
         }
 
-        pub fn get_subtraction_position(&mut self, old_position: i8, new_position: i8) -> i8 {
-            match (self.id, old_position, new_position) {
-                (0, 52..=56, 58..=62) => 57,
-                (1, 57..=61, 63..=67) => 62,
-                (2, 62..=66, 68..=72) => 67,
-                (3, 67..=71, 73..=77) => 72,
-                _ => new_position,
-            }
-        }
-
-        fn goal_positions(&mut self, old_position: i8, new_position: i8) -> i8 {
-            match (self.id(), old_position, new_position) {
-                (_, _, 99)
-                | (0, 50, 56)
-                | (0, 52..=56, 57)
-                | (0, 44..=49, 50)
-                | (0, 57, _)
-                | (1, 11, 17)
-                | (1, 57..=61, 62)
-                | (1, 5..=10, 11)
-                | (1, 62, _)
-                | (2, 24, 30)
-                | (2, 62..=66, 67)
-                | (2, 18..=23, 24)
-                | (2, 67, _)
-                | (3, 37, 43)
-                | (3, 67..=71, 72)
-                | (3, 31..=36, 37)
-                | (3, 72, _) => 99,
-                _ => new_position,
-            }
-        }
-
-        pub fn correct_position(&mut self) {
-            self.new_position = match (self.id, self.old_position, self.new_position) {
-                (0, 45..=50, 51..=55) => self.new_position + 1,
-                (1, 6..=11, 12..=16) => self.new_position + 45,
-                (2, 19..=24, 25..=29) => self.new_position + 37,
-                (3, 32..=37, 38..=42) => self.new_position + 29,
-                _ => self.new_position,
-            };
+        fn is_overshoot(&self, board: &Board, old_position: i8, new_position: i8) -> bool {
+            self.get_subtraction_position(board, old_position, new_position) != new_position
+        }
+
+        pub fn get_subtraction_position(
+            &self,
+            board: &Board,
+            old_position: i8,
+            new_position: i8,
+        ) -> i8 {
+            let geometry = board.geometry();
+            let column = geometry.home_column_start(self.id);
+            let goal = geometry.goal_square(self.id);
+            let overshoot = goal + 1..=goal + geometry.home_column_length;
+            match self.id < geometry.players
+                && (column..goal).contains(&old_position)
+                && overshoot.contains(&new_position)
+            {
+                true => goal,
+                false => new_position,
+            }
         }
 
-        pub fn enter_inside(&mut self, piece_id: i8, old_position: i8, new_position: i8) {
-            self.piece(piece_id).borrow_mut().set_position(new_position);
-            self.board()
-                .borrow_mut()
-                .move_inside(self.id(), piece_id, old_position, new_position);
+        fn goal_positions(&mut self, board: &Board, old_position: i8, new_position: i8) -> i8 {
+            let geometry = board.geometry();
+            let goal_position = geometry.goal_position();
+            let Some(entry) = geometry.entry_square(self.id) else {
+                return new_position;
+            };
+            let column = geometry.home_column_start(self.id);
+            let goal = geometry.goal_square(self.id);
+            let is_goal = new_position == goal_position
+                || (old_position == entry
+                    && new_position == entry + geometry.home_column_length + 1)
+                || ((column..goal).contains(&old_position) && new_position == goal)
+                || (self.rules.overshoot == Overshoot::Enter
+                    && (column..goal).contains(&old_position)
+                    && (goal..=goal + geometry.home_column_length).contains(&new_position))
+                || (self.rules.star_jumping
+                    && (entry - 6..entry).contains(&old_position)
+                    && new_position == entry)
+                || old_position == goal;
+            match is_goal {
+                true => goal_position,
+                false => new_position,
+            }
         }
 
-        pub fn enter_goal(&mut self, piece_id: i8, old_position: i8) {
-            self.piece(piece_id).borrow_mut().goal();
-            self.board()
-                .borrow_mut()
-                .enter_goal(self.id(), piece_id, old_position);
+        pub fn correct_position(&mut self, board: &Board) {
+            if self.is_entering_inside(board, self.old_position, self.new_position, 5) {
+                let entry = board.geometry().entry_square(self.id).unwrap_or_default();
+                let column = board.geometry().home_column_start(self.id);
+                self.new_position += column - entry - 1;
+            }
         }
 
-        pub fn free_piece(&mut self, piece_id: i8) {
-            self.new_position = self.invincible_positions(self.id());
-            self.piece(piece_id).borrow_mut().free();
-            self.piece(piece_id)
-                .borrow_mut()
-                .set_position(self.new_position);
-            self.board()
-                .borrow_mut()
-                .move_from_home(self.id(), piece_id, self.new_position);
+        pub fn enter_inside(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            old_position: i8,
+            new_position: i8,
+        ) -> std::result::Result<(), LudoError> {
+            board
+                .piece_mut(self.id, piece_id)
+                .set_position(new_position)?;
+            board.move_inside(self.id(), piece_id, old_position, new_position)
         }
 
-        fn invincible_positions(&self, id: i8) -> i8 {
-            match id {
-                0 => 0,
-                1 => 13,
-                2 => 26,
-                3 => 39,
-                _ => panic!("invalid player ID!"),
-            }
+        pub fn enter_goal(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            old_position: i8,
+        ) -> std::result::Result<(), LudoError> {
+            board.piece_mut(self.id, piece_id).goal();
+            board.enter_goal(self.id(), piece_id, old_position)
         }
 
-        pub fn roll_dice(&mut self) {
-            if let Some(dice) = &mut self.dice {
-                dice.roll();
+        pub fn free_piece(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+        ) -> std::result::Result<(), LudoError> {
+            self.new_position = self.invincible_positions(board, self.id())?;
+            board.piece_mut(self.id, piece_id).free();
+            board
+                .piece_mut(self.id, piece_id)
+                .set_position(self.new_position)?;
+            board.move_from_home(self.id(), piece_id, self.new_position)
+        }
+
+        fn invincible_positions(
+            &self,
+            board: &Board,
+            id: i8,
+        ) -> std::result::Result<i8, LudoError> {
+            board
+                .geometry()
+                .start_square(id)
+                .ok_or(LudoError::InvalidPlayer(id))
+        }
+
+        pub fn roll_dice(&mut self) -> std::result::Result<(), LudoError> {
+            match &mut self.dice {
+                Some(dice) => {
+                    dice.roll()?;
+                    Ok(())
+                }
+                None => Err(LudoError::NoDice),
             }
         }
 
@@ -456,24 +792,39 @@ mod players {
             }
         }
 
-        pub fn die_piece(&mut self, piece_id: i8, dice_number: i8) {
-            self.update_position(piece_id, dice_number);
-            if self.is_occupied_by_others(self.new_position).0 & !self.is_other_piece_invincible() {
-                self.send_other_piece_home(self.new_position);
+        pub fn die_piece(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+            dice_number: i8,
+        ) -> std::result::Result<(), LudoError> {
+            self.update_position(board, piece_id, dice_number)?;
+            if self.is_occupied_by_others(board, self.new_position).0
+                & !self.is_other_piece_invincible(board)?
+            {
+                self.send_other_piece_home(board, self.new_position)?;
             }
-            self.die(piece_id);
+            self.die(board, piece_id)
         }
 
-        fn is_other_piece_invincible(&mut self) -> bool {
-            self.invincible_positions(self.get_other_player_id()) == self.new_position
+        fn is_other_piece_invincible(
+            &mut self,
+            board: &Board,
+        ) -> std::result::Result<bool, LudoError> {
+            Ok(
+                self.invincible_positions(board, self.get_other_player_id(board))?
+                    == self.new_position,
+            )
         }
 
-        pub fn die(&mut self, piece_id: i8) {
-            let old_position = self.piece(piece_id).borrow_mut().position();
-            self.piece(piece_id).borrow_mut().dead();
-            self.board()
-                .borrow_mut()
-                .move_into_home(self.id(), piece_id, old_position);
+        pub fn die(
+            &mut self,
+            board: &mut Board,
+            piece_id: i8,
+        ) -> std::result::Result<(), LudoError> {
+            let old_position = self.piece(board, piece_id).position();
+            board.piece_mut(self.id, piece_id).dead();
+            board.move_into_home(self.id(), piece_id, old_position)
         }
 
         pub fn is_player_turn(&self) -> bool {
@@ -484,83 +835,110 @@ mod players {
             self.turn = true;
         }
 
+        pub fn end_turn(&mut self) {
+            self.turn = false;
+        }
+
         pub fn can_continue(&mut self) {
             if let Some(dice) = &mut self.dice {
-                self.turn = dice.get_value() == 6;
+                self.turn = self.rules.six_grants_extra_turn && dice.get_value() == 6;
+            }
+        }
+
+        fn end_move(&mut self, extra_turn: bool) {
+            match extra_turn {
+                true => self.my_turn(),
+                false => self.can_continue(),
             }
         }
 
-        pub fn valid_moves(&mut self, piece_id: i8, dice: i8) -> bool {
+        pub fn valid_moves(&mut self, board: &Board, piece_id: i8, dice: i8) -> bool {
             if piece_id > 3 {
                 return false;
             }
-            let binding = self.piece(piece_id);
-            let binding = binding.borrow_mut();
-            let is_home = binding.is_home();
-            let is_goal = binding.is_goal();
+            let piece = self.piece(board, piece_id);
+            let is_home = piece.is_home();
+            let is_goal = piece.is_goal();
             matches!(
                 (is_goal, is_home, dice),
                 (_, true, 6) | (false, false, 1..=6)
             )
         }
 
+        fn overshoots_exact(&mut self, board: &Board, piece_id: i8, dice_number: i8) -> bool {
+            let position = self.piece(board, piece_id).position();
+            self.rules.overshoot == Overshoot::Exact
+                && self.is_overshoot(board, position, position + dice_number)
+        }
+
         pub fn generate_vector_of_random_actions(
             &mut self,
+            board: &Board,
             actions: [Act; 10],
             dice_number: i8,
-        ) -> Vec<(Act, i8, i8)> {
+        ) -> std::result::Result<Vec<(Act, i8, i8)>, LudoError> {
             let mut movesets: Vec<(Act, i8, i8)> = Vec::new();
             for action in actions {
-                let mut action_vector = self.generate_action_vector(dice_number, action);
+                let mut action_vector = self.generate_action_vector(board, dice_number, action)?;
                 movesets.append(&mut action_vector);
             }
-            movesets
+            Ok(movesets)
         }
 
         pub fn select_random_piece(&mut self, action_vector: Vec<(Act, i8, i8)>) -> (Act, i8, i8) {
-            *action_vector
-                .choose(&mut rand::thread_rng())
-                .unwrap_or(&(Act::Nothing, self.id, 57))
+            let choice = match &mut self.rng {
+                Some(rng) => action_vector.choose(rng),
+                None => action_vector.choose(&mut rand::thread_rng()),
+            };
+            *choice.unwrap_or(&(Act::Nothing, self.id, 57))
         }
 
         pub fn generate_vector_of_ordered_actions(
             &mut self,
+            board: &Board,
             actions: [Act; 10],
             dice_number: i8,
             select_which_piece: Select,
-        ) -> Vec<(Act, i8, i8)> {
+        ) -> std::result::Result<Vec<(Act, i8, i8)>, LudoError> {
             let mut movesets: Vec<(Act, i8, i8)> = Vec::new();
             for action in actions {
-                let moveset = self.make_ordered_choice(dice_number, action, select_which_piece);
+                let moveset =
+                    self.make_ordered_choice(board, dice_number, action, select_which_piece)?;
                 if moveset.0 != Act::Nothing {
                     movesets.push(moveset);
                 }
             }
-            movesets
+            Ok(movesets)
         }
 
         pub fn get_ordered_action(
             &mut self,
+            board: &Board,
             actions: [Act; 10],
             dice_number: i8,
             select_which_piece: Select,
-        ) -> (Act, i8, i8) {
-            let action_vector =
-                self.generate_vector_of_ordered_actions(actions, dice_number, select_which_piece);
-            action_vector
+        ) -> std::result::Result<(Act, i8, i8), LudoError> {
+            let action_vector = self.generate_vector_of_ordered_actions(
+                board,
+                actions,
+                dice_number,
+                select_which_piece,
+            )?;
+            Ok(action_vector
                 .first()
                 .copied()
-                .unwrap_or((Act::Nothing, self.id(), 57))
+                .unwrap_or((Act::Nothing, self.id(), 57)))
         }
 
         pub fn make_ordered_choice(
             &mut self,
+            board: &Board,
             dice_number: i8,
             action: Act,
             select_which_piece: Select,
-        ) -> (Act, i8, i8) {
-            let action_vector = self.generate_action_vector(dice_number, action);
-            self.select_ordered_piece(action_vector, select_which_piece)
+        ) -> std::result::Result<(Act, i8, i8), LudoError> {
+            let action_vector = self.generate_action_vector(board, dice_number, action)?;
+            Ok(self.select_ordered_piece(action_vector, select_which_piece))
         }
 
         pub fn select_ordered_piece(
@@ -575,7 +953,10 @@ mod players {
                 Select::Furthest => {
                     action_vector.sort_by(|a, b| self.compare_heuristics(a, b, false))
                 }
-                Select::Random => action_vector.shuffle(&mut rand::thread_rng()),
+                Select::Random => match &mut self.rng {
+                    Some(rng) => action_vector.shuffle(rng),
+                    None => action_vector.shuffle(&mut rand::thread_rng()),
+                },
             }
             match action_vector.first() {
                 Some(&first_element) => first_element,
@@ -597,68 +978,87 @@ mod players {
             }
         }
 
-        pub fn generate_action_vector(&mut self, dice_number: i8, act: Act) -> Vec<(Act, i8, i8)> {
+        pub fn generate_action_vector(
+            &mut self,
+            board: &Board,
+            dice_number: i8,
+            act: Act,
+        ) -> std::result::Result<Vec<(Act, i8, i8)>, LudoError> {
             let mut action_vector: Vec<(Act, i8, i8)> = Vec::new();
             for piece_id in 0..4 {
-                let action = self.valid_choices(piece_id, dice_number, act);
+                let action = self.valid_choices(board, piece_id, dice_number, act);
                 if action != Act::Nothing {
-                    let heuristic = self.get_heuristics(piece_id);
+                    let heuristic = self.get_heuristics(board, piece_id)?;
                     action_vector.push((action, piece_id, heuristic));
                 }
             }
-            action_vector
+            Ok(action_vector)
         }
 
-        pub fn valid_choices(&mut self, piece_id: i8, dice_number: i8, action: Act) -> Act {
-            if !self.valid_moves(piece_id, dice_number) {
+        pub fn valid_choices(
+            &mut self,
+            board: &Board,
+            piece_id: i8,
+            dice_number: i8,
+            action: Act,
+        ) -> Act {
+            if !self.valid_moves(board, piece_id, dice_number)
+                || self.overshoots_exact(board, piece_id, dice_number)
+            {
                 return Act::Nothing;
             }
             match action {
-                Act::Free => self.try_to_free(piece_id, dice_number),
-                Act::Move => self.try_to_move(piece_id, dice_number),
-                Act::Join => self.try_to_join(piece_id, dice_number),
-                Act::Kill => self.try_to_kill(piece_id, dice_number),
-                Act::Die => self.try_to_die(piece_id, dice_number),
-                Act::Goal => self.try_to_win(piece_id, dice_number),
-                Act::Leave => self.try_to_leave(piece_id, dice_number),
-                Act::Safe => self.try_to_safe(piece_id, dice_number),
-                Act::Starjump => self.try_to_starjump(piece_id, dice_number),
+                Act::Free => self.try_to_free(board, piece_id, dice_number),
+                Act::Move => self.try_to_move(board, piece_id, dice_number),
+                Act::Join => self.try_to_join(board, piece_id, dice_number),
+                Act::Kill => self.try_to_kill(board, piece_id, dice_number),
+                Act::Die => self.try_to_die(board, piece_id, dice_number),
+                Act::Goal => self.try_to_win(board, piece_id, dice_number),
+                Act::Leave => self.try_to_leave(board, piece_id, dice_number),
+                Act::Safe => self.try_to_safe(board, piece_id, dice_number),
+                Act::Starjump => self.try_to_starjump(board, piece_id, dice_number),
                 _ => Act::Nothing,
             }
         }
 
-        pub fn try_to_free(&mut self, piece_id: i8, dice_number: i8) -> Act {
-            let binding = self.piece(piece_id);
-            let binding = binding.borrow_mut();
-            let is_home = binding.is_home();
-            let invincible_position = self.invincible_positions(self.id());
-            let is_occupied_by_others = self.is_occupied_by_others(invincible_position).0;
+        pub fn try_to_free(&mut self, board: &Board, piece_id: i8, dice_number: i8) -> Act {
+            let is_home = self.piece(board, piece_id).is_home();
+            let Ok(invincible_position) = self.invincible_positions(board, self.id()) else {
+                return Act::Nothing;
+            };
+            let is_occupied_by_others = self.is_occupied_by_others(board, invincible_position).0;
             match (is_home, is_occupied_by_others, dice_number) {
                 (true, false, 6) => Act::Free,
                 _ => Act::Nothing,
             }
         }
 
-        pub fn try_to_starjump(&mut self, piece_id: i8, dice_number: i8) -> Act {
-            self.update_position(piece_id, dice_number);
-            let is_star = self.board().borrow_mut().is_star(self.new_position);
-            let is_home = self.piece(piece_id).borrow_mut().is_home();
-            let is_occupied = self.is_occupied_by_others(self.new_position);
-            let is_star_occupied = self.is_star_occupied(self.old_position, self.new_position);
+        pub fn try_to_starjump(&mut self, board: &Board, piece_id: i8, dice_number: i8) -> Act {
+            if self.update_position(board, piece_id, dice_number).is_err() {
+                return Act::Nothing;
+            }
+            let is_star = self.is_star(board, self.new_position);
+            let is_home = self.piece(board, piece_id).is_home();
+            let is_occupied = self.is_occupied_by_others(board, self.new_position);
+            let is_star_occupied =
+                self.is_star_occupied(board, self.old_position, self.new_position);
             match (is_star, is_home, is_occupied.0, is_star_occupied.0) {
                 (true, false, false, false) => Act::Starjump,
                 _ => Act::Nothing,
             }
         }
 
-        pub fn try_to_safe(&mut self, piece_id: i8, dice_number: i8) -> Act {
-            self.update_position(piece_id, dice_number);
-            let is_globe = self.board().borrow_mut().is_globe(self.new_position);
-            let is_occupied_by_others = self.is_occupied_by_others(self.new_position);
-            let can_enter_inside = self.can_enter_inside(self.old_position, self.new_position);
-            let is_inside = self.is_inside(self.old_position, self.new_position);
-            self.correct_position();
-            let is_goal_position = self.is_goal_position();
+        pub fn try_to_safe(&mut self, board: &Board, piece_id: i8, dice_number: i8) -> Act {
+            if self.update_position(board, piece_id, dice_number).is_err() {
+                return Act::Nothing;
+            }
+            let is_globe = self.is_globe(board, self.new_position);
+            let is_occupied_by_others = self.is_occupied_by_others(board, self.new_position);
+            let can_enter_inside =
+                self.can_enter_inside(board, self.old_position, self.new_position);
+            let is_inside = self.is_inside(board, self.old_position, self.new_position);
+            self.correct_position(board);
+            let is_goal_position = self.is_goal_position(board);
 
             match (
                 is_globe,
@@ -673,12 +1073,14 @@ mod players {
             }
         }
 
-        pub fn try_to_leave(&mut self, piece_id: i8, dice_number: i8) -> Act {
-            self.update_position(piece_id, dice_number);
-            let occupied_by_selves = self.is_occupied_by_selves(self.old_position);
-            let is_occupied = self.is_occupied_or_more(self.new_position);
-            let is_star = self.board().borrow_mut().is_star(self.new_position);
-            let is_globe = self.board().borrow_mut().is_globe(self.new_position);
+        pub fn try_to_leave(&mut self, board: &Board, piece_id: i8, dice_number: i8) -> Act {
+            if self.update_position(board, piece_id, dice_number).is_err() {
+                return Act::Nothing;
+            }
+            let occupied_by_selves = self.is_occupied_by_selves(board, self.old_position);
+            let is_occupied = self.is_occupied_or_more(board, self.new_position);
+            let is_star = self.is_star(board, self.new_position);
+            let is_globe = self.is_globe(board, self.new_position);
 
             match (occupied_by_selves.1, is_star, is_globe, is_occupied.0) {
                 (true, false, false, false) => Act::Leave,
@@ -686,18 +1088,18 @@ mod players {
             }
         }
 
-        pub fn try_to_move(&mut self, piece_id: i8, dice_number: i8) -> Act {
-            self.update_position(piece_id, dice_number);
-            self.correct_position();
-            let binding = self.piece(piece_id);
-            let binding = binding.borrow_mut();
-            let is_home = binding.is_home();
-            let is_goalpos = self.is_goal_position();
-            let is_starpos = self.board().borrow_mut().is_star(self.new_position);
-            let is_globepos = self.board().borrow_mut().is_globe(self.new_position);
-            let is_self_occupied = self.is_occupied_by_selves(self.old_position).1;
-            let is_newpos_occupied = self.is_occupied_or_more(self.new_position).0;
-            let is_outside = self.new_position < 52;
+        pub fn try_to_move(&mut self, board: &Board, piece_id: i8, dice_number: i8) -> Act {
+            if self.update_position(board, piece_id, dice_number).is_err() {
+                return Act::Nothing;
+            }
+            self.correct_position(board);
+            let is_home = self.piece(board, piece_id).is_home();
+            let is_goalpos = self.is_goal_position(board);
+            let is_starpos = self.is_star(board, self.new_position);
+            let is_globepos = self.is_globe(board, self.new_position);
+            let is_self_occupied = self.is_occupied_by_selves(board, self.old_position).1;
+            let is_newpos_occupied = self.is_occupied_or_more(board, self.new_position).0;
+            let is_outside = self.new_position < board.geometry().track_length;
 
             if is_home
                 | is_goalpos
@@ -710,24 +1112,27 @@ mod players {
             Act::Move
         }
 
-        fn is_goal_position(&mut self) -> bool {
-            self.goal_positions(self.old_position, self.new_position) == 99
+        fn is_goal_position(&mut self, board: &Board) -> bool {
+            let goal_position = board.geometry().goal_position();
+            self.goal_positions(board, self.old_position, self.new_position) == goal_position
         }
 
-        fn is_occupied_or_more(&mut self, position: i8) -> (bool, bool) {
-            let is_occupied_more = self.board().borrow_mut().is_occupied_more(position);
-            let is_occupied = self.board().borrow_mut().is_occupied(position);
+        fn is_occupied_or_more(&mut self, board: &Board, position: i8) -> (bool, bool) {
+            let is_occupied_more = board.is_occupied_more(position);
+            let is_occupied = board.is_occupied(position);
             (is_occupied, is_occupied_more)
         }
 
-        pub fn try_to_join(&mut self, piece_id: i8, dice_number: i8) -> Act {
-            self.update_position(piece_id, dice_number);
-            let is_starpos = self.board().borrow_mut().is_star(self.new_position);
-            let is_self_occupied = self.is_occupied_by_selves(self.new_position);
-            let is_star_self_occupied = self.is_star_occupied_by_selves();
-            let is_other_occupied = self.is_occupied_by_others(self.new_position);
-            let is_goalpos = self.is_goal_position();
-            let is_home = self.piece(piece_id).borrow_mut().is_home();
+        pub fn try_to_join(&mut self, board: &Board, piece_id: i8, dice_number: i8) -> Act {
+            if self.update_position(board, piece_id, dice_number).is_err() {
+                return Act::Nothing;
+            }
+            let is_starpos = self.is_star(board, self.new_position);
+            let is_self_occupied = self.is_occupied_by_selves(board, self.new_position);
+            let is_star_self_occupied = self.is_star_occupied_by_selves(board);
+            let is_other_occupied = self.is_occupied_by_others(board, self.new_position);
+            let is_goalpos = self.is_goal_position(board);
+            let is_home = self.piece(board, piece_id).is_home();
             match (
                 is_self_occupied.0,
                 is_starpos,
@@ -742,29 +1147,28 @@ mod players {
             }
         }
 
-        fn is_occupied_by_selves(&mut self, position: i8) -> (bool, bool) {
-            let occupied_by_self = self
-                .board()
-                .borrow_mut()
-                .is_occupied_self(self.id(), position);
-            let occupied_by_self_more = self
-                .board()
-                .borrow_mut()
-                .is_occupied_by_more_self(self.id(), position);
+        fn is_occupied_by_selves(&mut self, board: &Board, position: i8) -> (bool, bool) {
+            let occupied_by_self = board.is_occupied_self(self.id(), position);
+            let occupied_by_self_more = board.is_occupied_by_more_self(self.id(), position);
             (occupied_by_self, occupied_by_self_more)
         }
 
-        pub fn try_to_die(&mut self, piece_id: i8, dice_number: i8) -> Act {
-            self.update_position(piece_id, dice_number);
-            let occupied_by_others = self.is_occupied_by_others(self.new_position);
+        pub fn try_to_die(&mut self, board: &Board, piece_id: i8, dice_number: i8) -> Act {
+            if self.update_position(board, piece_id, dice_number).is_err() {
+                return Act::Nothing;
+            }
+            let occupied_by_others = self.is_occupied_by_others(board, self.new_position);
             let is_star_occupied_by_others =
-                self.is_star_occupied_by_others(self.old_position, self.new_position);
-            let is_globepos = self.board().borrow_mut().is_globe(self.new_position);
-            let is_home = self.piece(piece_id).borrow_mut().is_home();
-            let other_player_id = self.get_other_player_id();
-            let invincible_position = self.invincible_positions(other_player_id);
-            let is_other_player_invincible = self.is_occupied_by_others(invincible_position).0
-                & (self.new_position == invincible_position);
+                self.is_star_occupied_by_others(board, self.old_position, self.new_position);
+            let is_globepos = self.is_globe(board, self.new_position);
+            let is_home = self.piece(board, piece_id).is_home();
+            let other_player_id = self.get_other_player_id(board);
+            let Ok(invincible_position) = self.invincible_positions(board, other_player_id) else {
+                return Act::Nothing;
+            };
+            let is_other_player_invincible =
+                self.is_occupied_by_others(board, invincible_position).0
+                    & (self.new_position == invincible_position);
 
             match (
                 is_home,
@@ -782,43 +1186,52 @@ mod players {
             }
         }
 
-        pub fn is_occupied_by_others(&mut self, new_position: i8) -> (bool, bool) {
-            let occupied_by_other_more = self
-                .board()
-                .borrow_mut()
-                .is_occupied_by_other_more(self.id(), new_position);
-            let occupied_by_other = self
-                .board()
-                .borrow_mut()
-                .is_occupied_by_other(self.id(), new_position);
+        pub fn is_occupied_by_others(&mut self, board: &Board, new_position: i8) -> (bool, bool) {
+            let occupied_by_other_more =
+                board.is_occupied_by_other_more(self.id(), new_position) && self.rules.blockades;
+            let occupied_by_other = board.is_occupied_by_other(self.id(), new_position);
             (occupied_by_other, occupied_by_other_more)
         }
 
-        pub fn try_to_win(&mut self, piece_id: i8, dice_number: i8) -> Act {
-            self.update_position(piece_id, dice_number);
-            let is_occupied_by_others = self.is_occupied_by_others(self.new_position);
-            let is_goalpos = self.is_goal_position();
+        pub fn try_to_win(&mut self, board: &Board, piece_id: i8, dice_number: i8) -> Act {
+            if self.update_position(board, piece_id, dice_number).is_err() {
+                return Act::Nothing;
+            }
+            let is_occupied_by_others = self.is_occupied_by_others(board, self.new_position);
+            let is_goalpos = self.is_goal_position(board);
             match (is_occupied_by_others.1, is_goalpos) {
                 (false, true) => Act::Goal,
                 _ => Act::Nothing,
             }
         }
 
-        pub fn try_to_kill(&mut self, piece_id: i8, dice_number: i8) -> Act {
-            self.update_position(piece_id, dice_number);
-            let piece = self.piece(piece_id);
-            let is_home = piece.borrow().is_home();
-            let other_player_id = self.get_other_player_id();
-            let invincible_position = self.invincible_positions(other_player_id);
+        pub fn try_to_kill(&mut self, board: &Board, piece_id: i8, dice_number: i8) -> Act {
+            if self.update_position(board, piece_id, dice_number).is_err() {
+                return Act::Nothing;
+            }
+            let is_home = self.piece(board, piece_id).is_home();
+            let other_player_id = self.get_other_player_id(board);
+            let Ok(invincible_position) = self.invincible_positions(board, other_player_id) else {
+                return Act::Nothing;
+            };
             let is_invincible_position_occupied_by_others =
-                self.is_occupied_by_others(invincible_position).0;
+                self.is_occupied_by_others(board, invincible_position).0;
             let is_new_position_same_as_invincible_position =
                 self.new_position == invincible_position;
-            let is_new_position_occupied_by_others = self.is_occupied_by_others(self.new_position);
+            let is_new_position_occupied_by_others =
+                self.is_occupied_by_others(board, self.new_position);
             let is_star_position_occupied_by_others =
-                self.is_star_occupied_by_others(self.old_position, self.new_position);
-            let is_globe = self.board().borrow_mut().is_globe(self.new_position);
-            let can_enter_inside = self.can_enter_inside(self.old_position, self.new_position);
+                self.is_star_occupied_by_others(board, self.old_position, self.new_position);
+            let is_globe = self.is_globe(board, self.new_position);
+            let can_enter_inside =
+                self.can_enter_inside(board, self.old_position, self.new_position);
+            let star_position = self.star_position(board, self.old_position, self.new_position);
+            if self.is_occupied_by_own_piece(board, self.new_position)
+                || (is_star_position_occupied_by_others.0
+                    && self.is_occupied_by_own_piece(board, star_position))
+            {
+                return Act::Nothing;
+            }
             match (
                 is_home,
                 is_invincible_position_occupied_by_others,
@@ -841,46 +1254,50 @@ mod players {
             }
         }
 
-        pub fn get_other_player_id(&self) -> i8 {
-            let other_piece = self
-                .board()
-                .borrow_mut()
-                .outside(self.new_position)
+        /// A capture clears the whole square, so it is refused while one of the
+        /// player's own pieces shares the square with the victims.
+        fn is_occupied_by_own_piece(&self, board: &Board, position: i8) -> bool {
+            board.geometry().is_track(position)
+                && board
+                    .square(position)
+                    .pieces
+                    .iter()
+                    .any(|piece| piece.player_id == self.id)
+        }
+
+        pub fn get_other_player_id(&self, board: &Board) -> i8 {
+            board
+                .square(self.new_position)
                 .pieces
-                .get(0)
-                .cloned();
-            if let Some(id) = other_piece.map(|piece| piece.borrow().color() as i8) {
-                id
-            } else {
-                0
-            }
+                .first()
+                .map(|piece| piece.player_id)
+                .unwrap_or_default()
         }
 
-        pub fn is_star_occupied_by_selves(&mut self) -> (bool, bool) {
-            let is_star = self.board().borrow_mut().is_star(self.new_position);
+        pub fn is_star_occupied_by_selves(&mut self, board: &Board) -> (bool, bool) {
+            let is_star = self.is_star(board, self.new_position);
             if is_star {
-                let star_position = self.star_position(self.old_position, self.new_position);
-                let is_star_occupied_by_self = self
-                    .board()
-                    .borrow_mut()
-                    .is_occupied_self(self.id(), star_position);
-                let is_star_occupied_by_more_selves = self
-                    .board()
-                    .borrow_mut()
-                    .is_occupied_by_more_self(self.id(), star_position);
+                let star_position = self.star_position(board, self.old_position, self.new_position);
+                let is_star_occupied_by_self = board.is_occupied_self(self.id(), star_position);
+                let is_star_occupied_by_more_selves =
+                    board.is_occupied_by_more_self(self.id(), star_position);
                 (is_star_occupied_by_self, is_star_occupied_by_more_selves)
             } else {
                 (false, false)
             }
         }
 
-        pub fn is_star_occupied(&mut self, old_position: i8, new_position: i8) -> (bool, bool) {
-            let is_star = self.board().borrow_mut().is_star(new_position);
+        pub fn is_star_occupied(
+            &mut self,
+            board: &Board,
+            old_position: i8,
+            new_position: i8,
+        ) -> (bool, bool) {
+            let is_star = self.is_star(board, new_position);
             if is_star {
-                let star_position = self.star_position(old_position, new_position);
-                let is_star_occupied = self.board().borrow_mut().is_occupied(star_position);
-                let is_star_occupied_more =
-                    self.board().borrow_mut().is_occupied_more(star_position);
+                let star_position = self.star_position(board, old_position, new_position);
+                let is_star_occupied = board.is_occupied(star_position);
+                let is_star_occupied_more = board.is_occupied_more(star_position);
                 (is_star_occupied, is_star_occupied_more)
             } else {
                 (false, false)
@@ -889,56 +1306,48 @@ mod players {
 
         pub fn is_star_occupied_by_others(
             &mut self,
+            board: &Board,
             old_position: i8,
             new_position: i8,
         ) -> (bool, bool) {
-            let is_star = self.board().borrow_mut().is_star(new_position);
+            let is_star = self.is_star(board, new_position);
             match is_star {
                 true => {
-                    let star_position = self.star_position(old_position, new_position);
-                    let occupied_by_other_star = self
-                        .board()
-                        .borrow_mut()
-                        .is_occupied_by_other(self.id(), star_position);
-                    let occupied_by_other_more_star = self
-                        .board()
-                        .borrow_mut()
-                        .is_occupied_by_other_more(self.id(), star_position);
+                    let star_position = self.star_position(board, old_position, new_position);
+                    let occupied_by_other_star =
+                        board.is_occupied_by_other(self.id(), star_position);
+                    let occupied_by_other_more_star = board
+                        .is_occupied_by_other_more(self.id(), star_position)
+                        && self.rules.blockades;
                     (occupied_by_other_star, occupied_by_other_more_star)
                 }
                 _ => (false, false),
             }
         }
 
-        pub fn get_heuristics(&mut self, piece_id: i8) -> i8 {
-            if self.piece(piece_id).borrow_mut().is_home() {
-                return 57;
-            }
-            if self.piece(piece_id).borrow_mut().is_goal() {
-                return 0;
+        pub fn get_heuristics(
+            &self,
+            board: &Board,
+            piece_id: i8,
+        ) -> std::result::Result<i8, LudoError> {
+            let geometry = board.geometry();
+            if self.piece(board, piece_id).is_home() {
+                return Ok(geometry.track_length + geometry.home_column_length);
             }
-            let position = self.piece(piece_id).borrow_mut().position();
-            match (self.id, position) {
-                (0, 0..=50) => 56 - position,
-                (0, 52..=56) => 57 - position,
-                (1, 13..=51) => 69 - position,
-                (1, 0..=11) => 17 - position,
-                (1, 57..=61) => 62 - position,
-                (2, 25..=51) => 82 - position,
-                (2, 0..=24) => 30 - position,
-                (2, 62..=66) => 67 - position,
-                (3, 39..=51) => 95 - position,
-                (3, 67..=71) => 72 - position,
-                (3, 0..=37) => 43 - position,
-                _ => panic!("invalid Heuristics!"),
+            if self.piece(board, piece_id).is_goal() {
+                return Ok(0);
             }
+            let position = self.piece(board, piece_id).position();
+            geometry
+                .distance_to_goal(self.id, position)
+                .ok_or(LudoError::InvalidPosition(position))
         }
 
-        pub fn is_finished(&self) -> bool {
-            self.pieces.iter().all(|piece| piece.borrow_mut().is_goal())
+        pub fn is_finished(&self, board: &Board) -> bool {
+            board.player_pieces(self.id).iter().all(Piece::is_goal)
         }
 
-        pub fn print_status(&mut self) {
+        pub fn print_status(&mut self, board: &Board) {
             let mut table = Table::new();
             table.add_row(row![
                 "Player",
@@ -959,10 +1368,10 @@ mod players {
                 format!("{:<10}", format!("{:?}", self.action.0)),
                 format!("{:<13}", self.old_position),
                 format!("{:<13}", self.new_position),
-                format!("{:<8}", self.piece(0).borrow().position()),
-                format!("{:<8}", self.piece(1).borrow_mut().position()),
-                format!("{:<8}", self.piece(2).borrow_mut().position()),
-                format!("{:<8}", self.piece(3).borrow_mut().position())
+                format!("{:<8}", self.piece(board, 0).position()),
+                format!("{:<8}", self.piece(board, 1).position()),
+                format!("{:<8}", self.piece(board, 2).position()),
+                format!("{:<8}", self.piece(board, 3).position())
             ]);
             table.printstd();
         }
@@ -974,18 +1383,12 @@ mod players {
             1 => Color::Yellow,
             2 => Color::Blue,
             3 => Color::Red,
+            4 => Color::Orange,
+            5 => Color::Purple,
             _ => panic!("invalid player id!"),
         }
     }
 
-    fn get_piece_and_id_from_other_players(piece: Rc<RefCell<Piece>>) -> (i8, i8) {
-        let (other_player_id, other_piece_id) = {
-            let piece_borrow = piece.borrow_mut();
-            (piece_borrow.color() as i8, piece_borrow.id())
-        };
-        (other_player_id, other_piece_id)
-    }
-
     impl Display for Act {
         fn fmt(&self, f: &mut Formatter<'_>) -> Result {
             match self {
@@ -1003,6 +1406,26 @@ mod players {
         }
     }
 
+    impl FromStr for Act {
+        type Err = String;
+
+        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+            match s {
+                "Free" => Ok(Act::Free),
+                "Move" => Ok(Act::Move),
+                "Join" => Ok(Act::Join),
+                "Kill" => Ok(Act::Kill),
+                "Die" => Ok(Act::Die),
+                "Goal" => Ok(Act::Goal),
+                "Leave" => Ok(Act::Leave),
+                "Safe" => Ok(Act::Safe),
+                "Starjump" => Ok(Act::Starjump),
+                "Nothing" => Ok(Act::Nothing),
+                _ => Err(format!("Unknown action: {}", s)),
+            }
+        }
+    }
+
     impl Display for Select {
         fn fmt(&self, f: &mut Formatter<'_>) -> Result {
             match self {
@@ -1014,4 +1437,7 @@ mod players {
     }
 }
 
-pub use players::{Act, Player, Select};
+pub use players::{
+    compact_legal_moves, legal_moves, legal_moves_with_rules, Act, Move, MoveUndo, Player, Select,
+};
+pub use rules::{Overshoot, RuleSet};
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Overshoot {
+    Bounce,
+    Exact,
+    Enter,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleSet {
+    pub six_grants_extra_turn: bool,
+    pub first_round_attempts: u8,
+    pub star_jumping: bool,
+    pub globe_safety: bool,
+    pub blockades: bool,
+    pub overshoot: Overshoot,
+    pub capture_grants_extra_roll: bool,
+    pub triple_six_forfeits_turn: bool,
+}
+
+impl RuleSet {
+    pub fn standard() -> Self {
+        RuleSet {
+            six_grants_extra_turn: true,
+            first_round_attempts: 3,
+            star_jumping: true,
+            globe_safety: true,
+            blockades: true,
+            overshoot: Overshoot::Bounce,
+            capture_grants_extra_roll: true,
+            triple_six_forfeits_turn: false,
+        }
+    }
+
+    pub fn exact_goal() -> Self {
+        RuleSet {
+            overshoot: Overshoot::Exact,
+            ..Self::standard()
+        }
+    }
+
+    pub fn no_bounce() -> Self {
+        RuleSet {
+            overshoot: Overshoot::Enter,
+            ..Self::standard()
+        }
+    }
+
+    pub fn triple_six_forfeits() -> Self {
+        RuleSet {
+            triple_six_forfeits_turn: true,
+            ..Self::standard()
+        }
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
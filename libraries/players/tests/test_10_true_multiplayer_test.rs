@@ -2,7 +2,6 @@ use board::Board;
 use dice::Dice;
 use players::{Act, Player, Select};
 use std::time::Instant;
-use std::{cell::RefCell, rc::Rc};
 
 mod multiplayer_tests {
     use super::*;
@@ -23,53 +22,49 @@ mod multiplayer_tests {
     #[test]
     #[ignore = "long test"]
     fn player_0_vs_1_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
 
         let mut player0 = Player::new(0);
-        player0.setup(board.clone());
         let mut player1 = Player::new(1);
-        player1.setup(board);
 
         loop {
             player1.get_dice(dice.clone());
             player1.my_turn();
-            play_random(&mut player1, ACTIONS);
-            if player1.is_finished() {
+            play_random(&mut player1, &mut board, ACTIONS);
+            if player1.is_finished(&board) {
                 println!("Player 0 wins");
                 break;
             }
             player1.drop_dice();
             player1.get_dice(dice.clone());
             player0.my_turn();
-            play_random(&mut player0, ACTIONS);
-            if player0.is_finished() {
+            play_random(&mut player0, &mut board, ACTIONS);
+            if player0.is_finished(&board) {
                 println!("Player 1 wins");
                 break;
             }
             player0.drop_dice();
         }
-        assert!(player0.is_finished() || player1.is_finished());
+        assert!(player0.is_finished(&board) || player1.is_finished(&board));
     }
 
     #[test]
     #[ignore = "very long test"]
     fn player_0_vs_1_1000_iteration_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
 
         let mut player0 = Player::new(0);
-        player0.setup(board.clone());
         let mut player1 = Player::new(1);
-        player1.setup(board.clone());
         let mut winrates = [0.0; 2];
         let max_iter: usize = 1000;
         for _ in 0..max_iter {
             loop {
                 player1.get_dice(dice.clone());
                 player1.my_turn();
-                play_random(&mut player1, ACTIONS);
-                if player1.is_finished() {
+                play_random(&mut player1, &mut board, ACTIONS);
+                if player1.is_finished(&board) {
                     // println!("Player 0 wins");
                     winrates[0] += 1.0;
                     break;
@@ -77,16 +72,16 @@ mod multiplayer_tests {
                 player1.drop_dice();
                 player0.get_dice(dice.clone());
                 player0.my_turn();
-                play_random(&mut player0, ACTIONS);
-                if player0.is_finished() {
+                play_random(&mut player0, &mut board, ACTIONS);
+                if player0.is_finished(&board) {
                     // println!("Player 1 wins");
                     winrates[1] += 1.0;
                     break;
                 }
                 player0.drop_dice();
             }
-            assert!(player0.is_finished() || player1.is_finished());
-            board.borrow_mut().reset();
+            assert!(player0.is_finished(&board) || player1.is_finished(&board));
+            board.reset();
         }
         println!(
             "Player 0 winrate: {}",
@@ -101,53 +96,49 @@ mod multiplayer_tests {
     #[test]
     #[ignore = "long test"]
     fn player_0_vs_2_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
 
         let mut player0 = Player::new(0);
-        player0.setup(board.clone());
         let mut player2 = Player::new(2);
-        player2.setup(board);
 
         loop {
             player2.get_dice(dice.clone());
             player2.my_turn();
-            play_random(&mut player2, ACTIONS);
-            if player2.is_finished() {
+            play_random(&mut player2, &mut board, ACTIONS);
+            if player2.is_finished(&board) {
                 println!("Player 0 wins");
                 break;
             }
             player2.drop_dice();
             player0.get_dice(dice.clone());
             player0.my_turn();
-            play_random(&mut player0, ACTIONS);
-            if player0.is_finished() {
+            play_random(&mut player0, &mut board, ACTIONS);
+            if player0.is_finished(&board) {
                 println!("Player 2 wins");
                 break;
             }
             player0.drop_dice();
         }
-        assert!(player0.is_finished() || player2.is_finished());
+        assert!(player0.is_finished(&board) || player2.is_finished(&board));
     }
 
     #[test]
     #[ignore = "very long test"]
     fn player_0_vs_2_1000_iteration_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
 
         let mut player0 = Player::new(0);
-        player0.setup(board.clone());
         let mut player2 = Player::new(2);
-        player2.setup(board.clone());
         let mut winrates = [0.0; 2];
         let max_iter: usize = 1000;
         for _ in 0..max_iter {
             loop {
                 player2.get_dice(dice.clone());
                 player2.my_turn();
-                play_random(&mut player2, ACTIONS);
-                if player2.is_finished() {
+                play_random(&mut player2, &mut board, ACTIONS);
+                if player2.is_finished(&board) {
                     println!("Player 0 wins");
                     winrates[0] += 1.0;
                     break;
@@ -155,16 +146,16 @@ mod multiplayer_tests {
                 player2.drop_dice();
                 player2.get_dice(dice.clone());
                 player0.my_turn();
-                play_random(&mut player0, ACTIONS);
-                if player0.is_finished() {
+                play_random(&mut player0, &mut board, ACTIONS);
+                if player0.is_finished(&board) {
                     println!("Player 2 wins");
                     winrates[1] += 1.0;
                     break;
                 }
                 player0.drop_dice();
             }
-            assert!(player0.is_finished() || player2.is_finished());
-            board.borrow_mut().reset();
+            assert!(player0.is_finished(&board) || player2.is_finished(&board));
+            board.reset();
         }
         println!(
             "Player 0 winrate: {}",
@@ -179,53 +170,49 @@ mod multiplayer_tests {
     #[test]
     #[ignore = "long test"]
     fn player_0_vs_3_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
 
         let mut player0 = Player::new(0);
-        player0.setup(board.clone());
         let mut player3 = Player::new(3);
-        player3.setup(board);
 
         loop {
             player3.get_dice(dice.clone());
             player3.my_turn();
-            play_random(&mut player3, ACTIONS);
-            if player3.is_finished() {
+            play_random(&mut player3, &mut board, ACTIONS);
+            if player3.is_finished(&board) {
                 println!("Player 0 wins");
                 break;
             }
             player3.drop_dice();
             player0.get_dice(dice.clone());
             player0.my_turn();
-            play_random(&mut player0, ACTIONS);
-            if player0.is_finished() {
+            play_random(&mut player0, &mut board, ACTIONS);
+            if player0.is_finished(&board) {
                 println!("Player 3 wins");
                 break;
             }
             player0.drop_dice();
         }
-        assert!(player0.is_finished() || player3.is_finished());
+        assert!(player0.is_finished(&board) || player3.is_finished(&board));
     }
 
     #[test]
     #[ignore = "very long test"]
     fn player_0_vs_3_1000_iteration_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
 
         let mut player0 = Player::new(0);
-        player0.setup(board.clone());
         let mut player3 = Player::new(3);
-        player3.setup(board.clone());
         let mut winrates = [0.0; 2];
         let max_iter: usize = 1000;
         for _ in 0..max_iter {
             loop {
                 player3.get_dice(dice.clone());
                 player3.my_turn();
-                play_random(&mut player3, ACTIONS);
-                if player3.is_finished() {
+                play_random(&mut player3, &mut board, ACTIONS);
+                if player3.is_finished(&board) {
                     println!("Player 0 wins");
                     winrates[0] += 1.0;
                     break;
@@ -233,15 +220,15 @@ mod multiplayer_tests {
                 player3.drop_dice();
                 player0.get_dice(dice.clone());
                 player0.my_turn();
-                play_random(&mut player0, ACTIONS);
-                if player0.is_finished() {
+                play_random(&mut player0, &mut board, ACTIONS);
+                if player0.is_finished(&board) {
                     println!("Player 3 wins");
                     winrates[1] += 1.0;
                     break;
                 }
             }
-            assert!(player0.is_finished() || player3.is_finished());
-            board.borrow_mut().reset();
+            assert!(player0.is_finished(&board) || player3.is_finished(&board));
+            board.reset();
         }
         println!(
             "Player 0 winrate: {}",
@@ -256,53 +243,49 @@ mod multiplayer_tests {
     #[test]
     #[ignore = "long test"]
     fn player_1_vs_2_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
 
         let mut player1 = Player::new(1);
-        player1.setup(board.clone());
         let mut player2 = Player::new(2);
-        player2.setup(board);
 
         loop {
             player2.get_dice(dice.clone());
             player2.my_turn();
-            play_random(&mut player2, ACTIONS);
-            if player2.is_finished() {
+            play_random(&mut player2, &mut board, ACTIONS);
+            if player2.is_finished(&board) {
                 println!("Player 1 wins");
                 break;
             }
             player2.drop_dice();
             player1.get_dice(dice.clone());
             player1.my_turn();
-            play_random(&mut player1, ACTIONS);
-            if player1.is_finished() {
+            play_random(&mut player1, &mut board, ACTIONS);
+            if player1.is_finished(&board) {
                 println!("Player 2 wins");
                 break;
             }
             player1.drop_dice();
         }
-        assert!(player1.is_finished() || player2.is_finished());
+        assert!(player1.is_finished(&board) || player2.is_finished(&board));
     }
 
     #[test]
     #[ignore = "very long test"]
     fn player_1_vs_2_1000_iteration_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
 
         let mut player1 = Player::new(1);
-        player1.setup(board.clone());
         let mut player2 = Player::new(2);
-        player2.setup(board.clone());
         let mut winrates = [0.0; 2];
         let max_iter: usize = 1000;
         for _ in 0..max_iter {
             loop {
                 player2.get_dice(dice.clone());
                 player2.my_turn();
-                play_random(&mut player2, ACTIONS);
-                if player2.is_finished() {
+                play_random(&mut player2, &mut board, ACTIONS);
+                if player2.is_finished(&board) {
                     println!("Player 1 wins");
                     winrates[0] += 1.0;
                     break;
@@ -310,16 +293,16 @@ mod multiplayer_tests {
                 player2.drop_dice();
                 player1.get_dice(dice.clone());
                 player1.my_turn();
-                play_random(&mut player1, ACTIONS);
-                if player1.is_finished() {
+                play_random(&mut player1, &mut board, ACTIONS);
+                if player1.is_finished(&board) {
                     println!("Player 2 wins");
                     winrates[1] += 1.0;
                     break;
                 }
                 player1.drop_dice();
             }
-            assert!(player1.is_finished() || player2.is_finished());
-            board.borrow_mut().reset();
+            assert!(player1.is_finished(&board) || player2.is_finished(&board));
+            board.reset();
         }
         println!(
             "Player 1 winrate: {}",
@@ -334,53 +317,49 @@ mod multiplayer_tests {
     #[test]
     #[ignore = "long test"]
     fn player_1_vs_3_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
 
         let mut player1 = Player::new(1);
-        player1.setup(board.clone());
         let mut player3 = Player::new(3);
-        player3.setup(board);
 
         loop {
             player3.get_dice(dice.clone());
             player3.my_turn();
-            play_random(&mut player3, ACTIONS);
-            if player3.is_finished() {
+            play_random(&mut player3, &mut board, ACTIONS);
+            if player3.is_finished(&board) {
                 println!("Player 1 wins");
                 break;
             }
             player3.drop_dice();
             player1.get_dice(dice.clone());
             player1.my_turn();
-            play_random(&mut player1, ACTIONS);
-            if player1.is_finished() {
+            play_random(&mut player1, &mut board, ACTIONS);
+            if player1.is_finished(&board) {
                 println!("Player 3 wins");
                 break;
             }
             player1.drop_dice();
         }
-        assert!(player1.is_finished() || player3.is_finished());
+        assert!(player1.is_finished(&board) || player3.is_finished(&board));
     }
 
     #[test]
     #[ignore = "very long test"]
     fn player_1_vs_3_1000_iteration_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
 
         let mut player1 = Player::new(1);
-        player1.setup(board.clone());
         let mut player3 = Player::new(3);
-        player3.setup(board.clone());
         let mut winrates = [0.0; 2];
         let max_iter: usize = 1000;
         for _ in 0..max_iter {
             loop {
                 player3.get_dice(dice.clone());
                 player3.my_turn();
-                play_random(&mut player3, ACTIONS);
-                if player3.is_finished() {
+                play_random(&mut player3, &mut board, ACTIONS);
+                if player3.is_finished(&board) {
                     println!("Player 1 wins");
                     winrates[0] += 1.0;
                     break;
@@ -388,16 +367,16 @@ mod multiplayer_tests {
                 player3.drop_dice();
                 player1.get_dice(dice.clone());
                 player1.my_turn();
-                play_random(&mut player1, ACTIONS);
-                if player1.is_finished() {
+                play_random(&mut player1, &mut board, ACTIONS);
+                if player1.is_finished(&board) {
                     println!("Player 2 wins");
                     winrates[1] += 1.0;
                     break;
                 }
                 player1.drop_dice();
             }
-            assert!(player1.is_finished() || player3.is_finished());
-            board.borrow_mut().reset();
+            assert!(player1.is_finished(&board) || player3.is_finished(&board));
+            board.reset();
         }
         println!(
             "Player 1 winrate: {}",
@@ -412,53 +391,49 @@ mod multiplayer_tests {
     #[test]
     #[ignore = "long test"]
     fn player_2_vs_3_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
 
         let mut player2 = Player::new(2);
-        player2.setup(board.clone());
         let mut player3 = Player::new(3);
-        player3.setup(board);
 
         loop {
             player3.get_dice(dice.clone());
             player3.my_turn();
-            play_random(&mut player3, ACTIONS);
-            if player3.is_finished() {
+            play_random(&mut player3, &mut board, ACTIONS);
+            if player3.is_finished(&board) {
                 println!("Player 2 wins");
                 break;
             }
             player3.drop_dice();
             player2.get_dice(dice.clone());
             player2.my_turn();
-            play_random(&mut player2, ACTIONS);
-            if player2.is_finished() {
+            play_random(&mut player2, &mut board, ACTIONS);
+            if player2.is_finished(&board) {
                 println!("Player 3 wins");
                 break;
             }
             player2.drop_dice();
         }
-        assert!(player2.is_finished() || player3.is_finished());
+        assert!(player2.is_finished(&board) || player3.is_finished(&board));
     }
 
     #[test]
     #[ignore = "very long test"]
     fn player_2_vs_3_1000_iteration_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
 
         let mut player2 = Player::new(2);
-        player2.setup(board.clone());
         let mut player3 = Player::new(3);
-        player3.setup(board.clone());
         let mut winrates = [0.0; 2];
         let max_iter: usize = 1000;
         for _ in 0..max_iter {
             loop {
                 player3.get_dice(dice.clone());
                 player3.my_turn();
-                play_random(&mut player3, ACTIONS);
-                if player3.is_finished() {
+                play_random(&mut player3, &mut board, ACTIONS);
+                if player3.is_finished(&board) {
                     println!("Player 2 wins");
                     winrates[0] += 1.0;
                     break;
@@ -466,16 +441,16 @@ mod multiplayer_tests {
                 player3.drop_dice();
                 player2.get_dice(dice.clone());
                 player2.my_turn();
-                play_random(&mut player2, ACTIONS);
-                if player2.is_finished() {
+                play_random(&mut player2, &mut board, ACTIONS);
+                if player2.is_finished(&board) {
                     println!("Player 3 wins");
                     winrates[1] += 1.0;
                     break;
                 }
                 player2.drop_dice();
             }
-            assert!(player2.is_finished() || player3.is_finished());
-            board.borrow_mut().reset();
+            assert!(player2.is_finished(&board) || player3.is_finished(&board));
+            board.reset();
         }
         println!(
             "Player 2 winrate: {}",
@@ -490,17 +465,13 @@ mod multiplayer_tests {
     #[test]
     #[ignore = "super long test"]
     fn all_players_1000_iteration_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
 
         let mut player0 = Player::new(0);
-        player0.setup(board.clone());
         let mut player1 = Player::new(1);
-        player1.setup(board.clone());
         let mut player2 = Player::new(2);
-        player2.setup(board.clone());
         let mut player3 = Player::new(3);
-        player3.setup(board.clone());
 
         let mut winrates = [0.0; 4];
         let max_iter: usize = 1000;
@@ -509,8 +480,8 @@ mod multiplayer_tests {
             loop {
                 player0.get_dice(dice.clone());
                 player0.my_turn();
-                play_random(&mut player0, ACTIONS);
-                if player0.is_finished() {
+                play_random(&mut player0, &mut board, ACTIONS);
+                if player0.is_finished(&board) {
                     // println!("Player 0 wins");
                     winrates[0] += 1.0;
                     break;
@@ -518,8 +489,8 @@ mod multiplayer_tests {
                 player0.drop_dice();
                 player1.get_dice(dice.clone());
                 player1.my_turn();
-                play_random(&mut player1, ACTIONS);
-                if player1.is_finished() {
+                play_random(&mut player1, &mut board, ACTIONS);
+                if player1.is_finished(&board) {
                     // println!("Player 1 wins");
                     winrates[1] += 1.0;
                     break;
@@ -527,8 +498,8 @@ mod multiplayer_tests {
                 player1.drop_dice();
                 player2.get_dice(dice.clone());
                 player2.my_turn();
-                play_random(&mut player2, ACTIONS);
-                if player2.is_finished() {
+                play_random(&mut player2, &mut board, ACTIONS);
+                if player2.is_finished(&board) {
                     // println!("Player 2 wins");
                     winrates[2] += 1.0;
                     break;
@@ -536,8 +507,8 @@ mod multiplayer_tests {
                 player2.drop_dice();
                 player3.get_dice(dice.clone());
                 player3.my_turn();
-                play_random(&mut player3, ACTIONS);
-                if player3.is_finished() {
+                play_random(&mut player3, &mut board, ACTIONS);
+                if player3.is_finished(&board) {
                     // println!("Player 3 wins");
                     winrates[3] += 1.0;
                     break;
@@ -545,13 +516,13 @@ mod multiplayer_tests {
                 player3.drop_dice();
             }
             assert!(
-                player0.is_finished()
-                    || player1.is_finished()
-                    || player2.is_finished()
-                    || player3.is_finished()
+                player0.is_finished(&board)
+                    || player1.is_finished(&board)
+                    || player2.is_finished(&board)
+                    || player3.is_finished(&board)
             );
             let start = Instant::now();
-            board.borrow_mut().reset();
+            board.reset();
             let duration = start.elapsed();
             timer += duration.as_secs_f64();
             // println!("Time elapsed in expensive_function() is: {:?}", duration);
@@ -578,12 +549,16 @@ mod multiplayer_tests {
         );
     }
 
-    fn play_random(player: &mut Player, actions: [Act; 10]) {
-        player.roll_dice();
+    fn play_random(player: &mut Player, board: &mut Board, actions: [Act; 10]) {
+        player.roll_dice().unwrap();
         let dice_number = player.get_dice_number();
-        let movesets = player.generate_vector_of_random_actions(actions, dice_number);
+        let movesets = player
+            .generate_vector_of_random_actions(board, actions, dice_number)
+            .unwrap();
         player.action = player.select_random_piece(movesets);
-        player.make_move(player.action.1, dice_number, player.action.0);
+        player
+            .make_move(board, player.action.1, dice_number, player.action.0)
+            .unwrap();
     }
 }
 
@@ -591,24 +566,36 @@ mod multiplayer_tests {
 mod playstyle_tests {
     use super::*;
 
-    fn play_random(player: &mut Player, actions: [Act; 10]) {
-        player.roll_dice();
+    fn play_random(player: &mut Player, board: &mut Board, actions: [Act; 10]) {
+        player.roll_dice().unwrap();
         let dice_number = player.get_dice_number();
-        let movesets = player.generate_vector_of_random_actions(actions, dice_number);
+        let movesets = player
+            .generate_vector_of_random_actions(board, actions, dice_number)
+            .unwrap();
         player.action = player.select_random_piece(movesets);
-        player.make_move(player.action.1, dice_number, player.action.0);
+        player
+            .make_move(board, player.action.1, dice_number, player.action.0)
+            .unwrap();
     }
 
-    fn play_ordered(player: &mut Player, actions: [Act; 10], select_which_piece: Select) {
-        player.roll_dice();
+    fn play_ordered(
+        player: &mut Player,
+        board: &mut Board,
+        actions: [Act; 10],
+        select_which_piece: Select,
+    ) {
+        player.roll_dice().unwrap();
         let dice_number = player.get_dice_number();
-        let movesets =
-            player.generate_vector_of_ordered_actions(actions, dice_number, select_which_piece);
+        let movesets = player
+            .generate_vector_of_ordered_actions(board, actions, dice_number, select_which_piece)
+            .unwrap();
         player.action = movesets
             .first()
             .copied()
             .unwrap_or((Act::Nothing, player.id(), 57));
-        player.make_move(player.action.1, dice_number, player.action.0);
+        player
+            .make_move(board, player.action.1, dice_number, player.action.0)
+            .unwrap();
     }
 
     static ACTIONS: [Act; 10] = [
@@ -639,13 +626,11 @@ mod playstyle_tests {
 
     #[test]
     fn aggressive_player_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
 
         let mut aggressive_player = Player::new(0);
-        aggressive_player.setup(board.clone());
         let mut random_player = Player::new(2);
-        random_player.setup(board.clone());
         let take_nearest_piece = Select::Nearest;
         let mut winrates = [0.0; 2];
         let max_iter: usize = 1000;
@@ -654,8 +639,8 @@ mod playstyle_tests {
             loop {
                 random_player.get_dice(dice.clone());
                 random_player.my_turn();
-                play_random(&mut random_player, ACTIONS);
-                if random_player.is_finished() {
+                play_random(&mut random_player, &mut board, ACTIONS);
+                if random_player.is_finished(&board) {
                     println!("random_player wins");
                     winrates[0] += 1.0;
                     break;
@@ -663,16 +648,21 @@ mod playstyle_tests {
                 random_player.drop_dice();
                 aggressive_player.get_dice(dice.clone());
                 aggressive_player.my_turn();
-                play_ordered(&mut aggressive_player, AGGRO_ACTIONS, take_nearest_piece);
-                if aggressive_player.is_finished() {
+                play_ordered(
+                    &mut aggressive_player,
+                    &mut board,
+                    AGGRO_ACTIONS,
+                    take_nearest_piece,
+                );
+                if aggressive_player.is_finished(&board) {
                     println!("aggressive_player wins");
                     winrates[1] += 1.0;
                     break;
                 }
                 aggressive_player.drop_dice();
             }
-            assert!(aggressive_player.is_finished() || random_player.is_finished());
-            board.borrow_mut().reset();
+            assert!(aggressive_player.is_finished(&board) || random_player.is_finished(&board));
+            board.reset();
         }
         println!(
             "Random player winrate: {}",
@@ -699,13 +689,11 @@ mod playstyle_tests {
 
     #[test]
     fn fast_aggressive_player_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
 
         let mut fast_aggressive_player = Player::new(1);
-        fast_aggressive_player.setup(board.clone());
         let mut random_player = Player::new(3);
-        random_player.setup(board.clone());
         let take_closest = Select::Nearest;
         let mut winrate = [0.0; 2];
         let max_iter: usize = 1000;
@@ -714,8 +702,8 @@ mod playstyle_tests {
             loop {
                 random_player.get_dice(dice.clone());
                 random_player.my_turn();
-                play_random(&mut random_player, ACTIONS);
-                if random_player.is_finished() {
+                play_random(&mut random_player, &mut board, ACTIONS);
+                if random_player.is_finished(&board) {
                     println!("random_player wins");
                     winrate[0] += 1.0;
                     break;
@@ -725,18 +713,21 @@ mod playstyle_tests {
                 fast_aggressive_player.my_turn();
                 play_ordered(
                     &mut fast_aggressive_player,
+                    &mut board,
                     FAST_AGGRO_ACTIONS,
                     take_closest,
                 );
-                if fast_aggressive_player.is_finished() {
+                if fast_aggressive_player.is_finished(&board) {
                     println!("fast aggressive_player wins");
                     winrate[1] += 1.0;
                     break;
                 }
                 fast_aggressive_player.drop_dice();
             }
-            assert!(fast_aggressive_player.is_finished() || random_player.is_finished());
-            board.borrow_mut().reset();
+            assert!(
+                fast_aggressive_player.is_finished(&board) || random_player.is_finished(&board)
+            );
+            board.reset();
         }
         println!(
             "Random player winrate: {}",
@@ -763,13 +754,11 @@ mod playstyle_tests {
 
     #[test]
     fn safest_player_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
 
         let mut safe_player = Player::new(1);
-        safe_player.setup(board.clone());
         let mut random_player = Player::new(3);
-        random_player.setup(board.clone());
         let take_closest = Select::Nearest;
         let mut winrates = [0.0; 2];
         let max_iter: usize = 1000;
@@ -778,8 +767,8 @@ mod playstyle_tests {
             loop {
                 random_player.get_dice(dice.clone());
                 random_player.my_turn();
-                play_random(&mut random_player, ACTIONS);
-                if random_player.is_finished() {
+                play_random(&mut random_player, &mut board, ACTIONS);
+                if random_player.is_finished(&board) {
                     println!("random_player wins");
                     winrates[0] += 1.0;
                     break;
@@ -787,16 +776,16 @@ mod playstyle_tests {
                 random_player.drop_dice();
                 safe_player.get_dice(dice.clone());
                 safe_player.my_turn();
-                play_ordered(&mut safe_player, SAFE_ACTIONS, take_closest);
-                if safe_player.is_finished() {
+                play_ordered(&mut safe_player, &mut board, SAFE_ACTIONS, take_closest);
+                if safe_player.is_finished(&board) {
                     println!("safe player wins");
                     winrates[1] += 1.0;
                     break;
                 }
                 safe_player.drop_dice();
             }
-            assert!(safe_player.is_finished() || random_player.is_finished());
-            board.borrow_mut().reset();
+            assert!(safe_player.is_finished(&board) || random_player.is_finished(&board));
+            board.reset();
         }
         println!(
             "Random player winrate: {}",
@@ -823,13 +812,11 @@ mod playstyle_tests {
 
     #[test]
     fn fastest_player_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
 
         let mut fastest_player = Player::new(1);
-        fastest_player.setup(board.clone());
         let mut random_player = Player::new(2);
-        random_player.setup(board.clone());
 
         let take_closest = Select::Nearest;
         let mut winrates = [0.0; 2];
@@ -839,8 +826,8 @@ mod playstyle_tests {
             loop {
                 random_player.get_dice(dice.clone());
                 random_player.my_turn();
-                play_random(&mut random_player, ACTIONS);
-                if random_player.is_finished() {
+                play_random(&mut random_player, &mut board, ACTIONS);
+                if random_player.is_finished(&board) {
                     println!("random_player wins");
                     winrates[0] += 1.0;
                     break;
@@ -848,16 +835,16 @@ mod playstyle_tests {
                 random_player.drop_dice();
                 fastest_player.get_dice(dice.clone());
                 fastest_player.my_turn();
-                play_ordered(&mut fastest_player, FAST_ACTIONS, take_closest);
-                if fastest_player.is_finished() {
+                play_ordered(&mut fastest_player, &mut board, FAST_ACTIONS, take_closest);
+                if fastest_player.is_finished(&board) {
                     println!("fastest player wins");
                     winrates[1] += 1.0;
                     break;
                 }
                 fastest_player.drop_dice();
             }
-            assert!(fastest_player.is_finished() || random_player.is_finished());
-            board.borrow_mut().reset();
+            assert!(fastest_player.is_finished(&board) || random_player.is_finished(&board));
+            board.reset();
         }
         println!(
             "Random player winrate: {}",
@@ -872,16 +859,12 @@ mod playstyle_tests {
     #[test]
     #[ignore]
     fn final_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let dice = Dice::default();
         let mut fastest_player = Player::new(0);
-        fastest_player.setup(board.clone());
         let mut random_player = Player::new(1);
-        random_player.setup(board.clone());
         let mut fast_aggressive_player = Player::new(2);
-        fast_aggressive_player.setup(board.clone());
         let mut aggressive_player = Player::new(3);
-        aggressive_player.setup(board.clone());
         let take_closest = Select::Nearest;
         let max_iter: usize = 1000;
         let mut winrate: Vec<f32> = vec![0.0; 4];
@@ -890,8 +873,8 @@ mod playstyle_tests {
             loop {
                 fastest_player.get_dice(dice.clone());
                 fastest_player.my_turn();
-                play_ordered(&mut fastest_player, FAST_ACTIONS, take_closest);
-                if fastest_player.is_finished() {
+                play_ordered(&mut fastest_player, &mut board, FAST_ACTIONS, take_closest);
+                if fastest_player.is_finished(&board) {
                     println!("fastest_player wins");
                     winrate[0] += 1.0;
                     break;
@@ -899,8 +882,8 @@ mod playstyle_tests {
                 fastest_player.drop_dice();
                 random_player.get_dice(dice.clone());
                 random_player.my_turn();
-                play_random(&mut random_player, ACTIONS);
-                if random_player.is_finished() {
+                play_random(&mut random_player, &mut board, ACTIONS);
+                if random_player.is_finished(&board) {
                     println!("Random player wins");
                     winrate[1] += 1.0;
                     break;
@@ -910,10 +893,11 @@ mod playstyle_tests {
                 fast_aggressive_player.my_turn();
                 play_ordered(
                     &mut fast_aggressive_player,
+                    &mut board,
                     FAST_AGGRO_ACTIONS,
                     take_closest,
                 );
-                if fast_aggressive_player.is_finished() {
+                if fast_aggressive_player.is_finished(&board) {
                     println!("fast aggressive_player wins");
                     winrate[2] += 1.0;
                     break;
@@ -921,8 +905,13 @@ mod playstyle_tests {
                 fast_aggressive_player.drop_dice();
                 aggressive_player.get_dice(dice.clone());
                 aggressive_player.my_turn();
-                play_ordered(&mut aggressive_player, AGGRO_ACTIONS, take_closest);
-                if aggressive_player.is_finished() {
+                play_ordered(
+                    &mut aggressive_player,
+                    &mut board,
+                    AGGRO_ACTIONS,
+                    take_closest,
+                );
+                if aggressive_player.is_finished(&board) {
                     println!("aggressive_player wins");
                     winrate[3] += 1.0;
                     break;
@@ -930,12 +919,12 @@ mod playstyle_tests {
                 aggressive_player.drop_dice();
             }
             assert!(
-                fastest_player.is_finished()
-                    || random_player.is_finished()
-                    || fast_aggressive_player.is_finished()
-                    || aggressive_player.is_finished()
+                fastest_player.is_finished(&board)
+                    || random_player.is_finished(&board)
+                    || fast_aggressive_player.is_finished(&board)
+                    || aggressive_player.is_finished(&board)
             );
-            board.borrow_mut().reset();
+            board.reset();
         }
         println!("fastest_player winrate: {}", winrate[0] / max_iter as f32);
         println!("Random player winrate: {}", winrate[1] / max_iter as f32);
@@ -0,0 +1,132 @@
+use board::{Board, PlayerID};
+use players::{legal_moves, Act, Move, Player};
+
+#[cfg(test)]
+mod legal_moves_test {
+    use super::*;
+
+    const HOME: [i8; 4] = [-1; 4];
+
+    #[test]
+    fn free_moves_test() {
+        let board = Board::new();
+        let moves = legal_moves(&board, PlayerID::Player0, 6).unwrap();
+        assert_eq!(moves.len(), 4);
+        for (piece_id, legal_move) in moves.iter().enumerate() {
+            assert_eq!(
+                legal_move,
+                &Move {
+                    piece_id: piece_id as i8,
+                    from: -1,
+                    to: 0,
+                    act: Act::Free,
+                    captured: vec![],
+                }
+            );
+        }
+
+        for roll in 1..6 {
+            assert!(legal_moves(&board, PlayerID::Player0, roll)
+                .unwrap()
+                .is_empty());
+        }
+    }
+
+    #[test]
+    fn kill_move_test() {
+        let board = Board::from_positions([[1, -1, -1, -1], [4, -1, -1, -1], HOME, HOME]).unwrap();
+        let moves = legal_moves(&board, PlayerID::Player0, 3).unwrap();
+        assert!(moves.contains(&Move {
+            piece_id: 0,
+            from: 1,
+            to: 4,
+            act: Act::Kill,
+            captured: vec![(1, 0)],
+        }));
+        assert!(moves.iter().all(|legal_move| legal_move.piece_id == 0));
+        assert_eq!(
+            board.piece_positions(),
+            [[1, -1, -1, -1], [4, -1, -1, -1], HOME, HOME]
+        );
+    }
+
+    #[test]
+    fn goal_move_test() {
+        let board = Board::from_positions([[56, -1, -1, 99], HOME, HOME, HOME]).unwrap();
+        let moves = legal_moves(&board, PlayerID::Player0, 1).unwrap();
+        assert_eq!(
+            moves,
+            vec![Move {
+                piece_id: 0,
+                from: 56,
+                to: 99,
+                act: Act::Goal,
+                captured: vec![],
+            }]
+        );
+    }
+
+    #[test]
+    fn player_legal_moves_test() {
+        let mut board = Board::new();
+        let mut player = Player::new(1);
+        player.free_piece(&mut board, 0).unwrap();
+        player.old_position = 7;
+        player.new_position = 7;
+
+        let moves = player.legal_moves(&board, 4).unwrap();
+        assert_eq!(moves, legal_moves(&board, PlayerID::Player1, 4).unwrap());
+        assert!(moves
+            .iter()
+            .any(|legal_move| legal_move.from == 13 && legal_move.to == 17));
+        assert_eq!(player.old_position, 7);
+        assert_eq!(player.new_position, 7);
+        assert_eq!(player.piece(&board, 0).position(), 13);
+    }
+
+    #[test]
+    fn no_duplicate_moves_test() {
+        let board = Board::from_positions([[48, 48, -1, -1], HOME, HOME, HOME]).unwrap();
+        let mut player = Player::new(0);
+        for act in [Act::Move, Act::Leave, Act::Safe] {
+            assert_eq!(player.valid_choices(&board, 0, 4, act), act);
+        }
+
+        let moves = legal_moves(&board, PlayerID::Player0, 4).unwrap();
+        assert_eq!(moves.len(), 2);
+        for (piece_id, legal_move) in moves.iter().enumerate() {
+            assert_eq!(legal_move.piece_id, piece_id as i8);
+            assert_eq!(legal_move.to, 53);
+        }
+    }
+
+    #[test]
+    fn rejected_moves_are_skipped_test() {
+        let board = Board::from_positions([[50, 50, 99, 99], HOME, HOME, HOME]).unwrap();
+        let mut player = Player::new(0);
+        assert_eq!(player.valid_choices(&board, 0, 6, Act::Leave), Act::Leave);
+
+        let moves = legal_moves(&board, PlayerID::Player0, 6).unwrap();
+        assert_eq!(moves.len(), 2);
+        assert!(moves
+            .iter()
+            .all(|legal_move| legal_move.act == Act::Goal && legal_move.to == 99));
+    }
+
+    #[test]
+    fn own_piece_kill_test() {
+        let board = Board::from_positions([[3, 5, -1, -1], [11, -1, -1, -1], HOME, HOME]).unwrap();
+        let mut player = Player::new(0);
+        assert_eq!(player.valid_choices(&board, 0, 2, Act::Kill), Act::Nothing);
+
+        let moves = legal_moves(&board, PlayerID::Player0, 2).unwrap();
+        assert!(!moves.is_empty());
+        for legal_move in &moves {
+            assert_ne!(legal_move.act, Act::Kill);
+            assert_eq!(
+                player.valid_choices(&board, legal_move.piece_id, 2, legal_move.act),
+                legal_move.act
+            );
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use board::{Board, BoardGeometry};
+use players::{Act, Player};
+
+#[cfg(test)]
+mod six_player_tests {
+    use super::*;
+
+    const HOME: [i8; 4] = [-1; 4];
+
+    fn six_player_board(positions: &[[i8; 4]]) -> Board {
+        Board::from_positions_with_geometry(BoardGeometry::six_player(), positions).unwrap()
+    }
+
+    #[test]
+    fn free_piece_test() {
+        let mut board = six_player_board(&[HOME; 6]);
+        let mut player = Player::new(5);
+        assert_eq!(player.try_to_free(&board, 0, 6), Act::Free);
+        player.make_move(&mut board, 0, 6, Act::Free).unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 65);
+        assert_eq!(board.outside(65).pieces.len(), 1);
+        assert_eq!(player.get_heuristics(&board, 0).unwrap(), 82);
+        assert_eq!(player.get_heuristics(&board, 1).unwrap(), 83);
+    }
+
+    #[test]
+    fn wrap_around_test() {
+        let mut board = six_player_board(&[HOME, HOME, HOME, HOME, HOME, [75, -1, -1, -1]]);
+        let mut player = Player::new(5);
+        assert_eq!(player.try_to_move(&board, 0, 5), Act::Move);
+        player.make_move(&mut board, 0, 5, Act::Move).unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 2);
+        assert_eq!(board.outside(2).pieces.len(), 1);
+    }
+
+    #[test]
+    fn starjump_test() {
+        let mut board = six_player_board(&[HOME, HOME, HOME, HOME, HOME, [71, -1, -1, -1]]);
+        let mut player = Player::new(5);
+        assert_eq!(player.try_to_starjump(&board, 0, 5), Act::Starjump);
+        player.make_move(&mut board, 0, 5, Act::Starjump).unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 5);
+    }
+
+    #[test]
+    fn enter_home_column_test() {
+        let mut board = six_player_board(&[HOME, HOME, HOME, HOME, [48, -1, -1, -1], HOME]);
+        let mut player = Player::new(4);
+        assert_eq!(player.try_to_safe(&board, 0, 4), Act::Safe);
+        player.make_move(&mut board, 0, 4, Act::Safe).unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 99);
+        assert!(!player.piece(&board, 0).is_goal());
+        assert_eq!(board.inside(99).pieces.len(), 1);
+        assert_eq!(player.get_heuristics(&board, 0).unwrap(), 4);
+    }
+
+    #[test]
+    fn goal_test() {
+        let mut board = six_player_board(&[HOME, HOME, HOME, HOME, HOME, [105, -1, -1, -1]]);
+        let mut player = Player::new(5);
+        assert_eq!(player.try_to_win(&board, 0, 3), Act::Goal);
+        player.make_move(&mut board, 0, 3, Act::Goal).unwrap();
+        assert!(player.piece(&board, 0).is_goal());
+        assert_eq!(player.piece(&board, 0).position(), i8::MAX);
+        assert_eq!(board.goal(5).pieces.len(), 1);
+    }
+
+    #[test]
+    fn overshoot_test() {
+        let mut board = six_player_board(&[HOME, HOME, HOME, HOME, HOME, [106, -1, -1, -1]]);
+        let mut player = Player::new(5);
+        player.make_move(&mut board, 0, 4, Act::Move).unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 106);
+    }
+}
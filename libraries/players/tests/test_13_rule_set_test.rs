@@ -0,0 +1,195 @@
+use board::{Board, PlayerID};
+use dice::ScriptedDice;
+use errors::LudoError;
+use players::{legal_moves_with_rules, Act, Overshoot, Player, RuleSet};
+
+#[cfg(test)]
+mod rule_set_tests {
+    use super::*;
+
+    const HOME: [i8; 4] = [-1; 4];
+
+    fn setup_player(positions: [[i8; 4]; 4], rules: RuleSet) -> (Player, Board) {
+        let mut player = Player::new(0);
+        player.set_rules(rules);
+        (player, Board::from_positions(positions).unwrap())
+    }
+
+    fn roll(player: &mut Player, value: i8) {
+        player.get_dice(ScriptedDice::new(vec![value]));
+        player.roll_dice().unwrap();
+    }
+
+    #[test]
+    fn default_rules_test() {
+        let rules = RuleSet::default();
+        assert_eq!(rules, RuleSet::standard());
+        assert!(rules.six_grants_extra_turn);
+        assert_eq!(rules.first_round_attempts, 3);
+        assert!(rules.star_jumping);
+        assert!(rules.globe_safety);
+        assert!(rules.blockades);
+        assert_eq!(rules.overshoot, Overshoot::Bounce);
+        assert!(rules.capture_grants_extra_roll);
+        assert!(!rules.triple_six_forfeits_turn);
+        assert_eq!(Player::new(0).rules(), &rules);
+    }
+
+    #[test]
+    fn no_star_jumping_test() {
+        let positions = [[0, -1, -1, -1], HOME, HOME, HOME];
+        let (mut player, mut board) = setup_player(positions, RuleSet::standard());
+        assert_eq!(
+            player.valid_choices(&board, 0, 5, Act::Starjump),
+            Act::Starjump
+        );
+        player.make_move(&mut board, 0, 5, Act::Starjump).unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 11);
+
+        let rules = RuleSet {
+            star_jumping: false,
+            ..RuleSet::standard()
+        };
+        let (mut player, mut board) = setup_player(positions, rules);
+        assert_eq!(
+            player.valid_choices(&board, 0, 5, Act::Starjump),
+            Act::Nothing
+        );
+        assert_eq!(player.valid_choices(&board, 0, 5, Act::Move), Act::Move);
+        player.make_move(&mut board, 0, 5, Act::Move).unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 5);
+    }
+
+    #[test]
+    fn no_star_jump_into_goal_test() {
+        let positions = [[47, -1, -1, -1], HOME, HOME, HOME];
+        let (mut player, board) = setup_player(positions, RuleSet::standard());
+        assert_eq!(player.valid_choices(&board, 0, 3, Act::Goal), Act::Goal);
+
+        let rules = RuleSet {
+            star_jumping: false,
+            ..RuleSet::standard()
+        };
+        let (mut player, mut board) = setup_player(positions, rules);
+        assert_eq!(player.valid_choices(&board, 0, 3, Act::Goal), Act::Nothing);
+        player.make_move(&mut board, 0, 3, Act::Move).unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 50);
+    }
+
+    #[test]
+    fn no_globe_safety_test() {
+        let positions = [[4, -1, -1, -1], [8, -1, -1, -1], HOME, HOME];
+        let (mut player, board) = setup_player(positions, RuleSet::standard());
+        assert_eq!(player.valid_choices(&board, 0, 4, Act::Kill), Act::Nothing);
+        assert_eq!(player.valid_choices(&board, 0, 4, Act::Die), Act::Die);
+
+        let rules = RuleSet {
+            globe_safety: false,
+            ..RuleSet::standard()
+        };
+        let (mut player, mut board) = setup_player(positions, rules);
+        assert_eq!(player.valid_choices(&board, 0, 4, Act::Die), Act::Nothing);
+        assert_eq!(player.valid_choices(&board, 0, 4, Act::Kill), Act::Kill);
+        player.make_move(&mut board, 0, 4, Act::Kill).unwrap();
+        let positions = board.piece_positions();
+        assert_eq!(positions[0][0], 8);
+        assert_eq!(positions[1][0], -1);
+    }
+
+    #[test]
+    fn no_blockades_test() {
+        let positions = [[4, -1, -1, -1], [10, 10, -1, -1], HOME, HOME];
+        let (mut player, board) = setup_player(positions, RuleSet::standard());
+        assert_eq!(player.valid_choices(&board, 0, 6, Act::Kill), Act::Nothing);
+        assert_eq!(player.valid_choices(&board, 0, 6, Act::Die), Act::Die);
+
+        let rules = RuleSet {
+            blockades: false,
+            ..RuleSet::standard()
+        };
+        let (mut player, mut board) = setup_player(positions, rules);
+        assert_eq!(player.valid_choices(&board, 0, 6, Act::Die), Act::Nothing);
+        assert_eq!(player.valid_choices(&board, 0, 6, Act::Kill), Act::Kill);
+        player.make_move(&mut board, 0, 6, Act::Kill).unwrap();
+        let positions = board.piece_positions();
+        assert_eq!(positions[0][0], 10);
+        assert_eq!(positions[1], HOME);
+    }
+
+    #[test]
+    fn bounce_on_overshoot_test() {
+        let positions = [[55, -1, -1, -1], HOME, HOME, HOME];
+        let (mut player, mut board) = setup_player(positions, RuleSet::standard());
+        assert_eq!(player.valid_choices(&board, 0, 4, Act::Move), Act::Move);
+        player.make_move(&mut board, 0, 4, Act::Move).unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 55);
+    }
+
+    #[test]
+    fn exact_goal_test() {
+        let positions = [[55, -1, -1, -1], HOME, HOME, HOME];
+        let (mut player, mut board) = setup_player(positions, RuleSet::exact_goal());
+        for act in [Act::Move, Act::Goal, Act::Leave, Act::Safe] {
+            assert_eq!(player.valid_choices(&board, 0, 4, act), Act::Nothing);
+        }
+        assert!(matches!(
+            player.make_move(&mut board, 0, 4, Act::Move),
+            Err(LudoError::IllegalAction(_))
+        ));
+        assert_eq!(player.valid_choices(&board, 0, 2, Act::Goal), Act::Goal);
+
+        let moves = legal_moves_with_rules(&board, PlayerID::Player0, 4, &RuleSet::exact_goal());
+        assert!(moves.unwrap().is_empty());
+    }
+
+    #[test]
+    fn no_bounce_test() {
+        let positions = [[55, -1, -1, -1], HOME, HOME, HOME];
+        let (mut player, mut board) = setup_player(positions, RuleSet::no_bounce());
+        assert_eq!(player.valid_choices(&board, 0, 4, Act::Move), Act::Nothing);
+        assert_eq!(player.valid_choices(&board, 0, 4, Act::Goal), Act::Goal);
+        player.make_move(&mut board, 0, 4, Act::Goal).unwrap();
+        assert!(player.piece(&board, 0).is_goal());
+        assert!(!player.is_finished(&board));
+    }
+
+    #[test]
+    fn six_extra_turn_test() {
+        let positions = [[20, -1, -1, -1], HOME, HOME, HOME];
+        let (mut player, mut board) = setup_player(positions, RuleSet::standard());
+        roll(&mut player, 6);
+        player.make_move(&mut board, 0, 6, Act::Move).unwrap();
+        assert!(player.is_player_turn());
+
+        let rules = RuleSet {
+            six_grants_extra_turn: false,
+            ..RuleSet::standard()
+        };
+        let (mut player, mut board) = setup_player(positions, rules);
+        roll(&mut player, 6);
+        player.make_move(&mut board, 0, 6, Act::Move).unwrap();
+        assert!(!player.is_player_turn());
+        roll(&mut player, 6);
+        player.make_move(&mut board, 1, 6, Act::Free).unwrap();
+        assert!(!player.is_player_turn());
+    }
+
+    #[test]
+    fn capture_extra_roll_test() {
+        let positions = [[20, -1, -1, -1], [23, -1, -1, -1], HOME, HOME];
+        let (mut player, mut board) = setup_player(positions, RuleSet::standard());
+        roll(&mut player, 3);
+        player.make_move(&mut board, 0, 3, Act::Kill).unwrap();
+        assert!(player.is_player_turn());
+
+        let rules = RuleSet {
+            capture_grants_extra_roll: false,
+            ..RuleSet::standard()
+        };
+        let (mut player, mut board) = setup_player(positions, rules);
+        roll(&mut player, 3);
+        player.make_move(&mut board, 0, 3, Act::Kill).unwrap();
+        assert!(!player.is_player_turn());
+        assert_eq!(board.piece_positions()[1][0], -1);
+    }
+}
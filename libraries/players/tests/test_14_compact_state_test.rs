@@ -0,0 +1,106 @@
+use board::{Board, BoardGeometry, CompactState, PlayerID};
+use dice::ScriptedDice;
+use players::{compact_legal_moves, legal_moves_with_rules, Act, Player, RuleSet};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+#[cfg(test)]
+mod compact_state_tests {
+    use super::*;
+
+    const HOME: [i8; 4] = [-1; 4];
+
+    fn play(player: &mut Player, board: &mut Board, piece_id: i8, roll: i8, act: Act) -> bool {
+        player.get_dice(ScriptedDice::new(vec![roll]));
+        player.roll_dice().unwrap();
+        player.my_turn();
+        player.make_move(board, piece_id, roll, act).unwrap();
+        player.is_player_turn()
+    }
+
+    #[test]
+    fn compact_moves_match_board_test() {
+        let board =
+            Board::from_positions([[20, -1, -1, -1], [23, -1, -1, -1], HOME, HOME]).unwrap();
+        let mut state = CompactState::from_board(&board).unwrap();
+        state.roll = 3;
+        let rules = RuleSet::standard();
+        let moves = compact_legal_moves(&state, &rules).unwrap();
+        let board_moves = legal_moves_with_rules(&board, PlayerID::Player0, 3, &rules).unwrap();
+        assert_eq!(moves.len(), board_moves.len());
+        let kill = moves.iter().find(|m| m.captured != 0).unwrap();
+        assert_eq!(kill.to, 23);
+        assert!(kill.extra_turn);
+        assert_eq!(kill.captures().collect::<Vec<_>>(), vec![(1, 0)]);
+
+        let rules = RuleSet {
+            capture_grants_extra_roll: false,
+            ..RuleSet::standard()
+        };
+        let moves = compact_legal_moves(&state, &rules).unwrap();
+        assert!(!moves.iter().any(|m| m.extra_turn));
+    }
+
+    #[test]
+    fn three_player_moves_test() {
+        let positions = [[20, 12, -1, -1], [-1; 4], [23, 25, -1, -1]];
+        let mut board =
+            Board::from_positions_with_geometry(BoardGeometry::new(3), &positions).unwrap();
+        board.set_seats(&[0, 2]).unwrap();
+        board.set_roll(5);
+        let state = CompactState::from_board(&board).unwrap();
+        let rules = RuleSet::standard();
+        let moves = compact_legal_moves(&state, &rules).unwrap();
+        let board_moves = legal_moves_with_rules(&board, PlayerID::Player0, 5, &rules).unwrap();
+        let expected: Vec<_> = board_moves
+            .iter()
+            .map(|legal_move| legal_move.to_compact(5, &rules))
+            .collect();
+        assert_eq!(moves, expected);
+        assert!(moves.iter().any(|m| m.captures().eq([(2, 1)])));
+
+        let mut unseated = state;
+        unseated.turn = 1;
+        assert!(compact_legal_moves(&unseated, &rules).is_err());
+    }
+
+    #[test]
+    fn random_playout_test() {
+        let rules = RuleSet::standard();
+        let mut rng = StdRng::seed_from_u64(11);
+        for _ in 0..5 {
+            let mut board = Board::new();
+            let mut state = CompactState::new();
+            let mut players: Vec<Player> = (0..4).map(Player::new).collect();
+            for _ in 0..400 {
+                let roll = rng.gen_range(1..=6);
+                let player_id = state.turn as i8;
+                board.set_side_to_move(player_id);
+                assert_eq!(board.zobrist(), state.zobrist());
+                let board_moves = players[player_id as usize]
+                    .legal_moves(&board, roll)
+                    .unwrap();
+                state.roll = roll as u8;
+                let moves = compact_legal_moves(&state, &rules).unwrap();
+                assert_eq!(moves.len(), board_moves.len());
+                if moves.is_empty() {
+                    state.next_turn();
+                    continue;
+                }
+                let choice = rng.gen_range(0..moves.len());
+                let board_move = &board_moves[choice];
+                let extra_turn = play(
+                    &mut players[player_id as usize],
+                    &mut board,
+                    board_move.piece_id,
+                    roll,
+                    board_move.act,
+                );
+                assert_eq!(moves[choice].extra_turn, extra_turn);
+                state.apply(moves[choice]);
+                assert_eq!(state.positions().to_vec(), board.piece_positions());
+                assert_eq!(board.zobrist(), board.compute_zobrist());
+            }
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use board::Board;
+use dice::ScriptedDice;
+use players::{Act, Player, RuleSet};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+#[cfg(test)]
+mod move_undo_tests {
+    use super::*;
+
+    const HOME: [i8; 4] = [-1; 4];
+
+    fn roll(player: &mut Player, value: i8) {
+        player.get_dice(ScriptedDice::new(vec![value]));
+        player.roll_dice().unwrap();
+        player.my_turn();
+    }
+
+    #[test]
+    fn undo_capture_test() {
+        let mut board =
+            Board::from_positions([[20, -1, -1, -1], [23, 23, -1, -1], HOME, HOME]).unwrap();
+        board.move_into_home(1, 1, 23).unwrap();
+        board.piece_mut(1, 1).dead();
+        let before = board.clone();
+        let mut player = Player::new(0);
+        roll(&mut player, 3);
+
+        let undo = player.apply_move(&mut board, 0, 3, Act::Kill).unwrap();
+        assert_eq!(undo.played.from, 20);
+        assert_eq!(undo.played.to, 23);
+        assert_eq!(undo.played.captured, vec![(1, 0)]);
+        assert!(board.piece(1, 0).is_home());
+
+        player.unmake_move(&mut board, &undo);
+        assert_eq!(board, before);
+        assert_eq!(board.zobrist(), before.zobrist());
+        assert!(player.is_player_turn());
+    }
+
+    #[test]
+    fn undo_star_jump_test() {
+        let mut board = Board::from_positions([[0, -1, -1, -1], HOME, HOME, HOME]).unwrap();
+        let before = board.clone();
+        let mut player = Player::new(0);
+        roll(&mut player, 5);
+
+        let undo = player.apply_move(&mut board, 0, 5, Act::Starjump).unwrap();
+        assert!(undo.star_jump);
+        assert_eq!(undo.played.to, 11);
+
+        player.unmake_move(&mut board, &undo);
+        assert_eq!(board, before);
+    }
+
+    #[test]
+    fn undo_bounce_test() {
+        let mut board = Board::from_positions([[55, 53, -1, -1], HOME, HOME, HOME]).unwrap();
+        let before = board.clone();
+        let mut player = Player::new(0);
+        roll(&mut player, 5);
+
+        let undo = player.apply_move(&mut board, 1, 5, Act::Move).unwrap();
+        assert!(undo.bounced);
+        assert!(!undo.star_jump);
+        assert_eq!(board.piece(0, 1).position(), 56);
+
+        player.unmake_move(&mut board, &undo);
+        assert_eq!(board, before);
+    }
+
+    #[test]
+    fn redo_test() {
+        let mut board = Board::new();
+        let mut player = Player::new(0);
+        roll(&mut player, 6);
+
+        let undo = player.apply_move(&mut board, 0, 6, Act::Free).unwrap();
+        let after = board.clone();
+        player.unmake_move(&mut board, &undo);
+        assert_eq!(board, Board::new());
+
+        let redo = player.redo_move(&mut board, &undo).unwrap();
+        assert_eq!(board, after);
+        assert_eq!(redo, undo);
+    }
+
+    #[test]
+    fn failed_move_leaves_board_untouched_test() {
+        let mut board = Board::from_positions([[55, -1, -1, -1], HOME, HOME, HOME]).unwrap();
+        let before = board.clone();
+        let mut player = Player::new(0);
+        player.set_rules(RuleSet::exact_goal());
+        roll(&mut player, 4);
+
+        assert!(player.apply_move(&mut board, 0, 4, Act::Move).is_err());
+        assert_eq!(board, before);
+    }
+
+    #[test]
+    fn random_undo_test() {
+        let mut rng = StdRng::seed_from_u64(15);
+        for _ in 0..5 {
+            let mut board = Board::new();
+            let mut players: Vec<Player> = (0..4).map(Player::new).collect();
+            let mut history = Vec::new();
+            for turn in 0..300 {
+                let player_id = turn % 4;
+                let dice_number = rng.gen_range(1..=6);
+                let player = &mut players[player_id];
+                let moves = player.legal_moves(&board, dice_number).unwrap();
+                if moves.is_empty() {
+                    continue;
+                }
+                let choice = &moves[rng.gen_range(0..moves.len())];
+                roll(player, dice_number);
+
+                let before = board.clone();
+                let undo = player
+                    .apply_move(&mut board, choice.piece_id, dice_number, choice.act)
+                    .unwrap();
+                assert_eq!(undo.played.from, choice.from);
+                assert_eq!(undo.played.to, choice.to);
+                assert_eq!(undo.played.captured, choice.captured);
+                assert_eq!(board.validate(), Ok(()));
+                let after = board.clone();
+
+                player.unmake_move(&mut board, &undo);
+                assert_eq!(board, before);
+                player.redo_move(&mut board, &undo).unwrap();
+                assert_eq!(board, after);
+                history.push((player_id, undo, before));
+            }
+            while let Some((player_id, undo, before)) = history.pop() {
+                players[player_id].unmake_move(&mut board, &undo);
+                assert_eq!(board, before);
+            }
+            assert_eq!(board, Board::new());
+        }
+    }
+}
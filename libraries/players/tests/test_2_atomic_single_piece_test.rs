@@ -2,8 +2,6 @@ use board::Board;
 use pieces::Color;
 use players::Player;
 
-use std::{cell::RefCell, rc::Rc};
-
 mod atomic_single_piece_test {
 
     use super::*;
@@ -14,348 +12,258 @@ mod atomic_single_piece_test {
         let piece_id = 0;
         let new_position = 0;
 
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
-        let piece = player.piece(piece_id);
-
-        assert!(piece.borrow_mut().is_home());
-        assert!(player
-            .board()
-            .borrow_mut()
-            .home(PLAYER_ID)
-            .piece(0)
-            .borrow_mut()
+
+        assert!(player.piece(&board, piece_id).is_home());
+        assert!(board
+            .piece_at(board.home[PLAYER_ID as usize].piece(0))
             .is_home());
-        assert_eq!(player.board().borrow_mut().home(PLAYER_ID).pieces.len(), 4);
+        assert_eq!(board.home(PLAYER_ID).pieces.len(), 4);
 
-        player.free_piece(piece_id);
-        assert!(!piece.borrow_mut().is_home());
-        assert!(piece.borrow_mut().is_free());
-        assert_eq!(piece.borrow_mut().position(), 0);
+        player.free_piece(&mut board, piece_id).unwrap();
+        assert!(!player.piece(&board, piece_id).is_home());
+        assert!(player.piece(&board, piece_id).is_free());
+        assert_eq!(player.piece(&board, piece_id).position(), 0);
 
-        assert_eq!(player.board().borrow_mut().home(PLAYER_ID).pieces.len(), 3);
+        assert_eq!(board.home(PLAYER_ID).pieces.len(), 3);
+        assert_eq!(board.outside(new_position).pieces.len(), 1);
         assert_eq!(
-            player
-                .board()
-                .borrow_mut()
-                .outside(new_position)
-                .pieces
-                .len(),
-            1
-        );
-        assert_eq!(
-            player.board().borrow_mut().outside(new_position).player_id,
+            board.outside(new_position).player_id,
             Some(board::PlayerID::Player0)
         );
         assert_eq!(
-            player
-                .board()
-                .borrow_mut()
-                .outside(new_position)
-                .piece(piece_id)
-                .borrow()
+            board
+                .piece_at(board.square(new_position).piece(piece_id))
                 .color(),
             Color::Green
         );
 
-        assert!(!player
-            .board()
-            .borrow_mut()
-            .outside(new_position)
-            .piece(piece_id)
-            .borrow_mut()
+        assert!(!board
+            .piece_at(board.square(new_position).piece(piece_id))
             .is_home());
-        assert!(player
-            .board()
-            .borrow_mut()
-            .outside(new_position)
-            .piece(piece_id)
-            .borrow_mut()
+        assert!(board
+            .piece_at(board.square(new_position).piece(piece_id))
             .is_free());
     }
 
     #[test]
     fn update_piece_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
         let piece_id = 0;
-        player.free_piece(piece_id);
+        player.free_piece(&mut board, piece_id).unwrap();
 
         let position = 4;
-        player.update_outside(piece_id, 0, position);
-        let piece = player.piece(piece_id);
-        assert_eq!(piece.borrow_mut().position(), position);
-        assert_eq!(
-            player.board().borrow_mut().outside(position).pieces.len(),
-            1
-        );
+        player
+            .update_outside(&mut board, piece_id, 0, position)
+            .unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), position);
+        assert_eq!(board.outside(position).pieces.len(), 1);
         assert_eq!(
-            player.board().borrow_mut().outside(position).player_id,
+            board.outside(position).player_id,
             Some(board::PlayerID::Player0)
         );
         assert_eq!(
-            player
-                .board()
-                .borrow_mut()
-                .outside(position)
-                .piece(piece_id)
-                .borrow()
+            board
+                .piece_at(board.square(position).piece(piece_id))
                 .color(),
             Color::Green
         );
-        assert!(piece.borrow_mut().is_free());
-        assert!(player
-            .board()
-            .borrow_mut()
-            .outside(position)
-            .piece(piece_id)
-            .borrow_mut()
+        assert!(player.piece(&board, piece_id).is_free());
+        assert!(board
+            .piece_at(board.square(position).piece(piece_id))
             .is_free());
         assert_eq!(
-            player
-                .board()
-                .borrow_mut()
-                .outside(position)
-                .piece(piece_id)
-                .borrow_mut()
+            board
+                .piece_at(board.square(position).piece(piece_id))
                 .position(),
             position
         );
     }
     #[test]
     fn return_home_or_death_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
         let piece_id = 0;
 
-        player.free_piece(piece_id);
-        player.update_outside(piece_id, 0, 1);
-
-        let piece = player.piece(piece_id);
-        assert_eq!(piece.borrow_mut().position(), 1);
-        assert_eq!(player.board().borrow_mut().outside(1).pieces.len(), 1);
+        player.free_piece(&mut board, piece_id).unwrap();
+        player.update_outside(&mut board, piece_id, 0, 1).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 1);
+        assert_eq!(board.outside(1).pieces.len(), 1);
 
-        player.die(piece_id);
+        player.die(&mut board, piece_id).unwrap();
 
-        assert_eq!(piece.borrow_mut().position(), -1);
-        assert_eq!(player.board().borrow_mut().outside(1).pieces.len(), 0);
-        assert_eq!(player.board().borrow_mut().home(PLAYER_ID).pieces.len(), 4);
+        assert_eq!(player.piece(&board, piece_id).position(), -1);
+        assert_eq!(board.outside(1).pieces.len(), 0);
+        assert_eq!(board.home(PLAYER_ID).pieces.len(), 4);
     }
     #[test]
     fn move_piece_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
         let piece_id = 0;
 
         for position in 1..=6 {
-            player.free_piece(0);
-            player.move_piece(0, position);
-
-            let piece = player.piece(piece_id);
-            assert_eq!(piece.borrow_mut().position(), position);
-            assert_eq!(
-                player.board().borrow_mut().outside(position).pieces.len(),
-                1
-            );
+            player.free_piece(&mut board, 0).unwrap();
+            player.move_piece(&mut board, 0, position).unwrap();
+            assert_eq!(player.piece(&board, piece_id).position(), position);
+            assert_eq!(board.outside(position).pieces.len(), 1);
             assert_eq!(
-                player.board().borrow_mut().outside(position).player_id,
+                board.outside(position).player_id,
                 Some(board::PlayerID::Player0)
             );
             assert_eq!(
-                player
-                    .board()
-                    .borrow_mut()
-                    .outside(position)
-                    .piece(piece_id)
-                    .borrow()
+                board
+                    .piece_at(board.square(position).piece(piece_id))
                     .color(),
                 Color::Green
             );
-            assert!(piece.borrow_mut().is_free());
-            assert!(player
-                .board()
-                .borrow_mut()
-                .outside(position)
-                .piece(piece_id)
-                .borrow_mut()
+            assert!(player.piece(&board, piece_id).is_free());
+            assert!(board
+                .piece_at(board.square(position).piece(piece_id))
                 .is_free());
             assert_eq!(
-                player
-                    .board()
-                    .borrow_mut()
-                    .outside(position)
-                    .piece(piece_id)
-                    .borrow_mut()
+                board
+                    .piece_at(board.square(position).piece(piece_id))
                     .position(),
                 position
             );
-            player.die(piece_id);
+            player.die(&mut board, piece_id).unwrap();
         }
     }
 
     #[test]
     fn update_piece_state_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         let piece_id: i8 = 0;
         let mut next_position: i8 = 4;
         print!("next_position: {}", next_position);
 
-        player.free_piece(piece_id);
-        player.move_piece(piece_id, next_position);
-
-        let piece = player.piece(piece_id);
-        assert_eq!(piece.borrow_mut().position(), next_position);
-        assert!(piece.borrow_mut().is_free());
-        assert!(player
-            .board()
-            .borrow_mut()
-            .outside(next_position)
-            .piece(piece_id)
-            .borrow_mut()
+        player.free_piece(&mut board, piece_id).unwrap();
+        player
+            .move_piece(&mut board, piece_id, next_position)
+            .unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), next_position);
+        assert!(player.piece(&board, piece_id).is_free());
+        assert!(board
+            .piece_at(board.square(next_position).piece(piece_id))
             .is_free());
         assert_eq!(
-            player
-                .board()
-                .borrow_mut()
-                .outside(next_position)
-                .piece(piece_id)
-                .borrow_mut()
+            board
+                .piece_at(board.square(next_position).piece(piece_id))
                 .position(),
             next_position
         );
-        assert_eq!(
-            player
-                .board()
-                .borrow_mut()
-                .outside(next_position)
-                .pieces
-                .len(),
-            1
-        );
-        assert_eq!(player.board().borrow_mut().outside(0).pieces.len(), 0);
+        assert_eq!(board.outside(next_position).pieces.len(), 1);
+        assert_eq!(board.outside(0).pieces.len(), 0);
 
         next_position = 2;
-        player.move_piece(0, next_position);
-        assert_eq!(player.board().borrow_mut().outside(6).pieces.len(), 1);
-        assert_eq!(player.board().borrow_mut().outside(4).pieces.len(), 0);
+        player.move_piece(&mut board, 0, next_position).unwrap();
+        assert_eq!(board.outside(6).pieces.len(), 1);
+        assert_eq!(board.outside(4).pieces.len(), 0);
     }
 
     #[test]
     fn valid_move_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         let piece_id = 0;
-        let piece_move = player.valid_moves(piece_id, 1);
+        let piece_move = player.valid_moves(&board, piece_id, 1);
         assert!(!piece_move);
 
-        let piece_move = player.valid_moves(piece_id, 7);
+        let piece_move = player.valid_moves(&board, piece_id, 7);
         assert!(!piece_move);
 
-        let piece_move = player.valid_moves(piece_id, 6);
+        let piece_move = player.valid_moves(&board, piece_id, 6);
         assert!(piece_move);
-        player.free_piece(piece_id);
+        player.free_piece(&mut board, piece_id).unwrap();
 
-        let piece_move = player.valid_moves(piece_id, 6);
+        let piece_move = player.valid_moves(&board, piece_id, 6);
         assert!(piece_move);
 
         let piece_id = 4;
-        let piece_move = player.valid_moves(piece_id, 1);
+        let piece_move = player.valid_moves(&board, piece_id, 1);
         assert!(!piece_move);
     }
 
     #[test]
     fn enter_inside_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
         let piece_id = 0;
 
-        player.free_piece(piece_id);
-        player.update_outside(piece_id, 0, 50);
-        assert_eq!(player.piece(piece_id).borrow_mut().position(), 50);
-        assert!(player.piece(piece_id).borrow_mut().is_free());
-        assert_eq!(player.board().borrow_mut().outside(50).pieces.len(), 1);
+        player.free_piece(&mut board, piece_id).unwrap();
+        player.update_outside(&mut board, piece_id, 0, 50).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 50);
+        assert!(player.piece(&board, piece_id).is_free());
+        assert_eq!(board.outside(50).pieces.len(), 1);
 
-        player.enter_inside(piece_id, 50, 52);
-        assert_eq!(player.piece(piece_id).borrow_mut().position(), 52);
-        assert!(player.piece(piece_id).borrow_mut().is_free());
-        assert_eq!(player.board().borrow_mut().inside(52).pieces.len(), 1);
+        player.enter_inside(&mut board, piece_id, 50, 52).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 52);
+        assert!(player.piece(&board, piece_id).is_free());
+        assert_eq!(board.inside(52).pieces.len(), 1);
     }
 
     #[test]
     fn update_inside_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
         let piece_id = 0;
 
-        player.free_piece(piece_id);
-        player.update_outside(piece_id, 0, 50);
-        player.enter_inside(piece_id, 50, 52);
-        assert_eq!(player.piece(piece_id).borrow_mut().position(), 52);
-        assert!(player.piece(piece_id).borrow_mut().is_free());
-        assert_eq!(player.board().borrow_mut().inside(52).pieces.len(), 1);
+        player.free_piece(&mut board, piece_id).unwrap();
+        player.update_outside(&mut board, piece_id, 0, 50).unwrap();
+        player.enter_inside(&mut board, piece_id, 50, 52).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 52);
+        assert!(player.piece(&board, piece_id).is_free());
+        assert_eq!(board.inside(52).pieces.len(), 1);
 
-        player.update_inside(piece_id, 52, 54);
-        assert_eq!(player.piece(piece_id).borrow_mut().position(), 54);
-        assert_eq!(player.board().borrow_mut().inside(54).pieces.len(), 1);
+        player.update_inside(&mut board, piece_id, 52, 54).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 54);
+        assert_eq!(board.inside(54).pieces.len(), 1);
     }
 
     #[test]
     fn move_back_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
         let piece_id = 0;
 
-        player.free_piece(piece_id);
-        player.update_outside(piece_id, 0, 50);
-        player.enter_inside(piece_id, 50, 52);
-        player.update_inside(piece_id, 52, 54);
-        assert_eq!(player.piece(piece_id).borrow_mut().position(), 54);
-        assert_eq!(player.board().borrow_mut().inside(54).pieces.len(), 1);
+        player.free_piece(&mut board, piece_id).unwrap();
+        player.update_outside(&mut board, piece_id, 0, 50).unwrap();
+        player.enter_inside(&mut board, piece_id, 50, 52).unwrap();
+        player.update_inside(&mut board, piece_id, 52, 54).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 54);
+        assert_eq!(board.inside(54).pieces.len(), 1);
 
-        player.update_inside(piece_id, 54, 58);
-        assert_eq!(player.piece(piece_id).borrow_mut().position(), 56);
-        assert_eq!(player.board().borrow_mut().inside(56).pieces.len(), 1);
+        player.update_inside(&mut board, piece_id, 54, 58).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 56);
+        assert_eq!(board.inside(56).pieces.len(), 1);
     }
 
     #[test]
     fn enter_goal_from_outside_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
         let piece_id = 0;
 
-        player.free_piece(piece_id);
-        player.update_outside(piece_id, 0, 50);
-        player.enter_goal(piece_id, 50);
-
-        let piece = player.piece(piece_id);
-        assert_eq!(piece.borrow().position(), 99);
-        assert_eq!(piece.borrow().color(), pieces::Color::Green);
-        assert!(piece.borrow().is_goal());
-        let boardstate = player.board();
-        assert_eq!(boardstate.borrow_mut().goal(PLAYER_ID).pieces.len(), 1);
+        player.free_piece(&mut board, piece_id).unwrap();
+        player.update_outside(&mut board, piece_id, 0, 50).unwrap();
+        player.enter_goal(&mut board, piece_id, 50).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 99);
+        assert_eq!(player.piece(&board, piece_id).color(), pieces::Color::Green);
+        assert!(player.piece(&board, piece_id).is_goal());
+        assert_eq!(board.goal(PLAYER_ID).pieces.len(), 1);
         assert_eq!(
-            boardstate.borrow_mut().goal(PLAYER_ID).player_id,
+            board.goal(PLAYER_ID).player_id,
             Some(board::PlayerID::Player0)
         );
         assert_eq!(
-            boardstate
-                .borrow_mut()
-                .goal(PLAYER_ID)
-                .piece(piece_id)
-                .borrow()
+            board
+                .piece_at(board.goal[PLAYER_ID as usize].piece(piece_id))
                 .color(),
             pieces::Color::Green
         );
@@ -363,41 +271,28 @@ mod atomic_single_piece_test {
 
     #[test]
     fn enter_goal_from_inside_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         let piece_id = 0;
-        player.free_piece(piece_id);
-        player.move_piece(piece_id, 49);
-        player.enter_inside(piece_id, 49, 54);
-
-        assert_eq!(player.piece(piece_id).borrow().position(), 54);
-        assert!(!player.piece(piece_id).borrow().is_goal());
-        let boardstate = player.board();
-        assert_eq!(boardstate.borrow_mut().inside(54).pieces.len(), 1);
-        assert_eq!(
-            boardstate.borrow().inside[2].player_id,
-            Some(board::PlayerID::Player0)
-        );
+        player.free_piece(&mut board, piece_id).unwrap();
+        player.move_piece(&mut board, piece_id, 49).unwrap();
+        player.enter_inside(&mut board, piece_id, 49, 54).unwrap();
 
-        player.enter_goal(piece_id, 54);
+        assert_eq!(player.piece(&board, piece_id).position(), 54);
+        assert!(!player.piece(&board, piece_id).is_goal());
+        assert_eq!(board.inside(54).pieces.len(), 1);
+        assert_eq!(board.inside[2].player_id, Some(board::PlayerID::Player0));
 
-        assert_eq!(player.piece(piece_id).borrow().position(), 99);
-        assert!(player.piece(piece_id).borrow().is_goal());
+        player.enter_goal(&mut board, piece_id, 54).unwrap();
 
-        let boardstate = player.board();
-        assert_eq!(boardstate.borrow_mut().goal(0).pieces.len(), 1);
-        assert_eq!(
-            player.board().borrow().goal[0].player_id,
-            Some(board::PlayerID::Player0)
-        );
+        assert_eq!(player.piece(&board, piece_id).position(), 99);
+        assert!(player.piece(&board, piece_id).is_goal());
+        assert_eq!(board.goal(0).pieces.len(), 1);
+        assert_eq!(board.goal[0].player_id, Some(board::PlayerID::Player0));
         assert_eq!(
-            boardstate
-                .borrow_mut()
-                .goal(PLAYER_ID)
-                .piece(piece_id)
-                .borrow()
+            board
+                .piece_at(board.goal[PLAYER_ID as usize].piece(piece_id))
                 .color(),
             pieces::Color::Green
         );
@@ -405,40 +300,38 @@ mod atomic_single_piece_test {
 
     #[test]
     fn enter_globe_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         let piece_id = 0;
-        player.free_piece(piece_id);
-        assert!(player.piece(piece_id).borrow().is_free());
-
-        player.move_piece(piece_id, 8);
-        assert_eq!(player.piece(piece_id).borrow().position(), 8);
-        assert!(player.piece(piece_id).borrow().is_free());
-        assert_eq!(player.board().borrow_mut().outside(8).pieces.len(), 1);
-
-        player.enter_globe(piece_id, 8, 8);
-        assert_eq!(player.piece(piece_id).borrow().position(), 8);
-        assert!(player.piece(piece_id).borrow().is_free());
-        assert_eq!(player.board().borrow_mut().outside(8).pieces.len(), 1);
+        player.free_piece(&mut board, piece_id).unwrap();
+        assert!(player.piece(&board, piece_id).is_free());
+
+        player.move_piece(&mut board, piece_id, 8).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 8);
+        assert!(player.piece(&board, piece_id).is_free());
+        assert_eq!(board.outside(8).pieces.len(), 1);
+
+        player.enter_globe(&mut board, piece_id, 8, 8).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 8);
+        assert!(player.piece(&board, piece_id).is_free());
+        assert_eq!(board.outside(8).pieces.len(), 1);
     }
 
     #[test]
     fn starjump_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         let piece_id = 0;
-        player.free_piece(piece_id);
-        player.move_piece(piece_id, 5);
-        assert_ne!(player.piece(piece_id).borrow().position(), 11);
-        assert!(player.piece(piece_id).borrow().is_free());
-
-        player.update_outside(piece_id, 5, 4);
-        player.starjump(piece_id, 4, 5);
-        assert_eq!(player.piece(piece_id).borrow().position(), 11);
-        assert!(player.piece(piece_id).borrow().is_free());
+        player.free_piece(&mut board, piece_id).unwrap();
+        player.move_piece(&mut board, piece_id, 5).unwrap();
+        assert_ne!(player.piece(&board, piece_id).position(), 11);
+        assert!(player.piece(&board, piece_id).is_free());
+
+        player.update_outside(&mut board, piece_id, 5, 4).unwrap();
+        player.starjump(&mut board, piece_id, 4, 5).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 11);
+        assert!(player.piece(&board, piece_id).is_free());
     }
 }
@@ -1,6 +1,5 @@
 use board::Board;
 use players::Player;
-use std::{cell::RefCell, rc::Rc};
 
 #[cfg(test)]
 mod atomic_multipiece_test {
@@ -11,225 +10,165 @@ mod atomic_multipiece_test {
 
     #[test]
     fn free_all_pieces_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         for piece_id in 0..4 {
-            player.free_piece(piece_id);
-            assert!(!player.piece(piece_id).borrow_mut().is_home());
-            assert!(player.piece(piece_id).borrow_mut().is_free());
-            assert_eq!(player.piece(piece_id).borrow_mut().position(), 0);
+            player.free_piece(&mut board, piece_id).unwrap();
+            assert!(!player.piece(&board, piece_id).is_home());
+            assert!(player.piece(&board, piece_id).is_free());
+            assert_eq!(player.piece(&board, piece_id).position(), 0);
         }
-        assert_eq!(player.board().borrow_mut().home(0).pieces.len(), 0);
-        assert_eq!(player.board().borrow_mut().outside(0).pieces.len(), 4);
+        assert_eq!(board.home(0).pieces.len(), 0);
+        assert_eq!(board.outside(0).pieces.len(), 4);
     }
 
     #[test]
     fn joining_other_pieces_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
-        player.free_piece(0);
-        player.free_piece(1);
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
 
-        player.update_outside(0, 0, 1);
-        player.update_outside(1, 0, 1);
+        player.update_outside(&mut board, 0, 0, 1).unwrap();
+        player.update_outside(&mut board, 1, 0, 1).unwrap();
 
-        assert_eq!(player.piece(0).borrow_mut().position(), 1);
-        assert_eq!(player.piece(1).borrow_mut().position(), 1);
-        assert_eq!(player.board().borrow_mut().outside(1).pieces.len(), 2);
+        assert_eq!(player.piece(&board, 0).position(), 1);
+        assert_eq!(player.piece(&board, 1).position(), 1);
+        assert_eq!(board.outside(1).pieces.len(), 2);
 
-        assert!(player.piece(0).borrow_mut().is_free());
+        assert!(player.piece(&board, 0).is_free());
 
-        assert_eq!(player.piece(0).borrow_mut().color(), pieces::Color::Green);
-        assert_eq!(player.piece(1).borrow_mut().color(), pieces::Color::Green);
+        assert_eq!(player.piece(&board, 0).color(), pieces::Color::Green);
+        assert_eq!(player.piece(&board, 1).color(), pieces::Color::Green);
 
-        player.update_outside(1, 1, 0);
-        player.join(1, 0, 1);
+        player.update_outside(&mut board, 1, 1, 0).unwrap();
+        player.join(&mut board, 1, 0, 1).unwrap();
 
-        assert_eq!(player.piece(0).borrow_mut().position(), 1);
-        assert_eq!(player.piece(1).borrow_mut().position(), 1);
-        assert_eq!(player.board().borrow_mut().outside(1).pieces.len(), 2);
+        assert_eq!(player.piece(&board, 0).position(), 1);
+        assert_eq!(player.piece(&board, 1).position(), 1);
+        assert_eq!(board.outside(1).pieces.len(), 2);
 
-        assert!(player.piece(0).borrow_mut().is_free());
-        assert!(player.piece(1).borrow_mut().is_free());
+        assert!(player.piece(&board, 0).is_free());
+        assert!(player.piece(&board, 1).is_free());
 
-        assert_eq!(player.piece(0).borrow_mut().color(), pieces::Color::Green);
-        assert_eq!(player.piece(1).borrow_mut().color(), pieces::Color::Green);
+        assert_eq!(player.piece(&board, 0).color(), pieces::Color::Green);
+        assert_eq!(player.piece(&board, 1).color(), pieces::Color::Green);
     }
 
     #[test]
     fn leaving_other_pieces_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
-
-        player.free_piece(0);
-        player.free_piece(1);
-
-        player.update_outside(0, 0, 1);
-        player.join(1, 0, 1);
-
-        assert_eq!(player.piece(0).borrow_mut().position(), 1);
-        assert_eq!(player.piece(1).borrow_mut().position(), 1);
-        assert_eq!(player.board().borrow_mut().outside(1).pieces.len(), 2);
-        assert!(player.piece(0).borrow_mut().is_free());
-        assert!(player.piece(1).borrow_mut().is_free());
-
-        player.leave(0, 1, 2);
-        assert_eq!(player.piece(0).borrow_mut().position(), 2);
-        assert_eq!(player.piece(1).borrow_mut().position(), 1);
-        assert_eq!(player.board().borrow_mut().outside(2).pieces.len(), 1);
-        assert_eq!(player.board().borrow_mut().outside(1).pieces.len(), 1);
-        assert!(player.piece(0).borrow_mut().is_free());
-        assert!(player.piece(1).borrow_mut().is_free());
+
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
+
+        player.update_outside(&mut board, 0, 0, 1).unwrap();
+        player.join(&mut board, 1, 0, 1).unwrap();
+
+        assert_eq!(player.piece(&board, 0).position(), 1);
+        assert_eq!(player.piece(&board, 1).position(), 1);
+        assert_eq!(board.outside(1).pieces.len(), 2);
+        assert!(player.piece(&board, 0).is_free());
+        assert!(player.piece(&board, 1).is_free());
+
+        player.leave(&mut board, 0, 1, 2).unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 2);
+        assert_eq!(player.piece(&board, 1).position(), 1);
+        assert_eq!(board.outside(2).pieces.len(), 1);
+        assert_eq!(board.outside(1).pieces.len(), 1);
+        assert!(player.piece(&board, 0).is_free());
+        assert!(player.piece(&board, 1).is_free());
     }
 
     #[test]
     fn all_pieces_at_same_place_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
-
-        player.free_piece(0);
-        player.free_piece(1);
-        player.free_piece(2);
-        player.free_piece(3);
-
-        player.update_outside(0, 0, 6);
-        player.join(1, 0, 6);
-        player.join(2, 0, 6);
-        player.join(3, 0, 6);
-
-        assert_eq!(player.piece(0).borrow().position(), 6);
-        assert_eq!(player.piece(1).borrow_mut().position(), 6);
-        assert_eq!(player.piece(2).borrow_mut().position(), 6);
-        assert_eq!(player.piece(3).borrow_mut().position(), 6);
-
-        assert!(player.piece(0).borrow_mut().is_free());
-        assert!(player.piece(1).borrow_mut().is_free());
-        assert!(player.piece(2).borrow_mut().is_free());
-        assert!(player.piece(3).borrow_mut().is_free());
-
-        player.leave(0, 6, 7);
-        assert_eq!(player.piece(0).borrow().position(), 7);
-        assert!(player.piece(3).borrow_mut().is_free());
-
-        player.leave(1, 6, 9);
-        assert_eq!(player.piece(1).borrow_mut().position(), 9);
-        assert!(player.piece(1).borrow_mut().is_free());
-
-        player.leave(2, 6, 10);
-        assert_eq!(player.piece(2).borrow_mut().position(), 10);
-        assert!(player.piece(2).borrow_mut().is_free());
+
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
+        player.free_piece(&mut board, 2).unwrap();
+        player.free_piece(&mut board, 3).unwrap();
+
+        player.update_outside(&mut board, 0, 0, 6).unwrap();
+        player.join(&mut board, 1, 0, 6).unwrap();
+        player.join(&mut board, 2, 0, 6).unwrap();
+        player.join(&mut board, 3, 0, 6).unwrap();
+
+        assert_eq!(player.piece(&board, 0).position(), 6);
+        assert_eq!(player.piece(&board, 1).position(), 6);
+        assert_eq!(player.piece(&board, 2).position(), 6);
+        assert_eq!(player.piece(&board, 3).position(), 6);
+
+        assert!(player.piece(&board, 0).is_free());
+        assert!(player.piece(&board, 1).is_free());
+        assert!(player.piece(&board, 2).is_free());
+        assert!(player.piece(&board, 3).is_free());
+
+        player.leave(&mut board, 0, 6, 7).unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 7);
+        assert!(player.piece(&board, 3).is_free());
+
+        player.leave(&mut board, 1, 6, 9).unwrap();
+        assert_eq!(player.piece(&board, 1).position(), 9);
+        assert!(player.piece(&board, 1).is_free());
+
+        player.leave(&mut board, 2, 6, 10).unwrap();
+        assert_eq!(player.piece(&board, 2).position(), 10);
+        assert!(player.piece(&board, 2).is_free());
     }
 
     #[test]
     fn all_pieces_in_goal_test_0() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
-
-        player.free_piece(0);
-        player.free_piece(1);
-        player.free_piece(2);
-        player.free_piece(3);
-
-        player.enter_goal(0, 0);
-        player.enter_goal(1, 0);
-        player.enter_goal(2, 0);
-        player.enter_goal(3, 0);
-
-        assert!(player.piece(0).borrow_mut().is_goal());
-        assert!(player.piece(1).borrow_mut().is_goal());
-        assert!(player.piece(2).borrow_mut().is_goal());
-        assert!(player.piece(3).borrow_mut().is_goal());
-        assert!(player
-            .board()
-            .borrow_mut()
-            .goal(0)
-            .piece(0)
-            .borrow_mut()
-            .is_goal());
-        assert!(player
-            .board()
-            .borrow_mut()
-            .goal(0)
-            .piece(1)
-            .borrow_mut()
-            .is_goal());
-        assert!(player
-            .board()
-            .borrow_mut()
-            .goal(0)
-            .piece(2)
-            .borrow_mut()
-            .is_goal());
-        assert!(player
-            .board()
-            .borrow_mut()
-            .goal(0)
-            .piece(3)
-            .borrow_mut()
-            .is_goal());
-        assert_eq!(player.board().borrow_mut().goal(0).pieces.len(), 4);
-        assert!(player.is_finished());
+
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
+        player.free_piece(&mut board, 2).unwrap();
+        player.free_piece(&mut board, 3).unwrap();
+
+        player.enter_goal(&mut board, 0, 0).unwrap();
+        player.enter_goal(&mut board, 1, 0).unwrap();
+        player.enter_goal(&mut board, 2, 0).unwrap();
+        player.enter_goal(&mut board, 3, 0).unwrap();
+
+        assert!(player.piece(&board, 0).is_goal());
+        assert!(player.piece(&board, 1).is_goal());
+        assert!(player.piece(&board, 2).is_goal());
+        assert!(player.piece(&board, 3).is_goal());
+        assert!(board.piece_at(board.goal[0].piece(0)).is_goal());
+        assert!(board.piece_at(board.goal[0].piece(1)).is_goal());
+        assert!(board.piece_at(board.goal[0].piece(2)).is_goal());
+        assert!(board.piece_at(board.goal[0].piece(3)).is_goal());
+        assert_eq!(board.goal(0).pieces.len(), 4);
+        assert!(player.is_finished(&board));
     }
 
     #[test]
     fn star_join_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
-        player.free_piece(0);
-        player.free_piece(1);
-
-        player.starjump(0, 0, 5);
-        assert_eq!(player.piece(0).borrow_mut().position(), 11);
-        assert!(player.piece(0).borrow_mut().is_free());
-
-        player.join_piece(1, 5);
-        assert_eq!(player.piece(1).borrow_mut().position(), 11);
-        assert_eq!(player.board().borrow_mut().outside(11).pieces.len(), 2);
-        assert_eq!(player.board().borrow_mut().outside(5).pieces.len(), 0);
-        assert_eq!(player.board().borrow_mut().outside(5).player_id, None);
-        assert_eq!(
-            player
-                .board()
-                .borrow_mut()
-                .outside(11)
-                .piece(0)
-                .borrow_mut()
-                .position(),
-            11
-        );
-        assert_eq!(
-            player
-                .board()
-                .borrow_mut()
-                .outside(11)
-                .piece(1)
-                .borrow_mut()
-                .position(),
-            11
-        );
-        assert!(player
-            .board()
-            .borrow_mut()
-            .outside(11)
-            .piece(0)
-            .borrow_mut()
-            .is_free());
-        assert!(player
-            .board()
-            .borrow_mut()
-            .outside(11)
-            .piece(1)
-            .borrow_mut()
-            .is_free());
-        assert!(player.piece(0).borrow_mut().is_free());
-        assert!(player.piece(1).borrow_mut().is_free());
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
+
+        player.starjump(&mut board, 0, 0, 5).unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 11);
+        assert!(player.piece(&board, 0).is_free());
+
+        player.join_piece(&mut board, 1, 5).unwrap();
+        assert_eq!(player.piece(&board, 1).position(), 11);
+        assert_eq!(board.outside(11).pieces.len(), 2);
+        assert_eq!(board.outside(5).pieces.len(), 0);
+        assert_eq!(board.outside(5).player_id, None);
+        assert_eq!(board.piece_at(board.square(11).piece(0)).position(), 11);
+        assert_eq!(board.piece_at(board.square(11).piece(1)).position(), 11);
+        assert!(board.piece_at(board.square(11).piece(0)).is_free());
+        assert!(board.piece_at(board.square(11).piece(1)).is_free());
+        assert!(player.piece(&board, 0).is_free());
+        assert!(player.piece(&board, 1).is_free());
     }
 }
@@ -1,8 +1,8 @@
 use board::Board;
 use dice::Dice;
+use errors::LudoError;
 use pieces::Color;
 use players::{Act, Player, Select};
-use std::{cell::RefCell, rc::Rc};
 
 static PLAYER_ID: i8 = 3;
 static OTHER_PLAYER_ID: i8 = 0;
@@ -13,538 +13,513 @@ mod player_3_choice_tests {
 
     #[test]
     fn try_to_free_test() {
-        let board: Rc<RefCell<Board>> = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let mut other_player = Player::new(OTHER_PLAYER_ID);
-        other_player.setup(board);
 
-        let result = player.try_to_free(0, 6);
+        let result = player.try_to_free(&board, 0, 6);
         assert_eq!(result, Act::Free);
 
         for i in 0..4 {
-            other_player.free_piece(i);
-            other_player.update_outside(i, 0, 39);
+            other_player.free_piece(&mut board, i).unwrap();
+            other_player.update_outside(&mut board, i, 0, 39).unwrap();
         }
 
-        let result = player.try_to_free(0, 6);
+        let result = player.try_to_free(&board, 0, 6);
         assert_eq!(result, Act::Nothing);
     }
 
     #[test]
     fn try_to_move_test() {
-        let board: Rc<RefCell<Board>> = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
-        let result = player.try_to_move(0, 1);
+        let result = player.try_to_move(&board, 0, 1);
         assert_eq!(result, Act::Nothing);
 
-        let result = player.try_to_move(0, 6);
+        let result = player.try_to_move(&board, 0, 6);
         assert_eq!(result, Act::Nothing);
 
-        player.free_piece(0);
-        let result = player.try_to_move(0, 6);
+        player.free_piece(&mut board, 0).unwrap();
+        let result = player.try_to_move(&board, 0, 6);
         assert_eq!(result, Act::Move);
 
-        player.update_outside(0, 39, 46);
-        let result = player.try_to_move(0, 1);
+        player.update_outside(&mut board, 0, 39, 46).unwrap();
+        let result = player.try_to_move(&board, 0, 1);
         assert_eq!(result, Act::Nothing);
 
-        player.update_outside(0, 46, 51);
-        let result = player.try_to_move(0, 1);
+        player.update_outside(&mut board, 0, 46, 51).unwrap();
+        let result = player.try_to_move(&board, 0, 1);
         assert_eq!(result, Act::Move);
 
-        player.update_outside(0, 51, 37);
-        let result = player.try_to_move(0, 1);
+        player.update_outside(&mut board, 0, 51, 37).unwrap();
+        let result = player.try_to_move(&board, 0, 1);
         assert_eq!(result, Act::Move);
 
-        player.enter_inside(0, 37, 67);
-        let result = player.try_to_move(0, 1);
+        player.enter_inside(&mut board, 0, 37, 67).unwrap();
+        let result = player.try_to_move(&board, 0, 1);
         assert_eq!(result, Act::Move);
 
-        let result = player.try_to_move(0, 6);
+        let result = player.try_to_move(&board, 0, 6);
         assert_eq!(result, Act::Move);
 
-        let result = player.try_to_move(0, 5);
+        let result = player.try_to_move(&board, 0, 5);
         assert_eq!(result, Act::Nothing);
     }
 
     #[test]
     fn try_to_move_test_2() {
-        let board: Rc<RefCell<Board>> = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let mut other_player = Player::new(OTHER_PLAYER_ID);
-        other_player.setup(board);
 
-        player.free_piece(0);
-        player.free_piece(1);
-        let result = player.try_to_move(0, 1);
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
+        let result = player.try_to_move(&board, 0, 1);
         assert_eq!(result, Act::Nothing);
 
-        player.update_outside(0, 39, 46);
+        player.update_outside(&mut board, 0, 39, 46).unwrap();
 
-        other_player.free_piece(0);
-        other_player.free_piece(1);
-        other_player.update_outside(0, 52, 48);
-        let result = player.try_to_move(0, 2);
+        other_player.free_piece(&mut board, 0).unwrap();
+        other_player.free_piece(&mut board, 1).unwrap();
+        other_player.update_outside(&mut board, 0, 52, 48).unwrap();
+        let result = player.try_to_move(&board, 0, 2);
         assert_eq!(result, Act::Nothing);
 
-        other_player.update_outside(1, 0, 48);
-        let result = player.try_to_move(0, 2);
+        other_player.update_outside(&mut board, 1, 0, 48).unwrap();
+        let result = player.try_to_move(&board, 0, 2);
         assert_eq!(result, Act::Nothing);
     }
 
     #[test]
     fn try_to_join_test() {
-        let board: Rc<RefCell<Board>> = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
-        let result = player.try_to_join(0, 1);
+        let result = player.try_to_join(&board, 0, 1);
         assert_eq!(result, Act::Nothing);
 
-        let result = player.try_to_join(0, 6);
+        let result = player.try_to_join(&board, 0, 6);
         assert_eq!(result, Act::Nothing);
 
-        player.free_piece(0);
-        player.free_piece(1);
-        player.free_piece(2);
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
+        player.free_piece(&mut board, 2).unwrap();
 
-        player.update_outside(0, 39, 40);
-        let result = player.try_to_join(1, 1);
+        player.update_outside(&mut board, 0, 39, 40).unwrap();
+        let result = player.try_to_join(&board, 1, 1);
         assert_eq!(result, Act::Join);
 
-        player.update_outside(0, 40, 44);
-        let result = player.try_to_join(1, 5);
+        player.update_outside(&mut board, 0, 40, 44).unwrap();
+        let result = player.try_to_join(&board, 1, 5);
         assert_eq!(result, Act::Nothing);
 
-        player.update_outside(0, 44, 47);
-        let result = player.try_to_join(1, 3);
+        player.update_outside(&mut board, 0, 44, 47).unwrap();
+        let result = player.try_to_join(&board, 1, 3);
         assert_eq!(result, Act::Nothing);
 
-        player.update_outside(0, 47, 50);
-        let result = player.try_to_join(1, 5);
+        player.update_outside(&mut board, 0, 47, 50).unwrap();
+        let result = player.try_to_join(&board, 1, 5);
         assert_eq!(result, Act::Join);
 
-        player.update_outside(0, 50, 0);
-        player.update_outside(1, 39, 46);
-        let result = player.try_to_join(1, 6);
+        player.update_outside(&mut board, 0, 50, 0).unwrap();
+        player.update_outside(&mut board, 1, 39, 46).unwrap();
+        let result = player.try_to_join(&board, 1, 6);
         assert_eq!(result, Act::Join);
 
-        player.update_outside(0, 0, 37);
-        player.update_outside(1, 46, 36);
-        let result = player.try_to_join(1, 1);
+        player.update_outside(&mut board, 0, 0, 37).unwrap();
+        player.update_outside(&mut board, 1, 46, 36).unwrap();
+        let result = player.try_to_join(&board, 1, 1);
         assert_eq!(result, Act::Nothing);
 
-        player.enter_inside(0, 37, 70);
-        let result = player.try_to_join(1, 5);
+        player.enter_inside(&mut board, 0, 37, 70).unwrap();
+        let result = player.try_to_join(&board, 1, 5);
         assert_eq!(result, Act::Nothing);
 
-        player.enter_inside(1, 36, 67);
-        let result = player.try_to_join(1, 3);
+        player.enter_inside(&mut board, 1, 36, 67).unwrap();
+        let result = player.try_to_join(&board, 1, 3);
         assert_eq!(result, Act::Nothing);
     }
 
     #[test]
     fn try_to_join_test_2() {
-        let board: Rc<RefCell<Board>> = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let mut other_player = Player::new(OTHER_PLAYER_ID);
-        other_player.setup(board);
 
-        let result = player.try_to_join(0, 1);
+        let result = player.try_to_join(&board, 0, 1);
         assert_eq!(result, Act::Nothing);
 
-        player.free_piece(0);
-        player.free_piece(1);
-        player.free_piece(2);
-        other_player.free_piece(0);
-        other_player.free_piece(1);
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
+        player.free_piece(&mut board, 2).unwrap();
+        other_player.free_piece(&mut board, 0).unwrap();
+        other_player.free_piece(&mut board, 1).unwrap();
 
-        other_player.update_outside(0, 0, 44);
-        player.update_outside(0, 39, 50);
+        other_player.update_outside(&mut board, 0, 0, 44).unwrap();
+        player.update_outside(&mut board, 0, 39, 50).unwrap();
 
-        let result = player.try_to_join(1, 5);
+        let result = player.try_to_join(&board, 1, 5);
         assert_eq!(result, Act::Nothing);
 
-        other_player.update_outside(1, 39, 31);
+        other_player.update_outside(&mut board, 1, 39, 31).unwrap();
 
-        let result = player.try_to_join(1, 5);
+        let result = player.try_to_join(&board, 1, 5);
         assert_eq!(result, Act::Nothing);
     }
 
     #[test]
     fn try_to_kill_test() {
-        let board: Rc<RefCell<Board>> = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let mut other_player = Player::new(OTHER_PLAYER_ID);
-        other_player.setup(board);
 
-        let result = player.try_to_kill(0, 6);
+        let result = player.try_to_kill(&board, 0, 6);
         assert_eq!(result, Act::Nothing);
 
-        other_player.free_piece(0);
-        other_player.free_piece(1);
+        other_player.free_piece(&mut board, 0).unwrap();
+        other_player.free_piece(&mut board, 1).unwrap();
 
-        other_player.update_outside(0, 0, 40);
-        let result = player.try_to_kill(0, 1);
+        other_player.update_outside(&mut board, 0, 0, 40).unwrap();
+        let result = player.try_to_kill(&board, 0, 1);
         assert_eq!(result, Act::Nothing);
 
-        player.free_piece(0);
-        let result = player.try_to_kill(0, 1);
+        player.free_piece(&mut board, 0).unwrap();
+        let result = player.try_to_kill(&board, 0, 1);
         assert_eq!(result, Act::Kill);
 
-        let result = player.try_to_kill(0, 13);
+        let result = player.try_to_kill(&board, 0, 13);
         assert_eq!(result, Act::Nothing);
 
-        other_player.update_outside(0, 40, 44);
-        let result = player.try_to_kill(0, 5);
+        other_player.update_outside(&mut board, 0, 40, 44).unwrap();
+        let result = player.try_to_kill(&board, 0, 5);
         assert_eq!(result, Act::Kill);
 
-        other_player.enter_globe(0, 44, 47);
-        let result = player.try_to_kill(0, 8);
+        other_player.enter_globe(&mut board, 0, 44, 47).unwrap();
+        let result = player.try_to_kill(&board, 0, 8);
         assert_eq!(result, Act::Nothing);
 
-        other_player.update_outside(0, 47, 50);
-        let result = player.try_to_kill(0, 5);
+        other_player.update_outside(&mut board, 0, 47, 50).unwrap();
+        let result = player.try_to_kill(&board, 0, 5);
         assert_eq!(result, Act::Kill);
 
-        other_player.update_outside(0, 50, 13);
-        player.update_outside(0, 39, 7);
-        let result = player.try_to_kill(0, 6);
+        other_player.update_outside(&mut board, 0, 50, 13).unwrap();
+        player.update_outside(&mut board, 0, 39, 7).unwrap();
+        let result = player.try_to_kill(&board, 0, 6);
         assert_eq!(result, Act::Kill);
     }
 
     #[test]
     fn try_to_kill_test_2() {
-        let board: Rc<RefCell<Board>> = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let mut other_player = Player::new(OTHER_PLAYER_ID);
-        other_player.setup(board);
 
-        other_player.free_piece(0);
-        other_player.free_piece(1);
-        other_player.update_outside(0, 0, 39);
-        other_player.join(1, 0, 39);
-        let result = player.try_to_kill(0, 6);
+        other_player.free_piece(&mut board, 0).unwrap();
+        other_player.free_piece(&mut board, 1).unwrap();
+        other_player.update_outside(&mut board, 0, 0, 39).unwrap();
+        other_player.join(&mut board, 1, 0, 39).unwrap();
+        let result = player.try_to_kill(&board, 0, 6);
         assert_eq!(result, Act::Kill);
 
-        other_player.update_outside(0, 39, 40);
-        other_player.join(1, 39, 40);
-        player.free_piece(0);
-        let result = player.try_to_kill(0, 1);
+        other_player.update_outside(&mut board, 0, 39, 40).unwrap();
+        other_player.join(&mut board, 1, 39, 40).unwrap();
+        player.free_piece(&mut board, 0).unwrap();
+        let result = player.try_to_kill(&board, 0, 1);
         assert_eq!(result, Act::Nothing);
 
-        player.update_outside(0, 39, 12);
-        other_player.update_outside(0, 40, 13);
-        other_player.join(1, 40, 13);
-        let result = player.try_to_kill(0, 1);
+        player.update_outside(&mut board, 0, 39, 12).unwrap();
+        other_player.update_outside(&mut board, 0, 40, 13).unwrap();
+        other_player.join(&mut board, 1, 40, 13).unwrap();
+        let result = player.try_to_kill(&board, 0, 1);
         assert_eq!(result, Act::Nothing);
 
-        other_player.enter_globe(0, 13, 21);
-        other_player.enter_globe(1, 13, 21);
-        let result = player.try_to_kill(0, 10);
+        other_player.enter_globe(&mut board, 0, 13, 21).unwrap();
+        other_player.enter_globe(&mut board, 1, 13, 21).unwrap();
+        let result = player.try_to_kill(&board, 0, 10);
         assert_eq!(result, Act::Nothing);
 
-        player.update_outside(0, 12, 39);
-        other_player.free_piece(2);
-        other_player.update_outside(2, 0, 50);
-        other_player.update_outside(0, 21, 44);
+        player.update_outside(&mut board, 0, 12, 39).unwrap();
+        other_player.free_piece(&mut board, 2).unwrap();
+        other_player.update_outside(&mut board, 2, 0, 50).unwrap();
+        other_player.update_outside(&mut board, 0, 21, 44).unwrap();
 
-        other_player.join(1, 21, 44);
-        let result = player.try_to_kill(0, 5);
+        other_player.join(&mut board, 1, 21, 44).unwrap();
+        let result = player.try_to_kill(&board, 0, 5);
         assert_eq!(result, Act::Nothing);
 
-        other_player.join(1, 44, 50);
-        let result = player.try_to_kill(0, 5);
+        other_player.join(&mut board, 1, 44, 50).unwrap();
+        let result = player.try_to_kill(&board, 0, 5);
         assert_eq!(result, Act::Kill);
 
-        other_player.leave(2, 50, 49);
-        let result = player.try_to_kill(0, 5);
+        other_player.leave(&mut board, 2, 50, 49).unwrap();
+        let result = player.try_to_kill(&board, 0, 5);
         assert_eq!(result, Act::Kill);
 
-        other_player.update_outside(0, 44, 40);
-        other_player.join(1, 50, 40);
-        let result = player.try_to_kill(0, 1);
+        other_player.update_outside(&mut board, 0, 44, 40).unwrap();
+        other_player.join(&mut board, 1, 50, 40).unwrap();
+        let result = player.try_to_kill(&board, 0, 1);
         assert_eq!(result, Act::Nothing);
 
-        other_player.leave(0, 40, 41);
-        let result = player.try_to_kill(0, 1);
+        other_player.leave(&mut board, 0, 40, 41).unwrap();
+        let result = player.try_to_kill(&board, 0, 1);
         assert_eq!(result, Act::Kill);
     }
 
     #[test]
     fn try_to_die_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let mut other_player = Player::new(OTHER_PLAYER_ID);
-        other_player.setup(board);
 
-        let result = player.try_to_die(0, 6);
+        let result = player.try_to_die(&board, 0, 6);
         assert_eq!(result, Act::Nothing);
 
-        player.free_piece(0);
-        other_player.free_piece(0);
-        other_player.free_piece(1);
+        player.free_piece(&mut board, 0).unwrap();
+        other_player.free_piece(&mut board, 0).unwrap();
+        other_player.free_piece(&mut board, 1).unwrap();
 
-        other_player.update_outside(0, 0, 40);
-        other_player.join(1, 0, 40);
-        let result = player.try_to_die(0, 1);
+        other_player.update_outside(&mut board, 0, 0, 40).unwrap();
+        other_player.join(&mut board, 1, 0, 40).unwrap();
+        let result = player.try_to_die(&board, 0, 1);
         assert_eq!(result, Act::Die);
 
-        other_player.update_outside(0, 40, 44);
-        let result = player.try_to_die(0, 5);
+        other_player.update_outside(&mut board, 0, 40, 44).unwrap();
+        let result = player.try_to_die(&board, 0, 5);
         assert_eq!(result, Act::Nothing);
 
-        other_player.update_outside(1, 40, 44);
-        let result = player.try_to_die(0, 5);
+        other_player.update_outside(&mut board, 1, 40, 44).unwrap();
+        let result = player.try_to_die(&board, 0, 5);
         assert_eq!(result, Act::Die);
 
-        other_player.leave(0, 44, 47);
-        let result = player.try_to_die(0, 5);
+        other_player.leave(&mut board, 0, 44, 47).unwrap();
+        let result = player.try_to_die(&board, 0, 5);
         assert_eq!(result, Act::Nothing);
 
-        let result = player.try_to_die(0, 8);
+        let result = player.try_to_die(&board, 0, 8);
         assert_eq!(result, Act::Die);
 
-        other_player.update_outside(1, 44, 50);
-        other_player.join(0, 47, 50);
+        other_player.update_outside(&mut board, 1, 44, 50).unwrap();
+        other_player.join(&mut board, 0, 47, 50).unwrap();
 
-        let result = player.try_to_die(0, 5);
+        let result = player.try_to_die(&board, 0, 5);
         assert_eq!(result, Act::Die);
 
-        other_player.free_piece(2);
-        other_player.update_outside(2, 0, 44);
-        let result = player.try_to_die(0, 5);
+        other_player.free_piece(&mut board, 2).unwrap();
+        other_player.update_outside(&mut board, 2, 0, 44).unwrap();
+        let result = player.try_to_die(&board, 0, 5);
         assert_eq!(result, Act::Die);
 
-        other_player.join(1, 50, 44);
-        let result = player.try_to_die(0, 5);
+        other_player.join(&mut board, 1, 50, 44).unwrap();
+        let result = player.try_to_die(&board, 0, 5);
         assert_eq!(result, Act::Die);
 
-        other_player.leave(1, 44, 45);
+        other_player.leave(&mut board, 1, 44, 45).unwrap();
 
-        let result = player.try_to_die(0, 5);
+        let result = player.try_to_die(&board, 0, 5);
         assert_eq!(result, Act::Nothing);
 
-        other_player.update_outside(1, 45, 13);
-        other_player.join(0, 50, 13);
-        player.update_outside(0, 39, 7);
-        let result = player.try_to_die(0, 6);
+        other_player.update_outside(&mut board, 1, 45, 13).unwrap();
+        other_player.join(&mut board, 0, 50, 13).unwrap();
+        player.update_outside(&mut board, 0, 39, 7).unwrap();
+        let result = player.try_to_die(&board, 0, 6);
         assert_eq!(result, Act::Die);
 
-        other_player.leave(1, 13, 0);
-        let result = player.try_to_die(0, 6);
+        other_player.leave(&mut board, 1, 13, 0).unwrap();
+        let result = player.try_to_die(&board, 0, 6);
         assert_eq!(result, Act::Nothing);
 
-        player.update_outside(0, 7, 46);
-        let result = player.try_to_die(0, 6);
+        player.update_outside(&mut board, 0, 7, 46).unwrap();
+        let result = player.try_to_die(&board, 0, 6);
         assert_eq!(result, Act::Die);
     }
 
     #[test]
     fn try_to_win_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
 
-        let result = player.try_to_win(0, 6);
+        let result = player.try_to_win(&board, 0, 6);
         assert_eq!(result, Act::Nothing);
 
-        player.free_piece(0);
+        player.free_piece(&mut board, 0).unwrap();
         let old_position = 39;
         let new_position = 67;
-        player.enter_inside(0, old_position, new_position);
-
-        let piece = player.piece(0);
-        assert_eq!(piece.borrow().position(), new_position);
-        assert_eq!(board.borrow_mut().inside(new_position).pieces.len(), 1);
+        player
+            .enter_inside(&mut board, 0, old_position, new_position)
+            .unwrap();
+        assert_eq!(player.piece(&board, 0).position(), new_position);
+        assert_eq!(board.inside(new_position).pieces.len(), 1);
 
         let dice_number = 5;
-        let result = player.try_to_win(0, dice_number);
+        let result = player.try_to_win(&board, 0, dice_number);
         assert_eq!(result, Act::Goal);
     }
 
     #[test]
     fn try_to_win_test_2() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let mut other_player = Player::new(OTHER_PLAYER_ID);
-        other_player.setup(board);
 
-        other_player.free_piece(0);
-        other_player.free_piece(1);
-        player.free_piece(0);
-        player.free_piece(1);
+        other_player.free_piece(&mut board, 0).unwrap();
+        other_player.free_piece(&mut board, 1).unwrap();
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
 
-        other_player.update_outside(0, 0, 37);
-        player.update_outside(0, 39, 31);
-        let result = player.try_to_win(0, 6);
+        other_player.update_outside(&mut board, 0, 0, 37).unwrap();
+        player.update_outside(&mut board, 0, 39, 31).unwrap();
+        let result = player.try_to_win(&board, 0, 6);
         assert_eq!(result, Act::Goal);
 
-        other_player.join(1, 0, 37);
-        let result = player.try_to_win(0, 6);
+        other_player.join(&mut board, 1, 0, 37).unwrap();
+        let result = player.try_to_win(&board, 0, 6);
         assert_eq!(result, Act::Nothing);
     }
 
     #[test]
     fn try_to_leave_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
-        let result = player.try_to_leave(0, 6);
+        let result = player.try_to_leave(&board, 0, 6);
         assert_eq!(result, Act::Nothing);
 
-        player.free_piece(0);
-        player.free_piece(1);
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
 
-        player.update_outside(0, 39, 40);
-        player.update_outside(1, 39, 40);
+        player.update_outside(&mut board, 0, 39, 40).unwrap();
+        player.update_outside(&mut board, 1, 39, 40).unwrap();
 
-        let result = player.try_to_leave(0, 1);
+        let result = player.try_to_leave(&board, 0, 1);
         assert_eq!(result, Act::Leave);
 
-        player.update_outside(1, 40, 41);
-        let result = player.try_to_leave(0, 1);
+        player.update_outside(&mut board, 1, 40, 41).unwrap();
+        let result = player.try_to_leave(&board, 0, 1);
         assert_eq!(result, Act::Nothing);
 
-        player.free_piece(2);
-        let result = player.try_to_leave(0, 2);
+        player.free_piece(&mut board, 2).unwrap();
+        let result = player.try_to_leave(&board, 0, 2);
         assert_eq!(result, Act::Nothing);
 
-        player.update_outside(2, 39, 41);
-        let result = player.try_to_leave(0, 1);
+        player.update_outside(&mut board, 2, 39, 41).unwrap();
+        let result = player.try_to_leave(&board, 0, 1);
         assert_eq!(result, Act::Nothing);
 
-        player.update_outside(2, 41, 44);
-        player.update_outside(1, 41, 39);
-        player.update_outside(0, 40, 39);
-        let result = player.try_to_leave(0, 5);
+        player.update_outside(&mut board, 2, 41, 44).unwrap();
+        player.update_outside(&mut board, 1, 41, 39).unwrap();
+        player.update_outside(&mut board, 0, 40, 39).unwrap();
+        let result = player.try_to_leave(&board, 0, 5);
         assert_eq!(result, Act::Nothing);
 
-        player.update_outside(2, 44, 47);
-        let result = player.try_to_leave(0, 8);
+        player.update_outside(&mut board, 2, 44, 47).unwrap();
+        let result = player.try_to_leave(&board, 0, 8);
         assert_eq!(result, Act::Nothing);
 
-        player.update_outside(2, 47, 50);
-        let result = player.try_to_leave(0, 5);
+        player.update_outside(&mut board, 2, 47, 50).unwrap();
+        let result = player.try_to_leave(&board, 0, 5);
         assert_eq!(result, Act::Nothing);
 
-        player.update_outside(0, 39, 50);
-        let result = player.try_to_leave(0, 2);
+        player.update_outside(&mut board, 0, 39, 50).unwrap();
+        let result = player.try_to_leave(&board, 0, 2);
         assert_eq!(result, Act::Leave);
     }
 
     #[test]
     fn try_to_leave_test_2() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let mut other_player = Player::new(OTHER_PLAYER_ID);
-        other_player.setup(board);
 
-        player.free_piece(0);
-        player.free_piece(1);
-        other_player.free_piece(0);
-        other_player.free_piece(1);
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
+        other_player.free_piece(&mut board, 0).unwrap();
+        other_player.free_piece(&mut board, 1).unwrap();
 
-        other_player.update_outside(1, 0, 40);
-        let result = player.try_to_leave(0, 1);
+        other_player.update_outside(&mut board, 1, 0, 40).unwrap();
+        let result = player.try_to_leave(&board, 0, 1);
         assert_eq!(result, Act::Nothing);
 
-        other_player.update_outside(0, 0, 14);
-        let result = player.try_to_leave(0, 1);
+        other_player.update_outside(&mut board, 0, 0, 14).unwrap();
+        let result = player.try_to_leave(&board, 0, 1);
         assert_eq!(result, Act::Nothing);
     }
 
     #[test]
     fn try_to_safe_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
-        let result = player.try_to_safe(0, 6);
+        let result = player.try_to_safe(&board, 0, 6);
         assert_eq!(result, Act::Nothing);
 
-        player.free_piece(0);
-        player.free_piece(1);
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
 
-        player.move_piece(0, 6);
-        let result = player.try_to_safe(0, 1);
+        player.move_piece(&mut board, 0, 6).unwrap();
+        let result = player.try_to_safe(&board, 0, 1);
         assert_eq!(result, Act::Nothing);
-        let result = player.try_to_safe(0, 2);
+        let result = player.try_to_safe(&board, 0, 2);
         assert_eq!(result, Act::Safe);
 
-        player.update_outside(0, 45, 51);
-        let result = player.try_to_safe(0, 1);
+        player.update_outside(&mut board, 0, 45, 51).unwrap();
+        let result = player.try_to_safe(&board, 0, 1);
         assert_eq!(result, Act::Nothing);
 
-        player.update_outside(1, 39, 0);
-        let result = player.try_to_safe(0, 1);
+        player.update_outside(&mut board, 1, 39, 0).unwrap();
+        let result = player.try_to_safe(&board, 0, 1);
         assert_eq!(result, Act::Nothing);
 
-        player.update_outside(1, 0, 1);
-        let result = player.try_to_safe(0, 2);
+        player.update_outside(&mut board, 1, 0, 1).unwrap();
+        let result = player.try_to_safe(&board, 0, 2);
         assert_eq!(result, Act::Nothing);
 
-        player.update_outside(0, 51, 36);
-        let result = player.try_to_safe(0, 2);
+        player.update_outside(&mut board, 0, 51, 36).unwrap();
+        let result = player.try_to_safe(&board, 0, 2);
         assert_eq!(result, Act::Safe);
     }
 
     #[test]
     fn try_to_safe_test_2() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let mut other_player = Player::new(OTHER_PLAYER_ID);
-        other_player.setup(board);
 
-        player.free_piece(0);
-        other_player.free_piece(0);
+        player.free_piece(&mut board, 0).unwrap();
+        other_player.free_piece(&mut board, 0).unwrap();
 
-        other_player.update_outside(0, 0, 47);
-        let result = player.try_to_safe(0, 8);
+        other_player.update_outside(&mut board, 0, 0, 47).unwrap();
+        let result = player.try_to_safe(&board, 0, 8);
         assert_eq!(result, Act::Nothing);
     }
 
     #[test]
     fn try_to_starjump_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let mut other_player = Player::new(OTHER_PLAYER_ID);
-        other_player.setup(board);
 
-        let result = player.try_to_starjump(0, 6);
+        let result = player.try_to_starjump(&board, 0, 6);
         assert_eq!(result, Act::Nothing);
 
-        player.free_piece(0);
-        player.free_piece(1);
-        other_player.free_piece(0);
-        other_player.free_piece(1);
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
+        other_player.free_piece(&mut board, 0).unwrap();
+        other_player.free_piece(&mut board, 1).unwrap();
 
-        let result = player.try_to_starjump(0, 5);
+        let result = player.try_to_starjump(&board, 0, 5);
         assert_eq!(result, Act::Starjump);
 
-        other_player.update_outside(0, 0, 44);
-        let result = player.try_to_starjump(0, 5);
+        other_player.update_outside(&mut board, 0, 0, 44).unwrap();
+        let result = player.try_to_starjump(&board, 0, 5);
         assert_eq!(result, Act::Nothing);
 
-        other_player.update_outside(0, 44, 50);
-        let result = player.try_to_starjump(0, 5);
+        other_player.update_outside(&mut board, 0, 44, 50).unwrap();
+        let result = player.try_to_starjump(&board, 0, 5);
         assert_eq!(result, Act::Nothing);
 
-        player.update_outside(0, 39, 36);
-        let result = player.try_to_starjump(0, 1);
+        player.update_outside(&mut board, 0, 39, 36).unwrap();
+        let result = player.try_to_starjump(&board, 0, 1);
         assert_eq!(result, Act::Starjump);
     }
 }
@@ -555,353 +530,289 @@ mod player_3_move_tests {
 
     #[test]
     fn add_player_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
-        let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
+        let player = Player::new(PLAYER_ID);
         assert_eq!(player.id(), 3);
-        assert_eq!(player.board().as_ptr(), board.as_ptr());
     }
 
     #[test]
     fn get_pieces_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
-        let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
+        let board = Board::new();
+        let player = Player::new(PLAYER_ID);
         (0..4).for_each(|i| {
-            let piece = player.piece(i);
-            assert_eq!(piece.borrow().id(), i);
-            assert_eq!(piece.borrow().color(), Color::Red);
-            assert_ne!(piece.borrow().color(), Color::Blue);
-            assert_ne!(piece.borrow().color(), Color::Yellow);
-            assert_ne!(piece.borrow().color(), Color::Green);
+            assert_eq!(player.piece(&board, i).id(), i);
+            assert_eq!(player.piece(&board, i).color(), Color::Red);
+            assert_ne!(player.piece(&board, i).color(), Color::Blue);
+            assert_ne!(player.piece(&board, i).color(), Color::Yellow);
+            assert_ne!(player.piece(&board, i).color(), Color::Green);
         });
     }
 
     #[test]
     #[should_panic]
     fn get_pieces_test_2() {
-        let board = Rc::new(RefCell::new(Board::new()));
-        let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
-        let piece = player.piece(0);
-        assert_eq!(piece.borrow().id(), 0);
-        assert_eq!(piece.borrow().color(), Color::Yellow);
+        let board = Board::new();
+        let player = Player::new(PLAYER_ID);
+        assert_eq!(player.piece(&board, 0).id(), 0);
+        assert_eq!(player.piece(&board, 0).color(), Color::Yellow);
     }
 
     #[test]
     fn get_pieces_test_3() {
-        let board = Rc::new(RefCell::new(Board::new()));
-        let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
-        let piece = player.piece(0);
-        assert_eq!(piece.borrow().id(), 0);
-        assert_eq!(piece.borrow().color(), Color::Red);
+        let board = Board::new();
+        let player = Player::new(PLAYER_ID);
+        assert_eq!(player.piece(&board, 0).id(), 0);
+        assert_eq!(player.piece(&board, 0).color(), Color::Red);
     }
 
     #[test]
     #[should_panic]
     fn get_pieces_test_4() {
-        let board = Rc::new(RefCell::new(Board::new()));
-        let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
-        let piece = player.piece(0);
-        assert_eq!(piece.borrow().id(), 0);
-        assert_eq!(piece.borrow().color(), Color::Blue);
+        let board = Board::new();
+        let player = Player::new(PLAYER_ID);
+        assert_eq!(player.piece(&board, 0).id(), 0);
+        assert_eq!(player.piece(&board, 0).color(), Color::Blue);
     }
 
     #[test]
     #[should_panic]
     fn get_pieces_test_5() {
-        let board = Rc::new(RefCell::new(Board::new()));
-        let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
-        let piece = player.piece(0);
-        assert_eq!(piece.borrow().id(), 0);
-        assert_eq!(piece.borrow().color(), Color::Green);
+        let board = Board::new();
+        let player = Player::new(PLAYER_ID);
+        assert_eq!(player.piece(&board, 0).id(), 0);
+        assert_eq!(player.piece(&board, 0).color(), Color::Green);
     }
 
     #[test]
     fn player_with_dice_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
-        player.roll_dice();
+        assert_eq!(player.roll_dice(), Err(LudoError::NoDice));
         let result = player.get_dice_number();
         assert!(result == 0);
 
         let dice = Dice::default();
 
         player.get_dice(dice);
-        player.roll_dice();
+        player.roll_dice().unwrap();
         let result = player.get_dice_number();
         assert!(result > 0 && result < 7);
 
         player.drop_dice();
-        player.roll_dice();
+        assert_eq!(player.roll_dice(), Err(LudoError::NoDice));
         let result = player.get_dice_number();
         assert!(result == 0);
     }
 
     #[test]
     fn move_piece_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
         let piece_id = 0;
-        player.free_piece(piece_id);
+        player.free_piece(&mut board, piece_id).unwrap();
         for i in 39..=88 {
-            player.move_piece(piece_id, 1);
-            assert_eq!(player.piece(piece_id).borrow().position(), (i + 1) % 52);
-            assert_eq!(player.board().borrow_mut().outside(i + 1).pieces.len(), 1);
+            player.move_piece(&mut board, piece_id, 1).unwrap();
+            assert_eq!(player.piece(&board, piece_id).position(), (i + 1) % 52);
+            assert_eq!(board.outside(i + 1).pieces.len(), 1);
             assert_eq!(
-                player.board().borrow_mut().outside(i + 1).player_id,
+                board.outside(i + 1).player_id,
                 Some(board::PlayerID::Player3)
             );
-            assert_eq!(player.board().borrow_mut().outside(i).pieces.len(), 0);
+            assert_eq!(board.outside(i).pieces.len(), 0);
         }
-        player.enter_inside(piece_id, 37, 67);
+        player.enter_inside(&mut board, piece_id, 37, 67).unwrap();
         let vec = (67..=72).chain((67..72).rev()).collect::<Vec<_>>();
 
         for i in 67..77 {
-            let oldpos = player.piece(piece_id).borrow().position();
+            let oldpos = player.piece(&board, piece_id).position();
             if (i + 1) == 72 {
                 continue;
             }
 
-            player.update_piece(piece_id, oldpos, i + 1);
+            player
+                .update_piece(&mut board, piece_id, oldpos, i + 1)
+                .unwrap();
             assert_eq!(
-                player.piece(piece_id).borrow().position(),
+                player.piece(&board, piece_id).position(),
                 vec[i as usize - 66]
             );
+            assert_eq!(board.inside(vec[i as usize - 66]).pieces.len(), 1);
             assert_eq!(
-                player
-                    .board()
-                    .borrow_mut()
-                    .inside(vec[i as usize - 66])
-                    .pieces
-                    .len(),
-                1
-            );
-            assert_eq!(
-                player
-                    .board()
-                    .borrow_mut()
-                    .inside(vec[i as usize - 66])
-                    .player_id,
+                board.inside(vec[i as usize - 66]).player_id,
                 Some(board::PlayerID::Player3)
             );
             if i == 72 {
                 continue;
             }
-            assert_eq!(
-                player
-                    .board()
-                    .borrow_mut()
-                    .inside(vec[i as usize - 67])
-                    .pieces
-                    .len(),
-                0
-            );
+            assert_eq!(board.inside(vec[i as usize - 67]).pieces.len(), 0);
         }
     }
 
     #[test]
     fn move_piece_test_2() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
         let piece_id = 0;
-        player.free_piece(piece_id);
+        player.free_piece(&mut board, piece_id).unwrap();
         for i in 39..89 {
-            player.move_piece(piece_id, 1);
-            assert_eq!(player.piece(piece_id).borrow().position(), (i + 1) % 52);
-            assert_eq!(player.board().borrow_mut().outside(i + 1).pieces.len(), 1);
+            player.move_piece(&mut board, piece_id, 1).unwrap();
+            assert_eq!(player.piece(&board, piece_id).position(), (i + 1) % 52);
+            assert_eq!(board.outside(i + 1).pieces.len(), 1);
             assert_eq!(
-                player.board().borrow_mut().outside(i + 1).player_id,
+                board.outside(i + 1).player_id,
                 Some(board::PlayerID::Player3)
             );
-            assert_eq!(player.board().borrow_mut().outside(i).pieces.len(), 0);
+            assert_eq!(board.outside(i).pieces.len(), 0);
         }
-        player.enter_inside(piece_id, 37, 67);
+        player.enter_inside(&mut board, piece_id, 37, 67).unwrap();
         let vec = (67..=72).collect::<Vec<_>>();
         for i in 67..71 {
-            let oldpos = player.piece(piece_id).borrow().position();
+            let oldpos = player.piece(&board, piece_id).position();
 
-            player.update_piece(piece_id, oldpos, i + 1);
+            player
+                .update_piece(&mut board, piece_id, oldpos, i + 1)
+                .unwrap();
             assert_eq!(
-                player.piece(piece_id).borrow().position(),
+                player.piece(&board, piece_id).position(),
                 vec[i as usize - 66]
             );
+            assert_eq!(board.inside(vec[i as usize - 66]).pieces.len(), 1);
             assert_eq!(
-                player
-                    .board()
-                    .borrow_mut()
-                    .inside(vec[i as usize - 66])
-                    .pieces
-                    .len(),
-                1
-            );
-            assert_eq!(
-                player
-                    .board()
-                    .borrow_mut()
-                    .inside(vec[i as usize - 66])
-                    .player_id,
+                board.inside(vec[i as usize - 66]).player_id,
                 Some(board::PlayerID::Player3)
             );
-            assert_eq!(
-                player
-                    .board()
-                    .borrow_mut()
-                    .inside(vec[i as usize - 67])
-                    .pieces
-                    .len(),
-                0
-            );
+            assert_eq!(board.inside(vec[i as usize - 67]).pieces.len(), 0);
         }
-        player.enter_goal(piece_id, 71);
-        assert_eq!(player.piece(piece_id).borrow().position(), 99);
+        player.enter_goal(&mut board, piece_id, 71).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 99);
     }
 
     #[test]
     fn move_all_pieces_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         for piece_id in 0..4 {
-            player.free_piece(piece_id);
+            player.free_piece(&mut board, piece_id).unwrap();
             for i in 39..89 {
-                player.move_piece(piece_id, 1);
-                assert_eq!(player.piece(piece_id).borrow().position(), (i + 1) % 52);
-                assert_eq!(player.board().borrow_mut().outside(i + 1).pieces.len(), 1);
+                player.move_piece(&mut board, piece_id, 1).unwrap();
+                assert_eq!(player.piece(&board, piece_id).position(), (i + 1) % 52);
+                assert_eq!(board.outside(i + 1).pieces.len(), 1);
                 assert_eq!(
-                    player.board().borrow_mut().outside(i + 1).player_id,
+                    board.outside(i + 1).player_id,
                     Some(board::PlayerID::Player3)
                 );
-                assert_eq!(player.board().borrow_mut().outside(i).pieces.len(), 0);
+                assert_eq!(board.outside(i).pieces.len(), 0);
             }
-            player.move_piece(piece_id, 1);
+            player.move_piece(&mut board, piece_id, 1).unwrap();
             let vec = (67..=72).chain((67..72).rev()).collect::<Vec<_>>();
             for i in 67..77 {
-                let oldpos = player.piece(piece_id).borrow().position();
+                let oldpos = player.piece(&board, piece_id).position();
                 if (i + 1) % 72 == 0 {
                     continue;
                 }
-                player.update_piece(piece_id, oldpos, i + 1);
+                player
+                    .update_piece(&mut board, piece_id, oldpos, i + 1)
+                    .unwrap();
                 assert_eq!(
-                    player.piece(piece_id).borrow().position(),
+                    player.piece(&board, piece_id).position(),
                     vec[i as usize - 66]
                 );
 
                 assert_eq!(
-                    player
-                        .board()
-                        .borrow_mut()
-                        .inside(vec[i as usize - 66])
-                        .player_id,
+                    board.inside(vec[i as usize - 66]).player_id,
                     Some(board::PlayerID::Player3)
                 );
             }
         }
-        assert_eq!(player.board().borrow_mut().inside(67).pieces.len(), 4);
+        assert_eq!(board.inside(67).pieces.len(), 4);
     }
 
     #[test]
     fn move_all_pieces_test_2() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         for piece_id in 0..4 {
-            player.free_piece(piece_id);
+            player.free_piece(&mut board, piece_id).unwrap();
             for i in 39..89 {
-                player.move_piece(piece_id, 1);
-                assert_eq!(player.piece(piece_id).borrow().position(), (i + 1) % 52);
-                assert_eq!(player.board().borrow_mut().outside(i + 1).pieces.len(), 1);
+                player.move_piece(&mut board, piece_id, 1).unwrap();
+                assert_eq!(player.piece(&board, piece_id).position(), (i + 1) % 52);
+                assert_eq!(board.outside(i + 1).pieces.len(), 1);
                 assert_eq!(
-                    player.board().borrow_mut().outside(i + 1).player_id,
+                    board.outside(i + 1).player_id,
                     Some(board::PlayerID::Player3)
                 );
-                assert_eq!(player.board().borrow_mut().outside(i).pieces.len(), 0);
+                assert_eq!(board.outside(i).pieces.len(), 0);
             }
-            player.move_piece(piece_id, 1);
+            player.move_piece(&mut board, piece_id, 1).unwrap();
             let vec = (67..=72).collect::<Vec<_>>();
             for i in 67..71 {
-                player.move_piece(piece_id, 1);
+                player.move_piece(&mut board, piece_id, 1).unwrap();
                 assert_eq!(
-                    player.piece(piece_id).borrow().position(),
+                    player.piece(&board, piece_id).position(),
                     vec[i as usize - 66]
                 );
                 assert_eq!(
-                    player
-                        .board()
-                        .borrow_mut()
-                        .inside(vec[i as usize - 66])
-                        .player_id,
+                    board.inside(vec[i as usize - 66]).player_id,
                     Some(board::PlayerID::Player3)
                 );
             }
-            player.enter_goal(piece_id, 71);
-            assert_eq!(player.piece(piece_id).borrow().position(), 99);
+            player.enter_goal(&mut board, piece_id, 71).unwrap();
+            assert_eq!(player.piece(&board, piece_id).position(), 99);
         }
-        assert!(player.is_finished());
+        assert!(player.is_finished(&board));
     }
 
     #[test]
     fn safety_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
         let piece_id = 0;
 
-        player.free_piece(piece_id);
-        player.move_piece(piece_id, 7);
-        player.save_piece(piece_id, 1);
+        player.free_piece(&mut board, piece_id).unwrap();
+        player.move_piece(&mut board, piece_id, 7).unwrap();
+        player.save_piece(&mut board, piece_id, 1).unwrap();
 
-        assert_eq!(player.piece(piece_id).borrow().position(), 47);
+        assert_eq!(player.piece(&board, piece_id).position(), 47);
 
-        player.update_piece(piece_id, 47, 37);
-        player.save_piece(piece_id, 1);
-        assert_eq!(player.piece(piece_id).borrow().position(), 67);
+        player.update_piece(&mut board, piece_id, 47, 37).unwrap();
+        player.save_piece(&mut board, piece_id, 1).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 67);
     }
 
     #[test]
     fn safety_test_2() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         for piece_id in 0..4 {
-            player.free_piece(piece_id);
-            player.move_piece(piece_id, 7);
-            player.save_piece(piece_id, 1);
+            player.free_piece(&mut board, piece_id).unwrap();
+            player.move_piece(&mut board, piece_id, 7).unwrap();
+            player.save_piece(&mut board, piece_id, 1).unwrap();
 
-            assert_eq!(player.piece(piece_id).borrow().position(), 47);
+            assert_eq!(player.piece(&board, piece_id).position(), 47);
 
-            player.update_piece(piece_id, 47, 37);
-            player.save_piece(piece_id, 1);
-            assert_eq!(player.piece(piece_id).borrow().position(), 67);
+            player.update_piece(&mut board, piece_id, 47, 37).unwrap();
+            player.save_piece(&mut board, piece_id, 1).unwrap();
+            assert_eq!(player.piece(&board, piece_id).position(), 67);
         }
     }
 
     #[test]
     fn safety_test_3() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
         for piece_id in 0..4 {
-            player.free_piece(piece_id);
+            player.free_piece(&mut board, piece_id).unwrap();
             for i in 27..=82 {
                 if i == 76 {
                     continue;
                 }
-                let is_globe = player.board().borrow().is_globe(i);
+                let is_globe = board.is_globe(i);
                 if is_globe {
-                    player.save_piece(piece_id, 1);
+                    player.save_piece(&mut board, piece_id, 1).unwrap();
                 } else {
-                    player.move_piece(piece_id, 1);
+                    player.move_piece(&mut board, piece_id, 1).unwrap();
                 }
             }
         }
@@ -909,549 +820,513 @@ mod player_3_move_tests {
 
     #[test]
     fn starjump_to_goal_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let mut other_player = Player::new(OTHER_PLAYER_ID);
-        other_player.setup(board);
 
         let piece_id = 0;
-        player.free_piece(piece_id);
-        other_player.free_piece(piece_id);
+        player.free_piece(&mut board, piece_id).unwrap();
+        other_player.free_piece(&mut board, piece_id).unwrap();
 
-        player.update_outside(piece_id, 39, 31);
-        other_player.update_outside(piece_id, 52, 37);
+        player.update_outside(&mut board, piece_id, 39, 31).unwrap();
+        other_player
+            .update_outside(&mut board, piece_id, 52, 37)
+            .unwrap();
 
-        assert_eq!(player.piece(piece_id).borrow().position(), 31);
+        assert_eq!(player.piece(&board, piece_id).position(), 31);
 
-        player.win_piece(piece_id, 6);
-        assert_eq!(player.piece(piece_id).borrow().position(), 99);
-        assert!(player.piece(piece_id).borrow().is_goal());
-        assert_eq!(player.board().borrow_mut().goal(PLAYER_ID).pieces.len(), 1);
+        player.win_piece(&mut board, piece_id, 6).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 99);
+        assert!(player.piece(&board, piece_id).is_goal());
+        assert_eq!(board.goal(PLAYER_ID).pieces.len(), 1);
 
-        assert!(other_player.piece(piece_id).borrow().is_home());
-        assert_eq!(other_player.piece(piece_id).borrow().position(), -1);
+        assert!(other_player.piece(&board, piece_id).is_home());
+        assert_eq!(other_player.piece(&board, piece_id).position(), -1);
     }
 
     #[test]
     fn starjump_to_goal_test_2() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         for piece_id in 0..4 {
-            player.free_piece(piece_id);
-            player.update_outside(piece_id, 39, 31);
+            player.free_piece(&mut board, piece_id).unwrap();
+            player.update_outside(&mut board, piece_id, 39, 31).unwrap();
 
-            assert_eq!(player.piece(piece_id).borrow().position(), 31);
+            assert_eq!(player.piece(&board, piece_id).position(), 31);
 
-            player.win_piece(piece_id, 6);
-            assert_eq!(player.piece(piece_id).borrow().position(), 99);
-            assert!(player.piece(piece_id).borrow().is_goal());
-            assert_eq!(
-                player.board().borrow_mut().goal(PLAYER_ID).pieces.len(),
-                piece_id as usize + 1
-            );
+            player.win_piece(&mut board, piece_id, 6).unwrap();
+            assert_eq!(player.piece(&board, piece_id).position(), 99);
+            assert!(player.piece(&board, piece_id).is_goal());
+            assert_eq!(board.goal(PLAYER_ID).pieces.len(), piece_id as usize + 1);
         }
-        assert!(player.is_finished());
+        assert!(player.is_finished(&board));
     }
 
     #[test]
     fn starjump_to_goal_test_3() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         for piece_id in 0..4 {
-            player.free_piece(piece_id);
+            player.free_piece(&mut board, piece_id).unwrap();
             for position in 40..=89 {
                 if position < 89 {
-                    player.move_piece(piece_id, 1);
-                    assert_eq!(player.piece(piece_id).borrow().position(), position % 52);
+                    player.move_piece(&mut board, piece_id, 1).unwrap();
+                    assert_eq!(player.piece(&board, piece_id).position(), position % 52);
                 } else {
-                    player.win_piece(piece_id, 1);
-                    assert_eq!(player.piece(piece_id).borrow().position(), 99);
-                    assert!(player.piece(piece_id).borrow().is_goal());
+                    player.win_piece(&mut board, piece_id, 1).unwrap();
+                    assert_eq!(player.piece(&board, piece_id).position(), 99);
+                    assert!(player.piece(&board, piece_id).is_goal());
                 }
             }
-            assert_eq!(
-                player.board().borrow_mut().goal(PLAYER_ID).pieces.len(),
-                piece_id as usize + 1
-            );
+            assert_eq!(board.goal(PLAYER_ID).pieces.len(), piece_id as usize + 1);
         }
-        assert!(player.is_finished());
+        assert!(player.is_finished(&board));
     }
 
     #[test]
     fn enter_goal_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         let piece_id = 0;
-        player.free_piece(piece_id);
-        player.update_piece(piece_id, 39, 37);
-        player.win_piece(piece_id, 6);
-        assert_eq!(player.piece(piece_id).borrow().position(), 99);
-        assert!(player.piece(piece_id).borrow().is_goal());
-        assert_eq!(player.board().borrow_mut().goal(PLAYER_ID).pieces.len(), 1);
+        player.free_piece(&mut board, piece_id).unwrap();
+        player.update_piece(&mut board, piece_id, 39, 37).unwrap();
+        player.win_piece(&mut board, piece_id, 6).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 99);
+        assert!(player.piece(&board, piece_id).is_goal());
+        assert_eq!(board.goal(PLAYER_ID).pieces.len(), 1);
     }
 
     #[test]
     fn enter_goal_test_2() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         for piece_id in 0..4 {
-            player.free_piece(piece_id);
-            player.update_piece(piece_id, 39, 37);
-            player.win_piece(piece_id, 6);
-            assert_eq!(player.piece(piece_id).borrow().position(), 99);
-            assert!(player.piece(piece_id).borrow().is_goal());
-            assert_eq!(
-                player.board().borrow_mut().goal(PLAYER_ID).pieces.len(),
-                piece_id as usize + 1
-            );
+            player.free_piece(&mut board, piece_id).unwrap();
+            player.update_piece(&mut board, piece_id, 39, 37).unwrap();
+            player.win_piece(&mut board, piece_id, 6).unwrap();
+            assert_eq!(player.piece(&board, piece_id).position(), 99);
+            assert!(player.piece(&board, piece_id).is_goal());
+            assert_eq!(board.goal(PLAYER_ID).pieces.len(), piece_id as usize + 1);
         }
-        assert!(player.is_finished());
+        assert!(player.is_finished(&board));
     }
 
     #[test]
     fn enter_goal_test_3() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
 
         for dice_number in (1..=6).rev() {
             for piece_id in 0..4 {
-                player.free_piece(piece_id);
-                player.update_piece(piece_id, 39, 37);
-                player.move_piece(piece_id, 6 - dice_number);
-                player.win_piece(piece_id, dice_number);
-                assert_eq!(player.piece(piece_id).borrow().position(), 99);
-                assert!(player.piece(piece_id).borrow().is_goal());
-                assert_eq!(
-                    player.board().borrow_mut().goal(PLAYER_ID).pieces.len(),
-                    piece_id as usize + 1
-                );
+                player.free_piece(&mut board, piece_id).unwrap();
+                player.update_piece(&mut board, piece_id, 39, 37).unwrap();
+                player
+                    .move_piece(&mut board, piece_id, 6 - dice_number)
+                    .unwrap();
+                player.win_piece(&mut board, piece_id, dice_number).unwrap();
+                assert_eq!(player.piece(&board, piece_id).position(), 99);
+                assert!(player.piece(&board, piece_id).is_goal());
+                assert_eq!(board.goal(PLAYER_ID).pieces.len(), piece_id as usize + 1);
             }
-            assert!(player.is_finished());
-            board.borrow_mut().reset();
+            assert!(player.is_finished(&board));
+            board.reset();
         }
     }
 
     #[test]
     fn starjump_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
         let piece_id = 0;
 
-        player.free_piece(piece_id);
-        player.starjump_piece(piece_id, 5);
-        assert_eq!(player.piece(piece_id).borrow().position(), 50);
-        assert_eq!(player.board().borrow_mut().outside(50).pieces.len(), 1);
+        player.free_piece(&mut board, piece_id).unwrap();
+        player.starjump_piece(&mut board, piece_id, 5).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 50);
+        assert_eq!(board.outside(50).pieces.len(), 1);
 
-        player.update_piece(piece_id, 50, 49);
-        player.starjump_piece(piece_id, 1);
-        assert_eq!(player.piece(piece_id).borrow().position(), 5);
-        assert_eq!(player.board().borrow_mut().outside(5).pieces.len(), 1);
+        player.update_piece(&mut board, piece_id, 50, 49).unwrap();
+        player.starjump_piece(&mut board, piece_id, 1).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 5);
+        assert_eq!(board.outside(5).pieces.len(), 1);
     }
 
     #[test]
     fn starjump_test_2() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
-        let starvec = vec![44, 50, 5, 11, 18, 24, 31, 37];
+        let starvec = [44, 50, 5, 11, 18, 24, 31, 37];
         let piece_id = 0;
 
-        player.free_piece(piece_id);
-        player.starjump_piece(piece_id, 5);
-        assert_eq!(player.piece(piece_id).borrow().position(), 50);
-        assert_eq!(player.board().borrow_mut().outside(50).pieces.len(), 1);
+        player.free_piece(&mut board, piece_id).unwrap();
+        player.starjump_piece(&mut board, piece_id, 5).unwrap();
+        assert_eq!(player.piece(&board, piece_id).position(), 50);
+        assert_eq!(board.outside(50).pieces.len(), 1);
 
         (1..7).for_each(|i| {
-            player.update_piece(piece_id, starvec[i], starvec[i] - 1);
-            player.starjump_piece(piece_id, 1);
+            player
+                .update_piece(&mut board, piece_id, starvec[i], starvec[i] - 1)
+                .unwrap();
+            player.starjump_piece(&mut board, piece_id, 1).unwrap();
 
-            assert_eq!(player.piece(piece_id).borrow().position(), starvec[i + 1]);
-            assert_eq!(
-                player
-                    .board()
-                    .borrow_mut()
-                    .outside(starvec[i + 1])
-                    .pieces
-                    .len(),
-                1
-            );
+            assert_eq!(player.piece(&board, piece_id).position(), starvec[i + 1]);
+            assert_eq!(board.outside(starvec[i + 1]).pieces.len(), 1);
         });
     }
 
     #[test]
     fn starjump_test_3() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
-        let starvec = vec![44, 50, 5, 11, 18, 24, 31, 37];
+        let starvec = [44, 50, 5, 11, 18, 24, 31, 37];
 
         for piece_id in 0..4 {
-            player.free_piece(piece_id);
-            player.starjump_piece(piece_id, 5);
-            assert_eq!(player.piece(piece_id).borrow().position(), 50);
+            player.free_piece(&mut board, piece_id).unwrap();
+            player.starjump_piece(&mut board, piece_id, 5).unwrap();
+            assert_eq!(player.piece(&board, piece_id).position(), 50);
 
             (1..7).for_each(|i| {
-                player.update_piece(piece_id, starvec[i], starvec[i] - 1);
-                player.starjump_piece(piece_id, 1);
+                player
+                    .update_piece(&mut board, piece_id, starvec[i], starvec[i] - 1)
+                    .unwrap();
+                player.starjump_piece(&mut board, piece_id, 1).unwrap();
 
-                assert_eq!(player.piece(piece_id).borrow().position(), starvec[i + 1]);
+                assert_eq!(player.piece(&board, piece_id).position(), starvec[i + 1]);
             });
 
-            player.update_piece(piece_id, starvec[7], starvec[7] - 1);
-            player.starjump_piece(piece_id, 1);
-            assert_eq!(player.piece(piece_id).borrow().position(), 99);
-            assert!(player.piece(piece_id).borrow().is_goal());
+            player
+                .update_piece(&mut board, piece_id, starvec[7], starvec[7] - 1)
+                .unwrap();
+            player.starjump_piece(&mut board, piece_id, 1).unwrap();
+            assert_eq!(player.piece(&board, piece_id).position(), 99);
+            assert!(player.piece(&board, piece_id).is_goal());
         }
-        assert!(player.is_finished());
+        assert!(player.is_finished(&board));
     }
 
     #[test]
     fn join_piece_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
-        player.free_piece(0);
-        player.free_piece(1);
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
 
-        player.move_piece(0, 1);
-        player.join_piece(1, 1);
-        assert_eq!(player.piece(0).borrow().position(), 40);
-        assert_eq!(player.piece(1).borrow().position(), 40);
-        assert_eq!(player.board().borrow_mut().outside(40).pieces.len(), 2);
+        player.move_piece(&mut board, 0, 1).unwrap();
+        player.join_piece(&mut board, 1, 1).unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 40);
+        assert_eq!(player.piece(&board, 1).position(), 40);
+        assert_eq!(board.outside(40).pieces.len(), 2);
     }
 
     #[test]
     fn join_piece_by_starjump_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
-        player.free_piece(0);
-        player.free_piece(1);
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
 
-        player.starjump_piece(0, 5);
-        player.join_piece(1, 5);
-        assert_eq!(player.piece(0).borrow().position(), 50);
-        assert_eq!(player.piece(1).borrow().position(), 50);
-        assert_eq!(player.board().borrow_mut().outside(50).pieces.len(), 2);
+        player.starjump_piece(&mut board, 0, 5).unwrap();
+        player.join_piece(&mut board, 1, 5).unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 50);
+        assert_eq!(player.piece(&board, 1).position(), 50);
+        assert_eq!(board.outside(50).pieces.len(), 2);
 
-        player.update_outside(0, 50, 5);
-        player.update_outside(1, 50, 3);
+        player.update_outside(&mut board, 0, 50, 5).unwrap();
+        player.update_outside(&mut board, 1, 50, 3).unwrap();
 
-        player.join_piece(1, 2);
+        player.join_piece(&mut board, 1, 2).unwrap();
     }
 
     #[test]
     fn join_piece_by_globe_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
-        player.free_piece(0);
-        player.free_piece(1);
-        player.update_outside(0, 39, 45);
-        player.update_outside(1, 39, 46);
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
+        player.update_outside(&mut board, 0, 39, 45).unwrap();
+        player.update_outside(&mut board, 1, 39, 46).unwrap();
 
-        player.move_piece(0, 2);
-        player.join_piece(1, 1);
+        player.move_piece(&mut board, 0, 2).unwrap();
+        player.join_piece(&mut board, 1, 1).unwrap();
 
-        assert_eq!(player.piece(0).borrow().position(), 47);
-        assert_eq!(player.piece(1).borrow().position(), 47);
+        assert_eq!(player.piece(&board, 0).position(), 47);
+        assert_eq!(player.piece(&board, 1).position(), 47);
     }
 
     #[test]
     fn join_piece_by_invincible_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
-        player.free_piece(0);
-        player.free_piece(1);
-        player.update_outside(0, 39, 50);
-        player.update_outside(1, 39, 51);
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
+        player.update_outside(&mut board, 0, 39, 50).unwrap();
+        player.update_outside(&mut board, 1, 39, 51).unwrap();
 
-        player.move_piece(0, 2);
-        player.join_piece(1, 1);
+        player.move_piece(&mut board, 0, 2).unwrap();
+        player.join_piece(&mut board, 1, 1).unwrap();
 
-        assert_eq!(player.piece(0).borrow().position(), 0);
-        assert_eq!(player.piece(1).borrow().position(), 0);
+        assert_eq!(player.piece(&board, 0).position(), 0);
+        assert_eq!(player.piece(&board, 1).position(), 0);
     }
 
     #[test]
     fn leave_piece_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
-        player.free_piece(0);
-        player.free_piece(1);
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
 
-        player.move_piece(0, 1);
-        player.join_piece(1, 1);
+        player.move_piece(&mut board, 0, 1).unwrap();
+        player.join_piece(&mut board, 1, 1).unwrap();
 
-        player.leave_piece(0, 1);
-        assert_eq!(player.piece(0).borrow().position(), 41);
+        player.leave_piece(&mut board, 0, 1).unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 41);
     }
 
     #[test]
     fn leave_piece_from_globe_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
-        player.free_piece(0);
-        player.free_piece(1);
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
 
-        player.move_piece(0, 8);
-        player.join_piece(1, 8);
+        player.move_piece(&mut board, 0, 8).unwrap();
+        player.join_piece(&mut board, 1, 8).unwrap();
 
-        player.leave_piece(0, 1);
-        assert_eq!(player.piece(0).borrow().position(), 48);
+        player.leave_piece(&mut board, 0, 1).unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 48);
     }
 
     #[test]
     fn leave_piece_from_invincible_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
-        player.free_piece(0);
-        player.free_piece(1);
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
 
-        player.move_piece(0, 7);
-        player.move_piece(0, 6);
-        player.move_piece(1, 7);
-        player.join_piece(1, 6);
+        player.move_piece(&mut board, 0, 7).unwrap();
+        player.move_piece(&mut board, 0, 6).unwrap();
+        player.move_piece(&mut board, 1, 7).unwrap();
+        player.join_piece(&mut board, 1, 6).unwrap();
 
-        player.leave_piece(0, 1);
-        assert_eq!(player.piece(0).borrow().position(), 1);
+        player.leave_piece(&mut board, 0, 1).unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 1);
     }
 
     #[test]
     fn kill_piece_test() {
-        let board: Rc<RefCell<Board>> = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let mut other_player = Player::new(OTHER_PLAYER_ID);
-        other_player.setup(board);
-
-        other_player.free_piece(0);
-        other_player.free_piece(1);
-        other_player.free_piece(2);
-        other_player.free_piece(3);
-
-        other_player.update_outside(0, 0, 39);
-        other_player.update_outside(1, 0, 39);
-        other_player.update_outside(2, 0, 39);
-        other_player.update_outside(3, 0, 40);
-
-        player.kill_piece(0, 6);
-        assert_eq!(other_player.piece(0).borrow().position(), -1);
-        assert_eq!(other_player.piece(1).borrow().position(), -1);
-        assert_eq!(other_player.piece(2).borrow().position(), -1);
-        assert!(other_player.piece(0).borrow().is_home());
-        assert!(other_player.piece(1).borrow().is_home());
-        assert!(other_player.piece(2).borrow().is_home());
-
-        player.kill_piece(0, 1);
-        assert_eq!(other_player.piece(3).borrow().position(), -1);
-        assert!(other_player.piece(3).borrow().is_home());
+
+        other_player.free_piece(&mut board, 0).unwrap();
+        other_player.free_piece(&mut board, 1).unwrap();
+        other_player.free_piece(&mut board, 2).unwrap();
+        other_player.free_piece(&mut board, 3).unwrap();
+
+        other_player.update_outside(&mut board, 0, 0, 39).unwrap();
+        other_player.update_outside(&mut board, 1, 0, 39).unwrap();
+        other_player.update_outside(&mut board, 2, 0, 39).unwrap();
+        other_player.update_outside(&mut board, 3, 0, 40).unwrap();
+
+        player.kill_piece(&mut board, 0, 6).unwrap();
+        assert_eq!(other_player.piece(&board, 0).position(), -1);
+        assert_eq!(other_player.piece(&board, 1).position(), -1);
+        assert_eq!(other_player.piece(&board, 2).position(), -1);
+        assert!(other_player.piece(&board, 0).is_home());
+        assert!(other_player.piece(&board, 1).is_home());
+        assert!(other_player.piece(&board, 2).is_home());
+
+        player.kill_piece(&mut board, 0, 1).unwrap();
+        assert_eq!(other_player.piece(&board, 3).position(), -1);
+        assert!(other_player.piece(&board, 3).is_home());
     }
 
     #[test]
     fn kill_piece_by_starjump_test() {
-        let board: Rc<RefCell<Board>> = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let mut other_player = Player::new(OTHER_PLAYER_ID);
-        other_player.setup(board);
 
-        other_player.free_piece(0);
-        other_player.free_piece(1);
-        player.free_piece(0);
+        other_player.free_piece(&mut board, 0).unwrap();
+        other_player.free_piece(&mut board, 1).unwrap();
+        player.free_piece(&mut board, 0).unwrap();
 
-        other_player.update_outside(0, 0, 44);
-        other_player.update_outside(1, 0, 50);
+        other_player.update_outside(&mut board, 0, 0, 44).unwrap();
+        other_player.update_outside(&mut board, 1, 0, 50).unwrap();
 
-        player.kill_piece(0, 5);
-        assert_eq!(other_player.piece(0).borrow().position(), -1);
-        assert!(other_player.piece(0).borrow().is_home());
-        assert_eq!(other_player.piece(1).borrow().position(), -1);
-        assert!(other_player.piece(1).borrow().is_home());
-        assert_eq!(player.piece(0).borrow().position(), 50);
+        player.kill_piece(&mut board, 0, 5).unwrap();
+        assert_eq!(other_player.piece(&board, 0).position(), -1);
+        assert!(other_player.piece(&board, 0).is_home());
+        assert_eq!(other_player.piece(&board, 1).position(), -1);
+        assert!(other_player.piece(&board, 1).is_home());
+        assert_eq!(player.piece(&board, 0).position(), 50);
     }
 
     #[test]
     fn kill_piece_and_join() {
-        let board: Rc<RefCell<Board>> = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let mut other_player = Player::new(OTHER_PLAYER_ID);
-        other_player.setup(board);
 
-        other_player.free_piece(0);
-        player.free_piece(0);
-        player.free_piece(1);
+        other_player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
 
-        player.starjump_piece(1, 5);
-        other_player.update_outside(0, 0, 44);
+        player.starjump_piece(&mut board, 1, 5).unwrap();
+        other_player.update_outside(&mut board, 0, 0, 44).unwrap();
 
-        player.kill_piece(0, 5);
-        assert_eq!(other_player.piece(0).borrow().position(), -1);
-        assert!(other_player.piece(0).borrow().is_home());
+        player.kill_piece(&mut board, 0, 5).unwrap();
+        assert_eq!(other_player.piece(&board, 0).position(), -1);
+        assert!(other_player.piece(&board, 0).is_home());
 
-        assert_eq!(player.piece(0).borrow().position(), 50);
-        assert_eq!(player.piece(1).borrow().position(), 50);
+        assert_eq!(player.piece(&board, 0).position(), 50);
+        assert_eq!(player.piece(&board, 1).position(), 50);
     }
 
     #[test]
     fn get_playerpiece_heuristics() {
-        let board: Rc<RefCell<Board>> = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         for piece_id in 0..4 {
-            let result = player.get_heuristics(piece_id);
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 57);
 
-            player.free_piece(piece_id);
-            let result = player.get_heuristics(piece_id);
+            player.free_piece(&mut board, piece_id).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 56);
 
-            player.move_piece(piece_id, 1);
-            let result = player.get_heuristics(piece_id);
+            player.move_piece(&mut board, piece_id, 1).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 55);
 
-            player.starjump_piece(piece_id, 4);
-            let result = player.get_heuristics(piece_id);
+            player.starjump_piece(&mut board, piece_id, 4).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 45);
 
-            player.move_piece(piece_id, 6);
-            let result = player.get_heuristics(piece_id);
+            player.move_piece(&mut board, piece_id, 6).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 39);
 
-            player.save_piece(piece_id, 4);
-            let result = player.get_heuristics(piece_id);
+            player.save_piece(&mut board, piece_id, 4).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 35);
 
-            player.starjump_piece(piece_id, 3);
-            let result = player.get_heuristics(piece_id);
+            player.starjump_piece(&mut board, piece_id, 3).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 25);
 
-            player.starjump_piece(piece_id, 6);
-            let result = player.get_heuristics(piece_id);
+            player.starjump_piece(&mut board, piece_id, 6).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 12);
 
-            player.win_piece(piece_id, 6);
-            let result = player.get_heuristics(piece_id);
+            player.win_piece(&mut board, piece_id, 6).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 0);
         }
     }
 
     #[test]
     fn get_playerpiece_heuristics_2() {
-        let board: Rc<RefCell<Board>> = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         for piece_id in 0..4 {
-            let result = player.get_heuristics(piece_id);
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 57);
 
-            player.free_piece(piece_id);
-            let result = player.get_heuristics(piece_id);
+            player.free_piece(&mut board, piece_id).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 56);
 
-            player.move_piece(piece_id, 1);
-            let result = player.get_heuristics(piece_id);
+            player.move_piece(&mut board, piece_id, 1).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 55);
 
-            player.starjump_piece(piece_id, 4);
-            let result = player.get_heuristics(piece_id);
+            player.starjump_piece(&mut board, piece_id, 4).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 45);
 
-            player.move_piece(piece_id, 6);
-            let result = player.get_heuristics(piece_id);
+            player.move_piece(&mut board, piece_id, 6).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 39);
 
-            player.save_piece(piece_id, 4);
-            let result = player.get_heuristics(piece_id);
+            player.save_piece(&mut board, piece_id, 4).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 35);
 
-            player.move_piece(piece_id, 5);
-            let result = player.get_heuristics(piece_id);
+            player.move_piece(&mut board, piece_id, 5).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 30);
 
-            player.starjump_piece(piece_id, 5);
-            let result = player.get_heuristics(piece_id);
+            player.starjump_piece(&mut board, piece_id, 5).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 19);
 
-            player.move_piece(piece_id, 6);
-            let result = player.get_heuristics(piece_id);
+            player.move_piece(&mut board, piece_id, 6).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 13);
 
-            player.starjump_piece(piece_id, 1);
-            let result = player.get_heuristics(piece_id);
+            player.starjump_piece(&mut board, piece_id, 1).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 6);
 
-            player.win_piece(piece_id, 6);
-            let result = player.get_heuristics(piece_id);
+            player.win_piece(&mut board, piece_id, 6).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 0);
         }
     }
 
     #[test]
     fn get_playerpiece_heuristics_3() {
-        let board: Rc<RefCell<Board>> = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         for piece_id in 0..4 {
-            let result = player.get_heuristics(piece_id);
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 57);
 
-            player.free_piece(piece_id);
-            let result = player.get_heuristics(piece_id);
+            player.free_piece(&mut board, piece_id).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 56);
 
-            player.move_piece(piece_id, 6);
-            let result = player.get_heuristics(piece_id);
+            player.move_piece(&mut board, piece_id, 6).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 50);
 
-            player.starjump_piece(piece_id, 5);
-            let result = player.get_heuristics(piece_id);
+            player.starjump_piece(&mut board, piece_id, 5).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 38);
 
-            player.starjump_piece(piece_id, 6);
-            let result = player.get_heuristics(piece_id);
+            player.starjump_piece(&mut board, piece_id, 6).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 25);
 
-            player.starjump_piece(piece_id, 6);
-            let result = player.get_heuristics(piece_id);
+            player.starjump_piece(&mut board, piece_id, 6).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 12);
 
-            player.save_piece(piece_id, 3);
-            let result = player.get_heuristics(piece_id);
+            player.save_piece(&mut board, piece_id, 3).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 9);
 
-            player.save_piece(piece_id, 4);
-            let result = player.get_heuristics(piece_id);
+            player.save_piece(&mut board, piece_id, 4).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 5);
 
-            player.win_piece(piece_id, 5);
-            let result = player.get_heuristics(piece_id);
+            player.win_piece(&mut board, piece_id, 5).unwrap();
+            let result = player.get_heuristics(&board, piece_id).unwrap();
             assert_eq!(result, 0);
         }
     }
@@ -1463,607 +1338,602 @@ mod player_3_valid_choices_tests {
 
     #[test]
     fn valid_choice_nothing_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
         let mut result: Act;
 
-        result = player.valid_choices(0, 1, Act::Nothing);
+        result = player.valid_choices(&board, 0, 1, Act::Nothing);
         assert_eq!(result, Act::Nothing);
 
-        player.free_piece(0);
-        result = player.valid_choices(0, 7, Act::Move);
+        player.free_piece(&mut board, 0).unwrap();
+        result = player.valid_choices(&board, 0, 7, Act::Move);
         assert_eq!(result, Act::Nothing);
 
-        player.enter_goal(0, 39);
-        result = player.valid_choices(0, 7, Act::Move);
+        player.enter_goal(&mut board, 0, 39).unwrap();
+        result = player.valid_choices(&board, 0, 7, Act::Move);
         assert_eq!(result, Act::Nothing);
     }
 
     #[test]
     fn valid_choice_test_1() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
-        let mut result = player.valid_choices(0, 0, Act::Free);
+        let mut result = player.valid_choices(&board, 0, 0, Act::Free);
         assert_eq!(result, Act::Nothing);
-        assert!(player.piece(0).borrow().is_home());
+        assert!(player.piece(&board, 0).is_home());
 
-        result = player.valid_choices(0, 5, Act::Free);
+        result = player.valid_choices(&board, 0, 5, Act::Free);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 6, Act::Move);
+        result = player.valid_choices(&board, 0, 6, Act::Move);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 6, Act::Free);
+        result = player.valid_choices(&board, 0, 6, Act::Free);
         assert_eq!(result, Act::Free);
 
-        player.free_piece(0);
-        assert!(player.piece(0).borrow().is_free());
+        player.free_piece(&mut board, 0).unwrap();
+        assert!(player.piece(&board, 0).is_free());
 
-        result = player.valid_choices(0, 6, Act::Free);
+        result = player.valid_choices(&board, 0, 6, Act::Free);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 1, Act::Move);
+        result = player.valid_choices(&board, 0, 1, Act::Move);
         assert_eq!(result, Act::Move);
 
-        player.move_piece(0, 1);
-        result = player.valid_choices(0, 4, Act::Move);
+        player.move_piece(&mut board, 0, 1).unwrap();
+        result = player.valid_choices(&board, 0, 4, Act::Move);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 4, Act::Starjump);
+        result = player.valid_choices(&board, 0, 4, Act::Starjump);
         assert_eq!(result, Act::Starjump);
 
-        player.starjump_piece(0, 4);
+        player.starjump_piece(&mut board, 0, 4).unwrap();
 
-        result = player.valid_choices(0, 2, Act::Move);
+        result = player.valid_choices(&board, 0, 2, Act::Move);
         assert_eq!(result, Act::Move);
 
-        player.move_piece(0, 2);
-        result = player.valid_choices(0, 6, Act::Move);
+        player.move_piece(&mut board, 0, 2).unwrap();
+        result = player.valid_choices(&board, 0, 6, Act::Move);
         assert_eq!(result, Act::Move);
 
-        player.move_piece(0, 6);
-        result = player.valid_choices(0, 2, Act::Move);
+        player.move_piece(&mut board, 0, 6).unwrap();
+        result = player.valid_choices(&board, 0, 2, Act::Move);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 2, Act::Starjump);
+        result = player.valid_choices(&board, 0, 2, Act::Starjump);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 2, Act::Move);
+        result = player.valid_choices(&board, 0, 2, Act::Move);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 2, Act::Safe);
+        result = player.valid_choices(&board, 0, 2, Act::Safe);
         assert_eq!(result, Act::Safe);
-        player.save_piece(0, 2);
+        player.save_piece(&mut board, 0, 2).unwrap();
 
-        result = player.valid_choices(0, 2, Act::Kill);
+        result = player.valid_choices(&board, 0, 2, Act::Kill);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 2, Act::Die);
+        result = player.valid_choices(&board, 0, 2, Act::Die);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 2, Act::Leave);
+        result = player.valid_choices(&board, 0, 2, Act::Leave);
         assert_eq!(result, Act::Nothing);
 
-        player.starjump_piece(0, 3);
-        player.starjump_piece(0, 6);
+        player.starjump_piece(&mut board, 0, 3).unwrap();
+        player.starjump_piece(&mut board, 0, 6).unwrap();
 
-        result = player.valid_choices(0, 6, Act::Move);
+        result = player.valid_choices(&board, 0, 6, Act::Move);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 6, Act::Starjump);
+        result = player.valid_choices(&board, 0, 6, Act::Starjump);
         assert_eq!(result, Act::Starjump);
 
-        result = player.valid_choices(0, 6, Act::Goal);
+        result = player.valid_choices(&board, 0, 6, Act::Goal);
         assert_eq!(result, Act::Goal);
 
-        result = player.valid_choices(0, 6, Act::Safe);
+        result = player.valid_choices(&board, 0, 6, Act::Safe);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 3, Act::Safe);
+        result = player.valid_choices(&board, 0, 3, Act::Safe);
         assert_eq!(result, Act::Safe);
 
-        player.save_piece(0, 3);
-        result = player.valid_choices(0, 6, Act::Move);
+        player.save_piece(&mut board, 0, 3).unwrap();
+        result = player.valid_choices(&board, 0, 6, Act::Move);
         assert_eq!(result, Act::Move);
 
-        result = player.valid_choices(0, 6, Act::Safe);
+        result = player.valid_choices(&board, 0, 6, Act::Safe);
         assert_eq!(result, Act::Safe);
 
-        player.move_piece(0, 6);
+        player.move_piece(&mut board, 0, 6).unwrap();
 
-        result = player.valid_choices(0, 1, Act::Move);
+        result = player.valid_choices(&board, 0, 1, Act::Move);
         assert_eq!(result, Act::Move);
 
-        result = player.valid_choices(0, 1, Act::Safe);
+        result = player.valid_choices(&board, 0, 1, Act::Safe);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 6, Act::Move);
+        result = player.valid_choices(&board, 0, 6, Act::Move);
         assert_eq!(result, Act::Move);
 
-        result = player.valid_choices(0, 3, Act::Move);
+        result = player.valid_choices(&board, 0, 3, Act::Move);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 3, Act::Starjump);
+        result = player.valid_choices(&board, 0, 3, Act::Starjump);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 3, Act::Safe);
+        result = player.valid_choices(&board, 0, 3, Act::Safe);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 3, Act::Goal);
+        result = player.valid_choices(&board, 0, 3, Act::Goal);
         assert_eq!(result, Act::Goal);
-        player.win_piece(0, 3);
-        assert!(player.piece(0).borrow().is_goal());
+        player.win_piece(&mut board, 0, 3).unwrap();
+        assert!(player.piece(&board, 0).is_goal());
     }
 
     #[test]
     fn valid_choice_test_2() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
-        let mut result = player.valid_choices(0, 0, Act::Free);
+        let mut result = player.valid_choices(&board, 0, 0, Act::Free);
         assert_eq!(result, Act::Nothing);
 
-        assert!(player.piece(0).borrow().is_home());
-        assert!(player.piece(1).borrow().is_home());
-        assert!(player.piece(2).borrow().is_home());
-        assert!(player.piece(3).borrow().is_home());
+        assert!(player.piece(&board, 0).is_home());
+        assert!(player.piece(&board, 1).is_home());
+        assert!(player.piece(&board, 2).is_home());
+        assert!(player.piece(&board, 3).is_home());
 
         (0..4).for_each(|i| {
-            result = player.valid_choices(i, 6, Act::Free);
+            result = player.valid_choices(&board, i, 6, Act::Free);
             assert_eq!(result, Act::Free);
-            player.free_piece(i);
+            player.free_piece(&mut board, i).unwrap();
         });
 
-        assert!(player.piece(0).borrow().is_free());
-        assert!(player.piece(1).borrow().is_free());
-        assert!(player.piece(2).borrow().is_free());
-        assert!(player.piece(3).borrow().is_free());
+        assert!(player.piece(&board, 0).is_free());
+        assert!(player.piece(&board, 1).is_free());
+        assert!(player.piece(&board, 2).is_free());
+        assert!(player.piece(&board, 3).is_free());
 
-        result = player.valid_choices(4, 6, Act::Free);
+        result = player.valid_choices(&board, 4, 6, Act::Free);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 1, Act::Move);
+        result = player.valid_choices(&board, 0, 1, Act::Move);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 1, Act::Leave);
+        result = player.valid_choices(&board, 0, 1, Act::Leave);
         assert_eq!(result, Act::Leave);
-        player.leave_piece(0, 1);
+        player.leave_piece(&mut board, 0, 1).unwrap();
 
-        result = player.valid_choices(1, 5, Act::Leave);
+        result = player.valid_choices(&board, 1, 5, Act::Leave);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(1, 5, Act::Starjump);
+        result = player.valid_choices(&board, 1, 5, Act::Starjump);
         assert_eq!(result, Act::Starjump);
-        player.starjump_piece(1, 5);
+        player.starjump_piece(&mut board, 1, 5).unwrap();
 
-        result = player.valid_choices(2, 5, Act::Leave);
+        result = player.valid_choices(&board, 2, 5, Act::Leave);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(2, 5, Act::Move);
+        result = player.valid_choices(&board, 2, 5, Act::Move);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(2, 5, Act::Starjump);
+        result = player.valid_choices(&board, 2, 5, Act::Starjump);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(2, 5, Act::Join);
+        result = player.valid_choices(&board, 2, 5, Act::Join);
         assert_eq!(result, Act::Join);
-        player.join_piece(2, 5);
+        player.join_piece(&mut board, 2, 5).unwrap();
 
-        result = player.valid_choices(3, 5, Act::Leave);
+        result = player.valid_choices(&board, 3, 5, Act::Leave);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(3, 6, Act::Move);
+        result = player.valid_choices(&board, 3, 6, Act::Move);
         assert_eq!(result, Act::Move);
-        player.move_piece(3, 6);
+        player.move_piece(&mut board, 3, 6).unwrap();
 
-        result = player.valid_choices(0, 5, Act::Join);
+        result = player.valid_choices(&board, 0, 5, Act::Join);
         assert_eq!(result, Act::Join);
 
-        player.join_piece(0, 5);
-        result = player.valid_choices(0, 2, Act::Move);
+        player.join_piece(&mut board, 0, 5).unwrap();
+        result = player.valid_choices(&board, 0, 2, Act::Move);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 2, Act::Leave);
+        result = player.valid_choices(&board, 0, 2, Act::Leave);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 2, Act::Safe);
+        result = player.valid_choices(&board, 0, 2, Act::Safe);
         assert_eq!(result, Act::Safe);
 
-        player.save_piece(0, 2);
+        player.save_piece(&mut board, 0, 2).unwrap();
 
-        result = player.valid_choices(3, 2, Act::Join);
+        result = player.valid_choices(&board, 3, 2, Act::Join);
         assert_eq!(result, Act::Join);
 
-        result = player.valid_choices(3, 2, Act::Safe);
+        result = player.valid_choices(&board, 3, 2, Act::Safe);
         assert_eq!(result, Act::Safe);
 
-        player.join_piece(3, 2);
+        player.join_piece(&mut board, 3, 2).unwrap();
 
-        result = player.valid_choices(0, 3, Act::Leave);
+        result = player.valid_choices(&board, 0, 3, Act::Leave);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 3, Act::Join);
+        result = player.valid_choices(&board, 0, 3, Act::Join);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 3, Act::Starjump);
+        result = player.valid_choices(&board, 0, 3, Act::Starjump);
         assert_eq!(result, Act::Starjump);
-        player.starjump_piece(0, 3);
+        player.starjump_piece(&mut board, 0, 3).unwrap();
 
-        result = player.valid_choices(3, 5, Act::Move);
+        result = player.valid_choices(&board, 3, 5, Act::Move);
         assert_eq!(result, Act::Move);
-        player.move_piece(3, 5);
+        player.move_piece(&mut board, 3, 5).unwrap();
 
-        result = player.valid_choices(1, 2, Act::Move);
+        result = player.valid_choices(&board, 1, 2, Act::Move);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(1, 2, Act::Safe);
+        result = player.valid_choices(&board, 1, 2, Act::Safe);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(1, 2, Act::Leave);
+        result = player.valid_choices(&board, 1, 2, Act::Leave);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(1, 2, Act::Join);
+        result = player.valid_choices(&board, 1, 2, Act::Join);
         assert_eq!(result, Act::Join);
-        player.join_piece(1, 2);
+        player.join_piece(&mut board, 1, 2).unwrap();
 
-        player.update_outside(0, 5, 11);
-        player.update_outside(2, 50, 5);
+        player.update_outside(&mut board, 0, 5, 11).unwrap();
+        player.update_outside(&mut board, 2, 50, 5).unwrap();
 
-        result = player.valid_choices(1, 5, Act::Move);
+        result = player.valid_choices(&board, 1, 5, Act::Move);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(1, 5, Act::Leave);
+        result = player.valid_choices(&board, 1, 5, Act::Leave);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(1, 5, Act::Starjump);
+        result = player.valid_choices(&board, 1, 5, Act::Starjump);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(1, 5, Act::Safe);
+        result = player.valid_choices(&board, 1, 5, Act::Safe);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(1, 5, Act::Join);
+        result = player.valid_choices(&board, 1, 5, Act::Join);
         assert_eq!(result, Act::Join);
-        player.join_piece(1, 5);
+        player.join_piece(&mut board, 1, 5).unwrap();
 
-        result = player.valid_choices(3, 5, Act::Join);
+        result = player.valid_choices(&board, 3, 5, Act::Join);
         assert_eq!(result, Act::Join);
-        player.join_piece(3, 5);
+        player.join_piece(&mut board, 3, 5).unwrap();
 
-        result = player.valid_choices(2, 6, Act::Join);
+        result = player.valid_choices(&board, 2, 6, Act::Join);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(2, 6, Act::Starjump);
+        result = player.valid_choices(&board, 2, 6, Act::Starjump);
         assert_eq!(result, Act::Starjump);
-        player.join_piece(2, 6);
+        player.join_piece(&mut board, 2, 6).unwrap();
 
-        player.update_outside(0, 11, 37);
-        player.update_outside(1, 11, 31);
-        player.update_outside(2, 18, 26);
-        player.update_outside(3, 11, 26);
+        player.update_outside(&mut board, 0, 11, 37).unwrap();
+        player.update_outside(&mut board, 1, 11, 31).unwrap();
+        player.update_outside(&mut board, 2, 18, 26).unwrap();
+        player.update_outside(&mut board, 3, 11, 26).unwrap();
 
-        result = player.valid_choices(3, 5, Act::Move);
+        result = player.valid_choices(&board, 3, 5, Act::Move);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(3, 5, Act::Starjump);
+        result = player.valid_choices(&board, 3, 5, Act::Starjump);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(3, 5, Act::Safe);
+        result = player.valid_choices(&board, 3, 5, Act::Safe);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(3, 5, Act::Leave);
+        result = player.valid_choices(&board, 3, 5, Act::Leave);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(3, 5, Act::Join);
+        result = player.valid_choices(&board, 3, 5, Act::Join);
         assert_eq!(result, Act::Join);
 
-        result = player.valid_choices(1, 6, Act::Join);
+        result = player.valid_choices(&board, 1, 6, Act::Join);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(1, 6, Act::Starjump);
+        result = player.valid_choices(&board, 1, 6, Act::Starjump);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(1, 6, Act::Goal);
+        result = player.valid_choices(&board, 1, 6, Act::Goal);
         assert_eq!(result, Act::Goal);
-        player.win_piece(1, 6);
+        player.win_piece(&mut board, 1, 6).unwrap();
 
-        player.move_piece(3, 6);
-        result = player.valid_choices(3, 2, Act::Safe);
+        player.move_piece(&mut board, 3, 6).unwrap();
+        result = player.valid_choices(&board, 3, 2, Act::Safe);
         assert_eq!(result, Act::Safe);
-        player.save_piece(3, 2);
-        player.move_piece(2, 6);
+        player.save_piece(&mut board, 3, 2).unwrap();
+        player.move_piece(&mut board, 2, 6).unwrap();
 
-        result = player.valid_choices(2, 2, Act::Join);
+        result = player.valid_choices(&board, 2, 2, Act::Join);
         assert_eq!(result, Act::Join);
 
-        result = player.valid_choices(2, 2, Act::Safe);
+        result = player.valid_choices(&board, 2, 2, Act::Safe);
         assert_eq!(result, Act::Safe);
-        player.save_piece(2, 2);
+        player.save_piece(&mut board, 2, 2).unwrap();
 
-        player.move_piece(0, 1);
+        player.move_piece(&mut board, 0, 1).unwrap();
 
-        result = player.valid_choices(2, 4, Act::Join);
+        result = player.valid_choices(&board, 2, 4, Act::Join);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(3, 4, Act::Safe);
+        result = player.valid_choices(&board, 3, 4, Act::Safe);
         assert_eq!(result, Act::Safe);
 
-        result = player.valid_choices(2, 4, Act::Move);
+        result = player.valid_choices(&board, 2, 4, Act::Move);
         assert_eq!(result, Act::Move);
-        player.save_piece(3, 4);
-        player.move_piece(2, 4);
+        player.save_piece(&mut board, 3, 4).unwrap();
+        player.move_piece(&mut board, 2, 4).unwrap();
 
-        result = player.valid_choices(2, 4, Act::Safe);
+        result = player.valid_choices(&board, 2, 4, Act::Safe);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(2, 4, Act::Leave);
+        result = player.valid_choices(&board, 2, 4, Act::Leave);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(2, 4, Act::Move);
+        result = player.valid_choices(&board, 2, 4, Act::Move);
         assert_eq!(result, Act::Move);
-        player.move_piece(2, 4);
+        player.move_piece(&mut board, 2, 4).unwrap();
 
-        result = player.valid_choices(0, 6, Act::Join);
+        result = player.valid_choices(&board, 0, 6, Act::Join);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 6, Act::Leave);
+        result = player.valid_choices(&board, 0, 6, Act::Leave);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 6, Act::Move);
+        result = player.valid_choices(&board, 0, 6, Act::Move);
         assert_eq!(result, Act::Move);
-        player.move_piece(0, 6);
+        player.move_piece(&mut board, 0, 6).unwrap();
 
-        result = player.valid_choices(3, 5, Act::Move);
+        result = player.valid_choices(&board, 3, 5, Act::Move);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(3, 5, Act::Goal);
+        result = player.valid_choices(&board, 3, 5, Act::Goal);
         assert_eq!(result, Act::Goal);
-        player.win_piece(3, 5);
+        player.win_piece(&mut board, 3, 5).unwrap();
 
-        result = player.valid_choices(0, 1, Act::Leave);
+        result = player.valid_choices(&board, 0, 1, Act::Leave);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 1, Act::Goal);
+        result = player.valid_choices(&board, 0, 1, Act::Goal);
         assert_eq!(result, Act::Goal);
-        player.win_piece(2, 1);
-        player.win_piece(0, 1);
+        player.win_piece(&mut board, 2, 1).unwrap();
+        player.win_piece(&mut board, 0, 1).unwrap();
 
-        assert!(player.is_finished());
+        assert!(player.is_finished(&board));
     }
 
     #[test]
     fn valid_choice_test_3() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let mut other_player = Player::new(OTHER_PLAYER_ID);
-        other_player.setup(board);
 
         let mut result: Act;
 
-        other_player.free_piece(0);
-        other_player.free_piece(1);
-        other_player.free_piece(2);
-        other_player.free_piece(3);
+        other_player.free_piece(&mut board, 0).unwrap();
+        other_player.free_piece(&mut board, 1).unwrap();
+        other_player.free_piece(&mut board, 2).unwrap();
+        other_player.free_piece(&mut board, 3).unwrap();
 
-        result = player.valid_choices(0, 6, Act::Free);
+        result = player.valid_choices(&board, 0, 6, Act::Free);
         assert_eq!(result, Act::Free);
 
-        other_player.update_piece(0, 0, 39);
+        other_player.update_piece(&mut board, 0, 0, 39).unwrap();
 
-        result = player.valid_choices(0, 6, Act::Free);
+        result = player.valid_choices(&board, 0, 6, Act::Free);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 6, Act::Kill);
+        result = player.valid_choices(&board, 0, 6, Act::Kill);
         assert_eq!(result, Act::Kill);
 
-        other_player.update_piece(1, 0, 39);
+        other_player.update_piece(&mut board, 1, 0, 39).unwrap();
 
-        result = player.valid_choices(0, 6, Act::Die);
+        result = player.valid_choices(&board, 0, 6, Act::Die);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 6, Act::Kill);
+        result = player.valid_choices(&board, 0, 6, Act::Kill);
         assert_eq!(result, Act::Kill);
 
-        other_player.move_piece(0, 1);
-        other_player.move_piece(1, 1);
+        other_player.move_piece(&mut board, 0, 1).unwrap();
+        other_player.move_piece(&mut board, 1, 1).unwrap();
 
-        player.free_piece(0);
-        player.free_piece(1);
-        player.free_piece(2);
+        player.free_piece(&mut board, 0).unwrap();
+        player.free_piece(&mut board, 1).unwrap();
+        player.free_piece(&mut board, 2).unwrap();
 
-        result = player.valid_choices(0, 1, Act::Move);
+        result = player.valid_choices(&board, 0, 1, Act::Move);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 1, Act::Kill);
+        result = player.valid_choices(&board, 0, 1, Act::Kill);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 1, Act::Leave);
+        result = player.valid_choices(&board, 0, 1, Act::Leave);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 1, Act::Die);
+        result = player.valid_choices(&board, 0, 1, Act::Die);
         assert_eq!(result, Act::Die);
 
-        other_player.move_piece(0, 1);
+        other_player.move_piece(&mut board, 0, 1).unwrap();
 
-        result = player.valid_choices(0, 1, Act::Move);
+        result = player.valid_choices(&board, 0, 1, Act::Move);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 1, Act::Kill);
+        result = player.valid_choices(&board, 0, 1, Act::Kill);
         assert_eq!(result, Act::Kill);
 
-        result = player.valid_choices(0, 1, Act::Leave);
+        result = player.valid_choices(&board, 0, 1, Act::Leave);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 1, Act::Die);
+        result = player.valid_choices(&board, 0, 1, Act::Die);
         assert_eq!(result, Act::Nothing);
 
-        other_player.move_piece(0, 3);
+        other_player.move_piece(&mut board, 0, 3).unwrap();
 
-        result = player.valid_choices(0, 5, Act::Starjump);
+        result = player.valid_choices(&board, 0, 5, Act::Starjump);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 5, Act::Die);
+        result = player.valid_choices(&board, 0, 5, Act::Die);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 5, Act::Kill);
+        result = player.valid_choices(&board, 0, 5, Act::Kill);
         assert_eq!(result, Act::Kill);
 
-        player.update_outside(1, 39, 50);
+        player.update_outside(&mut board, 1, 39, 50).unwrap();
 
-        result = player.valid_choices(0, 5, Act::Die);
+        result = player.valid_choices(&board, 0, 5, Act::Die);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 5, Act::Join);
+        result = player.valid_choices(&board, 0, 5, Act::Join);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 5, Act::Kill);
+        result = player.valid_choices(&board, 0, 5, Act::Kill);
         assert_eq!(result, Act::Kill);
 
-        other_player.update_piece(1, 40, 44);
+        other_player.update_piece(&mut board, 1, 40, 44).unwrap();
 
-        result = player.valid_choices(0, 5, Act::Join);
+        result = player.valid_choices(&board, 0, 5, Act::Join);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 5, Act::Kill);
+        result = player.valid_choices(&board, 0, 5, Act::Kill);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 5, Act::Die);
+        result = player.valid_choices(&board, 0, 5, Act::Die);
         assert_eq!(result, Act::Die);
 
-        player.move_piece(1, 1);
-        other_player.update_outside(2, 0, 50);
+        player.move_piece(&mut board, 1, 1).unwrap();
+        other_player.update_outside(&mut board, 2, 0, 50).unwrap();
 
-        result = player.valid_choices(0, 5, Act::Starjump);
+        result = player.valid_choices(&board, 0, 5, Act::Starjump);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 5, Act::Kill);
+        result = player.valid_choices(&board, 0, 5, Act::Kill);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 5, Act::Die);
+        result = player.valid_choices(&board, 0, 5, Act::Die);
         assert_eq!(result, Act::Die);
 
-        other_player.update_outside(1, 44, 50);
+        other_player.update_outside(&mut board, 1, 44, 50).unwrap();
 
-        result = player.valid_choices(0, 5, Act::Starjump);
+        result = player.valid_choices(&board, 0, 5, Act::Starjump);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 5, Act::Kill);
+        result = player.valid_choices(&board, 0, 5, Act::Kill);
         assert_eq!(result, Act::Kill);
 
-        player.kill_piece(0, 5);
-        assert!(player.piece(0).borrow().is_home());
-        assert_eq!(player.piece(0).borrow().position(), -1);
-        assert!(other_player.piece(0).borrow().is_home());
-        assert_eq!(other_player.piece(0).borrow().position(), -1);
+        player.kill_piece(&mut board, 0, 5).unwrap();
+        assert!(player.piece(&board, 0).is_home());
+        assert_eq!(player.piece(&board, 0).position(), -1);
+        assert!(other_player.piece(&board, 0).is_home());
+        assert_eq!(other_player.piece(&board, 0).position(), -1);
 
-        player.free_piece(0);
-        other_player.free_piece(0);
-        other_player.update_outside(0, 0, 44);
+        player.free_piece(&mut board, 0).unwrap();
+        other_player.free_piece(&mut board, 0).unwrap();
+        other_player.update_outside(&mut board, 0, 0, 44).unwrap();
 
-        result = player.valid_choices(0, 5, Act::Die);
+        result = player.valid_choices(&board, 0, 5, Act::Die);
         assert_eq!(result, Act::Die);
 
-        player.die_piece(0, 5);
-        assert!(player.piece(0).borrow().is_home());
-        assert_eq!(player.piece(0).borrow().position(), -1);
-        assert!(other_player.piece(0).borrow().is_home());
-        assert_eq!(other_player.piece(0).borrow().position(), -1);
+        player.die_piece(&mut board, 0, 5).unwrap();
+        assert!(player.piece(&board, 0).is_home());
+        assert_eq!(player.piece(&board, 0).position(), -1);
+        assert!(other_player.piece(&board, 0).is_home());
+        assert_eq!(other_player.piece(&board, 0).position(), -1);
 
-        player.free_piece(0);
-        other_player.free_piece(0);
-        other_player.update_outside(0, 0, 44);
+        player.free_piece(&mut board, 0).unwrap();
+        other_player.free_piece(&mut board, 0).unwrap();
+        other_player.update_outside(&mut board, 0, 0, 44).unwrap();
 
-        player.leave_piece(0, 6);
-        other_player.enter_globe(0, 44, 47);
+        player.leave_piece(&mut board, 0, 6).unwrap();
+        other_player.enter_globe(&mut board, 0, 44, 47).unwrap();
 
-        result = player.valid_choices(0, 2, Act::Safe);
+        result = player.valid_choices(&board, 0, 2, Act::Safe);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 2, Act::Kill);
+        result = player.valid_choices(&board, 0, 2, Act::Kill);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 2, Act::Die);
+        result = player.valid_choices(&board, 0, 2, Act::Die);
         assert_eq!(result, Act::Die);
 
-        other_player.move_piece(0, 1);
+        other_player.move_piece(&mut board, 0, 1).unwrap();
 
-        player.save_piece(0, 2);
-        player.enter_globe(2, 39, 47);
-        player.move_piece(1, 2);
+        player.save_piece(&mut board, 0, 2).unwrap();
+        player.enter_globe(&mut board, 2, 39, 47).unwrap();
+        player.move_piece(&mut board, 1, 2).unwrap();
 
-        other_player.update_outside(1, 50, 51);
-        other_player.update_outside(2, 50, 51);
+        other_player.update_outside(&mut board, 1, 50, 51).unwrap();
+        other_player.update_outside(&mut board, 2, 50, 51).unwrap();
 
-        result = player.valid_choices(0, 4, Act::Die);
+        result = player.valid_choices(&board, 0, 4, Act::Die);
         assert_eq!(result, Act::Die);
 
-        result = player.valid_choices(0, 5, Act::Die);
+        result = player.valid_choices(&board, 0, 5, Act::Die);
         assert_eq!(result, Act::Die);
 
-        other_player.update_outside(1, 51, 13);
-        other_player.update_outside(2, 51, 12);
+        other_player.update_outside(&mut board, 1, 51, 13).unwrap();
+        other_player.update_outside(&mut board, 2, 51, 12).unwrap();
 
-        player.update_outside(0, 47, 11);
+        player.update_outside(&mut board, 0, 47, 11).unwrap();
 
-        result = player.valid_choices(0, 2, Act::Kill);
+        result = player.valid_choices(&board, 0, 2, Act::Kill);
         assert_eq!(result, Act::Kill);
 
-        result = player.valid_choices(0, 2, Act::Die);
+        result = player.valid_choices(&board, 0, 2, Act::Die);
         assert_eq!(result, Act::Nothing);
 
-        other_player.move_piece(2, 1);
+        other_player.move_piece(&mut board, 2, 1).unwrap();
 
-        result = player.valid_choices(0, 2, Act::Kill);
+        result = player.valid_choices(&board, 0, 2, Act::Kill);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 2, Act::Die);
+        result = player.valid_choices(&board, 0, 2, Act::Die);
         assert_eq!(result, Act::Die);
 
-        other_player.update_outside(1, 13, 37);
-        player.update_outside(0, 11, 30);
+        other_player.update_outside(&mut board, 1, 13, 37).unwrap();
+        player.update_outside(&mut board, 0, 11, 30).unwrap();
 
-        result = player.valid_choices(0, 1, Act::Kill);
+        result = player.valid_choices(&board, 0, 1, Act::Kill);
         assert_eq!(result, Act::Kill);
 
-        result = player.valid_choices(0, 1, Act::Starjump);
+        result = player.valid_choices(&board, 0, 1, Act::Starjump);
         assert_eq!(result, Act::Nothing);
 
-        player.update_outside(1, 1, 32);
+        player.update_outside(&mut board, 1, 1, 32).unwrap();
 
-        result = player.valid_choices(1, 5, Act::Starjump);
+        result = player.valid_choices(&board, 1, 5, Act::Starjump);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(1, 5, Act::Goal);
+        result = player.valid_choices(&board, 1, 5, Act::Goal);
         assert_eq!(result, Act::Goal);
 
-        player.win_piece(1, 5);
-        assert_eq!(player.piece(1).borrow().position(), 99);
-        assert!(player.piece(1).borrow().is_goal());
-        assert_eq!(other_player.piece(1).borrow().position(), -1);
-        assert!(other_player.piece(1).borrow().is_home());
+        player.win_piece(&mut board, 1, 5).unwrap();
+        assert_eq!(player.piece(&board, 1).position(), 99);
+        assert!(player.piece(&board, 1).is_goal());
+        assert_eq!(other_player.piece(&board, 1).position(), -1);
+        assert!(other_player.piece(&board, 1).is_home());
 
-        other_player.update_outside(2, 13, 37);
-        player.move_piece(0, 6);
+        other_player.update_outside(&mut board, 2, 13, 37).unwrap();
+        player.move_piece(&mut board, 0, 6).unwrap();
 
-        result = player.valid_choices(0, 1, Act::Starjump);
+        result = player.valid_choices(&board, 0, 1, Act::Starjump);
         assert_eq!(result, Act::Nothing);
 
-        result = player.valid_choices(0, 1, Act::Goal);
+        result = player.valid_choices(&board, 0, 1, Act::Goal);
         assert_eq!(result, Act::Goal);
 
-        result = player.valid_choices(0, 1, Act::Kill);
+        result = player.valid_choices(&board, 0, 1, Act::Kill);
         assert_eq!(result, Act::Kill);
-        player.kill_piece(0, 1);
+        player.kill_piece(&mut board, 0, 1).unwrap();
 
-        assert_eq!(player.piece(0).borrow().position(), 99);
-        assert!(player.piece(0).borrow().is_goal());
+        assert_eq!(player.piece(&board, 0).position(), 99);
+        assert!(player.piece(&board, 0).is_goal());
 
-        assert_eq!(other_player.piece(1).borrow().position(), -1);
-        assert!(other_player.piece(1).borrow().is_home());
+        assert_eq!(other_player.piece(&board, 1).position(), -1);
+        assert!(other_player.piece(&board, 1).is_home());
     }
 }
 
@@ -2086,163 +1956,218 @@ mod player_3_play_test {
 
     #[test]
     fn make_move_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
 
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let mut other_player = Player::new(OTHER_PLAYER_ID);
-        other_player.setup(board);
 
-        other_player.free_piece(0);
+        other_player.free_piece(&mut board, 0).unwrap();
 
         let mut dice_number: i8;
         let mut choice: Act;
 
         dice_number = 6;
-        choice = player.valid_choices(0, dice_number, Act::Free);
-        player.make_move(0, dice_number, choice);
-        assert!(player.piece(0).borrow().is_free());
+        choice = player.valid_choices(&board, 0, dice_number, Act::Free);
+        player
+            .make_move(&mut board, 0, dice_number, choice)
+            .unwrap();
+        assert!(player.piece(&board, 0).is_free());
 
         dice_number = 5;
-        choice = player.valid_choices(1, dice_number, Act::Free);
-        player.make_move(1, dice_number, choice);
-        assert!(!player.piece(1).borrow().is_free());
+        choice = player.valid_choices(&board, 1, dice_number, Act::Free);
+        player
+            .make_move(&mut board, 1, dice_number, choice)
+            .unwrap();
+        assert!(!player.piece(&board, 1).is_free());
 
         dice_number = 1;
-        choice = player.valid_choices(0, dice_number, Act::Move);
-        player.make_move(0, dice_number, choice);
-        assert_eq!(player.piece(0).borrow().position(), 40);
+        choice = player.valid_choices(&board, 0, dice_number, Act::Move);
+        player
+            .make_move(&mut board, 0, dice_number, choice)
+            .unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 40);
 
         dice_number = 4;
-        choice = player.valid_choices(0, dice_number, Act::Move);
-        player.make_move(0, dice_number, choice);
-        assert_eq!(player.piece(0).borrow().position(), 40);
-        choice = player.valid_choices(0, dice_number, Act::Starjump);
-        player.make_move(0, dice_number, choice);
-        assert_eq!(player.piece(0).borrow().position(), 50);
+        choice = player.valid_choices(&board, 0, dice_number, Act::Move);
+        player
+            .make_move(&mut board, 0, dice_number, choice)
+            .unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 40);
+        choice = player.valid_choices(&board, 0, dice_number, Act::Starjump);
+        player
+            .make_move(&mut board, 0, dice_number, choice)
+            .unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 50);
 
         dice_number = 2;
-        choice = player.valid_choices(0, dice_number, Act::Move);
-        player.make_move(0, dice_number, choice);
-        assert_eq!(player.piece(0).borrow().position(), 50);
-
-        choice = player.valid_choices(0, dice_number, Act::Die);
-        player.make_move(0, dice_number, choice);
-        assert!(player.piece(0).borrow().is_home());
-        assert_eq!(player.piece(0).borrow().position(), -1);
+        choice = player.valid_choices(&board, 0, dice_number, Act::Move);
+        player
+            .make_move(&mut board, 0, dice_number, choice)
+            .unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 50);
+
+        choice = player.valid_choices(&board, 0, dice_number, Act::Die);
+        player
+            .make_move(&mut board, 0, dice_number, choice)
+            .unwrap();
+        assert!(player.piece(&board, 0).is_home());
+        assert_eq!(player.piece(&board, 0).position(), -1);
 
         dice_number = 6;
-        choice = player.valid_choices(0, dice_number, Act::Free);
-        player.make_move(0, dice_number, choice);
-        assert!(player.piece(0).borrow().is_free());
+        choice = player.valid_choices(&board, 0, dice_number, Act::Free);
+        player
+            .make_move(&mut board, 0, dice_number, choice)
+            .unwrap();
+        assert!(player.piece(&board, 0).is_free());
 
         dice_number = 6;
-        choice = player.valid_choices(0, dice_number, Act::Move);
-        player.make_move(0, dice_number, choice);
-        assert_eq!(player.piece(0).borrow().position(), 45);
+        choice = player.valid_choices(&board, 0, dice_number, Act::Move);
+        player
+            .make_move(&mut board, 0, dice_number, choice)
+            .unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 45);
 
         dice_number = 2;
-        choice = player.valid_choices(0, dice_number, Act::Safe);
-        player.make_move(0, dice_number, choice);
-        assert_eq!(player.piece(0).borrow().position(), 47);
+        choice = player.valid_choices(&board, 0, dice_number, Act::Safe);
+        player
+            .make_move(&mut board, 0, dice_number, choice)
+            .unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 47);
 
-        other_player.move_piece(0, 1);
+        other_player.move_piece(&mut board, 0, 1).unwrap();
 
         dice_number = 6;
-        choice = player.valid_choices(0, dice_number, Act::Kill);
-        player.make_move(0, dice_number, choice);
-        assert_eq!(player.piece(0).borrow().position(), 1);
-
-        choice = player.valid_choices(1, dice_number, Act::Free);
-        player.make_move(1, dice_number, choice);
-        assert!(player.piece(1).borrow().is_free());
-        choice = player.valid_choices(2, dice_number, Act::Free);
-        player.make_move(2, dice_number, choice);
-        assert!(player.piece(2).borrow().is_free());
-
-        choice = player.valid_choices(1, dice_number, Act::Move);
-        player.make_move(1, dice_number, choice);
-        assert_eq!(player.piece(1).borrow().position(), 39);
-
-        choice = player.valid_choices(1, dice_number, Act::Leave);
-        player.make_move(1, dice_number, choice);
-        assert_eq!(player.piece(1).borrow().position(), 45);
-
-        choice = player.valid_choices(2, dice_number, Act::Move);
-        player.make_move(2, dice_number, choice);
-        assert_eq!(player.piece(2).borrow().position(), 39);
-
-        choice = player.valid_choices(2, dice_number, Act::Join);
-        player.make_move(2, dice_number, choice);
-        assert_eq!(player.piece(2).borrow().position(), 45);
-
-        player.update_outside(0, 1, 36);
-
-        choice = player.valid_choices(0, dice_number, Act::Goal);
-        player.make_move(0, dice_number, choice);
-        assert_eq!(player.piece(0).borrow().position(), 23 + 13);
+        choice = player.valid_choices(&board, 0, dice_number, Act::Kill);
+        player
+            .make_move(&mut board, 0, dice_number, choice)
+            .unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 1);
+
+        choice = player.valid_choices(&board, 1, dice_number, Act::Free);
+        player
+            .make_move(&mut board, 1, dice_number, choice)
+            .unwrap();
+        assert!(player.piece(&board, 1).is_free());
+        choice = player.valid_choices(&board, 2, dice_number, Act::Free);
+        player
+            .make_move(&mut board, 2, dice_number, choice)
+            .unwrap();
+        assert!(player.piece(&board, 2).is_free());
+
+        choice = player.valid_choices(&board, 1, dice_number, Act::Move);
+        player
+            .make_move(&mut board, 1, dice_number, choice)
+            .unwrap();
+        assert_eq!(player.piece(&board, 1).position(), 39);
+
+        choice = player.valid_choices(&board, 1, dice_number, Act::Leave);
+        player
+            .make_move(&mut board, 1, dice_number, choice)
+            .unwrap();
+        assert_eq!(player.piece(&board, 1).position(), 45);
+
+        choice = player.valid_choices(&board, 2, dice_number, Act::Move);
+        player
+            .make_move(&mut board, 2, dice_number, choice)
+            .unwrap();
+        assert_eq!(player.piece(&board, 2).position(), 39);
+
+        choice = player.valid_choices(&board, 2, dice_number, Act::Join);
+        player
+            .make_move(&mut board, 2, dice_number, choice)
+            .unwrap();
+        assert_eq!(player.piece(&board, 2).position(), 45);
+
+        player.update_outside(&mut board, 0, 1, 36).unwrap();
+
+        choice = player.valid_choices(&board, 0, dice_number, Act::Goal);
+        player
+            .make_move(&mut board, 0, dice_number, choice)
+            .unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 23 + 13);
 
         dice_number = 1;
-        choice = player.valid_choices(0, dice_number, Act::Goal);
-        player.make_move(0, dice_number, choice);
-        assert_eq!(player.piece(0).borrow().position(), 99);
-        assert!(player.piece(0).borrow().is_goal());
+        choice = player.valid_choices(&board, 0, dice_number, Act::Goal);
+        player
+            .make_move(&mut board, 0, dice_number, choice)
+            .unwrap();
+        assert_eq!(player.piece(&board, 0).position(), 99);
+        assert!(player.piece(&board, 0).is_goal());
     }
 
     #[test]
     fn generate_action_vector_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         let mut result: Vec<(Act, i8, i8)>;
         let mut dice_number: i8;
 
         dice_number = 6;
-        result = player.generate_action_vector(dice_number, Act::Move);
+        result = player
+            .generate_action_vector(&board, dice_number, Act::Move)
+            .unwrap();
         assert_eq!(result.len(), 0);
-        result = player.generate_action_vector(dice_number, Act::Free);
+        result = player
+            .generate_action_vector(&board, dice_number, Act::Free)
+            .unwrap();
         assert_eq!(result.len(), 4);
         assert_eq!(result.first().unwrap().2, 57);
 
-        player.free_piece(0);
+        player.free_piece(&mut board, 0).unwrap();
 
-        result = player.generate_action_vector(dice_number, Act::Free);
+        result = player
+            .generate_action_vector(&board, dice_number, Act::Free)
+            .unwrap();
         assert_eq!(result.len(), 3);
 
-        result = player.generate_action_vector(dice_number, Act::Move);
+        result = player
+            .generate_action_vector(&board, dice_number, Act::Move)
+            .unwrap();
         assert_eq!(result.len(), 1);
         assert_eq!(result.first().unwrap().2, 56);
 
-        player.free_piece(1);
-        player.free_piece(2);
-        player.free_piece(3);
+        player.free_piece(&mut board, 1).unwrap();
+        player.free_piece(&mut board, 2).unwrap();
+        player.free_piece(&mut board, 3).unwrap();
 
-        result = player.generate_action_vector(dice_number, Act::Free);
+        result = player
+            .generate_action_vector(&board, dice_number, Act::Free)
+            .unwrap();
         assert_eq!(result.len(), 0);
 
-        result = player.generate_action_vector(dice_number, Act::Move);
+        result = player
+            .generate_action_vector(&board, dice_number, Act::Move)
+            .unwrap();
         assert_eq!(result.len(), 0);
 
-        result = player.generate_action_vector(dice_number, Act::Leave);
+        result = player
+            .generate_action_vector(&board, dice_number, Act::Leave)
+            .unwrap();
         assert_eq!(result.len(), 4);
         assert_eq!(result[0].2, 56);
         assert_eq!(result[1].2, 56);
         assert_eq!(result[2].2, 56);
         assert_eq!(result[3].2, 56);
 
-        player.leave_piece(0, dice_number);
+        player.leave_piece(&mut board, 0, dice_number).unwrap();
 
-        result = player.generate_action_vector(dice_number, Act::Leave);
+        result = player
+            .generate_action_vector(&board, dice_number, Act::Leave)
+            .unwrap();
         assert_eq!(result.len(), 0);
 
-        result = player.generate_action_vector(dice_number, Act::Join);
+        result = player
+            .generate_action_vector(&board, dice_number, Act::Join)
+            .unwrap();
         assert_eq!(result.len(), 3);
 
-        player.join_piece(1, dice_number);
+        player.join_piece(&mut board, 1, dice_number).unwrap();
 
         dice_number = 5;
-        result = player.generate_action_vector(dice_number, Act::Starjump);
+        result = player
+            .generate_action_vector(&board, dice_number, Act::Starjump)
+            .unwrap();
         assert_eq!(result.len(), 4);
         assert_eq!(result[0].2, 50);
         assert_eq!(result[1].2, 50);
@@ -2252,51 +2177,64 @@ mod player_3_play_test {
 
     #[test]
     fn select_ordered_piece_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         let mut action_vector: Vec<(Act, i8, i8)>;
         let mut dice_number: i8;
         let mut result: (Act, i8, i8);
 
         dice_number = 6;
-        action_vector = player.generate_action_vector(dice_number, Act::Move);
+        action_vector = player
+            .generate_action_vector(&board, dice_number, Act::Move)
+            .unwrap();
         assert_eq!(action_vector.len(), 0);
 
         result = player.select_ordered_piece(action_vector, Select::Nearest);
         assert_eq!(result.0, Act::Nothing);
         assert_eq!(result.2, 57);
 
-        action_vector = player.generate_action_vector(dice_number, Act::Free);
+        action_vector = player
+            .generate_action_vector(&board, dice_number, Act::Free)
+            .unwrap();
         assert_eq!(action_vector.len(), 4);
         result = player.select_ordered_piece(action_vector, Select::Nearest);
         assert_eq!(result.0, Act::Free);
         assert_eq!(result.2, 57);
 
-        player.free_piece(0);
+        player.free_piece(&mut board, 0).unwrap();
 
-        action_vector = player.generate_action_vector(dice_number, Act::Free);
+        action_vector = player
+            .generate_action_vector(&board, dice_number, Act::Free)
+            .unwrap();
         assert_eq!(action_vector.len(), 3);
 
-        action_vector = player.generate_action_vector(dice_number, Act::Move);
+        action_vector = player
+            .generate_action_vector(&board, dice_number, Act::Move)
+            .unwrap();
         assert_eq!(action_vector.len(), 1);
         assert_eq!(action_vector.first().unwrap().2, 56);
         result = player.select_ordered_piece(action_vector, Select::Nearest);
         assert_eq!(result.0, Act::Move);
         assert_eq!(result.2, 56);
 
-        player.free_piece(1);
-        player.free_piece(2);
-        player.free_piece(3);
+        player.free_piece(&mut board, 1).unwrap();
+        player.free_piece(&mut board, 2).unwrap();
+        player.free_piece(&mut board, 3).unwrap();
 
-        action_vector = player.generate_action_vector(dice_number, Act::Free);
+        action_vector = player
+            .generate_action_vector(&board, dice_number, Act::Free)
+            .unwrap();
         assert_eq!(action_vector.len(), 0);
 
-        action_vector = player.generate_action_vector(dice_number, Act::Move);
+        action_vector = player
+            .generate_action_vector(&board, dice_number, Act::Move)
+            .unwrap();
         assert_eq!(action_vector.len(), 0);
 
-        action_vector = player.generate_action_vector(dice_number, Act::Leave);
+        action_vector = player
+            .generate_action_vector(&board, dice_number, Act::Leave)
+            .unwrap();
         assert_eq!(action_vector.len(), 4);
         assert_eq!(action_vector[0].2, 56);
         assert_eq!(action_vector[1].2, 56);
@@ -2307,18 +2245,24 @@ mod player_3_play_test {
         assert_eq!(result.0, Act::Leave);
         assert_eq!(result.2, 56);
 
-        player.leave_piece(0, dice_number);
+        player.leave_piece(&mut board, 0, dice_number).unwrap();
 
-        action_vector = player.generate_action_vector(dice_number, Act::Leave);
+        action_vector = player
+            .generate_action_vector(&board, dice_number, Act::Leave)
+            .unwrap();
         assert_eq!(action_vector.len(), 0);
 
-        action_vector = player.generate_action_vector(dice_number, Act::Join);
+        action_vector = player
+            .generate_action_vector(&board, dice_number, Act::Join)
+            .unwrap();
         assert_eq!(action_vector.len(), 3);
 
-        player.join_piece(1, dice_number);
+        player.join_piece(&mut board, 1, dice_number).unwrap();
 
         dice_number = 5;
-        action_vector = player.generate_action_vector(dice_number, Act::Starjump);
+        action_vector = player
+            .generate_action_vector(&board, dice_number, Act::Starjump)
+            .unwrap();
         assert_eq!(action_vector.len(), 4);
         assert_eq!(action_vector[0].2, 50);
         assert_eq!(action_vector[1].2, 50);
@@ -2333,10 +2277,12 @@ mod player_3_play_test {
         assert_eq!(result.0, Act::Starjump);
         assert_eq!(result.2, 56);
 
-        player.leave_piece(0, 5);
-        player.leave_piece(0, 2);
+        player.leave_piece(&mut board, 0, 5).unwrap();
+        player.leave_piece(&mut board, 0, 2).unwrap();
 
-        action_vector = player.generate_action_vector(dice_number, Act::Starjump);
+        action_vector = player
+            .generate_action_vector(&board, dice_number, Act::Starjump)
+            .unwrap();
         assert_eq!(action_vector.len(), 4);
         assert_eq!(action_vector[0].2, 43);
         assert_eq!(action_vector[1].2, 50);
@@ -2351,60 +2297,75 @@ mod player_3_play_test {
 
     #[test]
     fn make_ordered_choice() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board);
 
         let mut dice_number: i8;
         let mut result: (Act, i8, i8);
 
         dice_number = 6;
 
-        result = player.make_ordered_choice(dice_number, Act::Move, Select::Nearest);
+        result = player
+            .make_ordered_choice(&board, dice_number, Act::Move, Select::Nearest)
+            .unwrap();
 
         assert_eq!(result.0, Act::Nothing);
         assert_eq!(result.2, 57);
 
-        result = player.make_ordered_choice(dice_number, Act::Free, Select::Nearest);
+        result = player
+            .make_ordered_choice(&board, dice_number, Act::Free, Select::Nearest)
+            .unwrap();
         assert_eq!(result.0, Act::Free);
         assert_eq!(result.2, 57);
 
-        player.free_piece(0);
+        player.free_piece(&mut board, 0).unwrap();
 
-        result = player.make_ordered_choice(dice_number, Act::Move, Select::Nearest);
+        result = player
+            .make_ordered_choice(&board, dice_number, Act::Move, Select::Nearest)
+            .unwrap();
         assert_eq!(result.0, Act::Move);
         assert_eq!(result.2, 56);
 
-        player.free_piece(1);
-        player.free_piece(2);
-        player.free_piece(3);
+        player.free_piece(&mut board, 1).unwrap();
+        player.free_piece(&mut board, 2).unwrap();
+        player.free_piece(&mut board, 3).unwrap();
 
-        result = player.make_ordered_choice(dice_number, Act::Leave, Select::Nearest);
+        result = player
+            .make_ordered_choice(&board, dice_number, Act::Leave, Select::Nearest)
+            .unwrap();
 
         assert_eq!(result.0, Act::Leave);
         assert_eq!(result.2, 56);
 
-        player.leave_piece(0, dice_number);
-        player.join_piece(1, dice_number);
+        player.leave_piece(&mut board, 0, dice_number).unwrap();
+        player.join_piece(&mut board, 1, dice_number).unwrap();
 
         dice_number = 5;
-        result = player.make_ordered_choice(dice_number, Act::Starjump, Select::Nearest);
+        result = player
+            .make_ordered_choice(&board, dice_number, Act::Starjump, Select::Nearest)
+            .unwrap();
         assert_eq!(result.0, Act::Starjump);
         assert_eq!(result.2, 50);
 
-        result = player.make_ordered_choice(dice_number, Act::Starjump, Select::Furthest);
+        result = player
+            .make_ordered_choice(&board, dice_number, Act::Starjump, Select::Furthest)
+            .unwrap();
         assert_eq!(result.0, Act::Starjump);
         assert_eq!(result.2, 56);
 
-        player.leave_piece(0, 5);
-        player.leave_piece(0, 2);
+        player.leave_piece(&mut board, 0, 5).unwrap();
+        player.leave_piece(&mut board, 0, 2).unwrap();
 
-        result = player.make_ordered_choice(dice_number, Act::Starjump, Select::Nearest);
+        result = player
+            .make_ordered_choice(&board, dice_number, Act::Starjump, Select::Nearest)
+            .unwrap();
         assert_eq!(result.0, Act::Starjump);
         assert_eq!(result.1, 0);
         assert_eq!(result.2, 43);
 
-        result = player.make_ordered_choice(dice_number, Act::Starjump, Select::Furthest);
+        result = player
+            .make_ordered_choice(&board, dice_number, Act::Starjump, Select::Furthest)
+            .unwrap();
         assert_eq!(result.0, Act::Starjump);
         assert_eq!(result.1, 2);
         assert_eq!(result.2, 56);
@@ -2413,62 +2374,72 @@ mod player_3_play_test {
     #[test]
     #[ignore]
     fn random_play_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let dice = Dice::default();
         player.my_turn();
         player.get_dice(dice);
 
         for _ in 0..1000 {
-            while !player.is_finished() {
-                play_random(&mut player, ACTIONS);
+            while !player.is_finished(&board) {
+                play_random(&mut player, &mut board, ACTIONS);
             }
-            board.borrow_mut().reset();
+            board.reset();
         }
     }
 
     #[test]
     #[ignore]
     fn ordered_play_test() {
-        let board = Rc::new(RefCell::new(Board::new()));
+        let mut board = Board::new();
         let mut player = Player::new(PLAYER_ID);
-        player.setup(board.clone());
         let dice = Dice::default();
         player.my_turn();
         player.get_dice(dice);
 
         for _ in 0..100 {
-            while !player.is_finished() {
-                play_ordered(&mut player, ACTIONS, Select::Nearest);
+            while !player.is_finished(&board) {
+                play_ordered(&mut player, &mut board, ACTIONS, Select::Nearest);
             }
-            board.borrow_mut().reset();
+            board.reset();
         }
     }
 
-    fn play_ordered(player: &mut Player, actions: [Act; 10], take_nearest_piece: Select) {
-        player.roll_dice();
+    fn play_ordered(
+        player: &mut Player,
+        board: &mut Board,
+        actions: [Act; 10],
+        take_nearest_piece: Select,
+    ) {
+        player.roll_dice().unwrap();
         let dice_number = player.get_dice_number();
-        let movesets =
-            player.generate_vector_of_ordered_actions(actions, dice_number, take_nearest_piece);
+        let movesets = player
+            .generate_vector_of_ordered_actions(board, actions, dice_number, take_nearest_piece)
+            .unwrap();
         player.action = movesets
             .first()
             .copied()
             .unwrap_or((Act::Nothing, player.id(), 57));
         println!("-------------------");
         println!("prior play");
-        player.print_status();
-        player.make_move(player.action.1, dice_number, player.action.0);
+        player.print_status(board);
+        player
+            .make_move(board, player.action.1, dice_number, player.action.0)
+            .unwrap();
         println!("-------------------");
         println!("posterior play");
-        player.print_status();
+        player.print_status(board);
     }
 
-    fn play_random(player: &mut Player, actions: [Act; 10]) {
-        player.roll_dice();
+    fn play_random(player: &mut Player, board: &mut Board, actions: [Act; 10]) {
+        player.roll_dice().unwrap();
         let dice_number = player.get_dice_number();
-        let movesets = player.generate_vector_of_random_actions(actions, dice_number);
+        let movesets = player
+            .generate_vector_of_random_actions(board, actions, dice_number)
+            .unwrap();
         player.action = player.select_random_piece(movesets);
-        player.make_move(player.action.1, dice_number, player.action.0);
+        player
+            .make_move(board, player.action.1, dice_number, player.action.0)
+            .unwrap();
     }
 }
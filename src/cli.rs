@@ -0,0 +1,155 @@
+use clap::{Args, Parser, Subcommand};
+use genetic_algorithm::{CrossoverType, MutationType, SelectionStrategy};
+use iplayers::Playstyle;
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "ludogame",
+    version,
+    about = "Ludo game, AI players and genetic algorithm experiments"
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Evolve genetic algorithm players against a line-up of opponents
+    Evolve(EvolveArgs),
+    /// Run an experiment file, or every .toml and .json experiment in a directory
+    Experiments(ExperimentsArgs),
+    /// Continue a GA run from a checkpoint file
+    Resume(ResumeArgs),
+    /// Draw SVG charts of the results of a finished GA run
+    Plot(PlotArgs),
+    /// Play games between computer players and report the results per seat
+    Simulate(SimulateArgs),
+    /// Rank playstyles by their winrate against a fixed line-up of opponents
+    Tournament(TournamentArgs),
+    /// Play an interactive game
+    Play(PlayArgs),
+    /// Check a recorded game and export it as SVG or HTML
+    Replay(ReplayArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct EvolveArgs {
+    #[arg(long, default_value_t = 100)]
+    pub population: usize,
+    #[arg(long, default_value_t = 0.5)]
+    pub mutation_rate: f64,
+    #[arg(long, default_value_t = 0.5)]
+    pub crossover_rate: f64,
+    #[arg(long, default_value_t = 2)]
+    pub elitism: usize,
+    #[arg(long, default_value_t = 100)]
+    pub generations: usize,
+    /// Games played by every individual per generation
+    #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u16).range(1..))]
+    pub games: u16,
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Worker threads, 0 uses every core
+    #[arg(long, default_value_t = 0)]
+    pub threads: usize,
+    /// Playstyles of the seats next to the evolved player
+    #[arg(long, value_delimiter = ',', default_values = ["random", "fast", "aggressive"])]
+    pub opponents: Vec<Playstyle>,
+    /// Crossover operators picked from at random for every child
+    #[arg(long, value_delimiter = ',', default_values = ["single_point", "uniform", "two_point"])]
+    pub crossover: Vec<CrossoverType>,
+    /// Mutation operators: reset, swap, insertion or inversion
+    #[arg(long, value_delimiter = ',', default_values = ["reset"])]
+    pub mutation: Vec<MutationType>,
+    /// How parents are picked: tournament[:k], roulette, rank or sus
+    #[arg(long, default_value = "tournament:2")]
+    pub selection: SelectionStrategy,
+    /// Experiment name, results are written to <output-dir>/<output>/
+    #[arg(long, default_value = "GA data")]
+    pub output: String,
+    /// Folder the experiment folder is created in
+    #[arg(long, default_value = "./data")]
+    pub output_dir: PathBuf,
+    /// Save a checkpoint to <output-dir>/<output>/<output>_checkpoint.json every N generations
+    #[arg(long, default_value_t = 0)]
+    pub checkpoint_every: usize,
+    /// Draw SVG charts of the results when the run is done
+    #[arg(long)]
+    pub plot: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ExperimentsArgs {
+    #[arg(default_value = "experiments")]
+    pub path: PathBuf,
+    /// Draw SVG charts of the results of every experiment
+    #[arg(long)]
+    pub plot: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ResumeArgs {
+    pub checkpoint: PathBuf,
+    /// Draw SVG charts of the results when the run is done
+    #[arg(long)]
+    pub plot: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct PlotArgs {
+    /// Experiment name, the folder holding its *_winrates.csv and *_params.csv
+    pub name: String,
+    #[arg(long, default_value = "./data")]
+    pub data: PathBuf,
+}
+
+#[derive(Debug, Args)]
+pub struct SimulateArgs {
+    /// Playstyle of every seat
+    #[arg(long, value_delimiter = ',', default_values = ["aggressive", "fast", "safe", "random"])]
+    pub players: Vec<Playstyle>,
+    #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u16).range(1..))]
+    pub games: u16,
+    #[arg(long)]
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, Args)]
+pub struct TournamentArgs {
+    /// Playstyles competing for the ranking, each takes seat 0 in turn
+    #[arg(long, value_delimiter = ',', default_values = ["aggressive", "fast", "safe", "random", "fastaggressive"])]
+    pub candidates: Vec<Playstyle>,
+    #[arg(long, value_delimiter = ',', default_values = ["random", "fast", "aggressive"])]
+    pub opponents: Vec<Playstyle>,
+    #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u16).range(1..))]
+    pub games: u16,
+    #[arg(long)]
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, Args)]
+pub struct PlayArgs {
+    /// Playstyle of every seat
+    #[arg(num_args = 2..=4, default_values = ["human", "aggressive", "fast", "safe"])]
+    pub seats: Vec<Playstyle>,
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Save the game as a record, JSON when the file ends in .json
+    #[arg(long)]
+    pub record: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct ReplayArgs {
+    /// Record written by `play --record`
+    pub record: PathBuf,
+    #[arg(long)]
+    pub svg: Option<PathBuf>,
+    #[arg(long)]
+    pub html: Option<PathBuf>,
+    /// Seconds every ply is shown in the animated SVG
+    #[arg(long, default_value_t = 1.0)]
+    pub delay: f64,
+}
@@ -1,187 +1,244 @@
-use genetic_algorithm::GeneticAlgorithm;
-use std::process::Command;
+mod cli;
+
+use board::{PlayerID, RenderOptions};
+use clap::Parser;
+use cli::{
+    Cli, Command, EvolveArgs, ExperimentsArgs, PlayArgs, ReplayArgs, ResumeArgs, SimulateArgs,
+    TournamentArgs,
+};
+use game::{Game, GameOutcome, GameRecord};
+use genetic_algorithm::{plot_results, Experiment, GeneticAlgorithm};
+use iplayers::{Playstyle, StdinInput};
+use std::path::Path;
 
-
-fn run_ga_control(filename: &str) {
-    let mut ga = GeneticAlgorithm::default();
-    ga.set_population_size(100);
-    ga.set_mutation_rate(0.5);
-    ga.set_crossover_rate(0.5);
-    ga.set_elitism_count(2);
-    ga.set_tournament_size(100);
-    ga.set_total_games(100);
-    ga.set_write_to_csv(true);
-    ga.set_csv_name(filename);
-    ga.run_gentic_algorithm();
+fn main() {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Evolve(args) => evolve(args),
+        Command::Experiments(args) => experiments(args),
+        Command::Resume(args) => resume(args),
+        Command::Plot(args) => plot_data(&args.data, &args.name),
+        Command::Simulate(args) => simulate(args),
+        Command::Tournament(args) => tournament(args),
+        Command::Play(args) => play(args),
+        Command::Replay(args) => replay(args),
+    };
+    if let Err(error) = result {
+        eprintln!("error: {}", error);
+        std::process::exit(1);
+    }
 }
 
-fn run_ga_0(filename: &str) {
-    let mut ga = GeneticAlgorithm::default();
-    ga.set_population_size(10);
-    ga.set_mutation_rate(0.5);
-    ga.set_crossover_rate(0.5);
-    ga.set_elitism_count(2);
-    ga.set_tournament_size(10);
-    ga.set_total_games(10);
-    ga.set_write_to_csv(true);
-    ga.set_csv_name(filename);
-    ga.run_gentic_algorithm();
+fn seat_game(playstyles: &[Playstyle], seed: Option<u64>) -> Result<Game, String> {
+    let player_ids = [
+        PlayerID::Player0,
+        PlayerID::Player1,
+        PlayerID::Player2,
+        PlayerID::Player3,
+    ];
+    if playstyles.len() > player_ids.len() {
+        return Err(format!(
+            "A game has at most 4 seats, got {}",
+            playstyles.len()
+        ));
+    }
+    let mut game =
+        Game::with_players(&player_ids[..playstyles.len()]).map_err(|error| error.to_string())?;
+    for (id, playstyle) in playstyles.iter().enumerate() {
+        game.give_iplayer_a_playstyle(id as i8, *playstyle)
+            .map_err(|error| error.to_string())?;
+    }
+    if let Some(seed) = seed {
+        game.set_seed(seed);
+    }
+    Ok(game)
 }
 
-fn run_ga_1(filename: &str) {
-    let mut ga = GeneticAlgorithm::default();
-    ga.set_population_size(100);
-    ga.set_mutation_rate(0.1);
-    ga.set_crossover_rate(0.5);
-    ga.set_elitism_count(2);
-    ga.set_tournament_size(100);
-    ga.set_total_games(100);
-    ga.set_write_to_csv(true);
-    ga.set_csv_name(filename);
-    ga.run_gentic_algorithm();
+fn computer_players(playstyles: &[Playstyle]) -> Result<(), String> {
+    match playstyles.contains(&Playstyle::Human) {
+        true => Err("Human players can only take part in `play`".to_string()),
+        false => Ok(()),
+    }
 }
 
-fn run_ga_2(filename: &str) {
-    let mut ga = GeneticAlgorithm::default();
-    ga.set_population_size(100);
-    ga.set_mutation_rate(0.5);
-    ga.set_crossover_rate(0.1);
-    ga.set_elitism_count(2);
-    ga.set_tournament_size(100);
-    ga.set_total_games(100);
-    ga.set_write_to_csv(true);
-    ga.set_csv_name(filename);
-    ga.run_gentic_algorithm();
+fn evolve(args: EvolveArgs) -> Result<(), String> {
+    let experiment = Experiment {
+        name: args.output,
+        population_size: args.population,
+        mutation_rate: args.mutation_rate,
+        crossover_rate: args.crossover_rate,
+        elitism_count: args.elitism,
+        generations: args.generations,
+        total_games: args.games,
+        threads: args.threads,
+        seed: args.seed,
+        opponents: args.opponents,
+        crossover: args.crossover,
+        mutation: args.mutation,
+        selection: args.selection,
+        output_dir: args.output_dir.display().to_string(),
+        checkpoint_interval: args.checkpoint_every,
+        ..Experiment::default()
+    };
+    let ga = experiment.run().map_err(|error| error.to_string())?;
+    report(&ga, args.plot)
 }
 
-fn run_ga_3(filename: &str) {
-    let mut ga = GeneticAlgorithm::default();
-    ga.set_population_size(100);
-    ga.set_mutation_rate(1.0);
-    ga.set_crossover_rate(1.0);
-    ga.set_elitism_count(2);
-    ga.set_tournament_size(100);
-    ga.set_total_games(100);
-    ga.set_write_to_csv(true);
-    ga.set_csv_name(filename);
-    ga.run_gentic_algorithm();
+fn experiments(args: ExperimentsArgs) -> Result<(), String> {
+    let experiments = match args.path.is_dir() {
+        true => Experiment::load_dir(&args.path),
+        false => Experiment::from_file(&args.path).map(|experiment| vec![experiment]),
+    }
+    .map_err(|error| error.to_string())?;
+    for experiment in &experiments {
+        println!("Running experiment {}", experiment.name);
+        let ga = experiment.run().map_err(|error| error.to_string())?;
+        report(&ga, args.plot)?;
+    }
+    Ok(())
 }
 
-fn run_ga_4(filename: &str) {
-    let mut ga = GeneticAlgorithm::default();
-    ga.set_population_size(100);
-    ga.set_mutation_rate(0.5);
-    ga.set_crossover_rate(0.5);
-    ga.set_elitism_count(2);
-    ga.set_tournament_size(100);
-    ga.set_total_games(200);
-    ga.set_write_to_csv(true);
-    ga.set_csv_name(filename);
-    ga.run_gentic_algorithm();
+fn resume(args: ResumeArgs) -> Result<(), String> {
+    let ga = GeneticAlgorithm::resume_from(&args.checkpoint).map_err(|error| error.to_string())?;
+    report(&ga, args.plot)
 }
 
-fn run_ga_5(filename: &str) {
-    let mut ga = GeneticAlgorithm::default();
-    ga.set_population_size(100);
-    ga.set_mutation_rate(0.95);
-    ga.set_crossover_rate(0.01);
-    ga.set_elitism_count(2);
-    ga.set_tournament_size(100);
-    ga.set_total_games(100);
-    ga.set_csv_name(filename);
-    ga.run_gentic_algorithm();
+fn report(ga: &GeneticAlgorithm, plot: bool) -> Result<(), String> {
+    if ga.write_to_csv() {
+        println!("Results written to {}/{}/", ga.output_dir(), ga.csv_name());
+        if plot {
+            plot_data(Path::new(ga.output_dir()), ga.csv_name())?;
+        }
+    }
+    Ok(())
 }
 
-fn run_ga_6(filename: &str) {
-    let mut ga = GeneticAlgorithm::default();
-    ga.set_population_size(100);
-    ga.set_mutation_rate(0.01);
-    ga.set_crossover_rate(0.95);
-    ga.set_elitism_count(2);
-    ga.set_tournament_size(100);
-    ga.set_total_games(100);
-    ga.set_csv_name(filename);
-    ga.run_gentic_algorithm();
+fn simulate(args: SimulateArgs) -> Result<(), String> {
+    computer_players(&args.players)?;
+    let mut game = seat_game(&args.players, args.seed)?;
+    game.start_game(args.games)
+        .map_err(|error| error.to_string())?;
+    println!("{} games", args.games);
+    for (id, playstyle) in args.players.iter().enumerate() {
+        let iplayer = game.iplayer(id as i8).map_err(|error| error.to_string())?;
+        iplayer.calculate_winrate(args.games);
+        println!(
+            "Player {} {:?}: winrate {:.1}%, timeouts {}",
+            id,
+            playstyle,
+            iplayer.get_winrate(),
+            iplayer.get_timeouts()
+        );
+    }
+    Ok(())
 }
 
-fn run_ga_7(filename: &str) {
-    let mut ga = GeneticAlgorithm::default();
-    ga.set_population_size(100);
-    ga.set_mutation_rate(0.5);
-    ga.set_crossover_rate(0.5);
-    ga.set_elitism_count(10);
-    ga.set_tournament_size(100);
-    ga.set_total_games(100);
-    ga.set_csv_name(filename);
-    ga.run_gentic_algorithm();
+fn tournament(args: TournamentArgs) -> Result<(), String> {
+    computer_players(&args.candidates)?;
+    computer_players(&args.opponents)?;
+    let mut results = Vec::new();
+    for candidate in &args.candidates {
+        let mut seats = vec![*candidate];
+        seats.extend(&args.opponents);
+        let mut game = seat_game(&seats, args.seed)?;
+        game.start_game(args.games)
+            .map_err(|error| error.to_string())?;
+        let iplayer = game.iplayer(0).map_err(|error| error.to_string())?;
+        iplayer.calculate_winrate(args.games);
+        results.push((*candidate, *iplayer.get_winrate()));
+    }
+    results.sort_by(|a, b| b.1.total_cmp(&a.1));
+    println!(
+        "{} games per candidate against {:?}",
+        args.games, args.opponents
+    );
+    for (rank, (playstyle, winrate)) in results.iter().enumerate() {
+        println!("{}. {:?}: winrate {:.1}%", rank + 1, playstyle, winrate);
+    }
+    Ok(())
 }
 
-
-fn run_ga_8(filename: &str) {
-    let mut ga = GeneticAlgorithm::default();
-    ga.set_population_size(100);
-    ga.set_mutation_rate(0.01);
-    ga.set_crossover_rate(0.95);
-    ga.set_elitism_count(10);
-    ga.set_tournament_size(100);
-    ga.set_total_games(1000);
-    ga.set_csv_name(filename);
-    ga.run_gentic_algorithm();
+fn play(args: PlayArgs) -> Result<(), String> {
+    let mut game = seat_game(&args.seats, args.seed)?;
+    for (id, playstyle) in args.seats.iter().enumerate() {
+        if *playstyle == Playstyle::Human {
+            game.iplayer(id as i8)
+                .map_err(|error| error.to_string())?
+                .set_input(StdinInput);
+        }
+        println!("Player {} plays {:?}", id, playstyle);
+    }
+    game.set_recording(args.record.is_some());
+    game.beginning().map_err(|error| error.to_string())?;
+    match game.run().map_err(|error| error.to_string())? {
+        GameOutcome::Winner { player_id, turns } => {
+            println!("Player {} wins after {} turns", player_id, turns)
+        }
+        GameOutcome::Placements { placements, turns } => println!(
+            "Players finished in the order {:?} after {} turns",
+            placements, turns
+        ),
+        GameOutcome::TurnLimit { leader, turns } => println!(
+            "The turn limit was reached after {} turns, leader: {:?}",
+            turns, leader
+        ),
+        GameOutcome::Stalemate { turns } => println!("The game stalled after {} turns", turns),
+    }
+    if let (Some(path), Some(record)) = (args.record, game.record()) {
+        let text = match path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            true => record.to_json().map_err(|error| error.to_string())?,
+            false => record.to_text(),
+        };
+        std::fs::write(&path, text).map_err(|error| format!("{}: {}", path.display(), error))?;
+        println!("Game recorded to {}", path.display());
+    }
+    Ok(())
 }
 
-fn main() {
-
-    let filename = "GA control test";
-    run_ga_control(filename);
-    plot_data(filename);
-
-    let filename = "GA test 0 - 10pop10gen10game";
-    run_ga_0(filename);
-    plot_data(filename);
-    
-    let filename = "GA test 1 - 0.1mut";
-    run_ga_1(filename);
-    plot_data(filename);
-    
-    let filename = "GA test 2 - 0.1cross";
-    run_ga_2(filename);
-    plot_data(filename);
-
-    let filename = "GA test 3 - 1mut1cross";
-    run_ga_3(filename);
-    plot_data(filename);
-
-    let filename = "GA test 4 - 200game";
-    run_ga_4(filename);
-    plot_data(filename);
-
-    let filename = "GA test 5 - 0.95mut0.01cross";
-    run_ga_5(filename);
-    plot_data(filename);
-
-    let filename = "GA test 6 - 0.01mut0.95cross";
-    run_ga_6(filename);
-    plot_data(filename);
-
-    let filename = "GA test 7 - 10elites";
-    run_ga_7(filename);
-    plot_data(filename);
-
-
-    let filename = "GA test 8 - final";
-    run_ga_8(filename);
-    plot_data(filename);
-
+fn replay(args: ReplayArgs) -> Result<(), String> {
+    let text = std::fs::read_to_string(&args.record)
+        .map_err(|error| format!("{}: {}", args.record.display(), error))?;
+    let record = match args
+        .record
+        .extension()
+        .is_some_and(|extension| extension == "json")
+    {
+        true => GameRecord::from_json(&text),
+        false => GameRecord::from_text(&text),
+    }
+    .map_err(|error| error.to_string())?;
+    Game::replay(&record).map_err(|error| error.to_string())?;
+    let boards = record.boards().map_err(|error| error.to_string())?;
+    if let Some(board) = boards.last() {
+        print!("{}", board.render(RenderOptions::default()));
+    }
+    match record.winner {
+        Some(winner) => println!(
+            "{} plies replayed, player {} won",
+            record.plies.len(),
+            winner
+        ),
+        None => println!("{} plies replayed, no winner", record.plies.len()),
+    }
+    if let Some(path) = args.svg {
+        let svg = record
+            .to_svg(args.delay)
+            .map_err(|error| error.to_string())?;
+        std::fs::write(&path, svg).map_err(|error| format!("{}: {}", path.display(), error))?;
+    }
+    if let Some(path) = args.html {
+        let html = record.to_html().map_err(|error| error.to_string())?;
+        std::fs::write(&path, html).map_err(|error| format!("{}: {}", path.display(), error))?;
+    }
+    Ok(())
 }
 
-fn plot_data(filename: &str) {
-    let output = Command::new("python")
-                         .arg("./src/plot.py")
-                         .arg(filename)
-                         .output()
-                         .expect("Failed to execute command");
-
-    println!("status: {}", output.status);
-    println!("stdout: {}", String::from_utf8_lossy(&output.stdout));
-    println!("stderr: {}", String::from_utf8_lossy(&output.stderr));
-}
\ No newline at end of file
+fn plot_data(output_dir: &Path, name: &str) -> Result<(), String> {
+    for chart in plot_results(output_dir, name).map_err(|error| error.to_string())? {
+        println!("Chart written to {}", chart.display());
+    }
+    Ok(())
+}